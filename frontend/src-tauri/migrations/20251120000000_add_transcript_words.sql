@@ -0,0 +1,7 @@
+-- Migration: Add word-level timing to transcripts
+-- Stores the words of each segment as a JSON array so playback can
+-- highlight the current word and seek to it:
+--   [{"text": "Hello", "start": 125.30, "end": 125.62}, ...]
+-- Times are seconds from recording start, like audio_start_time.
+-- NULL for segments saved before this migration or by providers without word timing.
+ALTER TABLE transcripts ADD COLUMN words TEXT;
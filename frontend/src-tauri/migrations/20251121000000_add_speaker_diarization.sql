@@ -0,0 +1,18 @@
+-- Migration: Add speaker diarization support
+-- 1. speaker column on transcripts: per-meeting speaker ID assigned by the local
+--    diarization stage ("speaker_1", "speaker_2", ...). NULL when diarization
+--    was unavailable or the segment was too short to identify.
+-- 2. meeting_speakers table: user-chosen display names for speaker IDs, so a
+--    rename applies to every segment of that speaker in the meeting (and to
+--    summaries and chat built from the transcript).
+
+ALTER TABLE transcripts ADD COLUMN speaker TEXT;
+
+CREATE TABLE IF NOT EXISTS meeting_speakers (
+    meeting_id TEXT NOT NULL,
+    speaker_id TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, speaker_id),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
@@ -0,0 +1,8 @@
+-- Migration: Add "Me vs. Others" source attribution to transcripts
+-- Set from per-stream energy of the separate mic and system audio streams:
+--   'local'   - the local speaker (microphone)
+--   'remote'  - remote participants (system audio)
+--   'overlap' - both sides talking at once
+--   'unknown' - no clear activity on either stream
+-- NULL for segments saved before this migration.
+ALTER TABLE transcripts ADD COLUMN source TEXT;
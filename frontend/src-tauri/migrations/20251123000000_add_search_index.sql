@@ -0,0 +1,73 @@
+-- Migration: Full-text search index (FTS5) over transcripts, summaries and chat
+-- One row per searchable document:
+--   source_type = 'transcript' -> source_id = transcripts.id (audio_start_time set)
+--   source_type = 'summary'    -> source_id = meeting_id (markdown from summary_processes.result)
+--   source_type = 'chat'       -> source_id = chat_messages.id
+-- unicode61 with remove_diacritics 2 lets "decisao" match "decisão" (Portuguese + English).
+-- The index is kept in sync by the triggers below; application code never writes to it.
+
+CREATE VIRTUAL TABLE IF NOT EXISTS search_index USING fts5(
+    content,
+    meeting_id UNINDEXED,
+    source_type UNINDEXED,
+    source_id UNINDEXED,
+    audio_start_time UNINDEXED,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- Transcripts
+CREATE TRIGGER IF NOT EXISTS search_index_transcripts_ai AFTER INSERT ON transcripts BEGIN
+    INSERT INTO search_index (content, meeting_id, source_type, source_id, audio_start_time)
+    VALUES (new.transcript, new.meeting_id, 'transcript', new.id, new.audio_start_time);
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_transcripts_au
+AFTER UPDATE OF transcript, meeting_id, audio_start_time ON transcripts BEGIN
+    DELETE FROM search_index WHERE source_type = 'transcript' AND source_id = old.id;
+    INSERT INTO search_index (content, meeting_id, source_type, source_id, audio_start_time)
+    VALUES (new.transcript, new.meeting_id, 'transcript', new.id, new.audio_start_time);
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_transcripts_ad AFTER DELETE ON transcripts BEGIN
+    DELETE FROM search_index WHERE source_type = 'transcript' AND source_id = old.id;
+END;
+
+-- Summaries (result is JSON: {"markdown": "..."}; non-JSON or missing markdown is skipped)
+CREATE TRIGGER IF NOT EXISTS search_index_summaries_ai AFTER INSERT ON summary_processes BEGIN
+    INSERT INTO search_index (content, meeting_id, source_type, source_id, audio_start_time)
+    SELECT json_extract(new.result, '$.markdown'), new.meeting_id, 'summary', new.meeting_id, NULL
+    WHERE json_valid(new.result) AND json_extract(new.result, '$.markdown') IS NOT NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_summaries_au AFTER UPDATE OF result ON summary_processes BEGIN
+    DELETE FROM search_index WHERE source_type = 'summary' AND source_id = old.meeting_id;
+    INSERT INTO search_index (content, meeting_id, source_type, source_id, audio_start_time)
+    SELECT json_extract(new.result, '$.markdown'), new.meeting_id, 'summary', new.meeting_id, NULL
+    WHERE json_valid(new.result) AND json_extract(new.result, '$.markdown') IS NOT NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_summaries_ad AFTER DELETE ON summary_processes BEGIN
+    DELETE FROM search_index WHERE source_type = 'summary' AND source_id = old.meeting_id;
+END;
+
+-- Chat messages
+CREATE TRIGGER IF NOT EXISTS search_index_chat_ai AFTER INSERT ON chat_messages BEGIN
+    INSERT INTO search_index (content, meeting_id, source_type, source_id, audio_start_time)
+    VALUES (new.content, new.meeting_id, 'chat', new.id, NULL);
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_chat_ad AFTER DELETE ON chat_messages BEGIN
+    DELETE FROM search_index WHERE source_type = 'chat' AND source_id = old.id;
+END;
+
+-- Backfill existing data
+INSERT INTO search_index (content, meeting_id, source_type, source_id, audio_start_time)
+SELECT transcript, meeting_id, 'transcript', id, audio_start_time FROM transcripts;
+
+INSERT INTO search_index (content, meeting_id, source_type, source_id, audio_start_time)
+SELECT json_extract(result, '$.markdown'), meeting_id, 'summary', meeting_id, NULL
+FROM summary_processes
+WHERE json_valid(result) AND json_extract(result, '$.markdown') IS NOT NULL;
+
+INSERT INTO search_index (content, meeting_id, source_type, source_id, audio_start_time)
+SELECT content, meeting_id, 'chat', id, NULL FROM chat_messages;
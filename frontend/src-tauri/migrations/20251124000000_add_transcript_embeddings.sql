@@ -0,0 +1,19 @@
+-- Migration: Local embedding index for retrieval-augmented meeting chat
+-- The transcript is split into overlapping chunks (summary::processor::chunk_text)
+-- and each chunk is embedded on-device. Rows for a meeting are replaced together
+-- whenever the transcript fingerprint or the embedding model changes.
+
+CREATE TABLE IF NOT EXISTS transcript_embeddings (
+    meeting_id TEXT NOT NULL,
+    chunk_index INTEGER NOT NULL,
+    content TEXT NOT NULL,
+    audio_start_time REAL,
+    audio_end_time REAL,
+    transcript_ids TEXT NOT NULL, -- JSON array of transcripts.id covered by the chunk
+    embedding BLOB NOT NULL,      -- little-endian f32 vector
+    model TEXT NOT NULL,
+    fingerprint TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, chunk_index),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
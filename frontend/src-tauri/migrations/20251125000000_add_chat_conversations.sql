@@ -0,0 +1,26 @@
+-- Create chat_conversations table for library-wide chat (not tied to one meeting)
+CREATE TABLE IF NOT EXISTS chat_conversations (
+    id TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    filters TEXT, -- JSON: {"date_from", "date_to", "title_query", "meeting_ids"}
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- Create conversation_messages table for the messages of each conversation
+CREATE TABLE IF NOT EXISTS conversation_messages (
+    id TEXT PRIMARY KEY,
+    conversation_id TEXT NOT NULL,
+    role TEXT NOT NULL CHECK(role IN ('user', 'assistant')),
+    content TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    metadata TEXT, -- JSON: {"citations": [...]} on assistant messages
+    FOREIGN KEY (conversation_id) REFERENCES chat_conversations(id) ON DELETE CASCADE
+);
+
+-- Create index for ordering messages within a conversation
+CREATE INDEX IF NOT EXISTS idx_conversation_messages_created_at
+    ON conversation_messages(conversation_id, created_at);
+
+-- Create index for listing conversations by recent activity
+CREATE INDEX IF NOT EXISTS idx_chat_conversations_updated_at ON chat_conversations(updated_at);
@@ -0,0 +1,41 @@
+-- Create summary_versions table: one row per summary generation run, so regenerating
+-- with another template or model no longer destroys the previous summary.
+-- summary_processes.result keeps holding the current (possibly user-edited) summary.
+CREATE TABLE IF NOT EXISTS summary_versions (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL,
+    version INTEGER NOT NULL, -- 1, 2, 3... per meeting
+    template_id TEXT,
+    provider TEXT,
+    model TEXT,
+    custom_prompt TEXT,
+    language TEXT,
+    processing_time REAL DEFAULT 0.0, -- seconds
+    chunk_count INTEGER DEFAULT 0,
+    result TEXT NOT NULL, -- JSON, same shape as summary_processes.result
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    UNIQUE (meeting_id, version)
+);
+
+-- Create index for listing the versions of a meeting
+CREATE INDEX IF NOT EXISTS idx_summary_versions_meeting_id ON summary_versions(meeting_id, version);
+
+-- Keep existing summaries as version 1 (template, language and prompt were not recorded)
+INSERT INTO summary_versions (
+    id, meeting_id, version, provider, model, processing_time, chunk_count, result, created_at
+)
+SELECT
+    lower(hex(randomblob(16))),
+    p.meeting_id,
+    1,
+    c.model,
+    c.model_name,
+    p.processing_time,
+    p.chunk_count,
+    p.result,
+    COALESCE(p.end_time, p.updated_at)
+FROM summary_processes p
+JOIN meetings m ON m.id = p.meeting_id
+LEFT JOIN transcript_chunks c ON c.meeting_id = p.meeting_id
+WHERE p.status = 'completed' AND p.result IS NOT NULL;
@@ -0,0 +1,24 @@
+-- Create action_items table: action items extracted from meetings (or added by hand),
+-- tracked across meetings. An item raised again in a later meeting is matched to the
+-- existing row (last_seen_meeting_id is updated) instead of being inserted twice.
+CREATE TABLE IF NOT EXISTS action_items (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL, -- meeting the item was first raised in
+    text TEXT NOT NULL,
+    owner TEXT,
+    due_date TEXT, -- YYYY-MM-DD when the date could be resolved, otherwise as stated
+    source_timestamp REAL, -- seconds from recording start, like audio_start_time
+    status TEXT NOT NULL DEFAULT 'open' CHECK(status IN ('open', 'done', 'cancelled')),
+    last_seen_meeting_id TEXT, -- latest meeting that mentioned the item again
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    completed_at TEXT,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    FOREIGN KEY (last_seen_meeting_id) REFERENCES meetings(id) ON DELETE SET NULL
+);
+
+-- Create index for listing the items of a meeting
+CREATE INDEX IF NOT EXISTS idx_action_items_meeting_id ON action_items(meeting_id);
+
+-- Create index for the cross-meeting open items query
+CREATE INDEX IF NOT EXISTS idx_action_items_status ON action_items(status, due_date);
@@ -0,0 +1,4 @@
+-- Add status to meetings: a recording's meeting row is created when it starts
+-- ('recording') and its transcript written as it is transcribed; stopping the
+-- recording marks it 'completed'. Existing meetings are all complete.
+ALTER TABLE meetings ADD COLUMN status TEXT NOT NULL DEFAULT 'completed';
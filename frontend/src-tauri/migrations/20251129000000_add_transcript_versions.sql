@@ -0,0 +1,21 @@
+-- Create transcript_versions table: alternate transcripts of a meeting, from
+-- re-transcribing its audio with other settings, and the transcripts a
+-- re-transcription or restore replaced. The transcripts table keeps holding
+-- the current transcript.
+CREATE TABLE IF NOT EXISTS transcript_versions (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL,
+    version INTEGER NOT NULL, -- 1, 2, 3... per meeting
+    label TEXT NOT NULL,
+    model TEXT,
+    language TEXT,
+    vad_settings TEXT, -- JSON
+    segment_count INTEGER NOT NULL DEFAULT 0,
+    segments TEXT NOT NULL, -- JSON array of transcript segments
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    UNIQUE (meeting_id, version)
+);
+
+-- Create index for listing the versions of a meeting
+CREATE INDEX IF NOT EXISTS idx_transcript_versions_meeting_id ON transcript_versions(meeting_id, version);
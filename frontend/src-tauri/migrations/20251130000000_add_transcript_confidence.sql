@@ -0,0 +1,5 @@
+-- Migration: Add segment confidence to transcripts
+-- Mean probability of the segment's words (0.0 - 1.0), as scored by the
+-- transcription model. Per-word confidence is kept in the words JSON.
+-- NULL for providers without scores and segments saved before this migration.
+ALTER TABLE transcripts ADD COLUMN confidence REAL;
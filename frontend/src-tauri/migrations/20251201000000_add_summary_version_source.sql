@@ -0,0 +1,4 @@
+-- Migration: Record where a summary version came from
+-- 'generated' for a generation run, 'manual' for the user's edits, kept as a
+-- version when restoring an older one would overwrite them.
+ALTER TABLE summary_versions ADD COLUMN source TEXT NOT NULL DEFAULT 'generated';
@@ -0,0 +1,4 @@
+-- Migration: Drop the chat embedding index
+-- Chat retrieval now ranks transcript chunks with the full-text search index
+-- (search_index), which the transcript triggers already keep up to date.
+DROP TABLE IF EXISTS transcript_embeddings;
@@ -0,0 +1,138 @@
+use crate::action_items::service::ActionItemService;
+use crate::database::models::ActionItem;
+use crate::database::repositories::action_item::{
+    ActionItemUpdate, ActionItemsRepository, NewActionItem,
+};
+use crate::state::AppState;
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+/// Action items raised in a meeting or carried over into it
+#[tauri::command]
+pub async fn api_get_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<ActionItem>, String> {
+    log_info!("api_get_action_items called for meeting_id: {}", meeting_id);
+    let pool = state.db_manager.pool();
+
+    ActionItemsRepository::get_meeting_items(pool, &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get action items for {}: {}", meeting_id, e);
+            format!("Failed to retrieve action items: {}", e)
+        })
+}
+
+/// Open action items across all meetings, optionally for one owner
+#[tauri::command]
+pub async fn api_get_open_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    owner: Option<String>,
+) -> Result<Vec<ActionItem>, String> {
+    log_info!("api_get_open_action_items called (owner: {:?})", owner);
+    let pool = state.db_manager.pool();
+
+    ActionItemsRepository::get_open_items(pool, owner.as_deref())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get open action items: {}", e);
+            format!("Failed to retrieve open action items: {}", e)
+        })
+}
+
+/// Add an action item to a meeting by hand
+#[tauri::command]
+pub async fn api_create_action_item<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    text: String,
+    owner: Option<String>,
+    due_date: Option<String>,
+    source_timestamp: Option<f64>,
+) -> Result<ActionItem, String> {
+    log_info!("api_create_action_item called for meeting_id: {}", meeting_id);
+    let pool = state.db_manager.pool();
+
+    let new_item = NewActionItem {
+        meeting_id: &meeting_id,
+        text: &text,
+        owner: owner.as_deref(),
+        due_date: due_date.as_deref(),
+        source_timestamp,
+        status: "open",
+    };
+
+    ActionItemsRepository::create_item(pool, &new_item)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to create action item for {}: {}", meeting_id, e);
+            format!("Failed to create action item: {}", e)
+        })
+}
+
+/// Edit an action item or change its status ('open', 'done', 'cancelled')
+#[tauri::command]
+pub async fn api_update_action_item<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    item_id: String,
+    update: ActionItemUpdate,
+) -> Result<ActionItem, String> {
+    log_info!("api_update_action_item called for item_id: {}", item_id);
+    let pool = state.db_manager.pool();
+
+    ActionItemsRepository::update_item(pool, &item_id, &update)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to update action item {}: {}", item_id, e);
+            format!("Failed to update action item: {}", e)
+        })?
+        .ok_or_else(|| format!("Action item not found: {}", item_id))
+}
+
+/// Delete an action item
+#[tauri::command]
+pub async fn api_delete_action_item<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    item_id: String,
+) -> Result<bool, String> {
+    log_info!("api_delete_action_item called for item_id: {}", item_id);
+    let pool = state.db_manager.pool();
+
+    ActionItemsRepository::delete_item(pool, &item_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to delete action item {}: {}", item_id, e);
+            format!("Failed to delete action item: {}", e)
+        })
+}
+
+/// Run the action item extraction pass on a meeting again (it also runs after
+/// every summary). Returns the meeting's items afterwards.
+#[tauri::command]
+pub async fn api_extract_action_items<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    model_provider: String,
+    model_name: String,
+) -> Result<Vec<ActionItem>, String> {
+    log_info!(
+        "api_extract_action_items called for meeting_id: {} with provider: {}",
+        meeting_id,
+        model_provider
+    );
+    let pool = state.db_manager.pool().clone();
+
+    ActionItemService::extract_with_settings(&app, &pool, &meeting_id, &model_provider, &model_name)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to extract action items for {}: {}", meeting_id, e);
+            e
+        })
+}
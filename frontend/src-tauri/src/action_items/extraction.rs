@@ -0,0 +1,347 @@
+// action_items/extraction.rs
+//
+// The extraction pass: prompt and JSON schema for asking the LLM for a meeting's
+// action items, parsing its answer, and matching extracted items against items
+// already tracked so that work carried over between meetings is not duplicated.
+
+use crate::api::MeetingTranscript;
+use crate::database::models::ActionItem;
+use crate::utils::format_timestamp;
+use serde::{Deserialize, Deserializer};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+/// Name of the JSON schema / Anthropic tool that carries the extracted items
+pub const EXTRACTION_SCHEMA_NAME: &str = "action_items";
+
+/// Open items from earlier meetings offered to the LLM for matching
+pub const MAX_CANDIDATES: usize = 100;
+
+/// Word overlap above which an extracted item is taken to be an existing one
+const MATCH_THRESHOLD: f64 = 0.6;
+
+/// An action item as returned by the LLM
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ExtractedItem {
+    pub text: String,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// Seconds from recording start; the LLM copies the transcript's "HH:MM:SS"
+    #[serde(default, deserialize_with = "deserialize_timestamp")]
+    pub source_timestamp: Option<f64>,
+    #[serde(default = "default_status")]
+    pub status: String,
+    /// Id of the already tracked item this one continues, if the LLM found one
+    #[serde(default)]
+    pub existing_id: Option<String>,
+}
+
+fn default_status() -> String {
+    "open".to_string()
+}
+
+#[derive(Deserialize)]
+struct ExtractionResponse {
+    action_items: Vec<ExtractedItem>,
+}
+
+/// JSON schema of the extraction answer
+pub fn extraction_schema() -> Value {
+    let nullable_string = |description: &str| {
+        json!({ "type": ["string", "null"], "description": description })
+    };
+
+    json!({
+        "type": "object",
+        "properties": {
+            "action_items": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "text": { "type": "string", "description": "The task, as a short imperative sentence" },
+                        "owner": nullable_string("Person responsible, if stated"),
+                        "due_date": nullable_string("YYYY-MM-DD, resolved against the meeting date; null if none"),
+                        "source_timestamp": nullable_string("HH:MM:SS of the transcript line where the item was raised"),
+                        "status": { "type": "string", "enum": ["open", "done", "cancelled"] },
+                        "existing_id": nullable_string("Id of the matching previously tracked item, if any"),
+                    },
+                    "required": ["text", "owner", "due_date", "source_timestamp", "status", "existing_id"],
+                    "additionalProperties": false,
+                },
+            },
+        },
+        "required": ["action_items"],
+        "additionalProperties": false,
+    })
+}
+
+/// System prompt of the extraction pass
+pub fn system_prompt(language: &str) -> &'static str {
+    match language {
+        "pt" => "Você extrai itens de ação de reuniões. Um item de ação é uma tarefa concreta que alguém se comprometeu a fazer. \
+Use apenas informações presentes no texto fonte. Escreva o texto de cada item em português do Brasil. \
+Se a transcrição mostrar que um item anterior foi concluído ou cancelado, retorne-o com esse status. \
+Responda apenas com um objeto JSON que siga o schema fornecido.",
+        _ => "You extract action items from meetings. An action item is a concrete task someone committed to doing. \
+Only use information present in the source text. \
+If the transcript shows that a previously tracked item was completed or cancelled, return it with that status. \
+Respond only with a JSON object that follows the provided schema.",
+    }
+}
+
+/// User prompt of the extraction pass. `transcript` is omitted when it does not
+/// fit the model's context; the summary then stands in for it.
+pub fn build_user_prompt(
+    meeting_title: &str,
+    meeting_date: &str,
+    transcript: Option<&str>,
+    summary: Option<&str>,
+    candidates: &[ActionItem],
+    schema: &Value,
+) -> String {
+    let mut prompt = format!("Meeting: {}\nMeeting date: {}\n", meeting_title, meeting_date);
+
+    if let Some(transcript) = transcript {
+        prompt.push_str(&format!(
+            "\n<transcript>\n{}\n</transcript>\n",
+            transcript
+        ));
+    }
+    if let Some(summary) = summary {
+        prompt.push_str(&format!("\n<summary>\n{}\n</summary>\n", summary));
+    }
+
+    if !candidates.is_empty() {
+        prompt.push_str(
+            "\nPreviously tracked action items. If an item in this meeting is one of these \
+(the same task, even if worded differently), set existing_id to its id instead of treating it as new:\n<tracked_items>\n",
+        );
+        for item in candidates {
+            prompt.push_str(&format!("[{}] {}", item.id, item.text));
+            if let Some(owner) = &item.owner {
+                prompt.push_str(&format!(" (owner: {})", owner));
+            }
+            if let Some(due_date) = &item.due_date {
+                prompt.push_str(&format!(" (due: {})", due_date));
+            }
+            prompt.push('\n');
+        }
+        prompt.push_str("</tracked_items>\n");
+    }
+
+    prompt.push_str(&format!(
+        "\nReturn every action item of this meeting, following this JSON schema:\n{}",
+        schema
+    ));
+    prompt
+}
+
+/// Transcript lines as "[HH:MM:SS] Speaker: text", for the LLM to cite timestamps
+pub fn format_transcript(transcripts: &[MeetingTranscript]) -> String {
+    transcripts
+        .iter()
+        .map(|t| {
+            let time = t
+                .audio_start_time
+                .map(|s| format!("[{}] ", format_timestamp(s)))
+                .unwrap_or_default();
+            let speaker = t
+                .speaker_name
+                .as_deref()
+                .map(|s| format!("{}: ", s))
+                .unwrap_or_default();
+            format!("{}{}{}", time, speaker, t.text.trim())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parse the LLM answer, dropping items without text
+pub fn parse_extraction(raw: &str) -> Result<Vec<ExtractedItem>, String> {
+    let start = raw.find('{').ok_or("Action item response is not a JSON object")?;
+    let end = raw.rfind('}').ok_or("Action item response is not a JSON object")?;
+    let response: ExtractionResponse = serde_json::from_str(&raw[start..=end])
+        .map_err(|e| format!("Failed to parse action items: {}", e))?;
+
+    Ok(response
+        .action_items
+        .into_iter()
+        .filter(|item| !item.text.trim().is_empty())
+        .map(|mut item| {
+            if !["open", "done", "cancelled"].contains(&item.status.as_str()) {
+                item.status = default_status();
+            }
+            item
+        })
+        .collect())
+}
+
+/// "HH:MM:SS", "MM:SS" or plain seconds
+pub fn parse_timestamp(value: &str) -> Option<f64> {
+    let value = value.trim().trim_start_matches('[').trim_end_matches(']');
+    let mut seconds = 0.0;
+    for part in value.split(':') {
+        let part: f64 = part.trim().parse().ok()?;
+        seconds = seconds * 60.0 + part;
+    }
+    (seconds >= 0.0).then_some(seconds)
+}
+
+fn deserialize_timestamp<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::Number(n)) => n.as_f64(),
+        Some(Value::String(s)) => parse_timestamp(&s),
+        _ => None,
+    })
+}
+
+/// The tracked item an extracted item continues: the one the LLM named, or
+/// failing that the closest by wording with a compatible owner
+pub fn find_match<'a>(item: &ExtractedItem, candidates: &'a [ActionItem]) -> Option<&'a ActionItem> {
+    if let Some(existing_id) = &item.existing_id {
+        if let Some(found) = candidates.iter().find(|c| &c.id == existing_id) {
+            return Some(found);
+        }
+    }
+
+    let words = significant_words(&item.text);
+    candidates
+        .iter()
+        .filter(|c| owners_compatible(item.owner.as_deref(), c.owner.as_deref()))
+        .map(|c| (c, similarity(&words, &significant_words(&c.text))))
+        .filter(|(_, score)| *score >= MATCH_THRESHOLD)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(c, _)| c)
+}
+
+/// Whether two extracted items are the same task (the LLM sometimes repeats one)
+pub fn is_duplicate(a: &ExtractedItem, b: &ExtractedItem) -> bool {
+    owners_compatible(a.owner.as_deref(), b.owner.as_deref())
+        && similarity(&significant_words(&a.text), &significant_words(&b.text)) >= MATCH_THRESHOLD
+}
+
+fn owners_compatible(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.trim().eq_ignore_ascii_case(b.trim()),
+        _ => true,
+    }
+}
+
+/// Lowercased words of 3+ letters, which skips most articles and prepositions
+fn significant_words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Jaccard similarity of two word sets
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / a.union(b).count() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn tracked(id: &str, text: &str, owner: Option<&str>) -> ActionItem {
+        ActionItem {
+            id: id.to_string(),
+            meeting_id: "m1".to_string(),
+            meeting_title: None,
+            text: text.to_string(),
+            owner: owner.map(str::to_string),
+            due_date: None,
+            source_timestamp: None,
+            status: "open".to_string(),
+            last_seen_meeting_id: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            completed_at: None,
+        }
+    }
+
+    fn extracted(text: &str, owner: Option<&str>) -> ExtractedItem {
+        ExtractedItem {
+            text: text.to_string(),
+            owner: owner.map(str::to_string),
+            due_date: None,
+            source_timestamp: None,
+            status: "open".to_string(),
+            existing_id: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_extraction() {
+        let raw = r#"```json
+{"action_items": [
+  {"text": "Send the contract to legal", "owner": "Ana", "due_date": "2025-11-28", "source_timestamp": "00:12:30", "status": "open", "existing_id": null},
+  {"text": "Book the venue", "source_timestamp": 95, "status": "finished"},
+  {"text": "  "}
+]}
+```"#;
+        let items = parse_extraction(raw).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].owner.as_deref(), Some("Ana"));
+        assert_eq!(items[0].source_timestamp, Some(750.0));
+        assert_eq!(items[1].source_timestamp, Some(95.0));
+        // Unknown statuses fall back to open
+        assert_eq!(items[1].status, "open");
+        assert!(parse_extraction("no items").is_err());
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("01:02:03"), Some(3723.0));
+        assert_eq!(parse_timestamp("[12:30]"), Some(750.0));
+        assert_eq!(parse_timestamp("42.5"), Some(42.5));
+        assert_eq!(parse_timestamp("soon"), None);
+    }
+
+    #[test]
+    fn test_find_match() {
+        let candidates = vec![
+            tracked("a", "Send the signed contract to legal", Some("Ana")),
+            tracked("b", "Book the venue for the offsite", None),
+        ];
+
+        // Named by the LLM
+        let mut item = extracted("Follow up on the venue", None);
+        item.existing_id = Some("b".to_string());
+        assert_eq!(find_match(&item, &candidates).unwrap().id, "b");
+
+        // Reworded, same owner
+        let item = extracted("Send signed contract to legal", Some("ana"));
+        assert_eq!(find_match(&item, &candidates).unwrap().id, "a");
+
+        // Same wording, different owner
+        let item = extracted("Send the signed contract to legal", Some("Bruno"));
+        assert!(find_match(&item, &candidates).is_none());
+
+        // Unrelated
+        assert!(find_match(&extracted("Prepare the budget", None), &candidates).is_none());
+    }
+
+    #[test]
+    fn test_is_duplicate() {
+        assert!(is_duplicate(
+            &extracted("Book the venue for the offsite", Some("Ana")),
+            &extracted("Book venue for offsite", None),
+        ));
+        assert!(!is_duplicate(
+            &extracted("Book the venue", Some("Ana")),
+            &extracted("Book the venue", Some("Bruno")),
+        ));
+    }
+}
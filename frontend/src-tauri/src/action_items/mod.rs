@@ -0,0 +1,6 @@
+pub mod commands;
+pub mod extraction;
+pub mod service;
+
+pub use commands::*;
+pub use service::*;
@@ -0,0 +1,261 @@
+use crate::action_items::extraction::{self, ExtractedItem};
+use crate::database::models::ActionItem;
+use crate::database::repositories::{
+    action_item::{ActionItemUpdate, ActionItemsRepository, NewActionItem},
+    meeting::MeetingsRepository,
+    summary::SummaryProcessesRepository,
+};
+use crate::summary::llm_client::{generate_summary_json, LLMProvider};
+use crate::summary::processor::rough_token_count;
+use crate::summary::versions;
+use crate::summary::SummaryService;
+use chrono::DateTime;
+use reqwest::Client;
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Emitter, Runtime};
+use tracing::info;
+
+/// Emitted with `{"meeting_id"}` after an extraction pass saved its items
+pub const ACTION_ITEMS_UPDATED_EVENT: &str = "action-items-updated";
+
+/// Model settings the extraction pass runs with (same as the summary's)
+pub struct ExtractionModel<'a> {
+    pub provider: &'a LLMProvider,
+    pub model_name: &'a str,
+    pub api_key: &'a str,
+    pub ollama_endpoint: Option<&'a str>,
+    pub token_threshold: usize,
+}
+
+pub struct ActionItemService;
+
+impl ActionItemService {
+    /// Extract a meeting's action items and save them.
+    ///
+    /// The LLM gets the timestamped transcript (or only the summary when the
+    /// transcript does not fit the model's context) plus the open items of earlier
+    /// meetings. Extracted items that continue one of those are matched to it,
+    /// by the id the LLM names or by wording, and update it instead of being
+    /// inserted again. Running the pass again on the same meeting matches its
+    /// own earlier items the same way.
+    pub async fn extract_for_meeting<R: Runtime>(
+        app: &AppHandle<R>,
+        pool: &SqlitePool,
+        client: &Client,
+        model: &ExtractionModel<'_>,
+        meeting_id: &str,
+        summary_markdown: Option<&str>,
+        language: &str,
+    ) -> Result<Vec<ActionItem>, String> {
+        info!("📋 Extracting action items for meeting_id: {}", meeting_id);
+
+        let meeting = MeetingsRepository::get_meeting(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to get meeting: {}", e))?
+            .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+
+        let transcript = extraction::format_transcript(&meeting.transcripts);
+        let transcript = if rough_token_count(&transcript) < model.token_threshold {
+            Some(transcript)
+        } else {
+            info!("Transcript exceeds the model's context, extracting from the summary only");
+            None
+        };
+        if transcript.is_none() && summary_markdown.is_none() {
+            return Err("No transcript or summary to extract action items from".to_string());
+        }
+
+        let candidates = Self::load_candidates(pool, meeting_id).await?;
+        let meeting_date = DateTime::parse_from_rfc3339(&meeting.created_at)
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|_| meeting.created_at.clone());
+
+        let schema = extraction::extraction_schema();
+        let user_prompt = extraction::build_user_prompt(
+            &meeting.title,
+            &meeting_date,
+            transcript.as_deref(),
+            summary_markdown,
+            &candidates,
+            &schema,
+        );
+
+        let response = generate_summary_json(
+            client,
+            model.provider,
+            model.model_name,
+            model.api_key,
+            extraction::system_prompt(language),
+            &user_prompt,
+            model.ollama_endpoint,
+            extraction::EXTRACTION_SCHEMA_NAME,
+            &schema,
+        )
+        .await?;
+        let extracted = extraction::parse_extraction(&response)?;
+
+        // Split into new items and updates of tracked ones
+        let mut new_items: Vec<ExtractedItem> = Vec::new();
+        let mut carried_over: Vec<(String, ActionItemUpdate)> = Vec::new();
+        for item in extracted {
+            if let Some(existing) = extraction::find_match(&item, &candidates) {
+                if carried_over.iter().any(|(id, _)| id == &existing.id) {
+                    continue;
+                }
+                carried_over.push((existing.id.clone(), Self::carry_over_update(existing, &item)));
+            } else if !new_items.iter().any(|n| extraction::is_duplicate(n, &item)) {
+                new_items.push(item);
+            }
+        }
+
+        let new_rows: Vec<NewActionItem> = new_items
+            .iter()
+            .map(|item| NewActionItem {
+                meeting_id,
+                text: &item.text,
+                owner: item.owner.as_deref(),
+                due_date: item.due_date.as_deref(),
+                source_timestamp: item.source_timestamp,
+                status: &item.status,
+            })
+            .collect();
+
+        ActionItemsRepository::save_extracted_items(pool, meeting_id, &new_rows, &carried_over)
+            .await
+            .map_err(|e| format!("Failed to save action items: {}", e))?;
+
+        info!(
+            "✓ Extracted {} new and {} carried-over action items for meeting_id: {}",
+            new_rows.len(),
+            carried_over.len(),
+            meeting_id
+        );
+
+        let _ = app.emit(
+            ACTION_ITEMS_UPDATED_EVENT,
+            serde_json::json!({ "meeting_id": meeting_id }),
+        );
+
+        ActionItemsRepository::get_meeting_items(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load action items: {}", e))
+    }
+
+    /// Run the extraction pass on demand, with the given model and the meeting's
+    /// current summary (if any)
+    pub async fn extract_with_settings<R: Runtime>(
+        app: &AppHandle<R>,
+        pool: &SqlitePool,
+        meeting_id: &str,
+        model_provider: &str,
+        model_name: &str,
+    ) -> Result<Vec<ActionItem>, String> {
+        let settings = SummaryService::resolve_model_settings(pool, model_provider).await?;
+
+        let summary = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to get summary: {}", e))?
+            .filter(|process| process.status.eq_ignore_ascii_case("completed"))
+            .and_then(|process| process.result)
+            .map(|result| versions::summary_markdown(&result));
+
+        let token_threshold = SummaryService::token_threshold(
+            &settings.provider,
+            model_name,
+            settings.ollama_endpoint.as_deref(),
+        )
+        .await;
+
+        let model = ExtractionModel {
+            provider: &settings.provider,
+            model_name,
+            api_key: &settings.api_key,
+            ollama_endpoint: settings.ollama_endpoint.as_deref(),
+            token_threshold,
+        };
+
+        Self::extract_for_meeting(
+            app,
+            pool,
+            &Client::new(),
+            &model,
+            meeting_id,
+            summary.as_deref(),
+            &settings.language,
+        )
+        .await
+    }
+
+    /// Items an extracted item may continue: this meeting's own (from an earlier
+    /// pass) and the most recent open items of other meetings
+    async fn load_candidates(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<ActionItem>, String> {
+        let mut candidates = ActionItemsRepository::get_meeting_items(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load action items: {}", e))?;
+
+        let mut open_items = ActionItemsRepository::get_open_items(pool, None)
+            .await
+            .map_err(|e| format!("Failed to load open action items: {}", e))?;
+        open_items.retain(|item| candidates.iter().all(|c| c.id != item.id));
+        open_items.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        open_items.truncate(extraction::MAX_CANDIDATES);
+
+        candidates.extend(open_items);
+        Ok(candidates)
+    }
+
+    /// What a new mention changes on a tracked item: its status, and owner or due
+    /// date when it now has one. The text the user already knows it by is kept.
+    fn carry_over_update(existing: &ActionItem, item: &ExtractedItem) -> ActionItemUpdate {
+        let changed = |new: &Option<String>, old: &Option<String>| {
+            new.as_ref()
+                .filter(|n| !n.trim().is_empty() && Some(n.trim()) != old.as_deref())
+                .cloned()
+        };
+
+        ActionItemUpdate {
+            text: None,
+            owner: changed(&item.owner, &existing.owner),
+            due_date: changed(&item.due_date, &existing.due_date),
+            status: (item.status != existing.status).then(|| item.status.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_carry_over_update() {
+        let existing = ActionItem {
+            id: "a".to_string(),
+            meeting_id: "m1".to_string(),
+            meeting_title: None,
+            text: "Send the contract to legal".to_string(),
+            owner: Some("Ana".to_string()),
+            due_date: None,
+            source_timestamp: Some(12.0),
+            status: "open".to_string(),
+            last_seen_meeting_id: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            completed_at: None,
+        };
+        let item = ExtractedItem {
+            text: "Contract sent to legal".to_string(),
+            owner: Some("Ana".to_string()),
+            due_date: Some("2025-12-01".to_string()),
+            source_timestamp: Some(40.0),
+            status: "done".to_string(),
+            existing_id: Some("a".to_string()),
+        };
+
+        let update = ActionItemService::carry_over_update(&existing, &item);
+        assert_eq!(update.text, None);
+        assert_eq!(update.owner, None);
+        assert_eq!(update.due_date.as_deref(), Some("2025-12-01"));
+        assert_eq!(update.status.as_deref(), Some("done"));
+    }
+}
@@ -5,10 +5,12 @@ use tauri::{AppHandle, Runtime};
 use tauri_plugin_store::StoreExt;
 
 use crate::{
+    audio::transcription::WordTimestamp,
     database::{
-        models::MeetingModel,
+        models::{MeetingModel, MeetingSpeaker},
         repositories::{
             meeting::MeetingsRepository, setting::SettingsRepository,
+            search::SearchRepository, speaker::SpeakersRepository,
             transcript::TranscriptsRepository,
         },
     },
@@ -29,6 +31,8 @@ pub struct ApiResponse<T> {
 pub struct Meeting {
     pub id: String,
     pub title: String,
+    /// `meetings.status`, e.g. "folder_missing" when the recording folder is gone
+    pub status: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -43,6 +47,17 @@ pub struct TranscriptSearchResult {
     #[serde(rename = "matchContext")]
     pub match_context: String,
     pub timestamp: String,
+    // Full-text search extras (absent for the plain LIKE fallback)
+    #[serde(rename = "highlightedContext", skip_serializing_if = "Option::is_none")]
+    pub highlighted_context: Option<String>, // Snippet with <mark>...</mark> around matches
+    #[serde(rename = "sourceType", skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>, // "transcript" | "summary" | "chat"
+    #[serde(rename = "sourceId", skip_serializing_if = "Option::is_none")]
+    pub source_id: Option<String>,
+    #[serde(rename = "audioStartTime", skip_serializing_if = "Option::is_none")]
+    pub audio_start_time: Option<f64>, // Seconds from recording start (transcript hits)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<f64>, // BM25 score, lower is better
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -121,6 +136,8 @@ pub struct MeetingDetails {
     pub title: String,
     pub created_at: String,
     pub updated_at: String,
+    /// `meetings.status`, e.g. "folder_missing" when the recording folder is gone
+    pub status: String,
     pub transcripts: Vec<MeetingTranscript>,
 }
 
@@ -136,6 +153,20 @@ pub struct MeetingTranscript {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Word-level timing (recording-relative) for word highlighting during playback
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTimestamp>,
+    // Diarization speaker ID and its resolved display name (user rename or "Speaker N")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker_name: Option<String>,
+    // Mic/system attribution: "local" | "remote" | "overlap" | "unknown"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    // Mean word confidence (0.0 - 1.0), if the transcription model scores words
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -168,6 +199,18 @@ pub struct TranscriptSegment {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Word-level timing (recording-relative) for word highlighting during playback
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTimestamp>,
+    // Diarization speaker ID ("speaker_1"), if the segment was labeled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    // Mic/system attribution from the live pipeline ("local" | "remote" | "overlap" | "unknown")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    // Mean word confidence (0.0 - 1.0), if the transcription model scores words
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -321,6 +364,7 @@ pub async fn api_get_meetings<R: Runtime>(
                 .map(|m| Meeting {
                     id: m.id,
                     title: m.title,
+                    status: m.status,
                 })
                 .collect();
             Ok(result)
@@ -347,7 +391,14 @@ pub async fn api_search_transcripts<R: Runtime>(
 
     let pool = state.db_manager.pool();
 
-    match TranscriptsRepository::search_transcripts(pool, &query).await {
+    // FTS5 (BM25-ranked) search; fall back to a plain scan if the query has no searchable terms
+    let search_result = match SearchRepository::search(pool, &query, None).await {
+        Ok(Some(results)) => Ok(results),
+        Ok(None) => TranscriptsRepository::search_transcripts(pool, &query).await,
+        Err(e) => Err(e),
+    };
+
+    match search_result {
         Ok(results) => {
             log_info!(
                 "Search completed successfully with {} results.",
@@ -869,6 +920,49 @@ pub async fn api_save_meeting_title<R: Runtime>(
     }
 }
 
+#[tauri::command]
+pub async fn api_get_meeting_speakers<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<MeetingSpeaker>, String> {
+    log_info!("api_get_meeting_speakers called for meeting_id: {}", meeting_id);
+    let pool = state.db_manager.pool();
+    SpeakersRepository::get_speakers(pool, &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get speakers for meeting {}: {}", meeting_id, e);
+            format!("Failed to get speakers: {}", e)
+        })
+}
+
+/// Rename a diarized speaker for one meeting. An empty name restores "Speaker N".
+#[tauri::command]
+pub async fn api_rename_speaker<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    speaker_id: String,
+    display_name: String,
+) -> Result<serde_json::Value, String> {
+    log_info!(
+        "api_rename_speaker called for meeting_id: {}, speaker_id: {}",
+        meeting_id,
+        speaker_id
+    );
+    let pool = state.db_manager.pool();
+    match SpeakersRepository::rename_speaker(pool, &meeting_id, &speaker_id, &display_name).await {
+        Ok(()) => {
+            log_info!("Successfully renamed speaker {}", speaker_id);
+            Ok(serde_json::json!({"message": "Speaker renamed successfully"}))
+        }
+        Err(e) => {
+            log_error!("Failed to rename speaker {}: {}", speaker_id, e);
+            Err(format!("Failed to rename speaker: {}", e))
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn api_save_transcript<R: Runtime>(
     _app: AppHandle<R>,
@@ -959,7 +1053,7 @@ pub async fn open_meeting_folder<R: Runtime>(
 
     // Get meeting with folder_path
     let meeting: Option<MeetingModel> = sqlx::query_as(
-        "SELECT id, title, created_at, updated_at, folder_path FROM meetings WHERE id = ?",
+        "SELECT id, title, created_at, updated_at, folder_path, status FROM meetings WHERE id = ?",
     )
     .bind(&meeting_id)
     .fetch_optional(pool)
@@ -0,0 +1,703 @@
+// audio/import.rs
+//
+// Import of existing recordings (Zoom/Teams exports, voice memos, ...) as meetings.
+// The file is decoded with symphonia, downmixed and resampled to 16kHz mono,
+// split into speech segments by the VAD and transcribed in batches of segments with
+// the transcription engine. Segment and word times are relative to the start of
+// the file, like those of a live recording are to the recording start.
+
+use super::audio_processing::{audio_to_mono, create_meeting_folder};
+use super::recording_saver::{self, DeviceInfo, MeetingMetadata};
+use super::transcription::{TranscriptResult, TranscriptionEngine, TranscriptionError};
+use super::vad::{self, VadSettings};
+use crate::api::TranscriptSegment;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::summary::streaming::StreamCancellation;
+use crate::utils::format_timestamp;
+use log::{info, warn};
+use rubato::{
+    Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
+};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+/// Event emitted while a file is imported
+pub const IMPORT_PROGRESS_EVENT: &str = "import-progress";
+
+/// File extensions the import accepts
+pub const SUPPORTED_EXTENSIONS: [&str; 4] = ["mp4", "m4a", "wav", "mp3"];
+
+const TARGET_SAMPLE_RATE: u32 = 16000;
+
+/// Same redemption time as the live pipeline, so imported segments match recorded ones
+const VAD_REDEMPTION_TIME_MS: u32 = 400;
+
+/// Input frames per resampler call
+const RESAMPLER_CHUNK_SIZE: usize = 4096;
+
+/// Segments shorter than this are not worth a transcription call (100ms at 16kHz)
+const MIN_SEGMENT_SAMPLES: usize = 1600;
+
+/// Segments handed to the engine at a time. It batches those of similar length;
+/// between batches the import can be cancelled and reports progress.
+const TRANSCRIBE_BATCH_SEGMENTS: usize = 16;
+
+/// Steps of an import, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportStage {
+    Decoding,
+    DetectingSpeech,
+    Transcribing,
+    Saving,
+}
+
+impl ImportStage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Decoding => "decoding",
+            Self::DetectingSpeech => "detecting_speech",
+            Self::Transcribing => "transcribing",
+            Self::Saving => "saving",
+        }
+    }
+
+    /// Share of the overall progress (0-100) covered by this stage
+    fn progress_range(self) -> (f32, f32) {
+        match self {
+            Self::Decoding => (0.0, 30.0),
+            Self::DetectingSpeech => (30.0, 40.0),
+            Self::Transcribing => (40.0, 95.0),
+            Self::Saving => (95.0, 100.0),
+        }
+    }
+
+    /// Overall progress when this stage is `fraction` (0-1) done
+    pub fn overall_progress(self, fraction: f32) -> f32 {
+        let (start, end) = self.progress_range();
+        start + (end - start) * fraction.clamp(0.0, 1.0)
+    }
+}
+
+/// Payload of `import-progress` events
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportProgress {
+    pub file_path: String,
+    /// An `ImportStage` while running, then "completed", "cancelled" or "failed"
+    pub status: String,
+    /// Overall progress, 0-100
+    pub progress: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meeting_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ImportProgress {
+    fn new(file_path: &str, status: &str, progress: f32) -> Self {
+        Self {
+            file_path: file_path.to_string(),
+            status: status.to_string(),
+            progress,
+            meeting_id: None,
+            error: None,
+        }
+    }
+
+    pub fn stage(file_path: &str, stage: ImportStage, fraction: f32) -> Self {
+        Self::new(file_path, stage.as_str(), stage.overall_progress(fraction))
+    }
+
+    pub fn completed(file_path: &str, meeting_id: &str) -> Self {
+        Self {
+            meeting_id: Some(meeting_id.to_string()),
+            ..Self::new(file_path, "completed", 100.0)
+        }
+    }
+
+    pub fn cancelled(file_path: &str) -> Self {
+        Self::new(file_path, "cancelled", 0.0)
+    }
+
+    pub fn failed(file_path: &str, error: &str) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::new(file_path, "failed", 0.0)
+        }
+    }
+}
+
+/// Decoded audio, resampled to 16kHz mono
+#[derive(Debug)]
+pub struct DecodedAudio {
+    pub samples: Vec<f32>,
+    /// Sample rate of the file's audio track
+    pub source_sample_rate: u32,
+}
+
+/// A transcribed file
+#[derive(Debug)]
+pub struct TranscribedFile {
+    pub segments: Vec<TranscriptSegment>,
+    pub duration_seconds: f64,
+    pub source_sample_rate: u32,
+}
+
+/// Result of transcribing a file
+#[derive(Debug)]
+pub enum TranscribeOutcome {
+    Completed(TranscribedFile),
+    Cancelled,
+}
+
+/// Progress callback: current stage and how much of it is done (0-1)
+pub type ProgressCallback = Arc<dyn Fn(ImportStage, f32) + Send + Sync>;
+
+/// Whether the file has an extension the import can decode
+pub fn is_supported_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Decode, segment and transcribe an audio or video file.
+///
+/// Decoding and VAD run on a blocking thread; `cancellation` is checked between
+/// decoded packets and between transcribed segments.
+pub async fn transcribe_file(
+    engine: &TranscriptionEngine,
+    path: &Path,
+    language: Option<String>,
+    cancellation: Arc<StreamCancellation>,
+    on_progress: ProgressCallback,
+) -> Result<TranscribeOutcome, String> {
+    transcribe_file_after(engine, path, 0.0, language, cancellation, on_progress).await
+}
+
+/// Like `transcribe_file`, but only the audio after `offset_seconds` is
+/// transcribed. Segment times stay relative to the start of the file.
+pub async fn transcribe_file_after(
+    engine: &TranscriptionEngine,
+    path: &Path,
+    offset_seconds: f64,
+    language: Option<String>,
+    cancellation: Arc<StreamCancellation>,
+    on_progress: ProgressCallback,
+) -> Result<TranscribeOutcome, String> {
+    let vad_settings = VadSettings {
+        redemption_time_ms: VAD_REDEMPTION_TIME_MS,
+        ..VadSettings::default()
+    };
+    transcribe_file_with(
+        engine,
+        path,
+        offset_seconds,
+        language,
+        vad_settings,
+        cancellation,
+        on_progress,
+    )
+    .await
+}
+
+/// Like `transcribe_file_after`, with custom speech detection settings
+pub async fn transcribe_file_with(
+    engine: &TranscriptionEngine,
+    path: &Path,
+    offset_seconds: f64,
+    language: Option<String>,
+    vad_settings: VadSettings,
+    cancellation: Arc<StreamCancellation>,
+    on_progress: ProgressCallback,
+) -> Result<TranscribeOutcome, String> {
+    if !is_supported_file(path) {
+        return Err(format!(
+            "Unsupported file type: {} (expected one of: {})",
+            path.display(),
+            SUPPORTED_EXTENSIONS.join(", ")
+        ));
+    }
+    let offset_seconds = offset_seconds.max(0.0);
+
+    let decode_path = path.to_path_buf();
+    let decode_cancellation = cancellation.clone();
+    let decode_progress = on_progress.clone();
+    let speech = tokio::task::spawn_blocking(move || {
+        let decoded = match decode_to_16k_mono(&decode_path, &decode_cancellation, &|fraction| {
+            decode_progress(ImportStage::Decoding, fraction)
+        })? {
+            Some(decoded) => decoded,
+            None => return Ok(None),
+        };
+        let duration_seconds = decoded.samples.len() as f64 / TARGET_SAMPLE_RATE as f64;
+        info!("Decoded {} ({:.1}s of audio)", decode_path.display(), duration_seconds);
+
+        decode_progress(ImportStage::DetectingSpeech, 0.0);
+        let skipped =
+            ((offset_seconds * TARGET_SAMPLE_RATE as f64) as usize).min(decoded.samples.len());
+        let segments =
+            vad::get_speech_chunks_with_settings(&decoded.samples[skipped..], &vad_settings)
+                .map_err(|e| format!("Speech detection failed: {}", e))?;
+        Ok::<_, String>(Some((segments, duration_seconds, decoded.source_sample_rate)))
+    })
+    .await
+    .map_err(|e| format!("Decoding task failed: {}", e))??;
+
+    let (speech, duration_seconds, source_sample_rate) = match speech {
+        Some(speech) if !cancellation.is_cancelled() => speech,
+        _ => return Ok(TranscribeOutcome::Cancelled),
+    };
+    info!("VAD found {} speech segments", speech.len());
+
+    let total = speech.len();
+    let mut segments = Vec::new();
+    let mut speech = speech
+        .into_iter()
+        .enumerate()
+        .filter(|(_, speech_segment)| speech_segment.samples.len() >= MIN_SEGMENT_SAMPLES)
+        .peekable();
+    while let Some(&(next_index, _)) = speech.peek() {
+        if cancellation.is_cancelled() {
+            return Ok(TranscribeOutcome::Cancelled);
+        }
+        on_progress(ImportStage::Transcribing, next_index as f32 / total as f32);
+
+        let (times, audio): (Vec<_>, Vec<_>) = speech
+            .by_ref()
+            .take(TRANSCRIBE_BATCH_SEGMENTS)
+            .map(|(index, speech_segment)| {
+                let start = offset_seconds + speech_segment.start_timestamp_ms / 1000.0;
+                let end = offset_seconds + speech_segment.end_timestamp_ms / 1000.0;
+                ((index, start, end), speech_segment.samples)
+            })
+            .unzip();
+        let results = tokio::select! {
+            results = transcribe_batch(engine, audio, &language) => results,
+            _ = cancellation.cancelled() => return Ok(TranscribeOutcome::Cancelled),
+        };
+
+        for ((index, start, end), result) in times.into_iter().zip(results) {
+            let result = match result {
+                Ok(result) => result,
+                Err(e) => {
+                    // One bad segment should not lose the rest of the file
+                    warn!("Transcription failed for segment at {:.1}s: {}", start, e);
+                    continue;
+                }
+            };
+            if result.text.is_empty() {
+                continue;
+            }
+
+            segments.push(TranscriptSegment {
+                id: format!("import-{}", index),
+                text: result.text,
+                timestamp: format_timestamp(start),
+                audio_start_time: Some(start),
+                audio_end_time: Some(end),
+                duration: Some(end - start),
+                words: result.words.into_iter().map(|w| w.offset_by(start)).collect(),
+                speaker: None,
+                source: None,
+                confidence: result.confidence,
+            });
+        }
+    }
+    on_progress(ImportStage::Transcribing, 1.0);
+
+    Ok(TranscribeOutcome::Completed(TranscribedFile {
+        segments,
+        duration_seconds,
+        source_sample_rate,
+    }))
+}
+
+/// Transcribe segments in a batch, or one by one if the batch fails, so that
+/// one bad segment does not lose the others. Results are in input order.
+async fn transcribe_batch(
+    engine: &TranscriptionEngine,
+    audio: Vec<Vec<f32>>,
+    language: &Option<String>,
+) -> Vec<Result<TranscriptResult, TranscriptionError>> {
+    match engine.transcribe_batch(audio.clone(), language.clone()).await {
+        Ok(results) => results.into_iter().map(Ok).collect(),
+        Err(e) => {
+            warn!("Batch transcription failed, retrying segment by segment: {}", e);
+            let mut results = Vec::with_capacity(audio.len());
+            for segment in audio {
+                results.push(engine.transcribe(segment, language.clone()).await);
+            }
+            results
+        }
+    }
+}
+
+/// Save a transcribed file as a new meeting.
+///
+/// The file is copied into a new meeting folder under `base_folder` as
+/// `audio.<ext>`, next to the `transcripts.json` and `metadata.json` a recording
+/// leaves, so it can be played back like one. Returns the new meeting's id.
+pub async fn save_as_meeting(
+    pool: &SqlitePool,
+    base_folder: &Path,
+    title: &str,
+    source: &Path,
+    transcribed: &TranscribedFile,
+) -> Result<String, String> {
+    let (meeting_folder, audio_file) = copy_into_meeting_folder(base_folder, title, source)?;
+
+    let meeting_id = match TranscriptsRepository::save_transcript(
+        pool,
+        title,
+        &transcribed.segments,
+        Some(meeting_folder.to_string_lossy().to_string()),
+    )
+    .await
+    {
+        Ok(meeting_id) => meeting_id,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&meeting_folder);
+            return Err(format!("Failed to save transcript: {}", e));
+        }
+    };
+
+    // The meeting is saved either way; these only mirror what a recording leaves in its folder
+    if let Err(e) = write_transcripts_json(&meeting_folder, &transcribed.segments) {
+        warn!("Failed to write transcripts.json for imported meeting {}: {}", meeting_id, e);
+    }
+    if let Err(e) = write_metadata(&meeting_folder, &meeting_id, title, &audio_file, transcribed) {
+        warn!("Failed to write metadata.json for imported meeting {}: {}", meeting_id, e);
+    }
+
+    Ok(meeting_id)
+}
+
+/// Create the meeting folder and copy the source file into it as `audio.<ext>`.
+/// Returns the folder and the copied file's name.
+fn copy_into_meeting_folder(
+    base_folder: &Path,
+    title: &str,
+    source: &Path,
+) -> Result<(PathBuf, String), String> {
+    let meeting_folder = create_meeting_folder(&base_folder.to_path_buf(), title)
+        .map_err(|e| format!("Failed to create meeting folder: {}", e))?;
+    // There are no recording checkpoints to keep for an import
+    let _ = std::fs::remove_dir(meeting_folder.join(".checkpoints"));
+
+    let extension = source
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let audio_file = format!("audio.{}", extension);
+
+    if let Err(e) = std::fs::copy(source, meeting_folder.join(&audio_file)) {
+        let _ = std::fs::remove_dir_all(&meeting_folder);
+        return Err(format!("Failed to copy file into the meeting folder: {}", e));
+    }
+
+    Ok((meeting_folder, audio_file))
+}
+
+/// Same layout as `RecordingSaver` writes for a recording
+pub(super) fn write_transcripts_json(
+    folder: &Path,
+    segments: &[TranscriptSegment],
+) -> anyhow::Result<()> {
+    let segments: Vec<recording_saver::TranscriptSegment> = segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| recording_saver::TranscriptSegment {
+            id: format!("seg_{}", index),
+            text: segment.text.clone(),
+            audio_start_time: segment.audio_start_time.unwrap_or_default(),
+            audio_end_time: segment.audio_end_time.unwrap_or_default(),
+            duration: segment.duration.unwrap_or_default(),
+            display_time: segment.timestamp.clone(),
+            // Same default as live segments from providers without confidence
+            confidence: segment.confidence.unwrap_or(0.85),
+            sequence_id: index as u64,
+            words: segment.words.clone(),
+            speaker: segment.speaker.clone(),
+            source: segment.source.clone(),
+        })
+        .collect();
+
+    let json = serde_json::json!({
+        "version": "1.0",
+        "segments": segments,
+        "last_updated": chrono::Utc::now().to_rfc3339(),
+        "total_segments": segments.len()
+    });
+    std::fs::write(folder.join("transcripts.json"), serde_json::to_string_pretty(&json)?)?;
+    Ok(())
+}
+
+fn write_metadata(
+    folder: &Path,
+    meeting_id: &str,
+    title: &str,
+    audio_file: &str,
+    transcribed: &TranscribedFile,
+) -> anyhow::Result<()> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let metadata = MeetingMetadata {
+        version: "1.0".to_string(),
+        meeting_id: Some(meeting_id.to_string()),
+        meeting_name: Some(title.to_string()),
+        created_at: now.clone(),
+        completed_at: Some(now),
+        duration_seconds: Some(transcribed.duration_seconds),
+        devices: DeviceInfo {
+            microphone: None,
+            system_audio: None,
+        },
+        audio_file: audio_file.to_string(),
+        transcript_file: "transcripts.json".to_string(),
+        sample_rate: transcribed.source_sample_rate,
+        status: "completed".to_string(),
+    };
+
+    std::fs::write(
+        folder.join("metadata.json"),
+        serde_json::to_string_pretty(&metadata)?,
+    )?;
+    Ok(())
+}
+
+/// Decode the first audio track of a file to 16kHz mono.
+/// Returns `None` if cancelled; `on_progress` gets the decoded fraction when
+/// the container knows the track length.
+pub fn decode_to_16k_mono(
+    path: &Path,
+    cancellation: &StreamCancellation,
+    on_progress: &dyn Fn(f32),
+) -> Result<Option<DecodedAudio>, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|ext| ext.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(&hint, stream, &FormatOptions::default(), &MetadataOptions::default())
+        .map_err(|e| format!("Unrecognized audio format: {}", e))?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL && t.codec_params.sample_rate.is_some())
+        .ok_or("File has no audio track")?;
+    let track_id = track.id;
+    let sample_rate = track.codec_params.sample_rate.unwrap_or(TARGET_SAMPLE_RATE);
+    let total_frames = track.codec_params.n_frames;
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| format!("Unsupported audio codec: {}", e))?;
+
+    let mut resampler = StreamingResampler::new(sample_rate, TARGET_SAMPLE_RATE)?;
+    let mut sample_buffer: Option<SampleBuffer<f32>> = None;
+    let mut last_reported = 0.0;
+
+    loop {
+        if cancellation.is_cancelled() {
+            return Ok(None);
+        }
+
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(e) => return Err(format!("Failed to read audio: {}", e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(SymphoniaError::DecodeError(e)) => {
+                // Corrupt packets are skipped, as players do
+                warn!("Skipping undecodable packet: {}", e);
+                continue;
+            }
+            Err(e) => return Err(format!("Failed to decode audio: {}", e)),
+        };
+
+        let spec = *decoded.spec();
+        let buffer = match &mut sample_buffer {
+            Some(buffer) if buffer.capacity() >= decoded.capacity() * spec.channels.count() => buffer,
+            _ => sample_buffer.insert(SampleBuffer::new(decoded.capacity() as u64, spec)),
+        };
+        buffer.copy_interleaved_ref(decoded);
+        resampler.push(&audio_to_mono(buffer.samples(), spec.channels.count() as u16))?;
+
+        if let Some(total_frames) = total_frames.filter(|&n| n > 0) {
+            let fraction = (packet.ts() + packet.dur()) as f32 / total_frames as f32;
+            if fraction - last_reported >= 0.01 {
+                last_reported = fraction;
+                on_progress(fraction);
+            }
+        }
+    }
+
+    on_progress(1.0);
+    Ok(Some(DecodedAudio {
+        samples: resampler.finish()?,
+        source_sample_rate: sample_rate,
+    }))
+}
+
+/// Sinc resampler fed with blocks of any size, keeping its state across blocks
+/// (resampling a long file in independent pieces leaves clicks at the seams)
+struct StreamingResampler {
+    resampler: Option<SincFixedIn<f32>>,
+    ratio: f64,
+    pending: Vec<f32>,
+    output: Vec<f32>,
+    input_frames: usize,
+}
+
+impl StreamingResampler {
+    fn new(from_sample_rate: u32, to_sample_rate: u32) -> Result<Self, String> {
+        let ratio = to_sample_rate as f64 / from_sample_rate as f64;
+
+        let resampler = if from_sample_rate == to_sample_rate {
+            None
+        } else {
+            // Same quality tiers as audio_processing::resample
+            let (sinc_len, interpolation, oversampling_factor) = if ratio > 1.0 {
+                (256, SincInterpolationType::Linear, 256)
+            } else if ratio <= 0.5 {
+                (512, SincInterpolationType::Cubic, 512)
+            } else {
+                (384, SincInterpolationType::Linear, 384)
+            };
+            let params = SincInterpolationParameters {
+                sinc_len,
+                f_cutoff: 0.95,
+                interpolation,
+                oversampling_factor,
+                window: WindowFunction::BlackmanHarris2,
+            };
+            Some(
+                SincFixedIn::<f32>::new(ratio, 1.0, params, RESAMPLER_CHUNK_SIZE, 1)
+                    .map_err(|e| format!("Failed to create resampler: {}", e))?,
+            )
+        };
+
+        Ok(Self {
+            resampler,
+            ratio,
+            pending: Vec::with_capacity(RESAMPLER_CHUNK_SIZE * 2),
+            output: Vec::new(),
+            input_frames: 0,
+        })
+    }
+
+    fn push(&mut self, samples: &[f32]) -> Result<(), String> {
+        self.input_frames += samples.len();
+        let Some(resampler) = &mut self.resampler else {
+            self.output.extend_from_slice(samples);
+            return Ok(());
+        };
+
+        self.pending.extend_from_slice(samples);
+        let mut consumed = 0;
+        while self.pending.len() - consumed >= RESAMPLER_CHUNK_SIZE {
+            let block = &self.pending[consumed..consumed + RESAMPLER_CHUNK_SIZE];
+            let out = resampler
+                .process(&[block], None)
+                .map_err(|e| format!("Resampling failed: {}", e))?;
+            self.output.extend_from_slice(&out[0]);
+            consumed += RESAMPLER_CHUNK_SIZE;
+        }
+        self.pending.drain(..consumed);
+        Ok(())
+    }
+
+    /// Flush the remaining input and return the whole output, aligned with the
+    /// input (the filter delay is trimmed from the start)
+    fn finish(mut self) -> Result<Vec<f32>, String> {
+        let Some(resampler) = &mut self.resampler else {
+            return Ok(self.output);
+        };
+
+        let delay = resampler.output_delay();
+        let expected = (self.input_frames as f64 * self.ratio).round() as usize;
+
+        if !self.pending.is_empty() {
+            let out = resampler
+                .process_partial(Some(&[self.pending.as_slice()]), None)
+                .map_err(|e| format!("Resampling failed: {}", e))?;
+            self.output.extend_from_slice(&out[0]);
+        }
+        // Push zeros through until the delayed tail has come out
+        while self.output.len() < delay + expected {
+            let out = resampler
+                .process_partial::<&[f32]>(None, None)
+                .map_err(|e| format!("Resampling failed: {}", e))?;
+            if out[0].is_empty() {
+                break;
+            }
+            self.output.extend_from_slice(&out[0]);
+        }
+
+        let mut output = self.output.split_off(delay.min(self.output.len()));
+        output.truncate(expected);
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_supported_file() {
+        assert!(is_supported_file(Path::new("/tmp/call.MP4")));
+        assert!(is_supported_file(Path::new("memo.m4a")));
+        assert!(!is_supported_file(Path::new("notes.txt")));
+        assert!(!is_supported_file(Path::new("noextension")));
+    }
+
+    #[test]
+    fn test_overall_progress() {
+        assert_eq!(ImportStage::Decoding.overall_progress(0.0), 0.0);
+        assert_eq!(ImportStage::Decoding.overall_progress(1.0), 30.0);
+        assert_eq!(ImportStage::Transcribing.overall_progress(2.0), 95.0);
+        assert_eq!(ImportProgress::stage("a.wav", ImportStage::DetectingSpeech, 0.0).status, "detecting_speech");
+    }
+
+    #[test]
+    fn test_streaming_resampler_keeps_length_and_timing() {
+        // 1.5s of a 48kHz tone that starts after 0.5s of silence, pushed in odd-sized blocks
+        let input: Vec<f32> = (0..72000)
+            .map(|i| if i < 24000 { 0.0 } else { (i as f32 * 0.05).sin() * 0.5 })
+            .collect();
+        let mut resampler = StreamingResampler::new(48000, 16000).unwrap();
+        for block in input.chunks(1000) {
+            resampler.push(block).unwrap();
+        }
+        let output = resampler.finish().unwrap();
+
+        assert_eq!(output.len(), 24000);
+        // Silence stays where it was (allowing for the filter's ringing)
+        assert!(output[..7800].iter().all(|s| s.abs() < 0.01));
+        assert!(output[8200..8400].iter().any(|s| s.abs() > 0.1));
+    }
+}
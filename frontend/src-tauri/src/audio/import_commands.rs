@@ -0,0 +1,147 @@
+// audio/import_commands.rs
+//
+// Tauri commands for importing existing audio/video files as meetings.
+
+use super::import::{
+    self, ImportProgress, ImportStage, TranscribeOutcome, IMPORT_PROGRESS_EVENT,
+    SUPPORTED_EXTENSIONS,
+};
+use super::recording_preferences::{get_default_recordings_folder, load_recording_preferences};
+use super::transcription::{get_or_init_transcription_engine, validate_transcription_model_ready};
+use crate::state::AppState;
+use crate::summary::streaming;
+use log::{error as log_error, info as log_info, warn as log_warn};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Imports are registered for cancellation under the imported file's path
+fn import_key(file_path: &str) -> String {
+    format!("import:{}", file_path)
+}
+
+/// Open a dialog to pick an audio or video file to import
+#[tauri::command]
+pub async fn select_import_file<R: Runtime>(app: AppHandle<R>) -> Result<Option<String>, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let file_path = app
+        .dialog()
+        .file()
+        .add_filter("Audio and video", &SUPPORTED_EXTENSIONS)
+        .blocking_pick_file();
+
+    Ok(file_path.map(|path| path.to_string()))
+}
+
+/// Transcribe an audio or video file and save it as a new meeting.
+///
+/// Progress is reported through `import-progress` events. The file is copied
+/// into a new meeting folder so it can be played back like a recording.
+/// Returns the new meeting's id, or `None` if the import was cancelled.
+#[tauri::command]
+pub async fn api_import_audio_file<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    file_path: String,
+    title: Option<String>,
+) -> Result<Option<String>, String> {
+    log_info!("api_import_audio_file called for: {}", file_path);
+
+    let registration = streaming::register_stream(import_key(&file_path));
+    let result = import_file(&app, &state, &file_path, title, registration.shared_cancellation()).await;
+
+    match &result {
+        Ok(Some(meeting_id)) => {
+            log_info!("✅ Imported {} as meeting {}", file_path, meeting_id);
+            let _ = app.emit(IMPORT_PROGRESS_EVENT, ImportProgress::completed(&file_path, meeting_id));
+        }
+        Ok(None) => {
+            log_info!("⏹️ Import of {} cancelled", file_path);
+            let _ = app.emit(IMPORT_PROGRESS_EVENT, ImportProgress::cancelled(&file_path));
+        }
+        Err(e) => {
+            log_error!("❌ Import of {} failed: {}", file_path, e);
+            let _ = app.emit(IMPORT_PROGRESS_EVENT, ImportProgress::failed(&file_path, e));
+        }
+    }
+    result
+}
+
+/// Cancel a running import. Returns false if none is running for the file.
+#[tauri::command]
+pub async fn api_cancel_import<R: Runtime>(
+    _app: AppHandle<R>,
+    file_path: String,
+) -> Result<bool, String> {
+    log_info!("api_cancel_import called for: {}", file_path);
+    let cancelled = streaming::cancel_stream(&import_key(&file_path));
+    if !cancelled {
+        log_warn!("No running import for: {}", file_path);
+    }
+    Ok(cancelled)
+}
+
+async fn import_file<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    file_path: &str,
+    title: Option<String>,
+    cancellation: Arc<streaming::StreamCancellation>,
+) -> Result<Option<String>, String> {
+    let path = PathBuf::from(file_path);
+    if !path.is_file() {
+        return Err(format!("File not found: {}", file_path));
+    }
+    if !import::is_supported_file(&path) {
+        return Err(format!(
+            "Unsupported file type (expected one of: {})",
+            SUPPORTED_EXTENSIONS.join(", ")
+        ));
+    }
+
+    let title = title
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .or_else(|| path.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Imported meeting".to_string());
+
+    validate_transcription_model_ready(app).await?;
+    let engine = get_or_init_transcription_engine(app).await?;
+
+    let progress_app = app.clone();
+    let progress_path = file_path.to_string();
+    let on_progress: import::ProgressCallback = Arc::new(move |stage, fraction| {
+        let _ = progress_app.emit(
+            IMPORT_PROGRESS_EVENT,
+            ImportProgress::stage(&progress_path, stage, fraction),
+        );
+    });
+
+    let transcribed = match import::transcribe_file(
+        &engine,
+        &path,
+        crate::get_language_preference_internal(),
+        cancellation,
+        on_progress.clone(),
+    )
+    .await?
+    {
+        TranscribeOutcome::Completed(transcribed) => transcribed,
+        TranscribeOutcome::Cancelled => return Ok(None),
+    };
+    if transcribed.segments.is_empty() {
+        return Err("No speech was found in the file".to_string());
+    }
+
+    on_progress(ImportStage::Saving, 0.0);
+    let base_folder = load_recording_preferences(app)
+        .await
+        .map(|prefs| prefs.save_folder)
+        .unwrap_or_else(|_| get_default_recordings_folder());
+    let meeting_id =
+        import::save_as_meeting(state.db_manager.pool(), &base_folder, &title, &path, &transcribed)
+            .await?;
+
+    Ok(Some(meeting_id))
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use anyhow::{Result, anyhow};
 use log::{info, warn, error};
 use super::encode::encode_single_audio;
@@ -49,6 +49,29 @@ impl IncrementalAudioSaver {
         })
     }
 
+    /// Reopen the checkpoints of a recording that was never finalized, e.g.
+    /// after a crash, so `finalize` merges them into audio.mp4
+    pub fn resume(meeting_folder: PathBuf, sample_rate: u32) -> Result<Self> {
+        let mut saver = Self::new(meeting_folder, sample_rate)?;
+        saver.checkpoint_count = count_checkpoints(&saver.meeting_folder);
+        info!("Resuming {} checkpoints in {}", saver.checkpoint_count, saver.checkpoints_dir.display());
+        Ok(saver)
+    }
+
+    /// Delete the newest checkpoint. After a crash it may have been cut off
+    /// mid-write, which makes the merge fail.
+    pub fn discard_last_checkpoint(&mut self) -> Result<()> {
+        if self.checkpoint_count == 0 {
+            return Err(anyhow!("No checkpoint to discard"));
+        }
+        self.checkpoint_count -= 1;
+        let checkpoint_path = self.checkpoints_dir
+            .join(format!("audio_chunk_{:03}.mp4", self.checkpoint_count));
+        warn!("Discarding checkpoint {}", checkpoint_path.display());
+        std::fs::remove_file(&checkpoint_path)?;
+        Ok(())
+    }
+
     /// Add an audio chunk to the buffer
     /// Automatically saves a checkpoint when buffer reaches 30 seconds
     pub fn add_chunk(&mut self, chunk: AudioChunk) -> Result<()> {
@@ -229,6 +252,17 @@ impl IncrementalAudioSaver {
     }
 }
 
+/// Number of consecutive checkpoint files (audio_chunk_000.mp4, ...) in a
+/// meeting folder's .checkpoints directory
+pub fn count_checkpoints(meeting_folder: &Path) -> u32 {
+    let checkpoints_dir = meeting_folder.join(".checkpoints");
+    let mut count = 0;
+    while checkpoints_dir.join(format!("audio_chunk_{:03}.mp4", count)).is_file() {
+        count += 1;
+    }
+    count
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,6 +303,33 @@ mod tests {
         assert!(!meeting_folder.join(".checkpoints").exists());
     }
 
+    #[tokio::test]
+    async fn test_resume_after_crash() {
+        let temp_dir = tempdir().unwrap();
+        let meeting_folder = temp_dir.path().join("Crashed_Meeting");
+        std::fs::create_dir_all(meeting_folder.join(".checkpoints")).unwrap();
+
+        // Record 60 seconds, then "crash" without finalizing
+        {
+            let mut saver = IncrementalAudioSaver::new(meeting_folder.clone(), 48000).unwrap();
+            for _ in 0..120 {
+                let chunk = AudioChunk {
+                    data: vec![0.5f32; 24000],
+                    sample_rate: 48000,
+                    device_type: DeviceType::Microphone,
+                };
+                saver.add_chunk(chunk).unwrap();
+            }
+        }
+        assert_eq!(count_checkpoints(&meeting_folder), 2);
+
+        let mut saver = IncrementalAudioSaver::resume(meeting_folder.clone(), 48000).unwrap();
+        assert_eq!(saver.get_checkpoint_count(), 2);
+        let final_path = saver.finalize().await.unwrap();
+        assert!(final_path.exists());
+        assert_eq!(count_checkpoints(&meeting_folder), 0);
+    }
+
     #[tokio::test]
     async fn test_empty_recording() {
         let temp_dir = tempdir().unwrap();
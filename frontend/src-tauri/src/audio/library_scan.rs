@@ -0,0 +1,502 @@
+// audio/library_scan.rs
+//
+// Rebuilding the meetings library from the meeting folders on disk. Each folder
+// keeps the `metadata.json` and `transcripts.json` written by `RecordingSaver`,
+// so meetings lost from the database can be imported again. A rescan first
+// plans the changes without touching anything, for the user to review, then
+// applies them: importing folders without a meeting, pointing meetings at
+// folders that moved, and flagging meetings whose folder is gone.
+
+use super::recording_saver::MeetingMetadata;
+use super::recovery::{
+    dedup_folders, read_metadata, read_transcript_segments, same_folder, to_meeting_segment,
+    write_metadata, STATUS_RECORDING,
+};
+use crate::database::models::{
+    MeetingModel, MEETING_STATUS_COMPLETED, MEETING_STATUS_FOLDER_MISSING, MEETING_STATUS_RECORDING,
+};
+use crate::database::repositories::{
+    meeting::MeetingsRepository, transcript::TranscriptsRepository,
+};
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// A meeting folder with a transcript but no meeting in the database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrphanFolder {
+    pub folder_path: String,
+    /// Title the meeting is imported with
+    pub title: String,
+    pub created_at: String,
+    pub transcript_segments: usize,
+}
+
+/// A meeting whose folder was found somewhere else than the database says
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovedFolder {
+    pub meeting_id: String,
+    pub title: String,
+    pub previous_folder_path: Option<String>,
+    pub folder_path: String,
+}
+
+/// A meeting whose folder is missing, or was missing and is back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryMeeting {
+    pub meeting_id: String,
+    pub title: String,
+    pub folder_path: String,
+}
+
+/// A meeting folder the rescan leaves alone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedFolder {
+    pub folder_path: String,
+    pub reason: String,
+}
+
+/// Differences between the meeting folders on disk and the database, to review
+/// before applying them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LibraryScanReport {
+    /// Folders that match their meeting
+    pub matched: usize,
+    /// Folders to import as new meetings
+    pub orphans: Vec<OrphanFolder>,
+    /// Meetings to point at their folder's new location
+    pub moved: Vec<MovedFolder>,
+    /// Meetings to flag as missing their folder
+    pub missing: Vec<LibraryMeeting>,
+    /// Meetings flagged by an earlier rescan whose folder is back
+    pub reappeared: Vec<LibraryMeeting>,
+    pub skipped: Vec<SkippedFolder>,
+}
+
+/// What applying a rescan changed
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LibraryScanResult {
+    pub imported: usize,
+    pub relinked: usize,
+    pub flagged_missing: usize,
+    pub unflagged: usize,
+    /// Changes that could not be applied, with why
+    pub errors: Vec<String>,
+}
+
+/// Compare the meeting folders under `base_folders` with the meetings in the
+/// database. `active_folder` is the folder of a recording in progress.
+pub fn scan_library(
+    base_folders: &[PathBuf],
+    meetings: &[MeetingModel],
+    active_folder: Option<&Path>,
+) -> LibraryScanReport {
+    let mut report = LibraryScanReport::default();
+    // Meetings whose folder was found
+    let mut found: HashSet<&str> = HashSet::new();
+
+    for base_folder in dedup_folders(base_folders) {
+        let Ok(entries) = std::fs::read_dir(&base_folder) else {
+            continue;
+        };
+        let mut folders: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|folder| folder.is_dir())
+            .collect();
+        folders.sort();
+
+        for folder in folders {
+            if active_folder.is_some_and(|active| same_folder(active, &folder)) {
+                continue;
+            }
+            let Some(metadata) = read_metadata(&folder) else {
+                continue;
+            };
+            let folder_path = folder.to_string_lossy().to_string();
+            let skip = |reason: String| SkippedFolder {
+                folder_path: folder_path.clone(),
+                reason,
+            };
+
+            if metadata.status == STATUS_RECORDING {
+                report.skipped.push(skip(
+                    "Interrupted recording, recover it instead".to_string(),
+                ));
+                continue;
+            }
+
+            let by_path = meetings.iter().find(|meeting| {
+                meeting
+                    .folder_path
+                    .as_deref()
+                    .is_some_and(|path| same_folder(Path::new(path), &folder))
+            });
+            let by_id = metadata
+                .meeting_id
+                .as_deref()
+                .and_then(|id| meetings.iter().find(|meeting| meeting.id == id));
+
+            if let Some(meeting) = by_path {
+                found.insert(&meeting.id);
+                report.matched += 1;
+                if meeting.status == MEETING_STATUS_FOLDER_MISSING {
+                    report.reappeared.push(LibraryMeeting {
+                        meeting_id: meeting.id.clone(),
+                        title: meeting.title.clone(),
+                        folder_path,
+                    });
+                }
+            } else if let Some(meeting) = by_id {
+                let recorded_folder_exists = meeting
+                    .folder_path
+                    .as_deref()
+                    .is_some_and(|path| Path::new(path).is_dir());
+                if recorded_folder_exists || found.contains(meeting.id.as_str()) {
+                    report
+                        .skipped
+                        .push(skip(format!("Copy of the folder of \"{}\"", meeting.title)));
+                    continue;
+                }
+                found.insert(&meeting.id);
+                report.moved.push(MovedFolder {
+                    meeting_id: meeting.id.clone(),
+                    title: meeting.title.clone(),
+                    previous_folder_path: meeting.folder_path.clone(),
+                    folder_path,
+                });
+            } else {
+                let transcript_segments = read_transcript_segments(&folder).len();
+                if transcript_segments == 0 {
+                    report
+                        .skipped
+                        .push(skip("No transcript to import".to_string()));
+                    continue;
+                }
+                report.orphans.push(OrphanFolder {
+                    title: orphan_title(&folder, &metadata),
+                    created_at: metadata.created_at,
+                    folder_path,
+                    transcript_segments,
+                });
+            }
+        }
+    }
+
+    for meeting in meetings {
+        if found.contains(meeting.id.as_str())
+            || meeting.status == MEETING_STATUS_RECORDING
+            || meeting.status == MEETING_STATUS_FOLDER_MISSING
+        {
+            continue;
+        }
+        // Meetings without a folder, such as uploaded transcripts, have nothing to miss
+        let Some(folder_path) = meeting.folder_path.as_deref() else {
+            continue;
+        };
+        if !Path::new(folder_path).is_dir() {
+            report.missing.push(LibraryMeeting {
+                meeting_id: meeting.id.clone(),
+                title: meeting.title.clone(),
+                folder_path: folder_path.to_string(),
+            });
+        }
+    }
+
+    report
+        .orphans
+        .sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    report
+}
+
+/// Apply the changes of a rescan. A change that fails is reported and the rest
+/// are still applied.
+pub async fn apply_library_scan(
+    pool: &SqlitePool,
+    report: &LibraryScanReport,
+) -> LibraryScanResult {
+    let mut result = LibraryScanResult::default();
+
+    for orphan in &report.orphans {
+        match import_orphan(pool, orphan).await {
+            Ok(meeting_id) => {
+                info!("Imported {} as meeting {}", orphan.folder_path, meeting_id);
+                result.imported += 1;
+            }
+            Err(e) => result
+                .errors
+                .push(format!("Failed to import {}: {}", orphan.folder_path, e)),
+        }
+    }
+
+    for moved in &report.moved {
+        match relink(pool, moved).await {
+            Ok(()) => result.relinked += 1,
+            Err(e) => result
+                .errors
+                .push(format!("Failed to relink \"{}\": {}", moved.title, e)),
+        }
+    }
+
+    for (meetings, status, count) in [
+        (
+            &report.missing,
+            MEETING_STATUS_FOLDER_MISSING,
+            &mut result.flagged_missing,
+        ),
+        (
+            &report.reappeared,
+            MEETING_STATUS_COMPLETED,
+            &mut result.unflagged,
+        ),
+    ] {
+        for meeting in meetings {
+            match MeetingsRepository::set_meeting_status(pool, &meeting.meeting_id, status).await {
+                Ok(true) => *count += 1,
+                Ok(false) => {}
+                Err(e) => result
+                    .errors
+                    .push(format!("Failed to update \"{}\": {}", meeting.title, e)),
+            }
+        }
+    }
+
+    result
+}
+
+async fn import_orphan(pool: &SqlitePool, orphan: &OrphanFolder) -> Result<String, String> {
+    let folder = Path::new(&orphan.folder_path);
+    let mut metadata = read_metadata(folder).ok_or("metadata.json is missing or invalid")?;
+
+    // Imported since the scan, for example by applying it twice
+    if let Some(meeting_id) = metadata.meeting_id.as_deref() {
+        let existing = MeetingsRepository::get_meeting_model(pool, meeting_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        if existing.is_some() {
+            return Err("Already in the library".to_string());
+        }
+    }
+
+    let segments: Vec<_> = read_transcript_segments(folder)
+        .into_iter()
+        .map(to_meeting_segment)
+        .collect();
+    if segments.is_empty() {
+        return Err("No transcript to import".to_string());
+    }
+
+    let meeting_id = TranscriptsRepository::save_transcript(
+        pool,
+        &orphan.title,
+        &segments,
+        Some(orphan.folder_path.clone()),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    match DateTime::parse_from_rfc3339(&metadata.created_at) {
+        Ok(created_at) => {
+            MeetingsRepository::set_created_at(pool, &meeting_id, created_at.with_timezone(&Utc))
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        Err(e) => warn!(
+            "Invalid created_at in {}, keeping the import date: {}",
+            orphan.folder_path, e
+        ),
+    }
+
+    // The folder now belongs to the meeting, should the database be lost again
+    metadata.meeting_id = Some(meeting_id.clone());
+    if let Err(e) = write_metadata(folder, &metadata) {
+        warn!(
+            "Failed to record meeting id in {}: {}",
+            orphan.folder_path, e
+        );
+    }
+    Ok(meeting_id)
+}
+
+async fn relink(pool: &SqlitePool, moved: &MovedFolder) -> Result<(), String> {
+    MeetingsRepository::set_folder_path(pool, &moved.meeting_id, &moved.folder_path)
+        .await
+        .map_err(|e| e.to_string())?;
+    // A meeting flagged by an earlier rescan has found its folder
+    let meeting = MeetingsRepository::get_meeting_model(pool, &moved.meeting_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if meeting.is_some_and(|meeting| meeting.status == MEETING_STATUS_FOLDER_MISSING) {
+        MeetingsRepository::set_meeting_status(pool, &moved.meeting_id, MEETING_STATUS_COMPLETED)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn orphan_title(folder: &Path, metadata: &MeetingMetadata) -> String {
+    metadata
+        .meeting_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            folder
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| "Imported meeting".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::recording_saver::{self, DeviceInfo};
+    use crate::database::models::DateTimeUtc;
+    use tempfile::tempdir;
+
+    fn write_folder(
+        base: &Path,
+        name: &str,
+        meeting_id: Option<&str>,
+        status: &str,
+        segments: usize,
+    ) {
+        let folder = base.join(name);
+        std::fs::create_dir_all(&folder).unwrap();
+        let metadata = MeetingMetadata {
+            version: "1.0".to_string(),
+            meeting_id: meeting_id.map(str::to_string),
+            meeting_name: Some(name.to_string()),
+            created_at: "2026-01-01T10:00:00Z".to_string(),
+            completed_at: None,
+            duration_seconds: None,
+            devices: DeviceInfo {
+                microphone: None,
+                system_audio: None,
+            },
+            audio_file: "audio.mp4".to_string(),
+            transcript_file: "transcripts.json".to_string(),
+            sample_rate: 48000,
+            status: status.to_string(),
+        };
+        write_metadata(&folder, &metadata).unwrap();
+
+        let segments: Vec<_> = (0..segments)
+            .map(|index| recording_saver::TranscriptSegment {
+                id: format!("seg_{}", index),
+                text: format!("Segment {}", index),
+                audio_start_time: index as f64 * 5.0,
+                audio_end_time: index as f64 * 5.0 + 4.0,
+                duration: 4.0,
+                display_time: String::new(),
+                confidence: 0.9,
+                sequence_id: index as u64,
+                words: Vec::new(),
+                speaker: None,
+                source: None,
+            })
+            .collect();
+        let json = serde_json::json!({ "version": "1.0", "segments": segments });
+        std::fs::write(folder.join("transcripts.json"), json.to_string()).unwrap();
+    }
+
+    fn meeting(id: &str, folder_path: Option<PathBuf>, status: &str) -> MeetingModel {
+        MeetingModel {
+            id: id.to_string(),
+            title: id.to_string(),
+            created_at: DateTimeUtc(Utc::now()),
+            updated_at: DateTimeUtc(Utc::now()),
+            folder_path: folder_path.map(|path| path.to_string_lossy().to_string()),
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_scan_library() {
+        let base = tempdir().unwrap();
+        let base_path = base.path();
+        write_folder(base_path, "Matched", Some("matched"), "completed", 2);
+        write_folder(base_path, "Moved", Some("moved"), "completed", 2);
+        write_folder(base_path, "Back", Some("back"), "completed", 1);
+        write_folder(base_path, "Orphan", Some("lost"), "completed", 3);
+        write_folder(base_path, "Copy", Some("matched"), "completed", 2);
+        write_folder(base_path, "Crashed", None, "recording", 1);
+        write_folder(base_path, "Empty", None, "completed", 0);
+        write_folder(base_path, "In progress", Some("live"), "recording", 1);
+        std::fs::create_dir_all(base_path.join("Not a meeting")).unwrap();
+
+        let meetings = vec![
+            meeting(
+                "matched",
+                Some(base_path.join("Matched")),
+                MEETING_STATUS_COMPLETED,
+            ),
+            meeting(
+                "moved",
+                Some(base_path.join("Old place")),
+                MEETING_STATUS_COMPLETED,
+            ),
+            meeting(
+                "back",
+                Some(base_path.join("Back")),
+                MEETING_STATUS_FOLDER_MISSING,
+            ),
+            meeting(
+                "gone",
+                Some(base_path.join("Gone")),
+                MEETING_STATUS_COMPLETED,
+            ),
+            meeting(
+                "still gone",
+                Some(base_path.join("Still gone")),
+                MEETING_STATUS_FOLDER_MISSING,
+            ),
+            meeting("uploaded", None, MEETING_STATUS_COMPLETED),
+            meeting(
+                "live",
+                Some(base_path.join("In progress")),
+                MEETING_STATUS_RECORDING,
+            ),
+        ];
+
+        let active = base_path.join("In progress");
+        let report = scan_library(&[base_path.to_path_buf()], &meetings, Some(&active));
+
+        assert_eq!(report.matched, 2);
+        let orphans: Vec<_> = report.orphans.iter().map(|o| o.title.as_str()).collect();
+        assert_eq!(orphans, vec!["Orphan"]);
+        assert_eq!(report.orphans[0].transcript_segments, 3);
+        assert_eq!(report.moved.len(), 1);
+        assert_eq!(report.moved[0].meeting_id, "moved");
+        assert!(report.moved[0].folder_path.ends_with("Moved"));
+        let missing: Vec<_> = report
+            .missing
+            .iter()
+            .map(|m| m.meeting_id.as_str())
+            .collect();
+        assert_eq!(missing, vec!["gone"]);
+        let reappeared: Vec<_> = report
+            .reappeared
+            .iter()
+            .map(|m| m.meeting_id.as_str())
+            .collect();
+        assert_eq!(reappeared, vec!["back"]);
+        let skipped: Vec<_> = report
+            .skipped
+            .iter()
+            .map(|s| {
+                Path::new(&s.folder_path)
+                    .file_name()
+                    .unwrap()
+                    .to_str()
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(skipped, vec!["Copy", "Crashed", "Empty"]);
+    }
+}
@@ -0,0 +1,76 @@
+// audio/library_scan_commands.rs
+//
+// Tauri commands for rescanning the meetings library from the meeting folders on disk.
+
+use super::library_scan::{self, LibraryScanReport, LibraryScanResult};
+use super::recording_preferences::{get_default_recordings_folder, load_recording_preferences};
+use super::recovery_commands::active_recording_folder;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::state::AppState;
+use log::{error as log_error, info as log_info, warn as log_warn};
+use tauri::{AppHandle, Runtime};
+
+/// Compare the meeting folders in the recordings folder with the library,
+/// without changing anything
+#[tauri::command]
+pub async fn api_scan_library<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<LibraryScanReport, String> {
+    log_info!("api_scan_library called");
+    // Recordings are created in the default folder; also look in the one set in preferences
+    let mut base_folders = vec![get_default_recordings_folder()];
+    if let Ok(preferences) = load_recording_preferences(&app).await {
+        base_folders.push(preferences.save_folder);
+    }
+
+    let meetings = MeetingsRepository::get_meetings(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?;
+    let active_folder = active_recording_folder().await;
+    let report = tokio::task::spawn_blocking(move || {
+        library_scan::scan_library(&base_folders, &meetings, active_folder.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Library scan failed: {}", e))?;
+
+    log_info!(
+        "Library scan: {} matched, {} to import, {} moved, {} missing, {} reappeared, {} skipped",
+        report.matched,
+        report.orphans.len(),
+        report.moved.len(),
+        report.missing.len(),
+        report.reappeared.len(),
+        report.skipped.len()
+    );
+    Ok(report)
+}
+
+/// Apply the changes of a library scan the user reviewed
+#[tauri::command]
+pub async fn api_apply_library_scan<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    report: LibraryScanReport,
+) -> Result<LibraryScanResult, String> {
+    log_info!("api_apply_library_scan called");
+    let result = library_scan::apply_library_scan(state.db_manager.pool(), &report).await;
+
+    for error in &result.errors {
+        log_warn!("{}", error);
+    }
+    if result.imported + result.relinked + result.flagged_missing + result.unflagged == 0
+        && !result.errors.is_empty()
+    {
+        log_error!("❌ Library rescan changed nothing");
+        return Err(result.errors.join("\n"));
+    }
+    log_info!(
+        "✅ Library rescan applied: {} imported, {} relinked, {} flagged missing, {} unflagged",
+        result.imported,
+        result.relinked,
+        result.flagged_missing,
+        result.unflagged
+    );
+    Ok(result)
+}
@@ -0,0 +1,126 @@
+// audio/live_meeting.rs
+//
+// Writes a recording's transcript to the database while it is being recorded.
+// The meeting row is created when the recording starts and each transcript
+// update is saved as it arrives, so a UI crash or app kill loses nothing.
+
+use crate::api::TranscriptSegment;
+use crate::database::models::MEETING_STATUS_COMPLETED;
+use crate::database::repositories::{
+    meeting::MeetingsRepository, transcript::TranscriptsRepository,
+};
+use log::{error, info, warn};
+use sqlx::SqlitePool;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::transcription::{segment_confidence, TranscriptUpdate};
+
+/// The database meeting of the recording in progress
+pub struct LiveMeetingWriter {
+    meeting_id: String,
+    pool: SqlitePool,
+    sender: mpsc::UnboundedSender<TranscriptSegment>,
+    task: JoinHandle<()>,
+}
+
+impl LiveMeetingWriter {
+    /// Create the meeting in the "recording" status and start the task that saves its segments
+    pub async fn start(
+        pool: SqlitePool,
+        title: &str,
+        folder_path: Option<String>,
+    ) -> Result<Self, String> {
+        let meeting_id = TranscriptsRepository::create_live_meeting(&pool, title, folder_path)
+            .await
+            .map_err(|e| format!("Failed to create meeting: {}", e))?;
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let task_pool = pool.clone();
+        let task_meeting_id = meeting_id.clone();
+
+        // Saved one at a time, in arrival order, so a revised segment never loses to the original
+        let task = tokio::spawn(async move {
+            while let Some(segment) = receiver.recv().await {
+                if let Err(e) =
+                    TranscriptsRepository::upsert_segment(&task_pool, &task_meeting_id, &segment)
+                        .await
+                {
+                    error!("Failed to save live segment {}: {}", segment.id, e);
+                }
+            }
+        });
+
+        info!("📝 Live meeting {} created for '{}'", meeting_id, title);
+        Ok(Self {
+            meeting_id,
+            pool,
+            sender,
+            task,
+        })
+    }
+
+    pub fn meeting_id(&self) -> &str {
+        &self.meeting_id
+    }
+
+    /// Queue a transcript update for saving. Partial results are skipped; the
+    /// final result for the same sequence id replaces them.
+    pub fn add_update(&self, update: &TranscriptUpdate) {
+        if update.is_partial {
+            return;
+        }
+        let segment = segment_from_update(&self.meeting_id, update);
+        if self.sender.send(segment).is_err() {
+            warn!(
+                "Live meeting writer already finished, dropping segment {}",
+                update.sequence_id
+            );
+        }
+    }
+
+    /// Wait for the queued segments to be saved and mark the meeting completed
+    pub async fn finish(self) -> String {
+        let Self {
+            meeting_id,
+            pool,
+            sender,
+            task,
+        } = self;
+
+        // Closing the channel ends the task once it has saved what is queued
+        drop(sender);
+        let _ = task.await;
+
+        match MeetingsRepository::set_meeting_status(&pool, &meeting_id, MEETING_STATUS_COMPLETED)
+            .await
+        {
+            Ok(true) => info!("✅ Live meeting {} completed", meeting_id),
+            Ok(false) => warn!("Live meeting {} was deleted during recording", meeting_id),
+            Err(e) => error!("Failed to complete live meeting {}: {}", meeting_id, e),
+        }
+        meeting_id
+    }
+}
+
+/// Id of a live meeting's segment. Stable per sequence id, so later revisions
+/// of a segment overwrite it.
+pub fn segment_id(meeting_id: &str, sequence_id: u64) -> String {
+    format!("{}-seg-{}", meeting_id, sequence_id)
+}
+
+fn segment_from_update(meeting_id: &str, update: &TranscriptUpdate) -> TranscriptSegment {
+    TranscriptSegment {
+        id: segment_id(meeting_id, update.sequence_id),
+        text: update.text.clone(),
+        timestamp: update.timestamp.clone(),
+        audio_start_time: Some(update.audio_start_time),
+        audio_end_time: Some(update.audio_end_time),
+        duration: Some(update.duration),
+        words: update.words.clone(),
+        speaker: update.speaker.clone(),
+        source: Some(update.source.clone()),
+        // update.confidence holds a placeholder when the words aren't scored
+        confidence: segment_confidence(&update.words),
+    }
+}
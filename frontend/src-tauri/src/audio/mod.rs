@@ -3,6 +3,16 @@ pub mod audio_processing;
 pub mod encode;
 pub mod ffmpeg;
 pub mod vad;
+pub mod import;  // Import of existing audio/video files as meetings
+pub mod import_commands;
+pub mod recovery;  // Recovery of recordings interrupted by a crash
+pub mod recovery_commands;
+pub mod library_scan;  // Rebuilding the library from the meeting folders on disk
+pub mod library_scan_commands;
+pub mod retranscribe;  // Re-transcription of saved meetings from their audio
+pub mod retranscribe_commands;
+pub mod refine;  // Refinement pass over a recording after it stops
+pub mod refine_commands;
 
 // Modularized device management
 pub mod devices;
@@ -22,6 +32,8 @@ pub mod recording_manager;
 pub mod recording_commands;
 pub mod recording_preferences;
 pub mod recording_saver;
+pub mod live_meeting;  // Transcript written to the database while recording
+pub mod source_attribution;  // NEW: Me vs. Others attribution from separate mic/system streams
 pub mod incremental_saver;  // NEW: Incremental audio saving with checkpoints
 pub mod level_monitor;
 pub mod simple_level_monitor;
@@ -11,7 +11,8 @@ use rubato::{Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolat
 use super::devices::AudioDevice;
 use super::recording_state::{AudioChunk, AudioError, RecordingState, DeviceType};
 use super::audio_processing::{audio_to_mono, LoudnessNormalizer, NoiseSuppressionProcessor, HighPassFilter};
-use super::vad::{ContinuousVadProcessor};
+use super::vad::{ContinuousVadProcessor, VadSettings};
+use super::source_attribution::SourceActivityTracker;
 
 /// Ring buffer for synchronized audio mixing
 /// Accumulates samples from mic and system streams until we have aligned windows
@@ -613,6 +614,8 @@ impl AudioCapture {
             timestamp,
             chunk_id,
             device_type: self.device_type.clone(),
+            source: None,
+            is_partial: false,
         };
 
         // NOTE: Raw audio is NOT sent to recording saver to prevent echo
@@ -694,6 +697,8 @@ pub struct AudioPipeline {
     mixer: ProfessionalAudioMixer,
     // Recording sender for pre-mixed audio
     recording_sender_for_mixed: Option<mpsc::UnboundedSender<AudioChunk>>,
+    // Mic vs. system energy timeline for "Me vs. Others" attribution
+    source_tracker: SourceActivityTracker,
 }
 
 impl AudioPipeline {
@@ -707,6 +712,7 @@ impl AudioPipeline {
         mic_device_kind: super::device_detection::InputDeviceKind,
         system_device_name: String,
         system_device_kind: super::device_detection::InputDeviceKind,
+        max_segment_ms: u32,
     ) -> Self {
         // Log device characteristics for adaptive buffering
         info!("🎛️ AudioPipeline initializing with device characteristics:");
@@ -726,7 +732,13 @@ impl AudioPipeline {
 
         let redemption_time = if cfg!(target_os = "macos") { 400 } else { 400 };
 
-        let vad_processor = match ContinuousVadProcessor::new(sample_rate, redemption_time) {
+        let vad_settings = VadSettings {
+            redemption_time_ms: redemption_time,
+            max_segment_ms,
+            ..VadSettings::default()
+        };
+
+        let vad_processor = match ContinuousVadProcessor::with_settings(sample_rate, &vad_settings) {
             Ok(processor) => {
                 info!("VAD-driven pipeline: VAD segments will be sent directly to Whisper (no time-based accumulation)");
                 processor
@@ -760,6 +772,7 @@ impl AudioPipeline {
             ring_buffer,
             mixer,
             recording_sender_for_mixed: None,  // Will be set by manager
+            source_tracker: SourceActivityTracker::new(sample_rate),
         }
     }
 
@@ -822,6 +835,9 @@ impl AudioPipeline {
                     // STEP 2: Mix audio in fixed windows when both streams have sufficient data
                     while self.ring_buffer.can_mix() {
                         if let Some((mic_window, sys_window)) = self.ring_buffer.extract_window() {
+                            // Per-stream energy BEFORE mixing - used to tag VAD segments as local/remote/overlap
+                            self.source_tracker.push_window(&mic_window, &sys_window);
+
                             // Simple mixing without aggressive ducking
                             let mixed_clean = self.mixer.mix_window(&mic_window, &sys_window);
 
@@ -838,8 +854,9 @@ impl AudioPipeline {
                                         let duration_ms = segment.end_timestamp_ms - segment.start_timestamp_ms;
 
                                         if segment.samples.len() >= 800 {  // Minimum 50ms at 16kHz - matches Parakeet capability
-                                            info!("📤 Sending VAD segment: {:.1}ms, {} samples",
-                                                  duration_ms, segment.samples.len());
+                                            let source = self.source_tracker.classify(segment.start_timestamp_ms, segment.end_timestamp_ms);
+                                            info!("📤 Sending VAD segment: {:.1}ms, {} samples, source={}",
+                                                  duration_ms, segment.samples.len(), source);
 
                                             let transcription_chunk = AudioChunk {
                                                 data: segment.samples,
@@ -847,6 +864,8 @@ impl AudioPipeline {
                                                 timestamp: segment.start_timestamp_ms / 1000.0,
                                                 chunk_id: self.chunk_id_counter,
                                                 device_type: DeviceType::Microphone,  // Mixed audio
+                                                source: Some(source),
+                                                is_partial: false,
                                             };
 
                                             if let Err(e) = self.transcription_sender.send(transcription_chunk) {
@@ -865,6 +884,9 @@ impl AudioPipeline {
                                 }
                             }
 
+                            // STEP 3b: Send speech still in progress for a partial result
+                            self.send_partial_speech();
+
                             // STEP 4: Send mixed audio for recording (WAV file)
                             if let Some(ref sender) = self.recording_sender_for_mixed {
                                 let recording_chunk = AudioChunk {
@@ -873,6 +895,8 @@ impl AudioPipeline {
                                     timestamp: chunk.timestamp,
                                     chunk_id: self.chunk_id_counter,
                                     device_type: DeviceType::Microphone,  // Mixed audio
+                                    source: None,
+                                    is_partial: false,
                                 };
                                 let _ = sender.send(recording_chunk);
                             }
@@ -897,6 +921,34 @@ impl AudioPipeline {
         Ok(())
     }
 
+    /// Send the speech in progress for a partial transcript, when it is due.
+    /// Its complete VAD segment follows later and replaces the partial result.
+    fn send_partial_speech(&mut self) {
+        let Some(segment) = self.vad_processor.take_partial_speech() else {
+            return;
+        };
+
+        let source = self.source_tracker.classify(segment.start_timestamp_ms, segment.end_timestamp_ms);
+        debug!("📤 Sending partial speech: {:.1}ms, {} samples, source={}",
+               segment.end_timestamp_ms - segment.start_timestamp_ms, segment.samples.len(), source);
+
+        let transcription_chunk = AudioChunk {
+            data: segment.samples,
+            sample_rate: 16000,
+            timestamp: segment.start_timestamp_ms / 1000.0,
+            chunk_id: self.chunk_id_counter,
+            device_type: DeviceType::Microphone,  // Mixed audio
+            source: Some(source),
+            is_partial: true,
+        };
+
+        if let Err(e) = self.transcription_sender.send(transcription_chunk) {
+            warn!("Failed to send partial speech: {}", e);
+        } else {
+            self.chunk_id_counter += 1;
+        }
+    }
+
     fn flush_remaining_audio(&mut self) -> Result<()> {
         info!("Flushing remaining audio from pipeline (processed {} chunks)", self.processed_chunks);
 
@@ -908,8 +960,9 @@ impl AudioPipeline {
 
                     // Send segments >= 50ms (800 samples at 16kHz) - matches main pipeline filter
                     if segment.samples.len() >= 800 {
-                        info!("📤 Sending final VAD segment to Whisper: {:.1}ms duration, {} samples",
-                              duration_ms, segment.samples.len());
+                        let source = self.source_tracker.classify(segment.start_timestamp_ms, segment.end_timestamp_ms);
+                        info!("📤 Sending final VAD segment to Whisper: {:.1}ms duration, {} samples, source={}",
+                              duration_ms, segment.samples.len(), source);
 
                         let transcription_chunk = AudioChunk {
                             data: segment.samples,
@@ -917,6 +970,8 @@ impl AudioPipeline {
                             timestamp: segment.start_timestamp_ms / 1000.0,
                             chunk_id: self.chunk_id_counter,
                             device_type: DeviceType::Microphone,
+                            source: Some(source),
+                            is_partial: false,
                         };
 
                         if let Err(e) = self.transcription_sender.send(transcription_chunk) {
@@ -966,6 +1021,7 @@ impl AudioPipelineManager {
         mic_device_kind: super::device_detection::InputDeviceKind,
         system_device_name: String,
         system_device_kind: super::device_detection::InputDeviceKind,
+        max_segment_ms: u32,
     ) -> Result<()> {
         // Log device information for adaptive buffering
         info!("🎙️ Starting pipeline with device info:");
@@ -989,6 +1045,7 @@ impl AudioPipelineManager {
             mic_device_kind,
             system_device_name,
             system_device_kind,
+            max_segment_ms,
         );
 
         // CRITICAL FIX: Connect recording sender to receive pre-mixed audio
@@ -1039,6 +1096,8 @@ impl AudioPipelineManager {
                 timestamp: 0.0,
                 chunk_id: u64::MAX, // Special ID to indicate flush
                 device_type: super::recording_state::DeviceType::Microphone,
+                source: None,
+                is_partial: false,
             };
 
             if let Err(e) = sender.send(flush_chunk) {
@@ -1059,6 +1118,8 @@ impl AudioPipelineManager {
                         timestamp: 0.0,
                         chunk_id: u64::MAX - (i as u64),
                         device_type: super::recording_state::DeviceType::Microphone,
+                        source: None,
+                        is_partial: false,
                     };
                     let _ = sender.send(additional_flush);
                 }
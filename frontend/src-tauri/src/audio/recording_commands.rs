@@ -10,10 +10,12 @@ use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc, Mutex,
 };
-use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tauri::{AppHandle, Emitter, EventId, Listener, Manager, Runtime};
 use tokio::task::JoinHandle;
 
-use super::{parse_audio_device, RecordingManager, DeviceEvent, DeviceMonitorType};
+use super::live_meeting::LiveMeetingWriter;
+use super::{parse_audio_device, recording_preferences, RecordingManager, DeviceEvent, DeviceMonitorType};
+use crate::state::AppState;
 
 // Import transcription modules
 use super::transcription::{
@@ -35,6 +37,10 @@ static IS_RECORDING: AtomicBool = AtomicBool::new(false);
 static RECORDING_MANAGER: Mutex<Option<RecordingManager>> = Mutex::new(None);
 static TRANSCRIPTION_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
 
+// Database meeting of the recording in progress, and the listener feeding it
+static LIVE_MEETING: Mutex<Option<LiveMeetingWriter>> = Mutex::new(None);
+static TRANSCRIPT_LISTENER: Mutex<Option<EventId>> = Mutex::new(None);
+
 // ============================================================================
 // PUBLIC TYPES
 // ============================================================================
@@ -109,6 +115,7 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
         )
     });
     manager.set_meeting_name(Some(effective_meeting_name));
+    manager.set_max_segment_ms(recording_preferences::load_max_segment_ms(&app));
 
     // Set up error callback
     let app_for_error = app.clone();
@@ -122,6 +129,9 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
         .await
         .map_err(|e| format!("Failed to start recording: {}", e))?;
 
+    // Create the meeting in the database so transcripts are saved as they arrive
+    start_live_meeting(&app, &mut manager).await;
+
     // Store the manager globally to keep it alive
     {
         let mut global_manager = RECORDING_MANAGER.lock().unwrap();
@@ -140,38 +150,8 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
         *global_task = Some(task_handle);
     }
 
-    // CRITICAL: Listen for transcript-update events and save to recording manager
-    // This enables transcript history persistence for page reload sync
-    let app_for_listener = app.clone();
-    tokio::spawn(async move {
-        use tauri::Listener;
-
-        app_for_listener.listen("transcript-update", move |event: tauri::Event| {
-            // Parse the transcript update from the event payload
-            if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
-                // Create structured transcript segment
-                let segment = crate::audio::recording_saver::TranscriptSegment {
-                    id: format!("seg_{}", update.sequence_id),
-                    text: update.text.clone(),
-                    audio_start_time: update.audio_start_time,
-                    audio_end_time: update.audio_end_time,
-                    duration: update.duration,
-                    display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
-                    confidence: update.confidence,
-                    sequence_id: update.sequence_id,
-                };
-
-                // Save to recording manager
-                if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
-                    if let Some(manager) = manager_guard.as_ref() {
-                        manager.add_transcript_segment(segment);
-                    }
-                }
-            }
-        });
-
-        info!("✅ Transcript-update event listener registered for history persistence");
-    });
+    // CRITICAL: Save transcript updates to the recording manager and the live meeting
+    listen_for_transcript_updates(&app);
 
     // Emit success event
     app.emit("recording-started", serde_json::json!({
@@ -264,6 +244,7 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
         )
     });
     manager.set_meeting_name(Some(effective_meeting_name));
+    manager.set_max_segment_ms(recording_preferences::load_max_segment_ms(&app));
 
     // Set up error callback
     let app_for_error = app.clone();
@@ -277,6 +258,9 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
         .await
         .map_err(|e| format!("Failed to start recording: {}", e))?;
 
+    // Create the meeting in the database so transcripts are saved as they arrive
+    start_live_meeting(&app, &mut manager).await;
+
     // Store the manager globally to keep it alive
     {
         let mut global_manager = RECORDING_MANAGER.lock().unwrap();
@@ -295,38 +279,8 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
         *global_task = Some(task_handle);
     }
 
-    // CRITICAL: Listen for transcript-update events and save to recording manager
-    // This enables transcript history persistence for page reload sync
-    let app_for_listener = app.clone();
-    tokio::spawn(async move {
-        use tauri::Listener;
-
-        app_for_listener.listen("transcript-update", move |event: tauri::Event| {
-            // Parse the transcript update from the event payload
-            if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
-                // Create structured transcript segment
-                let segment = crate::audio::recording_saver::TranscriptSegment {
-                    id: format!("seg_{}", update.sequence_id),
-                    text: update.text.clone(),
-                    audio_start_time: update.audio_start_time,
-                    audio_end_time: update.audio_end_time,
-                    duration: update.duration,
-                    display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
-                    confidence: update.confidence,
-                    sequence_id: update.sequence_id,
-                };
-
-                // Save to recording manager
-                if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
-                    if let Some(manager) = manager_guard.as_ref() {
-                        manager.add_transcript_segment(segment);
-                    }
-                }
-            }
-        });
-
-        info!("✅ Transcript-update event listener registered for history persistence");
-    });
+    // CRITICAL: Save transcript updates to the recording manager and the live meeting
+    listen_for_transcript_updates(&app);
 
     // Emit success event
     app.emit("recording-started", serde_json::json!({
@@ -460,6 +414,9 @@ pub async fn stop_recording<R: Runtime>(
         info!("ℹ️ No transcription task found to wait for");
     }
 
+    // Every transcript update has been emitted; stop feeding the manager and the live meeting
+    stop_listening_for_transcript_updates(&app);
+
     // Step 3: Now safely unload Whisper model after ALL chunks are processed
     let _ = app.emit(
         "recording-shutdown-progress",
@@ -638,13 +595,18 @@ pub async fn stop_recording<R: Runtime>(
         (None, None)
     };
 
+    // Wait for the live meeting's last segments to be saved and mark it completed
+    let live_meeting = LIVE_MEETING.lock().unwrap().take();
+    let meeting_id = match live_meeting {
+        Some(writer) => Some(writer.finish().await),
+        None => None,
+    };
+
     // Set recording flag to false
     info!("🔍 Setting IS_RECORDING to false");
     IS_RECORDING.store(false, Ordering::SeqCst);
 
-    // Step 4.5: Prepare metadata for frontend (NO database save)
-    // NOTE: We do NOT save to database here. The frontend will save after all transcripts are displayed.
-    // This ensures the user sees all transcripts streaming in before the database save happens.
+    // Step 4.5: Prepare metadata for frontend
     let (folder_path_str, meeting_name_str) = match (&meeting_folder, &meeting_name) {
         (Some(path), Some(name)) => (
             Some(path.to_string_lossy().to_string()),
@@ -656,9 +618,9 @@ pub async fn stop_recording<R: Runtime>(
     info!("📤 Preparing recording metadata for frontend save");
     info!("   folder_path: {:?}", folder_path_str);
     info!("   meeting_name: {:?}", meeting_name_str);
-
-    // Database save removed - frontend will handle this after receiving all transcripts
-    info!("ℹ️ Skipping database save in Rust - frontend will save after all transcripts received");
+    // The transcript was saved to the live meeting as it arrived; without one (database
+    // unavailable at start) the frontend saves it after receiving all transcripts
+    info!("   meeting_id: {:?}", meeting_id);
 
     // Step 5: Complete shutdown
     let _ = app.emit(
@@ -670,13 +632,14 @@ pub async fn stop_recording<R: Runtime>(
         }),
     );
 
-    // Emit final stop event with folder_path and meeting_name for frontend to save
+    // Emit final stop event with folder_path, meeting_name and the live meeting's id
     app.emit(
         "recording-stopped",
         serde_json::json!({
-            "message": "Recording stopped - frontend will save after all transcripts received",
+            "message": "Recording stopped",
             "folder_path": folder_path_str,
-            "meeting_name": meeting_name_str
+            "meeting_name": meeting_name_str,
+            "meeting_id": meeting_id
         }),
     )
     .map_err(|e| e.to_string())?;
@@ -684,10 +647,95 @@ pub async fn stop_recording<R: Runtime>(
     // Update tray menu to reflect stopped state
     crate::tray::update_tray_menu(&app);
 
+    // The audio is saved by now; a second, more accurate pass may refine the live transcript
+    if let Some(meeting_id) = meeting_id {
+        super::refine_commands::refine_after_recording(&app, meeting_id);
+    }
+
     info!("🎉 Recording stopped successfully with ZERO transcript chunks lost");
     Ok(())
 }
 
+/// Create the recording's meeting in the database and note its id in metadata.json.
+/// On failure the recording continues and the frontend saves the transcript at stop.
+async fn start_live_meeting<R: Runtime>(app: &AppHandle<R>, manager: &mut RecordingManager) {
+    let Some(state) = app.try_state::<AppState>() else {
+        warn!("Database not ready - transcript will be saved when the recording stops");
+        return;
+    };
+    let title = manager
+        .get_meeting_name()
+        .unwrap_or_else(|| "New Meeting".to_string());
+    let folder_path = manager
+        .get_meeting_folder()
+        .map(|path| path.to_string_lossy().to_string());
+
+    match LiveMeetingWriter::start(state.db_manager.pool().clone(), &title, folder_path).await {
+        Ok(writer) => {
+            manager.set_meeting_id(writer.meeting_id().to_string());
+            *LIVE_MEETING.lock().unwrap() = Some(writer);
+        }
+        Err(e) => warn!("⚠️ {} - transcript will be saved when the recording stops", e),
+    }
+}
+
+/// Save transcript-update events to the recording manager (transcript history for
+/// page reload sync and transcripts.json) and to the live meeting
+fn listen_for_transcript_updates<R: Runtime>(app: &AppHandle<R>) {
+    let listener = app.listen("transcript-update", move |event: tauri::Event| {
+        // Parse the transcript update from the event payload
+        let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) else {
+            return;
+        };
+
+        // Only final results are saved. A partial result is replaced by the final
+        // one with the same sequence id, or withdrawn with an empty final one.
+        if update.is_partial || update.text.trim().is_empty() {
+            return;
+        }
+
+        if let Ok(writer_guard) = LIVE_MEETING.lock() {
+            if let Some(writer) = writer_guard.as_ref() {
+                writer.add_update(&update);
+            }
+        }
+
+        // Create structured transcript segment
+        let segment = crate::audio::recording_saver::TranscriptSegment {
+            id: format!("seg_{}", update.sequence_id),
+            text: update.text,
+            audio_start_time: update.audio_start_time,
+            audio_end_time: update.audio_end_time,
+            duration: update.duration,
+            display_time: update.timestamp, // Use wall-clock timestamp for display
+            confidence: update.confidence,
+            sequence_id: update.sequence_id,
+            words: update.words,
+            speaker: update.speaker,
+            source: Some(update.source),
+        };
+
+        // Save to recording manager
+        if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
+            if let Some(manager) = manager_guard.as_ref() {
+                manager.add_transcript_segment(segment);
+            }
+        }
+    });
+
+    // A listener left over from a previous recording would save into this one twice
+    if let Some(previous) = TRANSCRIPT_LISTENER.lock().unwrap().replace(listener) {
+        app.unlisten(previous);
+    }
+    info!("✅ Transcript-update event listener registered for history persistence");
+}
+
+fn stop_listening_for_transcript_updates<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(listener) = TRANSCRIPT_LISTENER.lock().unwrap().take() {
+        app.unlisten(listener);
+    }
+}
+
 /// Check if recording is active
 pub async fn is_recording() -> bool {
     IS_RECORDING.load(Ordering::SeqCst)
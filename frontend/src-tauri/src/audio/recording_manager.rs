@@ -15,6 +15,7 @@ use super::pipeline::AudioPipelineManager;
 use super::stream::AudioStreamManager;
 use super::recording_saver::RecordingSaver;
 use super::device_monitor::{AudioDeviceMonitor, DeviceEvent, DeviceMonitorType};
+use super::vad::VadSettings;
 
 /// Stream manager type enumeration
 pub enum StreamManagerType {
@@ -29,6 +30,8 @@ pub struct RecordingManager {
     recording_saver: RecordingSaver,
     device_monitor: Option<AudioDeviceMonitor>,
     device_event_receiver: Option<mpsc::UnboundedReceiver<DeviceEvent>>,
+    // Longest speech sent for transcription as one segment
+    max_segment_ms: u32,
 }
 
 // SAFETY: RecordingManager contains types that we've marked as Send
@@ -49,6 +52,7 @@ impl RecordingManager {
             recording_saver: RecordingSaver::new(),
             device_monitor: Some(device_monitor),
             device_event_receiver: Some(device_event_receiver),
+            max_segment_ms: VadSettings::default().max_segment_ms,
         }
     }
 
@@ -109,6 +113,7 @@ impl RecordingManager {
             mic_kind,
             sys_name,
             sys_kind,
+            self.max_segment_ms,
         )?;
 
         // Give the pipeline a moment to fully initialize before starting streams
@@ -413,6 +418,16 @@ impl RecordingManager {
         self.recording_saver.set_meeting_name(name);
     }
 
+    /// Set the longest speech sent for transcription as one segment, before recording starts
+    pub fn set_max_segment_ms(&mut self, max_segment_ms: u32) {
+        self.max_segment_ms = max_segment_ms;
+    }
+
+    /// Set the database meeting this recording is saved to
+    pub fn set_meeting_id(&mut self, meeting_id: String) {
+        self.recording_saver.set_meeting_id(meeting_id);
+    }
+
     /// Add a structured transcript segment to be saved later
     pub fn add_transcript_segment(&self, segment: super::recording_saver::TranscriptSegment) {
         self.recording_saver.add_transcript_segment(segment);
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tauri::{AppHandle, Runtime};
 use log::{info, warn};
+use tauri_plugin_store::StoreExt;
 
 #[cfg(target_os = "macos")]
 use log::error;
@@ -9,6 +10,11 @@ use anyhow::Result;
 
 #[cfg(target_os = "macos")]
 use crate::audio::capture::AudioCaptureBackend;
+use crate::audio::vad::VadSettings;
+
+/// Store and key of the live transcription's maximum segment length, set by the frontend
+const PREFERENCES_STORE: &str = "preferences.json";
+const MAX_SEGMENT_PREFERENCE_KEY: &str = "max_segment_ms";
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RecordingPreferences {
@@ -86,6 +92,27 @@ pub fn generate_recording_filename(format: &str) -> String {
 }
 
 
+/// Longest speech the live transcription sends as one segment before splitting it, in ms.
+/// The VAD default if unset or out of range.
+pub fn load_max_segment_ms<R: Runtime>(app: &AppHandle<R>) -> u32 {
+    let default = VadSettings::default().max_segment_ms;
+    let max_segment_ms = app
+        .store(PREFERENCES_STORE)
+        .ok()
+        .and_then(|store| store.get(MAX_SEGMENT_PREFERENCE_KEY))
+        .and_then(|value| value.as_u64())
+        .map_or(default, |ms| ms.min(u32::MAX as u64) as u32);
+
+    let settings = VadSettings { max_segment_ms, ..VadSettings::default() };
+    match settings.validate() {
+        Ok(()) => max_segment_ms,
+        Err(e) => {
+            warn!("Ignoring saved maximum segment length: {}", e);
+            default
+        }
+    }
+}
+
 /// Load recording preferences from store
 pub async fn load_recording_preferences<R: Runtime>(
     _app: &AppHandle<R>,
@@ -11,6 +11,7 @@ use super::recording_state::AudioChunk;
 use super::recording_preferences::load_recording_preferences;
 use super::audio_processing::create_meeting_folder;
 use super::incremental_saver::IncrementalAudioSaver;
+use super::transcription::WordTimestamp;
 
 /// Structured transcript segment for JSON export
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +24,12 @@ pub struct TranscriptSegment {
     pub display_time: String,   // Formatted time for display like "[02:15]"
     pub confidence: f32,
     pub sequence_id: u64,
+    #[serde(default)]
+    pub words: Vec<WordTimestamp>, // Recording-relative word timing for playback highlighting
+    #[serde(default)]
+    pub speaker: Option<String>, // Diarization speaker ID, if identified
+    #[serde(default)]
+    pub source: Option<String>,  // "local" | "remote" | "overlap" from mic/system energy
 }
 
 /// Meeting metadata structure
@@ -92,6 +99,20 @@ impl RecordingSaver {
         }
     }
 
+    /// Record the database meeting this recording is saved to in metadata.json
+    pub fn set_meeting_id(&mut self, meeting_id: String) {
+        if let Some(ref mut metadata) = self.metadata {
+            metadata.meeting_id = Some(meeting_id);
+
+            if let Some(folder) = &self.meeting_folder {
+                let metadata_clone = metadata.clone();
+                if let Err(e) = self.write_metadata(folder, &metadata_clone) {
+                    warn!("Failed to update metadata with meeting id: {}", e);
+                }
+            }
+        }
+    }
+
     /// Add or update a structured transcript segment (upserts based on sequence_id)
     /// Also saves incrementally to disk
     pub fn add_transcript_segment(&self, segment: TranscriptSegment) {
@@ -130,6 +151,9 @@ impl RecordingSaver {
             display_time: "[00:00]".to_string(),
             confidence: 1.0,
             sequence_id: 0,
+            words: Vec::new(),
+            speaker: None,
+            source: None,
         };
         self.add_transcript_segment(segment);
     }
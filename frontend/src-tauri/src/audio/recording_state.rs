@@ -6,6 +6,7 @@ use anyhow::Result;
 
 use super::devices::AudioDevice;
 use super::buffer_pool::AudioBufferPool;
+use super::source_attribution::SpeechSource;
 
 /// Device type for audio chunks
 #[derive(Debug, Clone, PartialEq)]
@@ -22,6 +23,8 @@ pub struct AudioChunk {
     pub timestamp: f64,
     pub chunk_id: u64,
     pub device_type: DeviceType,
+    pub source: Option<SpeechSource>, // Mic/system attribution for VAD segments (None for raw capture chunks)
+    pub is_partial: bool, // Speech still in progress, sent for a partial result (false for complete VAD segments)
 }
 
 /// Processed audio chunk (post-VAD) for recording
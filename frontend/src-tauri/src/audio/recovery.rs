@@ -0,0 +1,472 @@
+// audio/recovery.rs
+//
+// Recovery of recordings interrupted by a crash or power loss. Until a
+// recording is stopped, its meeting folder has `metadata.json` in the
+// "recording" status and its audio only as 30s checkpoints in `.checkpoints/`.
+// Recovery merges the checkpoints into `audio.mp4`, transcribes the audio after
+// the last segment saved in `transcripts.json` and completes the meeting the
+// recording created in the database (or saves a new one for recordings made
+// before meetings were created at start).
+
+use super::import::{self, ImportStage, TranscribeOutcome};
+use super::incremental_saver::{count_checkpoints, IncrementalAudioSaver};
+use super::live_meeting;
+use super::recording_saver::{self, MeetingMetadata};
+use super::transcription::{segment_confidence, TranscriptionEngine};
+use crate::api::TranscriptSegment;
+use crate::database::models::MEETING_STATUS_COMPLETED;
+use crate::database::repositories::{
+    meeting::MeetingsRepository, transcript::TranscriptsRepository,
+};
+use crate::summary::streaming::StreamCancellation;
+use crate::utils::format_timestamp;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Status of a recording's `metadata.json` until it is stopped
+pub(super) const STATUS_RECORDING: &str = "recording";
+
+/// A meeting folder left behind by a recording that was never stopped
+#[derive(Debug, Clone, Serialize)]
+pub struct InterruptedRecording {
+    pub folder_path: String,
+    pub meeting_name: Option<String>,
+    pub created_at: String,
+    /// Audio saved in checkpoints, 30s each (the last one may be shorter)
+    pub checkpoint_count: u32,
+    /// Segments already transcribed during the recording
+    pub transcript_segments: usize,
+}
+
+#[derive(Deserialize)]
+struct TranscriptsFile {
+    #[serde(default)]
+    segments: Vec<recording_saver::TranscriptSegment>,
+}
+
+/// Find interrupted recordings in the meeting folders under `base_folders`,
+/// oldest first. `active_folder` is the folder of a recording in progress.
+pub fn find_interrupted_recordings(
+    base_folders: &[PathBuf],
+    active_folder: Option<&Path>,
+) -> Vec<InterruptedRecording> {
+    let mut recordings = Vec::new();
+
+    for base_folder in dedup_folders(base_folders) {
+        let Ok(entries) = std::fs::read_dir(&base_folder) else {
+            continue;
+        };
+        for folder in entries.flatten().map(|entry| entry.path()) {
+            if !folder.is_dir() || active_folder.is_some_and(|active| same_folder(active, &folder))
+            {
+                continue;
+            }
+            let Some(metadata) = read_metadata(&folder) else {
+                continue;
+            };
+            if metadata.status != STATUS_RECORDING {
+                continue;
+            }
+
+            let checkpoint_count = count_checkpoints(&folder);
+            let transcript_segments = read_transcript_segments(&folder).len();
+            let has_audio = checkpoint_count > 0 || folder.join(&metadata.audio_file).is_file();
+            if !has_audio && transcript_segments == 0 {
+                // Stopped before anything was saved
+                continue;
+            }
+
+            recordings.push(InterruptedRecording {
+                folder_path: folder.to_string_lossy().to_string(),
+                meeting_name: metadata.meeting_name,
+                created_at: metadata.created_at,
+                checkpoint_count,
+                transcript_segments,
+            });
+        }
+    }
+
+    recordings.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    recordings
+}
+
+/// Recover an interrupted recording as a new meeting.
+///
+/// Without `engine` the meeting keeps only the segments transcribed during the
+/// recording. Returns the meeting's id, or `None` if cancelled.
+pub async fn recover_recording(
+    pool: &SqlitePool,
+    folder: &Path,
+    engine: Option<&TranscriptionEngine>,
+    language: Option<String>,
+    cancellation: Arc<StreamCancellation>,
+) -> Result<Option<String>, String> {
+    let mut metadata = read_metadata(folder)
+        .filter(|metadata| metadata.status == STATUS_RECORDING)
+        .ok_or_else(|| format!("{} is not an interrupted recording", folder.display()))?;
+
+    // The meeting created when the recording started, unless it was deleted since
+    let live_meeting_id = match metadata.meeting_id.as_deref() {
+        Some(meeting_id) => MeetingsRepository::get_meeting_model(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to get meeting: {}", e))?
+            .map(|meeting| meeting.id),
+        None => None,
+    };
+
+    // Without one, an earlier recovery may have saved the meeting but failed to
+    // update metadata.json; finish that one rather than saving it twice
+    if live_meeting_id.is_none() {
+        let folder_path = folder.to_string_lossy().to_string();
+        if let Some(meeting) = MeetingsRepository::get_meeting_model_by_folder(pool, &folder_path)
+            .await
+            .map_err(|e| format!("Failed to get meeting: {}", e))?
+        {
+            info!("{} was recovered already as meeting {}", folder.display(), meeting.id);
+            let duration_seconds = metadata.duration_seconds.unwrap_or_default();
+            complete_metadata(folder, &mut metadata, &meeting.id, duration_seconds)?;
+            return Ok(Some(meeting.id));
+        }
+    }
+
+    let audio = merge_checkpoints(folder, &metadata).await?;
+
+    // Live segments are keyed by sequence id; saving them again under the same
+    // ids fills in any the database missed without duplicating the rest
+    let mut segments: Vec<TranscriptSegment> = read_transcript_segments(folder)
+        .into_iter()
+        .map(|segment| {
+            let sequence_id = segment.sequence_id;
+            let mut segment = to_meeting_segment(segment);
+            if let Some(meeting_id) = &live_meeting_id {
+                segment.id = live_meeting::segment_id(meeting_id, sequence_id);
+            }
+            segment
+        })
+        .collect();
+    let transcribed_until = segments
+        .iter()
+        .filter_map(|segment| segment.audio_end_time)
+        .fold(0.0, f64::max);
+    let mut duration_seconds = transcribed_until;
+
+    match (&audio, engine) {
+        (Some(audio), Some(engine)) => {
+            info!(
+                "Transcribing {} after {:.1}s",
+                audio.display(),
+                transcribed_until
+            );
+            let transcribed = match import::transcribe_file_after(
+                engine,
+                audio,
+                transcribed_until,
+                language,
+                cancellation,
+                Arc::new(|_: ImportStage, _: f32| {}),
+            )
+            .await?
+            {
+                TranscribeOutcome::Completed(transcribed) => transcribed,
+                TranscribeOutcome::Cancelled => return Ok(None),
+            };
+            info!(
+                "Transcribed {} segments the recording missed",
+                transcribed.segments.len()
+            );
+            duration_seconds = duration_seconds.max(transcribed.duration_seconds);
+            segments.extend(transcribed.segments.into_iter().enumerate().map(
+                |(index, mut segment)| {
+                    if let Some(meeting_id) = &live_meeting_id {
+                        segment.id = format!("{}-recovered-{}", meeting_id, index);
+                    }
+                    segment
+                },
+            ));
+        }
+        (Some(_), None) => {
+            warn!("No transcription model available, recovering only the saved transcript")
+        }
+        (None, _) => {}
+    }
+
+    if segments.is_empty() {
+        return Err("The recording has no speech to recover".to_string());
+    }
+
+    let title = metadata
+        .meeting_name
+        .clone()
+        .filter(|name| !name.trim().is_empty())
+        .or_else(|| {
+            folder
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| "Recovered recording".to_string());
+    let meeting_id = match live_meeting_id {
+        Some(meeting_id) => {
+            for segment in &segments {
+                TranscriptsRepository::upsert_segment(pool, &meeting_id, segment)
+                    .await
+                    .map_err(|e| format!("Failed to save transcript: {}", e))?;
+            }
+            MeetingsRepository::set_meeting_status(pool, &meeting_id, MEETING_STATUS_COMPLETED)
+                .await
+                .map_err(|e| format!("Failed to complete meeting: {}", e))?;
+            meeting_id
+        }
+        None => TranscriptsRepository::save_transcript(
+            pool,
+            &title,
+            &segments,
+            Some(folder.to_string_lossy().to_string()),
+        )
+        .await
+        .map_err(|e| format!("Failed to save transcript: {}", e))?,
+    };
+
+    // The meeting is saved either way
+    if let Err(e) = import::write_transcripts_json(folder, &segments) {
+        warn!(
+            "Failed to update transcripts.json in {}: {}",
+            folder.display(),
+            e
+        );
+    }
+    // If this fails the folder is offered again, and recovering it again finds
+    // the meeting saved here by its id or folder
+    if let Err(e) = complete_metadata(folder, &mut metadata, &meeting_id, duration_seconds) {
+        warn!("{}", e);
+    }
+
+    info!(
+        "✅ Recovered {} as meeting {} ({} segments)",
+        folder.display(),
+        meeting_id,
+        segments.len()
+    );
+    Ok(Some(meeting_id))
+}
+
+/// Mark a recovered recording completed in its `metadata.json`, as saved to `meeting_id`
+fn complete_metadata(
+    folder: &Path,
+    metadata: &mut MeetingMetadata,
+    meeting_id: &str,
+    duration_seconds: f64,
+) -> Result<(), String> {
+    metadata.meeting_id = Some(meeting_id.to_string());
+    metadata.completed_at = Some(chrono::Utc::now().to_rfc3339());
+    metadata.duration_seconds = Some(duration_seconds);
+    metadata.status = "completed".to_string();
+    write_metadata(folder, metadata)
+        .map_err(|e| format!("Failed to update metadata.json in {}: {}", folder.display(), e))
+}
+
+/// Stop offering a recording for recovery. Its files are kept. Returns the id
+/// of the meeting the recording created, which keeps the transcript saved
+/// before the interruption.
+pub fn dismiss_interrupted_recording(folder: &Path) -> Result<Option<String>, String> {
+    let mut metadata = read_metadata(folder)
+        .filter(|metadata| metadata.status == STATUS_RECORDING)
+        .ok_or_else(|| format!("{} is not an interrupted recording", folder.display()))?;
+    metadata.status = "error".to_string();
+    write_metadata(folder, &metadata)
+        .map_err(|e| format!("Failed to update metadata.json: {}", e))?;
+    Ok(metadata.meeting_id)
+}
+
+/// Merge the checkpoints into the recording's audio file. Returns the audio
+/// file, if the recording has any audio.
+async fn merge_checkpoints(
+    folder: &Path,
+    metadata: &MeetingMetadata,
+) -> Result<Option<PathBuf>, String> {
+    let audio_path = folder.join(&metadata.audio_file);
+    if count_checkpoints(folder) == 0 {
+        // Merged already, or cut off before the first checkpoint
+        return Ok(Some(audio_path).filter(|path| path.is_file()));
+    }
+
+    let mut saver = IncrementalAudioSaver::resume(folder.to_path_buf(), metadata.sample_rate)
+        .map_err(|e| format!("Failed to open checkpoints: {}", e))?;
+    let merged = match saver.finalize().await {
+        Ok(merged) => merged,
+        Err(e) if saver.get_checkpoint_count() > 1 => {
+            // The checkpoint being written at the time of the crash is likely incomplete
+            warn!(
+                "Merging checkpoints failed ({}), retrying without the last one",
+                e
+            );
+            saver
+                .discard_last_checkpoint()
+                .map_err(|e| format!("Failed to discard checkpoint: {}", e))?;
+            saver
+                .finalize()
+                .await
+                .map_err(|e| format!("Failed to merge checkpoints: {}", e))?
+        }
+        Err(e) => return Err(format!("Failed to merge checkpoints: {}", e)),
+    };
+
+    Ok(Some(merged))
+}
+
+pub(super) fn to_meeting_segment(segment: recording_saver::TranscriptSegment) -> TranscriptSegment {
+    TranscriptSegment {
+        id: segment.id,
+        text: segment.text,
+        timestamp: format_timestamp(segment.audio_start_time),
+        audio_start_time: Some(segment.audio_start_time),
+        audio_end_time: Some(segment.audio_end_time),
+        duration: Some(segment.duration),
+        words: segment.words,
+        confidence: segment_confidence(&segment.words),
+        speaker: segment.speaker,
+        source: segment.source,
+    }
+}
+
+pub(super) fn read_metadata(folder: &Path) -> Option<MeetingMetadata> {
+    let content = std::fs::read_to_string(folder.join("metadata.json")).ok()?;
+    serde_json::from_str(&content)
+        .map_err(|e| warn!("Invalid metadata.json in {}: {}", folder.display(), e))
+        .ok()
+}
+
+/// Segments of `transcripts.json`, none if it is missing or unreadable
+pub(super) fn read_transcript_segments(folder: &Path) -> Vec<recording_saver::TranscriptSegment> {
+    let Ok(content) = std::fs::read_to_string(folder.join("transcripts.json")) else {
+        return Vec::new();
+    };
+    match serde_json::from_str::<TranscriptsFile>(&content) {
+        Ok(file) => file
+            .segments
+            .into_iter()
+            .filter(|segment| !segment.text.trim().is_empty())
+            .collect(),
+        Err(e) => {
+            warn!("Invalid transcripts.json in {}: {}", folder.display(), e);
+            Vec::new()
+        }
+    }
+}
+
+/// Same atomic write as `RecordingSaver`
+pub(super) fn write_metadata(folder: &Path, metadata: &MeetingMetadata) -> anyhow::Result<()> {
+    let temp_path = folder.join(".metadata.json.tmp");
+    std::fs::write(&temp_path, serde_json::to_string_pretty(metadata)?)?;
+    std::fs::rename(&temp_path, folder.join("metadata.json"))?;
+    Ok(())
+}
+
+pub(super) fn same_folder(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+pub(super) fn dedup_folders(folders: &[PathBuf]) -> Vec<PathBuf> {
+    let mut unique: Vec<PathBuf> = Vec::new();
+    for folder in folders {
+        if !unique.iter().any(|existing| same_folder(existing, folder)) {
+            unique.push(folder.clone());
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use recording_saver::DeviceInfo;
+    use tempfile::tempdir;
+
+    fn write_meeting(base: &Path, name: &str, status: &str, segments: &[&str], checkpoints: u32) {
+        let folder = base.join(name);
+        std::fs::create_dir_all(folder.join(".checkpoints")).unwrap();
+        let metadata = MeetingMetadata {
+            version: "1.0".to_string(),
+            meeting_id: None,
+            meeting_name: Some(name.to_string()),
+            created_at: format!("2026-01-01T10:00:0{}Z", segments.len()),
+            completed_at: None,
+            duration_seconds: None,
+            devices: DeviceInfo {
+                microphone: None,
+                system_audio: None,
+            },
+            audio_file: "audio.mp4".to_string(),
+            transcript_file: "transcripts.json".to_string(),
+            sample_rate: 48000,
+            status: status.to_string(),
+        };
+        write_metadata(&folder, &metadata).unwrap();
+
+        let segments: Vec<_> = segments
+            .iter()
+            .enumerate()
+            .map(|(index, text)| recording_saver::TranscriptSegment {
+                id: format!("seg_{}", index),
+                text: text.to_string(),
+                audio_start_time: index as f64 * 5.0,
+                audio_end_time: index as f64 * 5.0 + 4.0,
+                duration: 4.0,
+                display_time: String::new(),
+                confidence: 0.9,
+                sequence_id: index as u64,
+                words: Vec::new(),
+                speaker: None,
+                source: None,
+            })
+            .collect();
+        let json = serde_json::json!({ "version": "1.0", "segments": segments });
+        std::fs::write(folder.join("transcripts.json"), json.to_string()).unwrap();
+
+        for index in 0..checkpoints {
+            let path = folder.join(format!(".checkpoints/audio_chunk_{:03}.mp4", index));
+            std::fs::write(path, b"checkpoint").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_find_interrupted_recordings() {
+        let base = tempdir().unwrap();
+        write_meeting(base.path(), "Crashed", "recording", &["Hello", "world"], 3);
+        write_meeting(base.path(), "Transcript only", "recording", &["Hi"], 0);
+        write_meeting(base.path(), "Finished", "completed", &["Done"], 0);
+        write_meeting(base.path(), "Nothing saved", "recording", &[], 0);
+        write_meeting(base.path(), "In progress", "recording", &["Live"], 1);
+        std::fs::create_dir_all(base.path().join("Not a meeting")).unwrap();
+
+        let active = base.path().join("In progress");
+        let base_folders = vec![base.path().to_path_buf(), base.path().to_path_buf()];
+        let found = find_interrupted_recordings(&base_folders, Some(&active));
+
+        let names: Vec<_> = found
+            .iter()
+            .map(|r| r.meeting_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Transcript only", "Crashed"]);
+        assert_eq!(found[1].checkpoint_count, 3);
+        assert_eq!(found[1].transcript_segments, 2);
+    }
+
+    #[test]
+    fn test_dismiss_interrupted_recording() {
+        let base = tempdir().unwrap();
+        write_meeting(base.path(), "Crashed", "recording", &["Hello"], 1);
+        let folder = base.path().join("Crashed");
+
+        dismiss_interrupted_recording(&folder).unwrap();
+        assert!(find_interrupted_recordings(&[base.path().to_path_buf()], None).is_empty());
+        // The audio is kept
+        assert_eq!(count_checkpoints(&folder), 1);
+        assert!(dismiss_interrupted_recording(&folder).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+// audio/recovery_commands.rs
+//
+// Tauri commands for recovering recordings interrupted by a crash.
+
+use super::recording_commands::{get_meeting_folder_path, is_recording};
+use super::recording_preferences::{get_default_recordings_folder, load_recording_preferences};
+use super::recovery::{self, InterruptedRecording};
+use super::transcription::{get_or_init_transcription_engine, validate_transcription_model_ready};
+use crate::database::models::MEETING_STATUS_COMPLETED;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::state::AppState;
+use crate::summary::streaming;
+use log::{error as log_error, info as log_info, warn as log_warn};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime};
+
+/// Recoveries are registered for cancellation under the meeting folder
+fn recovery_key(folder_path: &str) -> String {
+    format!("recovery:{}", folder_path)
+}
+
+/// Meeting folders of recordings that were never stopped, to offer recovery on startup
+#[tauri::command]
+pub async fn api_find_interrupted_recordings<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<InterruptedRecording>, String> {
+    // Recordings are created in the default folder; also look in the one set in preferences
+    let mut base_folders = vec![get_default_recordings_folder()];
+    if let Ok(preferences) = load_recording_preferences(&app).await {
+        base_folders.push(preferences.save_folder);
+    }
+
+    let active_folder = active_recording_folder().await;
+    let recordings = tokio::task::spawn_blocking(move || {
+        recovery::find_interrupted_recordings(&base_folders, active_folder.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Scan for interrupted recordings failed: {}", e))?;
+
+    if !recordings.is_empty() {
+        log_info!("Found {} interrupted recordings", recordings.len());
+    }
+    Ok(recordings)
+}
+
+/// Merge an interrupted recording's checkpoints, transcribe what the recording
+/// missed and complete its meeting. Returns the meeting's id, or `None` if the
+/// recovery was cancelled.
+#[tauri::command]
+pub async fn api_recover_recording<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    folder_path: String,
+) -> Result<Option<String>, String> {
+    log_info!("api_recover_recording called for: {}", folder_path);
+    check_not_recording_into(&folder_path).await?;
+
+    // Without a model the transcript saved during the recording is still worth keeping
+    let engine = match validate_transcription_model_ready(&app).await {
+        Ok(()) => match get_or_init_transcription_engine(&app).await {
+            Ok(engine) => Some(engine),
+            Err(e) => {
+                log_warn!("Transcription engine unavailable for recovery: {}", e);
+                None
+            }
+        },
+        Err(e) => {
+            log_warn!("Transcription model not ready for recovery: {}", e);
+            None
+        }
+    };
+
+    let registration = streaming::register_stream(recovery_key(&folder_path));
+    let result = recovery::recover_recording(
+        state.db_manager.pool(),
+        Path::new(&folder_path),
+        engine.as_ref(),
+        crate::get_language_preference_internal(),
+        registration.shared_cancellation(),
+    )
+    .await;
+
+    match &result {
+        Ok(Some(meeting_id)) => log_info!("✅ Recovered {} as meeting {}", folder_path, meeting_id),
+        Ok(None) => log_info!("⏹️ Recovery of {} cancelled", folder_path),
+        Err(e) => log_error!("❌ Recovery of {} failed: {}", folder_path, e),
+    }
+    result
+}
+
+/// Cancel a running recovery. Returns false if none is running for the folder.
+#[tauri::command]
+pub async fn api_cancel_recovery<R: Runtime>(
+    _app: AppHandle<R>,
+    folder_path: String,
+) -> Result<bool, String> {
+    log_info!("api_cancel_recovery called for: {}", folder_path);
+    Ok(streaming::cancel_stream(&recovery_key(&folder_path)))
+}
+
+/// Stop offering an interrupted recording for recovery, keeping its files and
+/// the transcript its meeting saved
+#[tauri::command]
+pub async fn api_dismiss_interrupted_recording<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    folder_path: String,
+) -> Result<(), String> {
+    log_info!(
+        "api_dismiss_interrupted_recording called for: {}",
+        folder_path
+    );
+    check_not_recording_into(&folder_path).await?;
+    let meeting_id = recovery::dismiss_interrupted_recording(Path::new(&folder_path))?;
+
+    // The meeting keeps what was saved before the interruption; it is no longer being recorded
+    if let Some(meeting_id) = meeting_id {
+        MeetingsRepository::set_meeting_status(
+            state.db_manager.pool(),
+            &meeting_id,
+            MEETING_STATUS_COMPLETED,
+        )
+        .await
+        .map_err(|e| format!("Failed to complete meeting: {}", e))?;
+    }
+    Ok(())
+}
+
+pub(super) async fn active_recording_folder() -> Option<PathBuf> {
+    if !is_recording().await {
+        return None;
+    }
+    get_meeting_folder_path()
+        .await
+        .ok()
+        .flatten()
+        .map(PathBuf::from)
+}
+
+async fn check_not_recording_into(folder_path: &str) -> Result<(), String> {
+    match active_recording_folder().await {
+        Some(active) if active == Path::new(folder_path) => {
+            Err("This meeting is still being recorded".to_string())
+        }
+        _ => Ok(()),
+    }
+}
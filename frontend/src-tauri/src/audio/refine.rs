@@ -0,0 +1,442 @@
+// audio/refine.rs
+//
+// Optional second transcription pass over a recording after it stops. The live
+// pass transcribes short VAD segments with the fast Int8 model, without context
+// across segments, which hurts accuracy at segment boundaries. This pass
+// transcribes the whole recording in long overlapping windows with the FP32
+// model, stitches the windows at their overlaps and moves the words into the
+// live segments by time, so their timings, speakers and sources are kept. The
+// refined transcript replaces the live one, which is kept as a transcript version.
+
+use super::import;
+use super::retranscribe::meeting_audio_file;
+use super::transcription::{segment_confidence, WordTimestamp};
+use crate::api::TranscriptSegment;
+use crate::database::models::{TranscriptVersion, MEETING_STATUS_RECORDING};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::database::repositories::transcript_version::{
+    NewTranscriptVersion, TranscriptVersionsRepository,
+};
+use crate::parakeet_engine::ParakeetEngine;
+use crate::summary::streaming::StreamCancellation;
+use log::{info, warn};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Event emitted while a recording's transcript is refined
+pub const TRANSCRIPT_REFINEMENT_EVENT: &str = "transcript-refinement";
+
+/// Model of the refinement pass
+pub const REFINE_MODEL: &str = "parakeet-tdt-0.6b-v3-fp32";
+
+const SAMPLE_RATE: usize = 16000;
+
+/// Length of the windows the recording is transcribed in
+const WINDOW_SECONDS: usize = 30;
+
+/// Audio shared by consecutive windows, so no word is cut at a window edge
+const OVERLAP_SECONDS: usize = 5;
+
+/// Share of the progress taken by decoding the recording
+const DECODE_PROGRESS: f32 = 10.0;
+
+/// Progress callback: overall progress, 0-100
+pub type RefineProgressCallback = Arc<dyn Fn(f32) + Send + Sync>;
+
+/// Payload of `transcript-refinement` events
+#[derive(Debug, Clone, Serialize)]
+pub struct RefinementProgress {
+    pub meeting_id: String,
+    /// "refining", then "completed", "cancelled" or "failed"
+    pub status: String,
+    /// Overall progress, 0-100
+    pub progress: f32,
+    /// Segments whose text the refinement changed, once completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changed_segments: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl RefinementProgress {
+    fn new(meeting_id: &str, status: &str, progress: f32) -> Self {
+        Self {
+            meeting_id: meeting_id.to_string(),
+            status: status.to_string(),
+            progress,
+            changed_segments: None,
+            error: None,
+        }
+    }
+
+    pub fn refining(meeting_id: &str, progress: f32) -> Self {
+        Self::new(meeting_id, "refining", progress)
+    }
+
+    pub fn completed(meeting_id: &str, changed_segments: usize) -> Self {
+        Self {
+            changed_segments: Some(changed_segments),
+            ..Self::new(meeting_id, "completed", 100.0)
+        }
+    }
+
+    pub fn cancelled(meeting_id: &str) -> Self {
+        Self::new(meeting_id, "cancelled", 0.0)
+    }
+
+    pub fn failed(meeting_id: &str, error: &str) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::new(meeting_id, "failed", 0.0)
+        }
+    }
+}
+
+/// Result of a refinement
+#[derive(Debug, Clone)]
+pub struct RefineResult {
+    pub meeting_title: String,
+    pub segment_count: usize,
+    pub changed_segments: usize,
+    /// The version keeping the live transcript; `None` if nothing changed
+    pub live_version: Option<TranscriptVersion>,
+}
+
+/// Words transcribed from one window, in recording time
+struct WindowWords {
+    start: f64,
+    end: f64,
+    words: Vec<WordTimestamp>,
+}
+
+/// Refine a meeting's transcript from the audio in its folder with `engine`,
+/// which must have the FP32 model loaded. `live_model` names the model of the
+/// live transcript for its version. Returns `None` if cancelled.
+pub async fn refine_meeting(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    engine: &ParakeetEngine,
+    live_model: Option<&str>,
+    cancellation: Arc<StreamCancellation>,
+    on_progress: RefineProgressCallback,
+) -> Result<Option<RefineResult>, String> {
+    let meeting = MeetingsRepository::get_meeting_model(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to get meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    if meeting.status == MEETING_STATUS_RECORDING {
+        return Err("This meeting is still being recorded".to_string());
+    }
+    let folder = meeting
+        .folder_path
+        .map(PathBuf::from)
+        .ok_or("This meeting has no recording folder")?;
+    let audio = meeting_audio_file(&folder)
+        .ok_or_else(|| format!("No audio file found in {}", folder.display()))?;
+
+    let live = TranscriptsRepository::get_segments(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to get transcript: {}", e))?;
+    if live.is_empty() {
+        return Err("The meeting has no transcript to refine".to_string());
+    }
+
+    info!(
+        "Refining transcript of meeting {} from {} ({} live segments)",
+        meeting_id,
+        audio.display(),
+        live.len()
+    );
+    let decode_cancellation = cancellation.clone();
+    let decode_progress = on_progress.clone();
+    let decoded = tokio::task::spawn_blocking(move || {
+        import::decode_to_16k_mono(&audio, &decode_cancellation, &|fraction| {
+            decode_progress(fraction * DECODE_PROGRESS)
+        })
+    })
+    .await
+    .map_err(|e| format!("Decoding task failed: {}", e))??;
+    let samples = match decoded {
+        Some(decoded) if !cancellation.is_cancelled() => decoded.samples,
+        _ => return Ok(None),
+    };
+
+    let ranges = window_ranges(
+        samples.len(),
+        WINDOW_SECONDS * SAMPLE_RATE,
+        OVERLAP_SECONDS * SAMPLE_RATE,
+    );
+    let mut windows = Vec::with_capacity(ranges.len());
+    for (index, range) in ranges.iter().enumerate() {
+        if cancellation.is_cancelled() {
+            return Ok(None);
+        }
+        on_progress(
+            DECODE_PROGRESS + (100.0 - DECODE_PROGRESS) * index as f32 / ranges.len() as f32,
+        );
+
+        let start = range.start as f64 / SAMPLE_RATE as f64;
+        let end = range.end as f64 / SAMPLE_RATE as f64;
+        let result = tokio::select! {
+            result = engine.transcribe_audio_with_timestamps(samples[range.clone()].to_vec()) => result,
+            _ = cancellation.cancelled() => return Ok(None),
+        };
+        let words = match result {
+            Ok(result) => result
+                .words()
+                .into_iter()
+                .map(|word| WordTimestamp::from(word).offset_by(start))
+                .collect(),
+            Err(e) => {
+                // The live text stays where a window is missing
+                warn!("Refinement failed for the window at {:.1}s: {}", start, e);
+                Vec::new()
+            }
+        };
+        windows.push(WindowWords { start, end, words });
+    }
+
+    let words = stitch_windows(&windows);
+    let (segments, changed_segments) = reconcile(&live, &words);
+    info!(
+        "Refined transcript of meeting {}: {} words, {} of {} segments changed",
+        meeting_id,
+        words.len(),
+        changed_segments,
+        segments.len()
+    );
+
+    let live_version = if changed_segments > 0 {
+        let backup = NewTranscriptVersion {
+            meeting_id,
+            label: "Live transcript",
+            model: live_model,
+            ..Default::default()
+        };
+        let version = TranscriptVersionsRepository::replace_transcript(pool, &backup, &segments)
+            .await
+            .map_err(|e| format!("Failed to replace transcript: {}", e))?;
+
+        // The database has the transcript either way; the folder only mirrors it
+        if let Err(e) = import::write_transcripts_json(&folder, &segments) {
+            warn!(
+                "Failed to update transcripts.json in {}: {}",
+                folder.display(),
+                e
+            );
+        }
+        Some(version)
+    } else {
+        None
+    };
+
+    Ok(Some(RefineResult {
+        meeting_title: meeting.title,
+        segment_count: segments.len(),
+        changed_segments,
+        live_version,
+    }))
+}
+
+/// Sample ranges of windows of `window` samples, each starting `overlap`
+/// samples before the previous one ends, covering `total` samples
+fn window_ranges(total: usize, window: usize, overlap: usize) -> Vec<Range<usize>> {
+    let step = window.saturating_sub(overlap).max(1);
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start < total {
+        let end = (start + window).min(total);
+        ranges.push(start..end);
+        if end == total {
+            break;
+        }
+        start += step;
+    }
+    ranges
+}
+
+/// Join the words of overlapping windows. Each overlap is split at its middle,
+/// since words near a window's edges are transcribed with the least context.
+fn stitch_windows(windows: &[WindowWords]) -> Vec<WordTimestamp> {
+    let mut stitched = Vec::new();
+    for (index, window) in windows.iter().enumerate() {
+        let from = match index.checked_sub(1) {
+            Some(previous) => (window.start + windows[previous].end) / 2.0,
+            None => f64::NEG_INFINITY,
+        };
+        let until = windows
+            .get(index + 1)
+            .map_or(f64::INFINITY, |next| (next.start + window.end) / 2.0);
+        stitched.extend(
+            window
+                .words
+                .iter()
+                .filter(|word| (from..until).contains(&midpoint(word)))
+                .cloned(),
+        );
+    }
+    stitched
+}
+
+/// Move refined words into the live segments: each word goes to the segment
+/// it falls in, or else the nearest one. Segments that get no words keep their
+/// live text. Returns the segments and how many of them changed text.
+fn reconcile(
+    live: &[TranscriptSegment],
+    words: &[WordTimestamp],
+) -> (Vec<TranscriptSegment>, usize) {
+    let mut assigned: Vec<Vec<WordTimestamp>> = vec![Vec::new(); live.len()];
+    for word in words {
+        if let Some(index) = segment_for(live, midpoint(word)) {
+            assigned[index].push(word.clone());
+        }
+    }
+
+    let mut changed = 0;
+    let segments = live
+        .iter()
+        .zip(assigned)
+        .map(|(segment, words)| {
+            if words.is_empty() {
+                return segment.clone();
+            }
+            let text = words
+                .iter()
+                .map(|word| word.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            if text != segment.text.trim() {
+                changed += 1;
+            }
+            TranscriptSegment {
+                text,
+                confidence: segment_confidence(&words).or(segment.confidence),
+                words,
+                ..segment.clone()
+            }
+        })
+        .collect();
+    (segments, changed)
+}
+
+/// Index of the live segment containing `time`, else of the nearest one
+fn segment_for(live: &[TranscriptSegment], time: f64) -> Option<usize> {
+    live.iter()
+        .enumerate()
+        .filter_map(|(index, segment)| {
+            let start = segment.audio_start_time?;
+            let end = segment.audio_end_time?;
+            let distance = if time < start {
+                start - time
+            } else {
+                (time - end).max(0.0)
+            };
+            Some((index, distance))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index)
+}
+
+fn midpoint(word: &WordTimestamp) -> f64 {
+    (word.start + word.end) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start: f64, end: f64) -> WordTimestamp {
+        WordTimestamp {
+            text: text.to_string(),
+            start,
+            end,
+            confidence: None,
+        }
+    }
+
+    fn segment(id: &str, text: &str, start: f64, end: f64) -> TranscriptSegment {
+        TranscriptSegment {
+            id: id.to_string(),
+            text: text.to_string(),
+            timestamp: String::new(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            words: Vec::new(),
+            speaker: Some("speaker_1".to_string()),
+            source: Some("local".to_string()),
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn test_window_ranges() {
+        assert_eq!(window_ranges(0, 30, 5), Vec::<Range<usize>>::new());
+        assert_eq!(window_ranges(20, 30, 5), vec![0..20]);
+        assert_eq!(window_ranges(30, 30, 5), vec![0..30]);
+        assert_eq!(window_ranges(60, 30, 5), vec![0..30, 25..55, 50..60]);
+    }
+
+    #[test]
+    fn test_stitch_windows_splits_overlaps_in_the_middle() {
+        // Both windows transcribed the words in their 25-30s overlap
+        let windows = vec![
+            WindowWords {
+                start: 0.0,
+                end: 30.0,
+                words: vec![
+                    word("one", 20.0, 21.0),
+                    word("two", 26.0, 27.0),
+                    word("thre", 29.0, 30.0),
+                ],
+            },
+            WindowWords {
+                start: 25.0,
+                end: 55.0,
+                words: vec![
+                    word("wo", 25.0, 25.4),
+                    word("two", 26.0, 27.0),
+                    word("three", 29.0, 30.0),
+                    word("four", 40.0, 41.0),
+                ],
+            },
+        ];
+        let texts: Vec<String> = stitch_windows(&windows)
+            .into_iter()
+            .map(|word| word.text)
+            .collect();
+        assert_eq!(texts, vec!["one", "two", "three", "four"]);
+    }
+
+    #[test]
+    fn test_reconcile_keeps_live_timings() {
+        let live = vec![
+            segment("a", "hello word", 0.0, 2.0),
+            segment("b", "how are", 3.0, 5.0),
+            segment("c", "bye", 8.0, 9.0),
+        ];
+        let words = vec![
+            word("Hello", 0.1, 0.5),
+            word("world.", 0.6, 1.0),
+            // In the pause between segments, nearer the second
+            word("So", 2.8, 2.9),
+            word("how", 3.0, 3.5),
+            word("are", 3.6, 4.0),
+            word("you?", 4.1, 4.6),
+        ];
+        let (segments, changed) = reconcile(&live, &words);
+
+        assert_eq!(changed, 2);
+        assert_eq!(segments[0].text, "Hello world.");
+        assert_eq!(segments[1].text, "So how are you?");
+        assert_eq!(segments[1].words.len(), 4);
+        assert_eq!(segments[1].audio_start_time, Some(3.0));
+        assert_eq!(segments[1].speaker.as_deref(), Some("speaker_1"));
+        // Nothing refined falls in the last segment
+        assert_eq!(segments[2].text, "bye");
+    }
+}
@@ -0,0 +1,164 @@
+// audio/refine_commands.rs
+//
+// Starts the refinement pass when a recording stops, if the user turned it on,
+// and the Tauri command to cancel it.
+
+use super::refine::{
+    self, RefineResult, RefinementProgress, REFINE_MODEL, TRANSCRIPT_REFINEMENT_EVENT,
+};
+use crate::notifications::commands::{
+    show_transcript_refined_notification, NotificationManagerState,
+};
+use crate::parakeet_engine::{commands as parakeet_commands, ParakeetEngine};
+use crate::state::AppState;
+use crate::summary::streaming;
+use log::{error as log_error, info as log_info, warn as log_warn};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tauri_plugin_store::StoreExt;
+use tokio::sync::Mutex;
+
+/// Store and key of the "refine transcripts after recording" preference, set by the frontend
+const PREFERENCES_STORE: &str = "preferences.json";
+const REFINE_PREFERENCE_KEY: &str = "refine_transcript_after_recording";
+
+/// Refinements run one at a time, each holding a full precision model
+static REFINEMENT_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// Refinements are registered for cancellation under the meeting id
+fn refine_key(meeting_id: &str) -> String {
+    format!("refine:{}", meeting_id)
+}
+
+/// Whether the user turned on refining transcripts after recording (off by default)
+pub fn refinement_enabled<R: Runtime>(app: &AppHandle<R>) -> bool {
+    app.store(PREFERENCES_STORE)
+        .ok()
+        .and_then(|store| store.get(REFINE_PREFERENCE_KEY))
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false)
+}
+
+/// Refine the transcript of a recording that just stopped in the background, if enabled.
+/// Progress and the result are reported through `transcript-refinement` events.
+pub fn refine_after_recording<R: Runtime>(app: &AppHandle<R>, meeting_id: String) {
+    if !refinement_enabled(app) {
+        return;
+    }
+    let app = app.clone();
+    tokio::spawn(async move {
+        let _running = REFINEMENT_LOCK.lock().await;
+        let registration = streaming::register_stream(refine_key(&meeting_id));
+        let _ = app.emit(
+            TRANSCRIPT_REFINEMENT_EVENT,
+            RefinementProgress::refining(&meeting_id, 0.0),
+        );
+
+        match refine(&app, &meeting_id, registration.shared_cancellation()).await {
+            Ok(Some(result)) => {
+                log_info!(
+                    "✅ Refined transcript of meeting {} ({} of {} segments changed)",
+                    meeting_id,
+                    result.changed_segments,
+                    result.segment_count
+                );
+                let _ = app.emit(
+                    TRANSCRIPT_REFINEMENT_EVENT,
+                    RefinementProgress::completed(&meeting_id, result.changed_segments),
+                );
+                if result.live_version.is_some() {
+                    notify_refined(&app, result.meeting_title).await;
+                }
+            }
+            Ok(None) => {
+                log_info!("⏹️ Refinement of meeting {} cancelled", meeting_id);
+                let _ = app.emit(
+                    TRANSCRIPT_REFINEMENT_EVENT,
+                    RefinementProgress::cancelled(&meeting_id),
+                );
+            }
+            Err(e) => {
+                log_error!("❌ Refinement of meeting {} failed: {}", meeting_id, e);
+                let _ = app.emit(
+                    TRANSCRIPT_REFINEMENT_EVENT,
+                    RefinementProgress::failed(&meeting_id, &e),
+                );
+            }
+        }
+    });
+}
+
+/// Cancel a running refinement. Returns false if none is running for the meeting.
+#[tauri::command]
+pub async fn api_cancel_transcript_refinement<R: Runtime>(
+    _app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<bool, String> {
+    log_info!(
+        "api_cancel_transcript_refinement called for meeting_id: {}",
+        meeting_id
+    );
+    let cancelled = streaming::cancel_stream(&refine_key(&meeting_id));
+    if !cancelled {
+        log_warn!("No running refinement for meeting_id: {}", meeting_id);
+    }
+    Ok(cancelled)
+}
+
+async fn refine<R: Runtime>(
+    app: &AppHandle<R>,
+    meeting_id: &str,
+    cancellation: Arc<streaming::StreamCancellation>,
+) -> Result<Option<RefineResult>, String> {
+    let state = app
+        .try_state::<AppState>()
+        .ok_or("Database not initialized")?;
+    let live_engine = parakeet_commands::PARAKEET_ENGINE.lock().unwrap().clone();
+    let live_model = match &live_engine {
+        Some(engine) => engine.get_current_model().await,
+        None => None,
+    };
+    let engine = refinement_engine(app, live_engine, live_model.as_deref()).await?;
+
+    let progress_app = app.clone();
+    let progress_meeting_id = meeting_id.to_string();
+    let on_progress: refine::RefineProgressCallback = Arc::new(move |progress| {
+        let _ = progress_app.emit(
+            TRANSCRIPT_REFINEMENT_EVENT,
+            RefinementProgress::refining(&progress_meeting_id, progress),
+        );
+    });
+
+    refine::refine_meeting(
+        state.db_manager.pool(),
+        meeting_id,
+        &engine,
+        live_model.as_deref(),
+        cancellation,
+        on_progress,
+    )
+    .await
+}
+
+/// An engine with the FP32 model: the live engine if it already runs it,
+/// otherwise a separate one so the next recording keeps its model
+async fn refinement_engine<R: Runtime>(
+    app: &AppHandle<R>,
+    live_engine: Option<Arc<ParakeetEngine>>,
+    live_model: Option<&str>,
+) -> Result<Arc<ParakeetEngine>, String> {
+    if let Some(engine) = live_engine.filter(|_| live_model == Some(REFINE_MODEL)) {
+        return Ok(engine);
+    }
+    Ok(Arc::new(parakeet_commands::standalone_engine(app, REFINE_MODEL).await?))
+}
+
+async fn notify_refined<R: Runtime>(app: &AppHandle<R>, meeting_title: String) {
+    let Some(manager_state) = app.try_state::<NotificationManagerState<R>>() else {
+        return;
+    };
+    if let Err(e) = show_transcript_refined_notification(&manager_state, Some(meeting_title)).await
+    {
+        log_warn!("Failed to show transcript refined notification: {}", e);
+    }
+}
@@ -0,0 +1,274 @@
+// audio/retranscribe.rs
+//
+// Re-transcription of a saved meeting from the audio in its folder, for example
+// after a model upgrade. The audio goes through the same decode, VAD and
+// transcription path as a file import. The new transcript either replaces the
+// current one (which is kept as a transcript version) or is stored as an
+// alternate version next to it.
+
+use super::import::{self, ImportStage, TranscribeOutcome};
+use super::recovery;
+use super::transcription::TranscriptionEngine;
+use super::vad::VadSettings;
+use crate::database::models::{TranscriptVersion, MEETING_STATUS_RECORDING};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript_version::{
+    NewTranscriptVersion, TranscriptVersionsRepository,
+};
+use crate::summary::streaming::StreamCancellation;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Event emitted while a meeting is re-transcribed
+pub const RETRANSCRIBE_PROGRESS_EVENT: &str = "retranscribe-progress";
+
+/// What to do with the new transcript
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RetranscribeMode {
+    /// Make it the current transcript; the current one is kept as a version
+    #[default]
+    Replace,
+    /// Keep it as a version next to the current transcript
+    Alternate,
+}
+
+/// Settings of a re-transcription. Unset fields use the current model,
+/// language preference (for engines that take a language) and the import's VAD settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetranscribeOptions {
+    /// Parakeet model to transcribe with (e.g. an FP32 instead of an Int8 model)
+    pub model: Option<String>,
+    /// Rejected for Parakeet, which detects the language itself
+    pub language: Option<String>,
+    pub vad: Option<VadSettings>,
+    pub mode: RetranscribeMode,
+}
+
+/// Payload of `retranscribe-progress` events
+#[derive(Debug, Clone, Serialize)]
+pub struct RetranscribeProgress {
+    pub meeting_id: String,
+    /// An `ImportStage` while running, then "completed", "cancelled" or "failed"
+    pub status: String,
+    /// Overall progress, 0-100
+    pub progress: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl RetranscribeProgress {
+    fn new(meeting_id: &str, status: &str, progress: f32) -> Self {
+        Self {
+            meeting_id: meeting_id.to_string(),
+            status: status.to_string(),
+            progress,
+            error: None,
+        }
+    }
+
+    pub fn stage(meeting_id: &str, stage: ImportStage, fraction: f32) -> Self {
+        Self::new(meeting_id, stage.as_str(), stage.overall_progress(fraction))
+    }
+
+    pub fn completed(meeting_id: &str) -> Self {
+        Self::new(meeting_id, "completed", 100.0)
+    }
+
+    pub fn cancelled(meeting_id: &str) -> Self {
+        Self::new(meeting_id, "cancelled", 0.0)
+    }
+
+    pub fn failed(meeting_id: &str, error: &str) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::new(meeting_id, "failed", 0.0)
+        }
+    }
+}
+
+/// Result of a re-transcription
+#[derive(Debug, Clone, Serialize)]
+pub struct RetranscribeResult {
+    pub meeting_id: String,
+    pub mode: RetranscribeMode,
+    pub segment_count: usize,
+    /// The alternate version, or when replacing, the version keeping the replaced transcript
+    pub version: TranscriptVersion,
+}
+
+/// Re-transcribe a meeting from the audio in its folder with `engine`.
+/// `model` names the engine's model for the version label. Returns `None` if
+/// cancelled.
+pub async fn retranscribe_meeting(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    engine: &TranscriptionEngine,
+    model: Option<&str>,
+    options: &RetranscribeOptions,
+    cancellation: Arc<StreamCancellation>,
+    on_progress: import::ProgressCallback,
+) -> Result<Option<RetranscribeResult>, String> {
+    let meeting = MeetingsRepository::get_meeting_model(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to get meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    if meeting.status == MEETING_STATUS_RECORDING {
+        return Err("This meeting is still being recorded".to_string());
+    }
+    let folder = meeting
+        .folder_path
+        .map(PathBuf::from)
+        .ok_or("This meeting has no recording folder")?;
+    let audio = meeting_audio_file(&folder)
+        .ok_or_else(|| format!("No audio file found in {}", folder.display()))?;
+
+    let vad_settings = options.vad.clone().unwrap_or_default();
+    vad_settings.validate()?;
+
+    info!(
+        "Re-transcribing meeting {} from {} (model: {:?}, mode: {:?})",
+        meeting_id,
+        audio.display(),
+        model,
+        options.mode
+    );
+    let transcribed = match import::transcribe_file_with(
+        engine,
+        &audio,
+        0.0,
+        options.language.clone(),
+        vad_settings.clone(),
+        cancellation,
+        on_progress.clone(),
+    )
+    .await?
+    {
+        TranscribeOutcome::Completed(transcribed) => transcribed,
+        TranscribeOutcome::Cancelled => return Ok(None),
+    };
+    if transcribed.segments.is_empty() {
+        return Err("No speech was found in the meeting's audio".to_string());
+    }
+
+    on_progress(ImportStage::Saving, 0.0);
+    let vad_json = serde_json::to_string(&vad_settings).ok();
+    let version = match options.mode {
+        RetranscribeMode::Alternate => {
+            let label = match model {
+                Some(model) => format!("Re-transcribed with {}", model),
+                None => "Re-transcribed".to_string(),
+            };
+            let new_version = NewTranscriptVersion {
+                meeting_id,
+                label: &label,
+                model,
+                language: options.language.as_deref(),
+                vad_settings: vad_json.as_deref(),
+            };
+            TranscriptVersionsRepository::create_version(pool, &new_version, &transcribed.segments)
+                .await
+                .map_err(|e| format!("Failed to save transcript version: {}", e))?
+        }
+        RetranscribeMode::Replace => {
+            let label = match model {
+                Some(model) => format!("Before re-transcription with {}", model),
+                None => "Before re-transcription".to_string(),
+            };
+            let backup = NewTranscriptVersion {
+                meeting_id,
+                label: &label,
+                ..Default::default()
+            };
+            let version = TranscriptVersionsRepository::replace_transcript(
+                pool,
+                &backup,
+                &transcribed.segments,
+            )
+            .await
+            .map_err(|e| format!("Failed to replace transcript: {}", e))?;
+
+            // The database has the transcript either way; the folder only mirrors it
+            if let Err(e) = import::write_transcripts_json(&folder, &transcribed.segments) {
+                warn!(
+                    "Failed to update transcripts.json in {}: {}",
+                    folder.display(),
+                    e
+                );
+            }
+            version
+        }
+    };
+
+    Ok(Some(RetranscribeResult {
+        meeting_id: meeting_id.to_string(),
+        mode: options.mode,
+        segment_count: transcribed.segments.len(),
+        version,
+    }))
+}
+
+/// The meeting's audio: the file named in `metadata.json`, or else an
+/// `audio.<ext>` the import can decode
+pub fn meeting_audio_file(folder: &Path) -> Option<PathBuf> {
+    let from_metadata = recovery::read_metadata(folder)
+        .map(|metadata| folder.join(metadata.audio_file))
+        .filter(|path| path.is_file() && import::is_supported_file(path));
+    from_metadata.or_else(|| {
+        import::SUPPORTED_EXTENSIONS
+            .iter()
+            .map(|ext| folder.join(format!("audio.{}", ext)))
+            .find(|path| path.is_file())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_meeting_audio_file() {
+        let folder = tempdir().unwrap();
+        assert_eq!(meeting_audio_file(folder.path()), None);
+
+        std::fs::write(folder.path().join("audio.m4a"), b"").unwrap();
+        assert_eq!(
+            meeting_audio_file(folder.path()),
+            Some(folder.path().join("audio.m4a"))
+        );
+
+        // metadata.json names the recording's file
+        std::fs::write(folder.path().join("audio.mp4"), b"").unwrap();
+        std::fs::write(
+            folder.path().join("metadata.json"),
+            r#"{"version":"1.0","meeting_id":null,"meeting_name":"M","created_at":"",
+               "completed_at":null,"duration_seconds":null,
+               "devices":{"microphone":null,"system_audio":null},
+               "audio_file":"audio.mp4","transcript_file":"transcripts.json",
+               "sample_rate":48000,"status":"completed"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            meeting_audio_file(folder.path()),
+            Some(folder.path().join("audio.mp4"))
+        );
+    }
+
+    #[test]
+    fn test_options_defaults() {
+        let options: RetranscribeOptions =
+            serde_json::from_str(r#"{"mode": "alternate", "vad": {"redemption_time_ms": 800}}"#)
+                .unwrap();
+        assert_eq!(options.mode, RetranscribeMode::Alternate);
+        assert_eq!(options.model, None);
+        assert_eq!(options.vad.unwrap().redemption_time_ms, 800);
+
+        let options: RetranscribeOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(options.mode, RetranscribeMode::Replace);
+    }
+}
@@ -0,0 +1,256 @@
+// audio/retranscribe_commands.rs
+//
+// Tauri commands for re-transcribing saved meetings and managing the
+// transcript versions that re-transcription leaves.
+
+use super::import;
+use super::retranscribe::{
+    self, RetranscribeOptions, RetranscribeProgress, RetranscribeResult,
+    RETRANSCRIBE_PROGRESS_EVENT,
+};
+use super::transcription::{
+    get_or_init_transcription_engine, validate_transcription_model_ready, TranscriptionEngine,
+};
+use crate::database::models::TranscriptVersion;
+use crate::database::repositories::transcript_version::TranscriptVersionsRepository;
+use crate::parakeet_engine::commands as parakeet_commands;
+use crate::state::AppState;
+use crate::summary::streaming;
+use log::{error as log_error, info as log_info, warn as log_warn};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Re-transcriptions are registered for cancellation under the meeting id
+fn retranscribe_key(meeting_id: &str) -> String {
+    format!("retranscribe:{}", meeting_id)
+}
+
+/// Re-transcribe a meeting from its saved audio.
+///
+/// Progress is reported through `retranscribe-progress` events. Returns `None`
+/// if the re-transcription was cancelled.
+#[tauri::command]
+pub async fn api_retranscribe_meeting<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    options: Option<RetranscribeOptions>,
+) -> Result<Option<RetranscribeResult>, String> {
+    log_info!(
+        "api_retranscribe_meeting called for meeting_id: {}, options: {:?}",
+        meeting_id,
+        options
+    );
+    let options = options.unwrap_or_default();
+
+    let registration = streaming::register_stream(retranscribe_key(&meeting_id));
+    let result = retranscribe(
+        &app,
+        &state,
+        &meeting_id,
+        &options,
+        registration.shared_cancellation(),
+    )
+    .await;
+
+    match &result {
+        Ok(Some(result)) => {
+            log_info!(
+                "✅ Re-transcribed meeting {} ({} segments, {:?})",
+                meeting_id,
+                result.segment_count,
+                result.mode
+            );
+            let _ = app.emit(
+                RETRANSCRIBE_PROGRESS_EVENT,
+                RetranscribeProgress::completed(&meeting_id),
+            );
+        }
+        Ok(None) => {
+            log_info!("⏹️ Re-transcription of meeting {} cancelled", meeting_id);
+            let _ = app.emit(
+                RETRANSCRIBE_PROGRESS_EVENT,
+                RetranscribeProgress::cancelled(&meeting_id),
+            );
+        }
+        Err(e) => {
+            log_error!(
+                "❌ Re-transcription of meeting {} failed: {}",
+                meeting_id,
+                e
+            );
+            let _ = app.emit(
+                RETRANSCRIBE_PROGRESS_EVENT,
+                RetranscribeProgress::failed(&meeting_id, e),
+            );
+        }
+    }
+    result
+}
+
+/// Cancel a running re-transcription. Returns false if none is running for the meeting.
+#[tauri::command]
+pub async fn api_cancel_retranscribe<R: Runtime>(
+    _app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<bool, String> {
+    log_info!(
+        "api_cancel_retranscribe called for meeting_id: {}",
+        meeting_id
+    );
+    let cancelled = streaming::cancel_stream(&retranscribe_key(&meeting_id));
+    if !cancelled {
+        log_warn!("No running re-transcription for meeting_id: {}", meeting_id);
+    }
+    Ok(cancelled)
+}
+
+/// Lists the alternate and replaced transcripts of a meeting, newest first
+#[tauri::command]
+pub async fn api_list_transcript_versions<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<TranscriptVersion>, String> {
+    log_info!(
+        "api_list_transcript_versions called for meeting_id: {}",
+        meeting_id
+    );
+    TranscriptVersionsRepository::get_versions(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!(
+                "Failed to list transcript versions for {}: {}",
+                meeting_id,
+                e
+            );
+            format!("Failed to list transcript versions: {}", e)
+        })
+}
+
+/// Makes a version the meeting's current transcript; the current one is kept as a version
+#[tauri::command]
+pub async fn api_restore_transcript_version<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    version_id: String,
+) -> Result<TranscriptVersion, String> {
+    log_info!(
+        "api_restore_transcript_version called for meeting_id: {}, version_id: {}",
+        meeting_id,
+        version_id
+    );
+    match TranscriptVersionsRepository::restore_version(
+        state.db_manager.pool(),
+        &meeting_id,
+        &version_id,
+    )
+    .await
+    {
+        Ok(Some(version)) => Ok(version),
+        Ok(None) => {
+            log_warn!(
+                "Transcript version {} not found for meeting_id: {}",
+                version_id,
+                meeting_id
+            );
+            Err(format!("Transcript version not found: {}", version_id))
+        }
+        Err(e) => {
+            log_error!("Failed to restore transcript version {}: {}", version_id, e);
+            Err(format!("Failed to restore transcript version: {}", e))
+        }
+    }
+}
+
+/// Deletes a transcript version. Returns false if it does not belong to the meeting.
+#[tauri::command]
+pub async fn api_delete_transcript_version<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    version_id: String,
+) -> Result<bool, String> {
+    log_info!(
+        "api_delete_transcript_version called for meeting_id: {}, version_id: {}",
+        meeting_id,
+        version_id
+    );
+    TranscriptVersionsRepository::delete_version(state.db_manager.pool(), &meeting_id, &version_id)
+        .await
+        .map_err(|e| format!("Failed to delete transcript version: {}", e))
+}
+
+async fn retranscribe<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    meeting_id: &str,
+    options: &RetranscribeOptions,
+    cancellation: Arc<streaming::StreamCancellation>,
+) -> Result<Option<RetranscribeResult>, String> {
+    validate_transcription_model_ready(app).await?;
+    let live_engine = get_or_init_transcription_engine(app).await?;
+    let current_model = live_engine.get_current_model().await;
+
+    // Another model gets an engine of its own, so recordings and imports
+    // running meanwhile keep the live engine's model
+    let engine = match options.model.as_deref() {
+        Some(model) if current_model.as_deref() != Some(model) => {
+            model_engine(app, &live_engine, model).await?
+        }
+        _ => live_engine,
+    };
+    let model = options.model.clone().or(current_model);
+
+    // The version records the language only if the engine was actually given it
+    let mut options = options.clone();
+    if engine.accepts_language() {
+        if options.language.is_none() {
+            options.language = crate::get_language_preference_internal();
+        }
+    } else if options.language.is_some() {
+        return Err(format!(
+            "{} detects the language itself; a language can't be chosen",
+            engine.provider_name()
+        ));
+    }
+
+    let progress_app = app.clone();
+    let progress_meeting_id = meeting_id.to_string();
+    let on_progress: import::ProgressCallback = Arc::new(move |stage, fraction| {
+        let _ = progress_app.emit(
+            RETRANSCRIBE_PROGRESS_EVENT,
+            RetranscribeProgress::stage(&progress_meeting_id, stage, fraction),
+        );
+    });
+
+    let result = retranscribe::retranscribe_meeting(
+        state.db_manager.pool(),
+        meeting_id,
+        &engine,
+        model.as_deref(),
+        &options,
+        cancellation,
+        on_progress,
+    )
+    .await;
+    result
+}
+
+/// A separate Parakeet engine with `model` loaded
+async fn model_engine<R: Runtime>(
+    app: &AppHandle<R>,
+    live_engine: &TranscriptionEngine,
+    model: &str,
+) -> Result<TranscriptionEngine, String> {
+    if !matches!(live_engine, TranscriptionEngine::Parakeet(_)) {
+        return Err(format!(
+            "{} does not support choosing a model",
+            live_engine.provider_name()
+        ));
+    }
+
+    let engine = parakeet_commands::standalone_engine(app, model).await?;
+    Ok(TranscriptionEngine::Parakeet(Arc::new(engine)))
+}
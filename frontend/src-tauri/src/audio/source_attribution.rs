@@ -0,0 +1,219 @@
+// audio/source_attribution.rs
+//
+// Cheap "Me vs. Others" attribution from the separate mic and system streams.
+// Before the pipeline mixes the two streams, we record short-frame energy for
+// each one on the same timeline the VAD uses. When VAD emits a segment, the
+// frames inside it tell us whether the local user (mic), remote participants
+// (system audio), or both were talking.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Energy frame length used for activity analysis
+const FRAME_MS: f64 = 30.0;
+
+/// Frames quieter than this (dBFS) count as silence
+const ACTIVITY_THRESHOLD_DB: f32 = -45.0;
+
+/// When both streams are active, mic energy this far below system energy is
+/// treated as speaker bleed into the mic rather than the local user talking
+const BLEED_MARGIN_DB: f32 = 10.0;
+
+/// Share of active frames (each side) above which a segment is tagged overlap
+const OVERLAP_RATIO: f32 = 0.25;
+
+/// How much frame history to keep. Live segments are classified as they end and
+/// the VAD splits speech at the maximum segment length (`VadSettings::max_segment_ms`,
+/// at most 120s), so this covers a whole segment but for its padding; a segment
+/// reaching further back is classified from the part still kept.
+const MAX_HISTORY_MS: f64 = 120_000.0;
+
+/// Who was speaking in a transcript segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechSource {
+    /// Local speaker (microphone)
+    Local,
+    /// Remote participants (system audio)
+    Remote,
+    /// Both sides talking at once
+    Overlap,
+    /// No clear activity on either stream
+    Unknown,
+}
+
+impl SpeechSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Remote => "remote",
+            Self::Overlap => "overlap",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for SpeechSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ActivityFrame {
+    start_ms: f64,
+    end_ms: f64,
+    mic_db: f32,
+    sys_db: f32,
+}
+
+/// Per-stream energy timeline aligned with the mixed audio fed to VAD
+pub struct SourceActivityTracker {
+    sample_rate: u32,
+    frame_samples: usize,
+    position_samples: u64,
+    frames: VecDeque<ActivityFrame>,
+}
+
+impl SourceActivityTracker {
+    pub fn new(sample_rate: u32) -> Self {
+        let frame_samples = ((sample_rate as f64 * FRAME_MS / 1000.0) as usize).max(1);
+        Self {
+            sample_rate,
+            frame_samples,
+            position_samples: 0,
+            frames: VecDeque::new(),
+        }
+    }
+
+    /// Record one aligned mixing window (same windows passed to the mixer, in order)
+    pub fn push_window(&mut self, mic_window: &[f32], sys_window: &[f32]) {
+        let len = mic_window.len().max(sys_window.len());
+        let mut offset = 0;
+
+        while offset < len {
+            let end = (offset + self.frame_samples).min(len);
+            let mic_db = rms_db(mic_window.get(offset..end.min(mic_window.len())).unwrap_or(&[]));
+            let sys_db = rms_db(sys_window.get(offset..end.min(sys_window.len())).unwrap_or(&[]));
+
+            let start_ms = self.samples_to_ms(self.position_samples + offset as u64);
+            let end_ms = self.samples_to_ms(self.position_samples + end as u64);
+            self.frames.push_back(ActivityFrame { start_ms, end_ms, mic_db, sys_db });
+
+            offset = end;
+        }
+
+        self.position_samples += len as u64;
+
+        let cutoff = self.samples_to_ms(self.position_samples) - MAX_HISTORY_MS;
+        while self.frames.front().map_or(false, |f| f.end_ms < cutoff) {
+            self.frames.pop_front();
+        }
+    }
+
+    /// Classify a VAD segment by the stream activity inside `[start_ms, end_ms]`
+    pub fn classify(&self, start_ms: f64, end_ms: f64) -> SpeechSource {
+        let mut local = 0usize;
+        let mut remote = 0usize;
+        let mut both = 0usize;
+
+        for frame in self
+            .frames
+            .iter()
+            .filter(|f| f.end_ms > start_ms && f.start_ms < end_ms)
+        {
+            let mic_active = frame.mic_db > ACTIVITY_THRESHOLD_DB;
+            let sys_active = frame.sys_db > ACTIVITY_THRESHOLD_DB;
+
+            match (mic_active, sys_active) {
+                (true, false) => local += 1,
+                (false, true) => remote += 1,
+                (true, true) if frame.mic_db < frame.sys_db - BLEED_MARGIN_DB => remote += 1,
+                (true, true) => both += 1,
+                (false, false) => {}
+            }
+        }
+
+        let total = local + remote + both;
+        if total == 0 {
+            return SpeechSource::Unknown;
+        }
+
+        let total = total as f32;
+        let local_ratio = local as f32 / total;
+        let remote_ratio = remote as f32 / total;
+        let both_ratio = both as f32 / total;
+
+        if both_ratio >= OVERLAP_RATIO || (local_ratio >= OVERLAP_RATIO && remote_ratio >= OVERLAP_RATIO) {
+            SpeechSource::Overlap
+        } else if local >= remote {
+            SpeechSource::Local
+        } else {
+            SpeechSource::Remote
+        }
+    }
+
+    fn samples_to_ms(&self, samples: u64) -> f64 {
+        samples as f64 * 1000.0 / self.sample_rate as f64
+    }
+}
+
+fn rms_db(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return f32::NEG_INFINITY;
+    }
+    let mean_square = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+    10.0 * mean_square.max(1e-12).log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16000;
+
+    fn tone(len: usize, amplitude: f32) -> Vec<f32> {
+        (0..len)
+            .map(|i| (i as f32 * 0.1).sin() * amplitude)
+            .collect()
+    }
+
+    #[test]
+    fn test_mic_only_is_local() {
+        let mut tracker = SourceActivityTracker::new(RATE);
+        tracker.push_window(&tone(16000, 0.3), &vec![0.0; 16000]);
+        assert_eq!(tracker.classify(0.0, 1000.0), SpeechSource::Local);
+    }
+
+    #[test]
+    fn test_system_only_is_remote() {
+        let mut tracker = SourceActivityTracker::new(RATE);
+        tracker.push_window(&vec![0.0; 16000], &tone(16000, 0.3));
+        assert_eq!(tracker.classify(0.0, 1000.0), SpeechSource::Remote);
+    }
+
+    #[test]
+    fn test_both_streams_is_overlap() {
+        let mut tracker = SourceActivityTracker::new(RATE);
+        tracker.push_window(&tone(16000, 0.3), &tone(16000, 0.3));
+        assert_eq!(tracker.classify(0.0, 1000.0), SpeechSource::Overlap);
+    }
+
+    #[test]
+    fn test_quiet_mic_bleed_is_remote() {
+        let mut tracker = SourceActivityTracker::new(RATE);
+        // Mic picks up the speakers ~20dB below the system signal
+        tracker.push_window(&tone(16000, 0.03), &tone(16000, 0.3));
+        assert_eq!(tracker.classify(0.0, 1000.0), SpeechSource::Remote);
+    }
+
+    #[test]
+    fn test_classify_uses_segment_range() {
+        let mut tracker = SourceActivityTracker::new(RATE);
+        tracker.push_window(&tone(16000, 0.3), &vec![0.0; 16000]);
+        tracker.push_window(&vec![0.0; 16000], &tone(16000, 0.3));
+        assert_eq!(tracker.classify(0.0, 1000.0), SpeechSource::Local);
+        assert_eq!(tracker.classify(1000.0, 2000.0), SpeechSource::Remote);
+        assert_eq!(tracker.classify(5000.0, 6000.0), SpeechSource::Unknown);
+    }
+}
@@ -13,7 +13,10 @@ pub mod engine;
 pub mod worker;
 
 // Re-export commonly used types
-pub use provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+pub use provider::{
+    has_low_confidence_marks, mark_low_confidence_words, segment_confidence, TranscriptionError,
+    TranscriptionProvider, TranscriptResult, WordTimestamp, LOW_CONFIDENCE_THRESHOLD,
+};
 // NOTE: WhisperProvider removed - using ParakeetProvider only
 // pub use whisper_provider::WhisperProvider;
 pub use parakeet_provider::ParakeetProvider;
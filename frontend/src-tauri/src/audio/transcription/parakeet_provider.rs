@@ -2,7 +2,9 @@
 //
 // Parakeet transcription provider implementation.
 
-use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+use super::provider::{
+    segment_confidence, TranscriptionError, TranscriptionProvider, TranscriptResult, WordTimestamp,
+};
 use async_trait::async_trait;
 use log::warn;
 use std::sync::Arc;
@@ -33,12 +35,17 @@ impl TranscriptionProvider for ParakeetProvider {
             );
         }
 
-        match self.engine.transcribe_audio(audio).await {
-            Ok(text) => Ok(TranscriptResult {
-                text: text.trim().to_string(),
-                confidence: None, // Parakeet doesn't provide confidence scores
-                is_partial: false, // Parakeet doesn't provide partial results
-            }),
+        match self.engine.transcribe_audio_with_timestamps(audio).await {
+            Ok(result) => {
+                let words: Vec<WordTimestamp> =
+                    result.words().into_iter().map(WordTimestamp::from).collect();
+                Ok(TranscriptResult {
+                    text: result.text.trim().to_string(),
+                    confidence: segment_confidence(&words),
+                    is_partial: false, // Parakeet doesn't provide partial results
+                    words,
+                })
+            }
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
     }
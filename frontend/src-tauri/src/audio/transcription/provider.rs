@@ -4,6 +4,7 @@
 // transcription engines (Whisper, Parakeet, future providers).
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 // ============================================================================
 // TRANSCRIPTION PROVIDER TRAIT & ERROR TYPES
@@ -37,12 +38,90 @@ impl std::fmt::Display for TranscriptionError {
 
 impl std::error::Error for TranscriptionError {}
 
+/// Word-level timing for a transcribed segment
+///
+/// Providers return times relative to the audio they were given; the worker
+/// offsets them so stored words are relative to the recording start.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordTimestamp {
+    pub text: String,
+    pub start: f64, // Seconds
+    pub end: f64,   // Seconds
+    /// Probability of the word, 0.0 to 1.0. None if the provider doesn't score words.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+}
+
+/// Words below this confidence are flagged as likely misheard
+pub const LOW_CONFIDENCE_THRESHOLD: f32 = 0.5;
+
+impl WordTimestamp {
+    /// Shift word times by `offset` seconds
+    pub fn offset_by(mut self, offset: f64) -> Self {
+        self.start += offset;
+        self.end += offset;
+        self
+    }
+
+    pub fn is_low_confidence(&self) -> bool {
+        self.confidence
+            .is_some_and(|confidence| confidence < LOW_CONFIDENCE_THRESHOLD)
+    }
+}
+
+/// Segment confidence as the mean of its word confidences, None if no word is scored
+pub fn segment_confidence(words: &[WordTimestamp]) -> Option<f32> {
+    let scores: Vec<f32> = words.iter().filter_map(|word| word.confidence).collect();
+    if scores.is_empty() {
+        None
+    } else {
+        Some(scores.iter().sum::<f32>() / scores.len() as f32)
+    }
+}
+
+/// Segment text with low-confidence words marked as `[word?]`, so an LLM can
+/// tell them apart. None if no word is low-confidence.
+pub fn mark_low_confidence_words(words: &[WordTimestamp]) -> Option<String> {
+    if !words.iter().any(WordTimestamp::is_low_confidence) {
+        return None;
+    }
+    let text = words
+        .iter()
+        .map(|word| {
+            if word.is_low_confidence() {
+                format!("[{}?]", word.text.trim())
+            } else {
+                word.text.trim().to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(text)
+}
+
+/// Whether `text` has words marked by [`mark_low_confidence_words`]
+pub fn has_low_confidence_marks(text: &str) -> bool {
+    text.contains("?]")
+}
+
+impl From<crate::parakeet_engine::TimestampedWord> for WordTimestamp {
+    fn from(word: crate::parakeet_engine::TimestampedWord) -> Self {
+        Self {
+            text: word.text,
+            start: word.start as f64,
+            end: word.end as f64,
+            confidence: Some(word.confidence),
+        }
+    }
+}
+
 /// Unified transcription result across all providers
 #[derive(Debug, Clone)]
 pub struct TranscriptResult {
     pub text: String,
     pub confidence: Option<f32>, // None if provider doesn't support confidence scores
     pub is_partial: bool,
+    pub words: Vec<WordTimestamp>, // Empty if provider doesn't support word timing
 }
 
 /// Trait for transcription providers (Whisper, Parakeet, future providers)
@@ -71,3 +150,35 @@ pub trait TranscriptionProvider: Send + Sync {
     /// Get the provider name (for logging/debugging)
     fn provider_name(&self) -> &'static str;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, confidence: Option<f32>) -> WordTimestamp {
+        WordTimestamp {
+            text: text.to_string(),
+            start: 0.0,
+            end: 0.0,
+            confidence,
+        }
+    }
+
+    #[test]
+    fn test_low_confidence_words() {
+        let words = vec![
+            word("Ship", Some(0.9)),
+            word("Acme", Some(0.2)),
+            word("today.", Some(0.7)),
+        ];
+        assert!((segment_confidence(&words).unwrap() - 0.6).abs() < 1e-6);
+        let marked = mark_low_confidence_words(&words).unwrap();
+        assert_eq!(marked, "Ship [Acme?] today.");
+        assert!(has_low_confidence_marks(&marked));
+
+        let unscored = vec![word("Hello", None)];
+        assert_eq!(segment_confidence(&unscored), None);
+        assert_eq!(mark_low_confidence_words(&unscored), None);
+        assert_eq!(mark_low_confidence_words(&words[..1]), None);
+    }
+}
@@ -3,17 +3,45 @@
 // Parallel transcription worker pool and chunk processing logic.
 
 use super::engine::TranscriptionEngine;
-use super::provider::TranscriptionError;
+use super::provider::{segment_confidence, TranscriptResult, TranscriptionError, WordTimestamp};
+use crate::audio::audio_processing::resample_audio;
+use crate::audio::source_attribution::SpeechSource;
 use crate::audio::AudioChunk;
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Runtime};
 
 // Sequence counter for transcript updates
 static SEQUENCE_COUNTER: AtomicU64 = AtomicU64::new(0);
 
+type ChunkTranscription = std::result::Result<TranscriptResult, TranscriptionError>;
+
+/// Share of the time partial results may spend transcribing; complete segments
+/// always run. Partial results re-transcribe speech that is still in progress.
+const PARTIAL_CPU_BUDGET: f64 = 0.25;
+
+/// Spaces out partial results so their transcription stays within PARTIAL_CPU_BUDGET
+#[derive(Default)]
+struct PartialBudget {
+    next_allowed: Option<Instant>,
+}
+
+impl PartialBudget {
+    fn allows(&self, now: Instant) -> bool {
+        self.next_allowed.map_or(true, |next| now >= next)
+    }
+
+    /// A partial result took `cost` to transcribe; idle long enough for it to
+    /// be the budgeted share
+    fn spend(&mut self, now: Instant, cost: Duration) {
+        self.next_allowed = Some(now + cost.mul_f64(1.0 / PARTIAL_CPU_BUDGET - 1.0));
+    }
+}
+
 // Speech detection flag - reset per recording session
 static SPEECH_DETECTED_EMITTED: AtomicBool = AtomicBool::new(false);
 
@@ -30,12 +58,19 @@ pub struct TranscriptUpdate {
     pub source: String,
     pub sequence_id: u64,
     pub chunk_start_time: f64, // Legacy field, kept for compatibility
+    // Speech still in progress; a later update with the same sequence_id replaces it
     pub is_partial: bool,
     pub confidence: f32,
     // NEW: Recording-relative timestamps for playback sync
     pub audio_start_time: f64, // Seconds from recording start (e.g., 125.3)
     pub audio_end_time: f64,   // Seconds from recording start (e.g., 128.6)
     pub duration: f64,          // Segment duration in seconds (e.g., 3.3)
+    // Word-level timing, recording-relative like audio_start_time (empty if unsupported)
+    #[serde(default)]
+    pub words: Vec<WordTimestamp>,
+    // Diarization speaker ID ("speaker_1"), None if diarization is unavailable
+    #[serde(default)]
+    pub speaker: Option<String>,
 }
 
 // NOTE: get_transcript_history and get_recording_meeting_name functions
@@ -63,6 +98,9 @@ pub fn start_transcription_task<R: Runtime>(
             }
         };
 
+        // Speaker diarization is optional - only runs when an embedding model is installed
+        let diarizer = crate::diarization::prepare_for_recording();
+
         // Create parallel workers for faster processing while preserving ALL chunks
         const NUM_WORKERS: usize = 1; // Serial processing ensures transcripts emit in chronological order
         let (work_sender, work_receiver) = tokio::sync::mpsc::unbounded_channel::<AudioChunk>();
@@ -88,6 +126,7 @@ pub fn start_transcription_task<R: Runtime>(
             let chunks_completed_clone = chunks_completed.clone();
             let input_finished_clone = input_finished.clone();
             let chunks_queued_clone = chunks_queued.clone();
+            let diarizer_clone = diarizer.clone();
 
             let worker_handle = tokio::spawn(async move {
                 info!("👷 Worker {} started", worker_id);
@@ -110,11 +149,20 @@ pub fn start_transcription_task<R: Runtime>(
                     warn!("⚠️ Worker {} pre-validation: {} model not loaded - chunks may be skipped", worker_id, engine_name);
                 }
 
+                // Sequence id of the partial result shown for the speech in progress
+                let mut partial_sequence: Option<u64> = None;
+                let mut partial_budget = PartialBudget::default();
+                // Complete segments transcribed in a batch with an earlier one, still to be emitted
+                let mut batched: VecDeque<(AudioChunk, ChunkTranscription)> = VecDeque::new();
+
                 loop {
                     // Try to get a chunk to process
-                    let chunk = {
-                        let mut receiver = work_receiver_clone.lock().await;
-                        receiver.recv().await
+                    let (chunk, mut transcribed) = match batched.pop_front() {
+                        Some((chunk, transcription)) => (Some(chunk), Some(transcription)),
+                        None => {
+                            let mut receiver = work_receiver_clone.lock().await;
+                            (receiver.recv().await, None)
+                        }
                     };
 
                     match chunk {
@@ -140,23 +188,76 @@ pub fn start_transcription_task<R: Runtime>(
                                 continue;
                             }
 
+                            let chunk_is_partial = chunk.is_partial;
+                            if chunk_is_partial {
+                                // Partial results are only a preview: skip them when complete
+                                // segments are waiting or the CPU budget is spent
+                                let backlog = chunks_queued_clone.load(Ordering::SeqCst)
+                                    .saturating_sub(chunks_completed_clone.load(Ordering::SeqCst) + 1);
+                                if backlog > 0 || !partial_budget.allows(Instant::now()) {
+                                    chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
+                                    continue;
+                                }
+                            }
+                            // The complete segment takes over the sequence id of its partial result
+                            let pending_partial = if chunk_is_partial { None } else { partial_sequence.take() };
+
                             let chunk_timestamp = chunk.timestamp;
+                            let chunk_source = chunk.source.unwrap_or(SpeechSource::Unknown);
                             let chunk_duration = chunk.data.len() as f64 / chunk.sample_rate as f64;
 
-                            // Transcribe with provider-agnostic approach
-                            match transcribe_chunk_with_provider(
-                                &engine_clone,
-                                chunk,
-                                &app_clone,
-                            )
-                            .await
+                            // Keep the 16kHz samples for speaker identification after transcription
+                            // (partial results go without a speaker)
+                            let diarization_samples = diarizer_clone.as_ref().filter(|_| !chunk_is_partial).map(|_| {
+                                if chunk.sample_rate != 16000 {
+                                    crate::audio::audio_processing::resample_audio(&chunk.data, chunk.sample_rate, 16000)
+                                } else {
+                                    chunk.data.clone()
+                                }
+                            });
+
+                            // When complete segments queue up (a CPU spike, a slow model), transcribe
+                            // them together in one batched Parakeet run instead of one by one
+                            if transcribed.is_none()
+                                && !chunk_is_partial
+                                && matches!(engine_clone, TranscriptionEngine::Parakeet(_))
                             {
-                                Ok((transcript, confidence_opt, is_partial)) => {
+                                let queued = take_queued_segments(&work_receiver_clone, &chunks_completed_clone).await;
+                                if !queued.is_empty() {
+                                    let mut transcriptions =
+                                        transcribe_chunks_batched(&engine_clone, &chunk, &queued, &app_clone)
+                                            .await
+                                            .into_iter();
+                                    transcribed = transcriptions.next();
+                                    batched.extend(queued.into_iter().zip(transcriptions));
+                                }
+                            }
+
+                            // Transcribe with provider-agnostic approach
+                            let started = Instant::now();
+                            let transcription = match transcribed {
+                                Some(transcription) => transcription,
+                                None => transcribe_chunk_with_provider(&engine_clone, chunk, &app_clone).await,
+                            };
+                            if chunk_is_partial {
+                                partial_budget.spend(Instant::now(), started.elapsed());
+                            }
+
+                            match transcription {
+                                Ok(result) => {
+                                    let TranscriptResult {
+                                        text: transcript,
+                                        confidence: confidence_opt,
+                                        is_partial,
+                                        words,
+                                    } = result;
+                                    let is_partial = is_partial || chunk_is_partial;
+
                                     // Provider-aware confidence threshold
                                     // NOTE: Whisper variant was removed - using Parakeet only
                                     let confidence_threshold = match &engine_clone {
                                         TranscriptionEngine::Provider(_) => 0.3,
-                                        TranscriptionEngine::Parakeet(_) => 0.0, // Parakeet has no confidence, accept all
+                                        TranscriptionEngine::Parakeet(_) => 0.0, // Keep every segment; low-confidence words are flagged instead
                                     };
 
                                     let confidence_str = match confidence_opt {
@@ -193,10 +294,28 @@ pub fn start_transcription_task<R: Runtime>(
                                         }
 
                                         // Generate sequence ID and calculate timestamps FIRST
-                                        let sequence_id = SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst);
+                                        // A partial result and the results replacing it share a sequence ID
+                                        let sequence_id = if is_partial {
+                                            *partial_sequence.get_or_insert_with(|| SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst))
+                                        } else {
+                                            pending_partial.unwrap_or_else(|| SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst))
+                                        };
                                         let audio_start_time = chunk_timestamp; // Already in seconds from recording start
                                         let audio_end_time = chunk_timestamp + chunk_duration;
 
+                                        // Word times are relative to the VAD segment - shift them onto the recording timeline
+                                        let words: Vec<WordTimestamp> = words
+                                            .into_iter()
+                                            .map(|word| word.offset_by(chunk_timestamp))
+                                            .collect();
+
+                                        let speaker = match (&diarizer_clone, diarization_samples) {
+                                            (Some(diarizer), Some(samples)) => {
+                                                identify_speaker(diarizer.clone(), samples).await
+                                            }
+                                            _ => None,
+                                        };
+
                                         // Save structured transcript segment to recording manager (only final results)
                                         // Save ALL segments (partial and final) to ensure complete JSON
                                         // Create structured segment with full timestamp data
@@ -209,7 +328,7 @@ pub fn start_transcription_task<R: Runtime>(
                                         let update = TranscriptUpdate {
                                             text: transcript,
                                             timestamp: format_current_timestamp(), // Wall-clock for reference
-                                            source: "Audio".to_string(),
+                                            source: chunk_source.to_string(), // "local" | "remote" | "overlap" | "unknown"
                                             sequence_id,
                                             chunk_start_time: chunk_timestamp, // Legacy compatibility
                                             is_partial,
@@ -218,6 +337,8 @@ pub fn start_transcription_task<R: Runtime>(
                                             audio_start_time,
                                             audio_end_time,
                                             duration: chunk_duration,
+                                            words,
+                                            speaker,
                                         };
 
                                         if let Err(e) = app_clone.emit("transcript-update", &update)
@@ -228,6 +349,9 @@ pub fn start_transcription_task<R: Runtime>(
                                             );
                                         }
                                         // PERFORMANCE: Removed verbose logging of every emission
+                                    } else if let Some(sequence_id) = pending_partial {
+                                        // The speech came to nothing after all - withdraw its partial result
+                                        withdraw_partial(&app_clone, sequence_id, chunk_timestamp, chunk_duration, chunk_source);
                                     } else if !transcript.trim().is_empty() && should_log_this_chunk
                                     {
                                         // PERFORMANCE: Only log low-confidence results occasionally
@@ -237,6 +361,9 @@ pub fn start_transcription_task<R: Runtime>(
                                     }
                                 }
                                 Err(e) => {
+                                    if let Some(sequence_id) = pending_partial {
+                                        withdraw_partial(&app_clone, sequence_id, chunk_timestamp, chunk_duration, chunk_source);
+                                    }
                                     // Improved error handling with specific cases
                                     match e {
                                         TranscriptionError::AudioTooShort { .. } => {
@@ -405,12 +532,12 @@ pub fn start_transcription_task<R: Runtime>(
 }
 
 /// Transcribe audio chunk using the appropriate provider (Whisper, Parakeet, or trait-based)
-/// Returns: (text, confidence Option, is_partial)
+/// Returns the cleaned result; word times are still relative to the chunk start
 async fn transcribe_chunk_with_provider<R: Runtime>(
     engine: &TranscriptionEngine,
     chunk: AudioChunk,
     app: &AppHandle<R>,
-) -> std::result::Result<(String, Option<f32>, bool), TranscriptionError> {
+) -> std::result::Result<TranscriptResult, TranscriptionError> {
     // Convert to 16kHz mono for transcription
     let transcription_data = if chunk.sample_rate != 16000 {
         crate::audio::audio_processing::resample_audio(&chunk.data, chunk.sample_rate, 16000)
@@ -447,11 +574,16 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
     // NOTE: Whisper variant was removed - using Parakeet and Provider only
     match engine {
         TranscriptionEngine::Parakeet(parakeet_engine) => {
-            match parakeet_engine.transcribe_audio(speech_samples).await {
-                Ok(text) => {
-                    let cleaned_text = text.trim().to_string();
+            match parakeet_engine.transcribe_audio_with_timestamps(speech_samples).await {
+                Ok(result) => {
+                    let cleaned_text = result.text.trim().to_string();
                     if cleaned_text.is_empty() {
-                        return Ok((String::new(), None, false));
+                        return Ok(TranscriptResult {
+                            text: String::new(),
+                            confidence: None,
+                            is_partial: false,
+                            words: Vec::new(),
+                        });
                     }
 
                     info!(
@@ -459,8 +591,15 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                         chunk.chunk_id, cleaned_text
                     );
 
-                    // Parakeet doesn't provide confidence or partial results
-                    Ok((cleaned_text, None, false))
+                    // Parakeet doesn't provide partial results
+                    let words: Vec<WordTimestamp> =
+                        result.words().into_iter().map(WordTimestamp::from).collect();
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        confidence: segment_confidence(&words),
+                        is_partial: false,
+                        words,
+                    })
                 }
                 Err(e) => {
                     error!(
@@ -490,7 +629,11 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                 Ok(result) => {
                     let cleaned_text = result.text.trim().to_string();
                     if cleaned_text.is_empty() {
-                        return Ok((String::new(), result.confidence, result.is_partial));
+                        return Ok(TranscriptResult {
+                            text: String::new(),
+                            words: Vec::new(),
+                            ..result
+                        });
                     }
 
                     let confidence_str = match result.confidence {
@@ -507,7 +650,10 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                         result.is_partial
                     );
 
-                    Ok((cleaned_text, result.confidence, result.is_partial))
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        ..result
+                    })
                 }
                 Err(e) => {
                     error!(
@@ -533,6 +679,109 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
     }
 }
 
+/// Complete segments already queued behind the one being transcribed, up to a
+/// batch. Partial results queued among them are out of date and dropped.
+async fn take_queued_segments(
+    receiver: &tokio::sync::Mutex<tokio::sync::mpsc::UnboundedReceiver<AudioChunk>>,
+    chunks_completed: &AtomicU64,
+) -> Vec<AudioChunk> {
+    let mut receiver = receiver.lock().await;
+    let mut queued = Vec::new();
+    while queued.len() + 1 < crate::parakeet_engine::MAX_BATCH_SIZE {
+        match receiver.try_recv() {
+            Ok(chunk) if chunk.is_partial => {
+                chunks_completed.fetch_add(1, Ordering::SeqCst);
+            }
+            Ok(chunk) => queued.push(chunk),
+            Err(_) => break,
+        }
+    }
+    queued
+}
+
+/// Transcribe a complete segment together with the segments queued behind it,
+/// in one batched run, or one by one if that fails. Results are in order, `chunk`'s first.
+async fn transcribe_chunks_batched<R: Runtime>(
+    engine: &TranscriptionEngine,
+    chunk: &AudioChunk,
+    queued: &[AudioChunk],
+    app: &AppHandle<R>,
+) -> Vec<ChunkTranscription> {
+    let audio: Vec<Vec<f32>> = std::iter::once(chunk)
+        .chain(queued)
+        .map(|chunk| {
+            if chunk.sample_rate != 16000 {
+                resample_audio(&chunk.data, chunk.sample_rate, 16000)
+            } else {
+                chunk.data.clone()
+            }
+        })
+        .collect();
+    let count = audio.len();
+    info!("Transcribing {} queued chunks in one batch (from chunk {})", count, chunk.chunk_id);
+
+    match engine
+        .transcribe_batch(audio, crate::get_language_preference_internal())
+        .await
+    {
+        Ok(results) => results.into_iter().map(Ok).collect(),
+        Err(e) => {
+            // One bad segment should not lose the others: retry them one by one
+            warn!("Batched transcription failed for {} chunks, retrying one by one: {}", count, e);
+            let mut results = Vec::with_capacity(count);
+            for chunk in std::iter::once(chunk).chain(queued) {
+                results.push(transcribe_chunk_with_provider(engine, chunk.clone(), app).await);
+            }
+            results
+        }
+    }
+}
+
+/// Replace a partial result with an empty final one, for speech that ended up without a transcript
+fn withdraw_partial<R: Runtime>(
+    app: &AppHandle<R>,
+    sequence_id: u64,
+    audio_start_time: f64,
+    duration: f64,
+    source: SpeechSource,
+) {
+    let update = TranscriptUpdate {
+        text: String::new(),
+        timestamp: format_current_timestamp(),
+        source: source.to_string(),
+        sequence_id,
+        chunk_start_time: audio_start_time,
+        is_partial: false,
+        confidence: 0.0,
+        audio_start_time,
+        audio_end_time: audio_start_time + duration,
+        duration,
+        words: Vec::new(),
+        speaker: None,
+    };
+    if let Err(e) = app.emit("transcript-update", &update) {
+        error!("Failed to withdraw partial transcript {}: {}", sequence_id, e);
+    }
+}
+
+/// Run speaker identification on a blocking thread (embedding inference is CPU-bound)
+async fn identify_speaker(
+    diarizer: Arc<crate::diarization::SpeakerDiarizer>,
+    samples: Vec<f32>,
+) -> Option<String> {
+    match tokio::task::spawn_blocking(move || diarizer.identify_speaker(&samples)).await {
+        Ok(Ok(speaker)) => speaker,
+        Ok(Err(e)) => {
+            warn!("Speaker identification failed: {}", e);
+            None
+        }
+        Err(e) => {
+            warn!("Speaker identification task panicked: {}", e);
+            None
+        }
+    }
+}
+
 /// Format current timestamp (wall-clock time)
 fn format_current_timestamp() -> String {
     let now = std::time::SystemTime::now()
@@ -555,3 +804,20 @@ fn format_recording_time(seconds: f64) -> String {
 
     format!("[{:02}:{:02}]", minutes, secs)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_budget() {
+        let start = Instant::now();
+        let mut budget = PartialBudget::default();
+        assert!(budget.allows(start));
+
+        // 200ms of transcription at a 25% budget waits out 600ms more
+        budget.spend(start, Duration::from_millis(200));
+        assert!(!budget.allows(start + Duration::from_millis(500)));
+        assert!(budget.allows(start + Duration::from_millis(600)));
+    }
+}
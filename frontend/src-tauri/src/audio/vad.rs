@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Result};
 use silero_rs::{VadConfig, VadSession, VadTransition};
 use log::{debug, info};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::time::Duration;
 
@@ -13,6 +14,98 @@ pub struct SpeechSegment {
     pub confidence: f32,
 }
 
+/// Speech in progress is transcribed for partial results once it is this long...
+const PARTIAL_MIN_SAMPLES: usize = 16000; // 1s at 16kHz
+/// ...and again each time it has grown by this much
+const PARTIAL_INTERVAL_SAMPLES: usize = 24000; // 1.5s at 16kHz
+/// Longer speech gets no more partial results; each one re-transcribes it all
+const PARTIAL_MAX_SAMPLES: usize = 30 * 16000; // 30s at 16kHz
+
+/// Whether speech in progress of `speech_len` samples is due for a partial
+/// result, the last one having been taken at `last_partial_len` samples (0 for none)
+fn partial_due(speech_len: usize, last_partial_len: usize) -> bool {
+    let next = if last_partial_len == 0 {
+        PARTIAL_MIN_SAMPLES
+    } else {
+        last_partial_len + PARTIAL_INTERVAL_SAMPLES
+    };
+    speech_len >= next && speech_len <= PARTIAL_MAX_SAMPLES
+}
+
+/// Audio kept from before speech is detected, to start the speech with its pre-speech pad
+const PRE_ROLL_SAMPLES: usize = 16000; // 1s at 16kHz
+/// Speech over the maximum segment length is split at the quietest point in
+/// this much audio before the limit
+const SPLIT_SEARCH_SAMPLES: usize = 3 * 16000; // 3s at 16kHz
+/// Frame size for finding the quietest point
+const SPLIT_FRAME_SAMPLES: usize = 320; // 20ms at 16kHz
+
+/// Where to split `samples`: the middle of the lowest-energy frame in their last `search` samples
+fn lowest_energy_split(samples: &[f32], search: usize) -> usize {
+    let mut split = samples.len();
+    let mut lowest_energy = f32::MAX;
+    let mut frame_start = samples.len().saturating_sub(search);
+    while frame_start + SPLIT_FRAME_SAMPLES <= samples.len() {
+        let frame = &samples[frame_start..frame_start + SPLIT_FRAME_SAMPLES];
+        let energy: f32 = frame.iter().map(|s| s * s).sum();
+        // The later of equally quiet frames keeps the first piece longer
+        if energy <= lowest_energy {
+            lowest_energy = energy;
+            split = frame_start + SPLIT_FRAME_SAMPLES / 2;
+        }
+        frame_start += SPLIT_FRAME_SAMPLES;
+    }
+    split
+}
+
+/// Speech detection tuning. The defaults are the live pipeline's, with the
+/// redemption time the file import uses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VadSettings {
+    /// Probability above which a frame starts speech
+    pub positive_speech_threshold: f32,
+    /// Probability below which a frame counts towards ending speech
+    pub negative_speech_threshold: f32,
+    /// Silence that ends a speech segment
+    pub redemption_time_ms: u32,
+    /// Speech shorter than this is dropped
+    pub min_speech_time_ms: u32,
+    /// Speech longer than this is split at a pause near the limit
+    pub max_segment_ms: u32,
+}
+
+impl Default for VadSettings {
+    fn default() -> Self {
+        Self {
+            positive_speech_threshold: 0.50,
+            negative_speech_threshold: 0.35,
+            redemption_time_ms: 400,
+            min_speech_time_ms: 250,
+            max_segment_ms: 20_000,
+        }
+    }
+}
+
+impl VadSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        let in_range = |t: f32| t > 0.0 && t < 1.0;
+        if !in_range(self.positive_speech_threshold) || !in_range(self.negative_speech_threshold) {
+            return Err("VAD thresholds must be between 0 and 1".to_string());
+        }
+        if self.negative_speech_threshold >= self.positive_speech_threshold {
+            return Err("The VAD negative threshold must be below the positive threshold".to_string());
+        }
+        if self.redemption_time_ms == 0 || self.redemption_time_ms > 10_000 {
+            return Err("The VAD redemption time must be between 1 and 10000 ms".to_string());
+        }
+        if self.max_segment_ms < 5_000 || self.max_segment_ms > 120_000 {
+            return Err("The VAD maximum segment length must be between 5000 and 120000 ms".to_string());
+        }
+        Ok(())
+    }
+}
+
 /// Processes audio in 30ms chunks but returns complete speech segments
 pub struct ContinuousVadProcessor {
     session: VadSession,
@@ -24,12 +117,32 @@ pub struct ContinuousVadProcessor {
     in_speech: bool,
     processed_samples: usize,
     speech_start_sample: usize,
+    // Length of current_speech when the last partial result was taken
+    last_partial_len: usize,
+    // Speech reaching this length is split (16kHz samples)
+    max_segment_samples: usize,
+    // Start of the rest of the current speech, once pieces of it have been split off
+    split_start_ms: Option<f64>,
+    // Latest audio, for the start of the next speech
+    pre_roll: VecDeque<f32>,
     // State tracking for smart logging
     last_logged_state: bool,
 }
 
 impl ContinuousVadProcessor {
     pub fn new(input_sample_rate: u32, redemption_time_ms: u32) -> Result<Self> {
+        Self::with_settings(
+            input_sample_rate,
+            &VadSettings {
+                redemption_time_ms,
+                ..VadSettings::default()
+            },
+        )
+    }
+
+    pub fn with_settings(input_sample_rate: u32, settings: &VadSettings) -> Result<Self> {
+        let redemption_time_ms = settings.redemption_time_ms;
+
         // Silero VAD MUST use 16kHz - this is hardcoded requirement
         const VAD_SAMPLE_RATE: u32 = 16000;
 
@@ -40,8 +153,8 @@ impl ContinuousVadProcessor {
         // CONTINUOUS SPEECH FIX: Tuned for capturing complete 5+ second utterances
         // Previous: 0.55/0.40 with 400ms redemption was fragmenting speech into 40ms segments
         // New: More lenient thresholds + longer redemption for continuous speech
-        config.positive_speech_threshold = 0.50;  // Silero default - good for continuous speech
-        config.negative_speech_threshold = 0.35;  // Silero default - allows natural pauses
+        config.positive_speech_threshold = settings.positive_speech_threshold;  // Silero default 0.50 - good for continuous speech
+        config.negative_speech_threshold = settings.negative_speech_threshold;  // Silero default 0.35 - allows natural pauses
 
         // CRITICAL FIX: Removed redemption_time capping to support long continuous speech
         // Previous: capped at 400ms, causing VAD to fragment 5-second speech into 40ms segments
@@ -53,10 +166,10 @@ impl ContinuousVadProcessor {
         // CRITICAL FIX: Increased min_speech_time to prevent tiny 40ms fragments
         // Previous: 100ms allowed too-short segments that Whisper rejects
         // New: 250ms ensures segments are substantial enough for Whisper (>100ms requirement)
-        config.min_speech_time = Duration::from_millis(250);  // Prevent tiny fragments
+        config.min_speech_time = Duration::from_millis(settings.min_speech_time_ms as u64);  // Prevent tiny fragments (250ms)
 
         debug!("Creating VAD session with: sample_rate={}Hz, redemption={}ms, min_speech={}ms, input_rate={}Hz",
-               VAD_SAMPLE_RATE, redemption_time_ms, 250, input_sample_rate);
+               VAD_SAMPLE_RATE, redemption_time_ms, settings.min_speech_time_ms, input_sample_rate);
 
         let session = VadSession::new(config)
             .map_err(|e| anyhow!("Failed to create VAD session: {:?}", e))?;
@@ -77,6 +190,10 @@ impl ContinuousVadProcessor {
             in_speech: false,
             processed_samples: 0,
             speech_start_sample: 0,
+            last_partial_len: 0,
+            max_segment_samples: settings.max_segment_ms as usize * 16,
+            split_start_ms: None,
+            pre_roll: VecDeque::with_capacity(PRE_ROLL_SAMPLES + vad_chunk_size),
             // Initialize state tracking
             last_logged_state: false,
         })
@@ -109,6 +226,25 @@ impl ContinuousVadProcessor {
         Ok(completed_segments)
     }
 
+    /// The speech in progress so far, if it is due for a partial result.
+    /// Partial results are spaced out and stop for long speech to bound their cost.
+    pub fn take_partial_speech(&mut self) -> Option<SpeechSegment> {
+        if !self.in_speech || !partial_due(self.current_speech.len(), self.last_partial_len) {
+            return None;
+        }
+        self.last_partial_len = self.current_speech.len();
+
+        // current_speech ends with the last processed chunk
+        let end_ms = self.processed_samples as f64 / 16.0;
+        let start_ms = (self.processed_samples - self.current_speech.len()) as f64 / 16.0;
+        Some(SpeechSegment {
+            samples: self.current_speech.clone(),
+            start_timestamp_ms: start_ms,
+            end_timestamp_ms: end_ms,
+            confidence: 0.5, // Speech may still be cut short
+        })
+    }
+
     /// Improved resampling from input sample rate to 16kHz with anti-aliasing
     /// Uses linear interpolation and basic low-pass filtering for better quality
     fn resample_to_16k(&self, samples: &[f32]) -> Result<Vec<f32>> {
@@ -179,7 +315,9 @@ impl ContinuousVadProcessor {
 
         // Force end any ongoing speech
         if self.in_speech && !self.current_speech.is_empty() {
-            let start_ms = (self.speech_start_sample as f64 / self.sample_rate as f64) * 1000.0;
+            let start_ms = self.split_start_ms.take().unwrap_or(
+                (self.speech_start_sample as f64 / self.sample_rate as f64) * 1000.0,
+            );
             let end_ms = (self.processed_samples as f64 / self.sample_rate as f64) * 1000.0;
 
             let segment = SpeechSegment {
@@ -191,6 +329,7 @@ impl ContinuousVadProcessor {
 
             self.speech_segments.push_back(segment);
             self.current_speech.clear();
+            self.last_partial_len = 0;
             self.in_speech = false;
         }
 
@@ -208,58 +347,111 @@ impl ContinuousVadProcessor {
 
         // Handle VAD transitions
         for transition in transitions {
-            match transition {
-                VadTransition::SpeechStart { timestamp_ms } => {
-                    // Only log if state changed
-                    if !self.last_logged_state {
-                        info!("VAD: Speech started at {}ms", timestamp_ms);
-                        self.last_logged_state = true;
-                    }
-                    self.in_speech = true;
-                    self.speech_start_sample = self.processed_samples + (timestamp_ms * self.sample_rate as usize / 1000);
-                    self.current_speech.clear();
+            self.handle_transition(transition);
+        }
+
+        self.advance(chunk);
+        Ok(())
+    }
+
+    /// Start or complete speech on a VAD transition, before its chunk is taken in
+    fn handle_transition(&mut self, transition: VadTransition) {
+        match transition {
+            VadTransition::SpeechStart { timestamp_ms } => {
+                // Only log if state changed
+                if !self.last_logged_state {
+                    info!("VAD: Speech started at {}ms", timestamp_ms);
+                    self.last_logged_state = true;
+                }
+                self.in_speech = true;
+                self.speech_start_sample = self.processed_samples + (timestamp_ms * self.sample_rate as usize / 1000);
+                self.current_speech.clear();
+                self.last_partial_len = 0;
+                self.split_start_ms = None;
+
+                // Start with the audio before detection that the VAD counts as speech
+                let pre_roll_len = self.processed_samples.saturating_sub(timestamp_ms * 16)
+                    .min(self.pre_roll.len());
+                self.current_speech.extend(self.pre_roll.range(self.pre_roll.len() - pre_roll_len..));
+            }
+            VadTransition::SpeechEnd { start_timestamp_ms, end_timestamp_ms, samples } => {
+                // Only log if we were previously in speech state
+                if self.last_logged_state {
+                    info!("VAD: Speech ended at {}ms (duration: {}ms)", end_timestamp_ms, end_timestamp_ms - start_timestamp_ms);
+                    self.last_logged_state = false;
                 }
-                VadTransition::SpeechEnd { start_timestamp_ms, end_timestamp_ms, samples } => {
-                    // Only log if we were previously in speech state
-                    if self.last_logged_state {
-                        info!("VAD: Speech ended at {}ms (duration: {}ms)", end_timestamp_ms, end_timestamp_ms - start_timestamp_ms);
-                        self.last_logged_state = false;
+                self.in_speech = false;
+
+                // Use samples from VAD transition if available, otherwise use accumulated samples
+                let (speech_samples, start_ms) = match self.split_start_ms.take() {
+                    // Pieces of this speech were sent already; only the rest is left
+                    Some(split_start_ms) if !samples.is_empty() => {
+                        let skip = ((split_start_ms - start_timestamp_ms as f64) * 16.0).max(0.0) as usize;
+                        (samples.get(skip..).unwrap_or(&[]).to_vec(), split_start_ms)
                     }
-                    self.in_speech = false;
-
-                    // Use samples from VAD transition if available, otherwise use accumulated samples
-                    let speech_samples = if !samples.is_empty() {
-                        samples
-                    } else {
-                        self.current_speech.clone()
+                    Some(split_start_ms) => (self.current_speech.clone(), split_start_ms),
+                    None if !samples.is_empty() => (samples, start_timestamp_ms as f64),
+                    None => (self.current_speech.clone(), start_timestamp_ms as f64),
+                };
+
+                if !speech_samples.is_empty() {
+                    let segment = SpeechSegment {
+                        samples: speech_samples,
+                        start_timestamp_ms: start_ms,
+                        end_timestamp_ms: end_timestamp_ms as f64,
+                        confidence: 0.9, // VAD confidence
                     };
 
-                    if !speech_samples.is_empty() {
-                        let segment = SpeechSegment {
-                            samples: speech_samples,
-                            start_timestamp_ms: start_timestamp_ms as f64,
-                            end_timestamp_ms: end_timestamp_ms as f64,
-                            confidence: 0.9, // VAD confidence
-                        };
+                    info!("VAD: Completed speech segment: {:.1}ms duration, {} samples",
+                          segment.end_timestamp_ms - start_ms, segment.samples.len());
 
-                        info!("VAD: Completed speech segment: {:.1}ms duration, {} samples",
-                              end_timestamp_ms - start_timestamp_ms, segment.samples.len());
-
-                        self.speech_segments.push_back(segment);
-                    }
-
-                    self.current_speech.clear();
+                    self.speech_segments.push_back(segment);
                 }
+
+                self.current_speech.clear();
+                self.last_partial_len = 0;
             }
         }
+    }
 
+    /// Take in a processed chunk, splitting speech that reached the maximum segment length
+    fn advance(&mut self, chunk: &[f32]) {
         // Accumulate speech if we're currently in a speech state
         if self.in_speech {
             self.current_speech.extend_from_slice(chunk);
         }
+        self.pre_roll.extend(chunk);
+        let excess = self.pre_roll.len().saturating_sub(PRE_ROLL_SAMPLES);
+        self.pre_roll.drain(..excess);
 
         self.processed_samples += chunk.len();
-        Ok(())
+
+        if self.in_speech && self.current_speech.len() >= self.max_segment_samples {
+            self.split_speech();
+        }
+    }
+
+    /// Complete the first piece of speech that reached the maximum segment length,
+    /// split at the quietest point near the limit. The rest stays in progress.
+    fn split_speech(&mut self) {
+        let split = lowest_energy_split(&self.current_speech, SPLIT_SEARCH_SAMPLES);
+
+        // current_speech ends with the last processed chunk
+        let start_ms = (self.processed_samples - self.current_speech.len()) as f64 / 16.0;
+        let end_ms = start_ms + split as f64 / 16.0;
+        let piece: Vec<f32> = self.current_speech.drain(..split).collect();
+
+        info!("VAD: Split long speech at {:.1}ms: {:.1}ms segment, {} samples",
+              end_ms, end_ms - start_ms, piece.len());
+
+        self.speech_segments.push_back(SpeechSegment {
+            samples: piece,
+            start_timestamp_ms: start_ms,
+            end_timestamp_ms: end_ms,
+            confidence: 0.9, // VAD confidence
+        });
+        self.split_start_ms = Some(end_ms);
+        self.last_partial_len = 0;
     }
 }
 
@@ -306,7 +498,21 @@ pub fn extract_speech_16k(samples_mono_16k: &[f32]) -> Result<Vec<f32>> {
 /// Simple convenience function to get speech chunks from audio
 /// Uses the optimized ContinuousVadProcessor with configurable redemption time
 pub fn get_speech_chunks(samples_mono_16k: &[f32], redemption_time_ms: u32) -> Result<Vec<SpeechSegment>> {
-    let mut processor = ContinuousVadProcessor::new(16000, redemption_time_ms)?;
+    get_speech_chunks_with_settings(
+        samples_mono_16k,
+        &VadSettings {
+            redemption_time_ms,
+            ..VadSettings::default()
+        },
+    )
+}
+
+/// `get_speech_chunks` with custom VAD settings
+pub fn get_speech_chunks_with_settings(
+    samples_mono_16k: &[f32],
+    settings: &VadSettings,
+) -> Result<Vec<SpeechSegment>> {
+    let mut processor = ContinuousVadProcessor::with_settings(16000, settings)?;
 
     // Process all audio
     let mut segments = processor.process_audio(samples_mono_16k)?;
@@ -317,3 +523,109 @@ pub fn get_speech_chunks(samples_mono_16k: &[f32], redemption_time_ms: u32) -> R
 }
 
  
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vad_settings_validate() {
+        assert!(VadSettings::default().validate().is_ok());
+
+        let inverted = VadSettings {
+            positive_speech_threshold: 0.3,
+            negative_speech_threshold: 0.5,
+            ..VadSettings::default()
+        };
+        assert!(inverted.validate().is_err());
+
+        let out_of_range = VadSettings {
+            positive_speech_threshold: 1.5,
+            ..VadSettings::default()
+        };
+        assert!(out_of_range.validate().is_err());
+
+        let no_redemption = VadSettings {
+            redemption_time_ms: 0,
+            ..VadSettings::default()
+        };
+        assert!(no_redemption.validate().is_err());
+
+        let short_segments = VadSettings {
+            max_segment_ms: 1_000,
+            ..VadSettings::default()
+        };
+        assert!(short_segments.validate().is_err());
+    }
+
+    #[test]
+    fn test_partial_due() {
+        // Not before a second of speech, then every 1.5s
+        assert!(!partial_due(PARTIAL_MIN_SAMPLES - 1, 0));
+        assert!(partial_due(PARTIAL_MIN_SAMPLES, 0));
+        assert!(!partial_due(40000, 30000));
+        assert!(partial_due(54000, 30000));
+        // Long speech gets none
+        assert!(!partial_due(PARTIAL_MAX_SAMPLES + PARTIAL_INTERVAL_SAMPLES, PARTIAL_MAX_SAMPLES));
+    }
+
+    #[test]
+    fn test_lowest_energy_split() {
+        // 10s of speech with pauses at 3s and 8.5s; only the later one is in the search window
+        let mut samples = vec![0.5f32; 10 * 16000];
+        samples[48000..48320].fill(0.0);
+        samples[136000..136640].fill(0.01);
+        samples[136320..136640].fill(0.0);
+        assert_eq!(lowest_energy_split(&samples, SPLIT_SEARCH_SAMPLES), 136480);
+
+        // Equally quiet frames split at the latest
+        let silence = vec![0.0f32; 10 * 16000];
+        assert_eq!(lowest_energy_split(&silence, SPLIT_SEARCH_SAMPLES), 160000 - 160);
+    }
+
+    #[test]
+    fn test_split_segments_are_contiguous() {
+        let settings = VadSettings {
+            max_segment_ms: 5_000,
+            ..VadSettings::default()
+        };
+        let mut processor = ContinuousVadProcessor::with_settings(16000, &settings).unwrap();
+
+        // 12s of speech without a pause, fed as the VAD would see it
+        let speech: Vec<f32> = (0..12 * 16000)
+            .map(|i| if i % 2 == 0 { 0.5 } else { -0.5 })
+            .collect();
+        processor.handle_transition(VadTransition::SpeechStart { timestamp_ms: 0 });
+        for chunk in speech.chunks(processor.chunk_size) {
+            processor.advance(chunk);
+        }
+        processor.handle_transition(VadTransition::SpeechEnd {
+            start_timestamp_ms: 0,
+            end_timestamp_ms: 12_000,
+            samples: speech.clone(),
+        });
+
+        let segments: Vec<SpeechSegment> = processor.speech_segments.drain(..).collect();
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].start_timestamp_ms, 0.0);
+        assert_eq!(segments[2].end_timestamp_ms, 12_000.0);
+        for pair in segments.windows(2) {
+            assert_eq!(pair[0].end_timestamp_ms, pair[1].start_timestamp_ms);
+        }
+        for segment in &segments {
+            let duration_samples = (segment.end_timestamp_ms - segment.start_timestamp_ms) * 16.0;
+            assert_eq!(segment.samples.len(), duration_samples as usize);
+        }
+    }
+
+    #[test]
+    fn test_vad_settings_partial_json_uses_defaults() {
+        let settings: VadSettings =
+            serde_json::from_str(r#"{"redemption_time_ms": 800}"#).unwrap();
+        assert_eq!(settings.redemption_time_ms, 800);
+        assert_eq!(
+            settings.positive_speech_threshold,
+            VadSettings::default().positive_speech_threshold
+        );
+    }
+}
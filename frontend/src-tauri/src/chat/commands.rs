@@ -1,7 +1,11 @@
+use crate::chat::library::ConversationFilters;
+use crate::chat::retrieval::ChatCitation;
 use crate::chat::service::ChatService;
-use crate::database::models::ChatMessage;
+use crate::database::models::{ChatConversation, ChatMessage, ConversationMessage};
 use crate::database::repositories::chat_message::ChatMessagesRepository;
+use crate::database::repositories::conversation::ConversationsRepository;
 use crate::state::AppState;
+use crate::summary::streaming;
 use log::{error as log_error, info as log_info};
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Runtime};
@@ -19,6 +23,20 @@ pub struct AskQuestionResponse {
     pub answer: String,
     pub user_message: ChatMessage,
     pub assistant_message: ChatMessage,
+    /// Transcript excerpts the answer cited, in order of first use
+    pub citations: Vec<ChatCitation>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AskLibraryQuestionResponse {
+    /// Id the answer streamed under, as sent with the question
+    pub request_id: String,
+    pub conversation: ChatConversation,
+    pub answer: String,
+    pub user_message: ConversationMessage,
+    pub assistant_message: ConversationMessage,
+    /// Cited excerpts with meeting_id / meeting_title and audio_start_time
+    pub citations: Vec<ChatCitation>,
 }
 
 /// Get all chat messages for a meeting
@@ -90,7 +108,7 @@ pub async fn api_delete_chat_messages<R: Runtime>(
     }
 }
 
-/// Ask a question about the meeting with full context
+/// Ask a question about the meeting, answered from the most relevant transcript excerpts
 #[tauri::command]
 pub async fn api_ask_question<R: Runtime>(
     app: AppHandle<R>,
@@ -127,3 +145,139 @@ pub async fn api_ask_question<R: Runtime>(
         }
     }
 }
+
+/// Stop the answer currently streaming for a meeting; the partial answer is kept
+#[tauri::command]
+pub async fn api_cancel_chat_answer<R: Runtime>(
+    _app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<bool, String> {
+    log_info!("api_cancel_chat_answer called for meeting_id: {}", meeting_id);
+    Ok(streaming::cancel_stream(&streaming::chat_stream_key(&meeting_id)))
+}
+
+/// Stop a streaming library answer by its request id; the partial answer is kept
+#[tauri::command]
+pub async fn api_cancel_library_answer<R: Runtime>(
+    _app: AppHandle<R>,
+    request_id: String,
+) -> Result<bool, String> {
+    log_info!("api_cancel_library_answer called for request: {}", request_id);
+    Ok(streaming::cancel_stream(&streaming::library_stream_key(&request_id)))
+}
+
+/// List library-wide chat conversations, most recent first
+#[tauri::command]
+pub async fn api_get_conversations<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<ChatConversation>, String> {
+    log_info!("api_get_conversations called");
+    let pool = state.db_manager.pool();
+
+    ConversationsRepository::get_conversations(pool)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get conversations: {}", e);
+            format!("Failed to retrieve conversations: {}", e)
+        })
+}
+
+/// Get all messages of a library conversation
+#[tauri::command]
+pub async fn api_get_conversation_messages<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    conversation_id: String,
+) -> Result<Vec<ConversationMessage>, String> {
+    log_info!("api_get_conversation_messages called for conversation_id: {}", conversation_id);
+    let pool = state.db_manager.pool();
+
+    ConversationsRepository::get_messages(pool, &conversation_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get messages for conversation {}: {}", conversation_id, e);
+            format!("Failed to retrieve conversation messages: {}", e)
+        })
+}
+
+/// Rename a library conversation
+#[tauri::command]
+pub async fn api_rename_conversation<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    conversation_id: String,
+    title: String,
+) -> Result<bool, String> {
+    log_info!("api_rename_conversation called for conversation_id: {}", conversation_id);
+    let pool = state.db_manager.pool();
+
+    ConversationsRepository::rename_conversation(pool, &conversation_id, &title)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to rename conversation {}: {}", conversation_id, e);
+            format!("Failed to rename conversation: {}", e)
+        })
+}
+
+/// Delete a library conversation and its messages
+#[tauri::command]
+pub async fn api_delete_conversation<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    conversation_id: String,
+) -> Result<bool, String> {
+    log_info!("api_delete_conversation called for conversation_id: {}", conversation_id);
+    let pool = state.db_manager.pool();
+
+    ConversationsRepository::delete_conversation(pool, &conversation_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to delete conversation {}: {}", conversation_id, e);
+            format!("Failed to delete conversation: {}", e)
+        })
+}
+
+/// Ask a question across all meetings (or those matching `filters`).
+/// Starts a new conversation when `conversation_id` is not given.
+#[tauri::command]
+pub async fn api_ask_library_question<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    request_id: String,
+    conversation_id: Option<String>,
+    question: String,
+    filters: Option<ConversationFilters>,
+    model_provider: String,
+    model_name: String,
+) -> Result<AskLibraryQuestionResponse, String> {
+    log_info!(
+        "api_ask_library_question called (request: {}, conversation: {:?}) with provider: {}",
+        request_id,
+        conversation_id,
+        model_provider
+    );
+    let pool = state.db_manager.pool().clone();
+
+    match ChatService::ask_library_question(
+        app,
+        pool,
+        request_id,
+        conversation_id,
+        question,
+        filters,
+        model_provider,
+        model_name,
+    )
+    .await
+    {
+        Ok(response) => {
+            log_info!("Library question answered successfully");
+            Ok(response)
+        }
+        Err(e) => {
+            log_error!("Failed to answer library question: {}", e);
+            Err(format!("Failed to answer question: {}", e))
+        }
+    }
+}
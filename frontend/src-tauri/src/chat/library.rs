@@ -0,0 +1,261 @@
+// chat/library.rs
+//
+// Library-wide chat: questions are answered from transcript chunks of every
+// meeting, or of a subset filtered by date range or title, so "what did we
+// decide about X last month?" works without opening a meeting first. Meetings
+// are chunked and ranked the same way as in per-meeting chat (retrieval.rs).
+
+use crate::chat::retrieval::{self, IndexedChunk, RetrievedChunk};
+use crate::database::models::MeetingModel;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+/// Number of transcript chunks sent to the LLM per library question
+pub const LIBRARY_TOP_K: usize = 8;
+
+/// Which meetings a library conversation searches. Stored as JSON on the conversation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConversationFilters {
+    /// Inclusive start date, "YYYY-MM-DD" (local time)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_from: Option<String>,
+    /// Inclusive end date, "YYYY-MM-DD" (local time)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_to: Option<String>,
+    /// Case-insensitive substring of the meeting title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_query: Option<String>,
+    /// Only these meetings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meeting_ids: Option<Vec<String>>,
+}
+
+/// Inclusive local-date range; `None` on either side is unbounded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateRange {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+impl DateRange {
+    pub fn is_unbounded(&self) -> bool {
+        self.from.is_none() && self.to.is_none()
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.from.map_or(true, |from| date >= from) && self.to.map_or(true, |to| date <= to)
+    }
+}
+
+impl ConversationFilters {
+    /// The explicit date range of the filters
+    pub fn date_range(&self) -> Result<DateRange, String> {
+        Ok(DateRange {
+            from: parse_date(self.date_from.as_deref())?,
+            to: parse_date(self.date_to.as_deref())?,
+        })
+    }
+}
+
+fn parse_date(value: Option<&str>) -> Result<Option<NaiveDate>, String> {
+    match value.map(str::trim).filter(|v| !v.is_empty()) {
+        Some(v) => NaiveDate::parse_from_str(v, "%Y-%m-%d")
+            .map(Some)
+            .map_err(|_| format!("Invalid date '{}', expected YYYY-MM-DD", v)),
+        None => Ok(None),
+    }
+}
+
+/// Date range implied by the question ("last month", "semana passada", ...), if any
+pub fn infer_date_range(question: &str, today: NaiveDate) -> Option<DateRange> {
+    let normalized: String = question
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let text = format!(" {} ", normalized.split_whitespace().collect::<Vec<_>>().join(" "));
+    let has = |phrases: &[&str]| phrases.iter().any(|p| text.contains(&format!(" {} ", p)));
+
+    let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let month_start = today.with_day(1)?;
+
+    let (from, to) = if has(&["yesterday", "ontem"]) {
+        let day = today - Duration::days(1);
+        (day, day)
+    } else if has(&["today", "hoje"]) {
+        (today, today)
+    } else if has(&["last week", "semana passada"]) {
+        (week_start - Duration::days(7), week_start - Duration::days(1))
+    } else if has(&["this week", "esta semana", "essa semana", "nesta semana", "nessa semana"]) {
+        (week_start, today)
+    } else if has(&["last month", "mês passado", "mes passado"]) {
+        let last_month_end = month_start - Duration::days(1);
+        (last_month_end.with_day(1)?, last_month_end)
+    } else if has(&["this month", "este mês", "esse mês", "este mes", "esse mes", "neste mês", "nesse mês"]) {
+        (month_start, today)
+    } else if has(&["last year", "ano passado"]) {
+        (
+            NaiveDate::from_ymd_opt(today.year() - 1, 1, 1)?,
+            NaiveDate::from_ymd_opt(today.year() - 1, 12, 31)?,
+        )
+    } else if has(&["this year", "este ano", "esse ano", "neste ano"]) {
+        (NaiveDate::from_ymd_opt(today.year(), 1, 1)?, today)
+    } else {
+        return None;
+    };
+
+    Some(DateRange { from: Some(from), to: Some(to) })
+}
+
+/// Local calendar date a meeting happened on
+pub fn meeting_date(meeting: &MeetingModel) -> NaiveDate {
+    meeting.created_at.0.with_timezone(&Local).date_naive()
+}
+
+/// Meetings matching the filters and date range
+pub fn filter_meetings(
+    meetings: Vec<MeetingModel>,
+    filters: &ConversationFilters,
+    range: DateRange,
+) -> Vec<MeetingModel> {
+    let title_query = filters
+        .title_query
+        .as_deref()
+        .map(|q| q.trim().to_lowercase())
+        .filter(|q| !q.is_empty());
+
+    meetings
+        .into_iter()
+        .filter(|m| {
+            filters
+                .meeting_ids
+                .as_ref()
+                .map_or(true, |ids| ids.is_empty() || ids.contains(&m.id))
+        })
+        .filter(|m| {
+            title_query
+                .as_ref()
+                .map_or(true, |q| m.title.to_lowercase().contains(q))
+        })
+        .filter(|m| range.contains(meeting_date(m)))
+        .collect()
+}
+
+/// The transcript chunks of every given meeting
+pub async fn load_library_chunks(
+    pool: &SqlitePool,
+    meetings: &[MeetingModel],
+) -> Result<Vec<IndexedChunk>, String> {
+    let mut index = Vec::new();
+    for meeting in meetings {
+        index.extend(retrieval::load_chunks(pool, &meeting.id).await?);
+    }
+    Ok(index)
+}
+
+/// Render retrieved chunks with "Meeting title (YYYY-MM-DD)" source lines
+pub fn format_library_excerpts(
+    retrieved: &[RetrievedChunk],
+    meetings: &HashMap<String, MeetingModel>,
+) -> String {
+    retrieval::format_excerpts_with(retrieved, |r| {
+        meetings
+            .get(&r.meeting_id)
+            .map(|m| format!("{} ({})", m.title, meeting_date(m).format("%Y-%m-%d")))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::DateTimeUtc;
+    use chrono::{TimeZone, Utc};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn meeting(id: &str, title: &str, y: i32, m: u32, d: u32) -> MeetingModel {
+        let at = Local
+            .with_ymd_and_hms(y, m, d, 12, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+        MeetingModel {
+            id: id.to_string(),
+            title: title.to_string(),
+            created_at: DateTimeUtc(at),
+            updated_at: DateTimeUtc(at),
+            folder_path: None,
+            status: "completed".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_infer_date_range() {
+        let today = date(2025, 11, 20); // Thursday
+
+        assert_eq!(
+            infer_date_range("What did we decide about pricing last month?", today),
+            Some(DateRange { from: Some(date(2025, 10, 1)), to: Some(date(2025, 10, 31)) })
+        );
+        assert_eq!(
+            infer_date_range("O que decidimos na semana passada?", today),
+            Some(DateRange { from: Some(date(2025, 11, 10)), to: Some(date(2025, 11, 16)) })
+        );
+        assert_eq!(
+            infer_date_range("anything from yesterday", today),
+            Some(DateRange { from: Some(date(2025, 11, 19)), to: Some(date(2025, 11, 19)) })
+        );
+        assert_eq!(infer_date_range("what is the launch date?", today), None);
+    }
+
+    #[test]
+    fn test_last_month_in_january() {
+        assert_eq!(
+            infer_date_range("mês passado", date(2026, 1, 5)),
+            Some(DateRange { from: Some(date(2025, 12, 1)), to: Some(date(2025, 12, 31)) })
+        );
+    }
+
+    #[test]
+    fn test_filter_meetings() {
+        let meetings = vec![
+            meeting("a", "Weekly sync", 2025, 10, 3),
+            meeting("b", "Pricing review", 2025, 10, 20),
+            meeting("c", "Weekly sync", 2025, 11, 7),
+        ];
+
+        let filters = ConversationFilters {
+            title_query: Some("weekly".to_string()),
+            ..Default::default()
+        };
+        let ids: Vec<String> = filter_meetings(meetings.clone(), &filters, DateRange::default())
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, vec!["a", "c"]);
+
+        let filters = ConversationFilters {
+            date_from: Some("2025-10-01".to_string()),
+            date_to: Some("2025-10-31".to_string()),
+            ..Default::default()
+        };
+        let range = filters.date_range().unwrap();
+        let ids: Vec<String> = filter_meetings(meetings, &filters, range)
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_invalid_filter_date() {
+        let filters = ConversationFilters {
+            date_from: Some("20/10/2025".to_string()),
+            ..Default::default()
+        };
+        assert!(filters.date_range().is_err());
+    }
+}
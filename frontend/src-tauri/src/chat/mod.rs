@@ -1,4 +1,6 @@
 pub mod commands;
+pub mod library;
+pub mod retrieval;
 pub mod service;
 
 pub use commands::*;
@@ -0,0 +1,553 @@
+// chat/retrieval.rs
+//
+// Retrieval-augmented context for meeting chat. Instead of sending the whole
+// transcript with every question, the transcript is split with
+// `summary::processor::chunk_text` and only the chunks most relevant to the
+// question go to the LLM, labeled [1], [2], ... so the answer can cite them.
+// Relevance comes from the full-text search index (`search_index`, FTS5): a
+// chunk scores the BM25 relevance of the transcript segments it covers. This
+// is lexical, so a chunk matches by the words it shares with the question
+// (accents and case aside), not by meaning.
+
+use crate::database::models::Transcript;
+use crate::database::repositories::{search::SearchRepository, speaker::SpeakersRepository};
+use crate::summary::processor::chunk_text;
+use crate::utils::format_timestamp;
+use log::info as log_info;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+/// Retrieval chunk size in tokens (~4 chars each): a minute or two of speech
+pub const CHUNK_SIZE_TOKENS: usize = 200;
+pub const CHUNK_OVERLAP_TOKENS: usize = 40;
+
+/// Number of transcript chunks sent to the LLM per question
+pub const TOP_K: usize = 6;
+
+/// Length of the excerpt stored with a citation for the UI
+const EXCERPT_CHARS: usize = 200;
+
+/// Very frequent words left out of the search, so a chunk can't match a
+/// question on "the" or "que" alone (English + Portuguese)
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "did", "do", "does", "for", "from",
+    "have", "how", "i", "in", "is", "it", "of", "on", "or", "so", "that", "the", "this", "to",
+    "was", "we", "what", "when", "where", "who", "why", "with", "you", "ao", "aos", "com",
+    "como", "da", "das", "de", "do", "dos", "e", "ela", "ele", "em", "eu", "foi", "mas", "na",
+    "nas", "no", "nos", "o", "os", "para", "por", "que", "quem", "se", "um", "uma", "você",
+];
+
+/// A transcript segment as input to chunking
+#[derive(Debug, Clone)]
+pub struct TimedSegment {
+    pub id: String,
+    pub text: String,
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+}
+
+/// A chunk of transcript with the recording time range it covers
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetrievalChunk {
+    pub chunk_index: i64,
+    pub content: String,
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+    pub transcript_ids: Vec<String>,
+}
+
+/// A chunk together with its meeting
+#[derive(Debug, Clone)]
+pub struct IndexedChunk {
+    pub meeting_id: String,
+    pub chunk: RetrievalChunk,
+}
+
+/// A chunk selected for a question; `label` is its [n] in the prompt
+#[derive(Debug, Clone)]
+pub struct RetrievedChunk {
+    pub label: usize,
+    pub meeting_id: String,
+    pub chunk: RetrievalChunk,
+    pub score: f32,
+}
+
+/// A transcript excerpt cited by a chat answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatCitation {
+    /// The [n] label the answer used
+    pub index: usize,
+    pub meeting_id: String,
+    /// Set for library-wide chat, where citations span meetings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meeting_title: Option<String>,
+    pub chunk_index: i64,
+    /// Recording-relative seconds, for seeking the audio
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+    pub transcript_ids: Vec<String>,
+    pub excerpt: String,
+    pub score: f32,
+}
+
+/// Split the meeting's transcript into chunks
+pub async fn load_chunks(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<IndexedChunk>, String> {
+    let transcripts = sqlx::query_as::<_, Transcript>(
+        "SELECT * FROM transcripts WHERE meeting_id = ?
+         ORDER BY COALESCE(audio_start_time, 0) ASC, timestamp ASC",
+    )
+    .bind(meeting_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to get transcripts: {}", e))?;
+
+    let names = SpeakersRepository::get_speaker_names(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to get speaker labels: {}", e))?;
+
+    // Label lines with speaker names (or "Me" / "Others") so retrieved chunks keep who said what
+    let segments: Vec<TimedSegment> = transcripts
+        .iter()
+        .map(|t| {
+            let text = match SpeakersRepository::segment_label(&names, t) {
+                Some(label) => format!("{}: {}", label, t.transcript.trim()),
+                None => t.transcript.trim().to_string(),
+            };
+            TimedSegment {
+                id: t.id.clone(),
+                text,
+                audio_start_time: t.audio_start_time,
+                audio_end_time: t.audio_end_time,
+            }
+        })
+        .collect();
+
+    Ok(chunk_segments(&segments, CHUNK_SIZE_TOKENS, CHUNK_OVERLAP_TOKENS)
+        .into_iter()
+        .map(|chunk| IndexedChunk {
+            meeting_id: meeting_id.to_string(),
+            chunk,
+        })
+        .collect())
+}
+
+/// Relevance of the given meetings' transcript segments to `query`, by transcript id.
+/// Segments that don't match any of its words are left out.
+pub async fn segment_scores(
+    pool: &SqlitePool,
+    query: &str,
+    meeting_ids: &[String],
+) -> Result<HashMap<String, f32>, String> {
+    let Some(fts_query) = build_retrieval_query(query) else {
+        return Ok(HashMap::new());
+    };
+
+    let ranks = SearchRepository::transcript_ranks(pool, &fts_query, meeting_ids)
+        .await
+        .map_err(|e| format!("Failed to search transcripts: {}", e))?;
+
+    log_info!(
+        "🔎 {} transcript segments of {} meetings match the question",
+        ranks.len(),
+        meeting_ids.len()
+    );
+
+    // bm25() is lower for better matches; flip it so scores add up
+    Ok(ranks.into_iter().map(|(id, rank)| (id, (-rank) as f32)).collect())
+}
+
+/// FTS5 query matching any of the question's words (stopwords left out)
+pub fn build_retrieval_query(question: &str) -> Option<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in question.split(|c: char| !c.is_alphanumeric()) {
+        let word = word.to_lowercase();
+        if word.chars().count() > 1
+            && !STOPWORDS.contains(&word.as_str())
+            && !terms.contains(&word)
+        {
+            terms.push(word);
+        }
+    }
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(
+            terms
+                .iter()
+                .map(|term| format!("\"{}\"", term))
+                .collect::<Vec<_>>()
+                .join(" OR "),
+        )
+    }
+}
+
+/// Split segments into overlapping chunks, keeping the time range of each chunk.
+///
+/// Segments are joined one per line and chunked with `chunk_text`; every chunk
+/// is an exact substring of the joined text, so its byte range tells us which
+/// segments (and therefore which audio range) it covers.
+pub fn chunk_segments(
+    segments: &[TimedSegment],
+    chunk_size_tokens: usize,
+    overlap_tokens: usize,
+) -> Vec<RetrievalChunk> {
+    let mut text = String::new();
+    let mut ranges = Vec::with_capacity(segments.len());
+
+    for segment in segments.iter().filter(|s| !s.text.is_empty()) {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        let start = text.len();
+        text.push_str(&segment.text);
+        ranges.push((start, text.len(), segment));
+    }
+
+    let mut chunks = Vec::new();
+    let mut search_from = 0;
+
+    for content in chunk_text(&text, chunk_size_tokens, overlap_tokens) {
+        let Some(offset) = text[search_from..].find(content.as_str()) else {
+            continue;
+        };
+        let start = search_from + offset;
+        let end = start + content.len();
+        search_from = start + content.chars().next().map_or(1, char::len_utf8);
+
+        let covered: Vec<&TimedSegment> = ranges
+            .iter()
+            .filter(|(s, e, _)| *s < end && *e > start)
+            .map(|(_, _, segment)| *segment)
+            .collect();
+
+        let trimmed = content.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        chunks.push(RetrievalChunk {
+            chunk_index: chunks.len() as i64,
+            content: trimmed.to_string(),
+            audio_start_time: covered.iter().find_map(|s| s.audio_start_time),
+            audio_end_time: covered
+                .iter()
+                .filter_map(|s| s.audio_end_time.or(s.audio_start_time))
+                .fold(None, |max: Option<f64>, t| Some(max.map_or(t, |m| m.max(t)))),
+            transcript_ids: covered.iter().map(|s| s.id.clone()).collect(),
+        });
+    }
+
+    chunks
+}
+
+/// Pick the `k` chunks of one meeting with the highest `segment_scores`, labeled in transcript order
+pub fn retrieve(
+    index: &[IndexedChunk],
+    scores: &HashMap<String, f32>,
+    k: usize,
+) -> Vec<RetrievedChunk> {
+    retrieve_ordered_by(index, scores, k, |_| ())
+}
+
+/// Like `retrieve` across meetings: chunks are labeled in the order of their
+/// meeting's `meeting_key`, then in transcript order
+pub fn retrieve_ordered_by<K, F>(
+    index: &[IndexedChunk],
+    scores: &HashMap<String, f32>,
+    k: usize,
+    meeting_key: F,
+) -> Vec<RetrievedChunk>
+where
+    K: Ord,
+    F: Fn(&str) -> K,
+{
+    let mut scored: Vec<(&IndexedChunk, f32)> = index
+        .iter()
+        .map(|c| {
+            let score = c
+                .chunk
+                .transcript_ids
+                .iter()
+                .filter_map(|id| scores.get(id))
+                .sum::<f32>();
+            (c, score)
+        })
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored.truncate(k);
+
+    // Transcript order reads better for the model than score order
+    scored.sort_by_key(|(c, _)| (meeting_key(&c.meeting_id), c.chunk.chunk_index));
+
+    scored
+        .into_iter()
+        .enumerate()
+        .map(|(i, (c, score))| RetrievedChunk {
+            label: i + 1,
+            meeting_id: c.meeting_id.clone(),
+            chunk: c.chunk.clone(),
+            score,
+        })
+        .collect()
+}
+
+/// Render retrieved chunks for the prompt: "[n] (00:12:30 - 00:13:45)" headers
+pub fn format_excerpts(retrieved: &[RetrievedChunk]) -> String {
+    format_excerpts_with(retrieved, |_| None)
+}
+
+/// Like `format_excerpts`, with an optional source line per chunk
+/// (e.g. meeting title and date) after the label
+pub fn format_excerpts_with<F>(retrieved: &[RetrievedChunk], source: F) -> String
+where
+    F: Fn(&RetrievedChunk) -> Option<String>,
+{
+    retrieved
+        .iter()
+        .map(|r| {
+            let time = match (r.chunk.audio_start_time, r.chunk.audio_end_time) {
+                (Some(start), Some(end)) => {
+                    format!(" ({} - {})", format_timestamp(start), format_timestamp(end))
+                }
+                (Some(start), None) => format!(" ({})", format_timestamp(start)),
+                _ => String::new(),
+            };
+            let source = source(r).map(|s| format!(" {}", s)).unwrap_or_default();
+            format!("[{}]{}{}\n{}", r.label, source, time, r.chunk.content)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Citations for the [n] references that actually appear in the answer
+pub fn citations_for_answer(retrieved: &[RetrievedChunk], answer: &str) -> Vec<ChatCitation> {
+    parse_citation_refs(answer, retrieved.len())
+        .into_iter()
+        .filter_map(|label| retrieved.iter().find(|r| r.label == label))
+        .map(|r| ChatCitation {
+            index: r.label,
+            meeting_id: r.meeting_id.clone(),
+            meeting_title: None,
+            chunk_index: r.chunk.chunk_index,
+            audio_start_time: r.chunk.audio_start_time,
+            audio_end_time: r.chunk.audio_end_time,
+            transcript_ids: r.chunk.transcript_ids.clone(),
+            excerpt: excerpt(&r.chunk.content),
+            score: r.score,
+        })
+        .collect()
+}
+
+/// Find `[n]` / `[n, m]` references in order of first use, ignoring labels above `max`
+pub fn parse_citation_refs(answer: &str, max: usize) -> Vec<usize> {
+    let mut refs = Vec::new();
+    let mut rest = answer;
+
+    while let Some(open) = rest.find('[') {
+        rest = &rest[open + 1..];
+        let Some(close) = rest.find(']') else {
+            break;
+        };
+        let inner = &rest[..close];
+
+        if !inner.trim().is_empty()
+            && inner.chars().all(|c| c.is_ascii_digit() || c == ',' || c.is_whitespace())
+        {
+            for label in inner.split(',').filter_map(|n| n.trim().parse::<usize>().ok()) {
+                if (1..=max).contains(&label) && !refs.contains(&label) {
+                    refs.push(label);
+                }
+            }
+        }
+    }
+
+    refs
+}
+
+fn excerpt(content: &str) -> String {
+    if content.chars().count() <= EXCERPT_CHARS {
+        return content.to_string();
+    }
+    let truncated: String = content.chars().take(EXCERPT_CHARS).collect();
+    match truncated.rfind(char::is_whitespace) {
+        Some(pos) => format!("{}…", &truncated[..pos]),
+        None => format!("{}…", truncated),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(id: &str, text: &str, start: f64, end: f64) -> TimedSegment {
+        TimedSegment {
+            id: id.to_string(),
+            text: text.to_string(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+        }
+    }
+
+    #[test]
+    fn test_chunks_carry_time_range_of_covered_segments() {
+        let segments: Vec<TimedSegment> = (0..20)
+            .map(|i| {
+                segment(
+                    &format!("t{}", i),
+                    &format!("segment number {} talks about topic {}", i, i),
+                    i as f64 * 10.0,
+                    i as f64 * 10.0 + 8.0,
+                )
+            })
+            .collect();
+
+        let chunks = chunk_segments(&segments, 40, 8);
+        assert!(chunks.len() > 1);
+
+        let first = &chunks[0];
+        assert_eq!(first.audio_start_time, Some(0.0));
+        assert_eq!(first.transcript_ids.first().map(String::as_str), Some("t0"));
+
+        let last = chunks.last().unwrap();
+        assert_eq!(last.audio_end_time, Some(198.0));
+        assert_eq!(last.transcript_ids.last().map(String::as_str), Some("t19"));
+
+        for pair in chunks.windows(2) {
+            assert!(pair[0].audio_start_time <= pair[1].audio_start_time);
+        }
+    }
+
+    #[test]
+    fn test_build_retrieval_query() {
+        assert_eq!(
+            build_retrieval_query("When do we deploy the billing service? Deploy it!").as_deref(),
+            Some("\"deploy\" OR \"billing\" OR \"service\"")
+        );
+        assert_eq!(
+            build_retrieval_query("O que foi a decisão?").as_deref(),
+            Some("\"decisão\"")
+        );
+        assert_eq!(build_retrieval_query("what is it?"), None);
+    }
+
+    #[test]
+    fn test_retrieve_prefers_relevant_chunk() {
+        let segments = [
+            segment("a", "Marketing wants new colors for the landing page", 0.0, 5.0),
+            segment("b", "We will deploy the billing service on Friday", 60.0, 65.0),
+        ];
+        let index: Vec<IndexedChunk> = segments
+            .iter()
+            .enumerate()
+            .map(|(i, s)| IndexedChunk {
+                meeting_id: "m1".to_string(),
+                chunk: RetrievalChunk {
+                    chunk_index: i as i64,
+                    content: s.text.clone(),
+                    audio_start_time: s.audio_start_time,
+                    audio_end_time: s.audio_end_time,
+                    transcript_ids: vec![s.id.clone()],
+                },
+            })
+            .collect();
+        let scores = HashMap::from([("a".to_string(), 0.5), ("b".to_string(), 2.0)]);
+
+        let retrieved = retrieve(&index, &scores, 1);
+        assert_eq!(retrieved.len(), 1);
+        assert_eq!(retrieved[0].label, 1);
+        assert_eq!(retrieved[0].chunk.transcript_ids, vec!["b".to_string()]);
+
+        let prompt = format_excerpts(&retrieved);
+        assert!(prompt.starts_with("[1] (00:01:00 - 00:01:05)\n"));
+
+        // Chunks without a matching segment are never sent
+        assert!(retrieve(&index, &HashMap::new(), 2).is_empty());
+    }
+
+    #[test]
+    fn test_chunk_scores_add_up_their_segments() {
+        let chunk = |chunk_index: i64, ids: &[&str]| IndexedChunk {
+            meeting_id: "m1".to_string(),
+            chunk: RetrievalChunk {
+                chunk_index,
+                content: ids.join(" "),
+                audio_start_time: None,
+                audio_end_time: None,
+                transcript_ids: ids.iter().map(|id| id.to_string()).collect(),
+            },
+        };
+        let index = vec![chunk(0, &["a"]), chunk(1, &["b", "c"])];
+        let scores = HashMap::from([
+            ("a".to_string(), 1.5),
+            ("b".to_string(), 1.0),
+            ("c".to_string(), 1.0),
+        ]);
+
+        let retrieved = retrieve(&index, &scores, 1);
+        assert_eq!(retrieved[0].chunk.chunk_index, 1);
+        assert_eq!(retrieved[0].score, 2.0);
+    }
+
+    #[test]
+    fn test_retrieve_orders_meetings_by_key() {
+        let chunk = |meeting_id: &str, chunk_index: i64| IndexedChunk {
+            meeting_id: meeting_id.to_string(),
+            chunk: RetrievalChunk {
+                chunk_index,
+                content: "billing deploy".to_string(),
+                audio_start_time: None,
+                audio_end_time: None,
+                transcript_ids: vec![format!("{}{}", meeting_id, chunk_index)],
+            },
+        };
+        let index = vec![chunk("a", 1), chunk("b", 0), chunk("a", 0)];
+        let scores: HashMap<String, f32> = ["a0", "a1", "b0"]
+            .iter()
+            .map(|id| (id.to_string(), 1.0))
+            .collect();
+
+        // Meeting "b" happened first
+        let order = |meeting_id: &str| if meeting_id == "b" { 0 } else { 1 };
+        let retrieved = retrieve_ordered_by(&index, &scores, 3, order);
+        let labeled: Vec<(usize, &str, i64)> = retrieved
+            .iter()
+            .map(|r| (r.label, r.meeting_id.as_str(), r.chunk.chunk_index))
+            .collect();
+        assert_eq!(labeled, vec![(1, "b", 0), (2, "a", 0), (3, "a", 1)]);
+    }
+
+    #[test]
+    fn test_parse_citation_refs() {
+        assert_eq!(
+            parse_citation_refs("Friday [2]. Owner is Ana [1, 3][2] see [9] or [note]", 3),
+            vec![2, 1, 3]
+        );
+        assert!(parse_citation_refs("no refs here", 3).is_empty());
+    }
+
+    #[test]
+    fn test_citations_for_answer_use_chunk_times() {
+        let retrieved = vec![RetrievedChunk {
+            label: 1,
+            meeting_id: "m1".to_string(),
+            chunk: RetrievalChunk {
+                chunk_index: 4,
+                content: "Ana: ship it Friday".to_string(),
+                audio_start_time: Some(120.0),
+                audio_end_time: Some(130.0),
+                transcript_ids: vec!["t7".to_string()],
+            },
+            score: 0.4,
+        }];
+
+        let citations = citations_for_answer(&retrieved, "Friday [1].");
+        assert_eq!(citations.len(), 1);
+        assert_eq!(citations[0].audio_start_time, Some(120.0));
+        assert_eq!(citations[0].meeting_id, "m1");
+        assert!(citations_for_answer(&retrieved, "Friday.").is_empty());
+    }
+}
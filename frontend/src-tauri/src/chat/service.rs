@@ -1,24 +1,35 @@
-use crate::chat::commands::AskQuestionResponse;
-use crate::database::models::ChatMessage;
+use crate::chat::commands::{AskLibraryQuestionResponse, AskQuestionResponse};
+use crate::chat::library::{self, ConversationFilters, DateRange};
+use crate::chat::retrieval::{self, ChatCitation, RetrievedChunk};
+use crate::database::models::{ChatMessage, ConversationMessage, MeetingModel};
 use crate::database::repositories::{
     chat_message::ChatMessagesRepository,
+    conversation::ConversationsRepository,
     meeting::MeetingsRepository,
     setting::SettingsRepository,
     summary::SummaryProcessesRepository,
 };
 use crate::summary::llm_client::{self, LLMProvider};
-use chrono::Utc;
+use crate::summary::streaming::{self, StreamEvent, StreamOutcome, CHAT_STREAM_EVENT};
+use chrono::{Local, Utc};
 use log::info as log_info;
 use reqwest::Client;
 use sqlx::SqlitePool;
-use tauri::{AppHandle, Runtime};
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Number of previous chat messages sent along with each question
+const MAX_HISTORY_MESSAGES: usize = 10;
 
 pub struct ChatService;
 
 impl ChatService {
-    /// Ask a question about a meeting with full context
+    /// Ask a question about a meeting, answered from the most relevant transcript excerpts.
+    ///
+    /// The answer streams to the frontend as `chat-stream` events. If the user cancels
+    /// mid-answer, the partial text is saved (flagged `cancelled` in the metadata).
     pub async fn ask_question<R: Runtime>(
-        _app: AppHandle<R>,
+        app: AppHandle<R>,
         pool: SqlitePool,
         meeting_id: String,
         question: String,
@@ -34,15 +45,7 @@ impl ChatService {
         let api_key = Self::get_api_key(&pool, &provider).await?;
 
         // 3. Get Ollama endpoint if applicable
-        let ollama_endpoint = if provider == LLMProvider::Ollama {
-            SettingsRepository::get_model_config(&pool)
-                .await
-                .ok()
-                .flatten()
-                .and_then(|s| s.ollama_endpoint)
-        } else {
-            None
-        };
+        let ollama_endpoint = Self::get_ollama_endpoint(&pool, &provider).await;
 
         // 4. Get meeting details
         let meeting = MeetingsRepository::get_meeting(&pool, &meeting_id)
@@ -50,20 +53,8 @@ impl ChatService {
             .map_err(|e| format!("Failed to get meeting: {}", e))?
             .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
 
-        // 5. Get transcripts - query directly from database
-        let transcripts: Vec<crate::database::models::Transcript> = sqlx::query_as(
-            "SELECT * FROM transcripts WHERE meeting_id = ? ORDER BY timestamp ASC"
-        )
-        .bind(&meeting_id)
-        .fetch_all(&pool)
-        .await
-        .map_err(|e| format!("Failed to get transcripts: {}", e))?;
-
-        let transcript_text = transcripts
-            .iter()
-            .map(|t| t.transcript.clone())
-            .collect::<Vec<_>>()
-            .join("\n\n");
+        // 5. Split the meeting's transcript into chunks
+        let index = retrieval::load_chunks(&pool, &meeting_id).await?;
 
         // 6. Get summary if it exists
         let summary_data = SummaryProcessesRepository::get_summary_data_for_meeting(&pool, &meeting_id)
@@ -93,17 +84,38 @@ impl ChatService {
             None
         };
 
-        // 7. Get chat history
+        // 7. Get recent chat history
         let chat_history = ChatMessagesRepository::get_messages(&pool, &meeting_id)
             .await
             .map_err(|e| format!("Failed to get chat history: {}", e))?;
+        let recent_history =
+            &chat_history[chat_history.len().saturating_sub(MAX_HISTORY_MESSAGES)..];
+
+        // 8. Retrieve the transcript chunks most relevant to the question. The previous
+        // question is included so follow-ups ("and who owns it?") keep their topic.
+        let retrieval_query = match recent_history.iter().rev().find(|m| m.role == "user") {
+            Some(previous) => format!("{}\n{}", previous.content, question),
+            None => question.clone(),
+        };
+        let scores = retrieval::segment_scores(
+            &pool,
+            &retrieval_query,
+            std::slice::from_ref(&meeting_id),
+        )
+        .await?;
+        let retrieved = retrieval::retrieve(&index, &scores, retrieval::TOP_K);
+
+        log_info!(
+            "🔎 Retrieved {} of {} transcript chunks for the question",
+            retrieved.len(),
+            index.len()
+        );
 
-        // 8. Build context for LLM
         let context = Self::build_context(
             &meeting.title,
-            &transcript_text,
+            &retrieved,
             summary_text.as_deref(),
-            &chat_history,
+            recent_history,
             &question,
         );
 
@@ -115,9 +127,10 @@ impl ChatService {
 
         log_info!("📝 Context built, sending to LLM (provider: {}, model: {})", model_provider, model_name);
 
-        // 11. Call LLM
+        // 11. Call LLM, streaming tokens to the frontend
+        let registration = streaming::register_stream(streaming::chat_stream_key(&meeting_id));
         let client = Client::new();
-        let answer = llm_client::generate_summary(
+        let outcome = llm_client::generate_summary_stream(
             &client,
             &provider,
             &model_name,
@@ -125,10 +138,47 @@ impl ChatService {
             &system_prompt,
             &context,
             ollama_endpoint.as_deref(),
+            registration.cancellation(),
+            |delta| {
+                let _ = app.emit(CHAT_STREAM_EVENT, StreamEvent::token(&meeting_id, delta));
+            },
         )
-        .await?;
+        .await
+        .map_err(|e| {
+            let _ = app.emit(CHAT_STREAM_EVENT, StreamEvent::failed(&meeting_id, &e));
+            e
+        })?;
+        drop(registration);
+
+        let (answer, cancelled) = match outcome {
+            StreamOutcome::Completed(answer) => {
+                log_info!("✅ Received answer from LLM");
+                let _ = app.emit(CHAT_STREAM_EVENT, StreamEvent::completed(&meeting_id, &answer));
+                (answer, false)
+            }
+            StreamOutcome::Cancelled(partial) => {
+                log_info!("⏹️ Answer cancelled after {} chars", partial.len());
+                let _ = app.emit(CHAT_STREAM_EVENT, StreamEvent::cancelled(&meeting_id, &partial));
+                if partial.is_empty() {
+                    return Err("Answer cancelled".to_string());
+                }
+                (partial, true)
+            }
+        };
 
-        log_info!("✅ Received answer from LLM");
+        let citations = retrieval::citations_for_answer(&retrieved, &answer);
+        let mut metadata = serde_json::Map::new();
+        if !citations.is_empty() {
+            metadata.insert("citations".to_string(), serde_json::json!(&citations));
+        }
+        if cancelled {
+            metadata.insert("cancelled".to_string(), serde_json::Value::Bool(true));
+        }
+        let metadata = if metadata.is_empty() {
+            None
+        } else {
+            serde_json::to_string(&metadata).ok()
+        };
 
         // 12. Save user message
         let user_message = ChatMessagesRepository::save_message(
@@ -147,7 +197,7 @@ impl ChatService {
             &meeting_id,
             "assistant",
             &answer,
-            None,
+            metadata,
         )
         .await
         .map_err(|e| format!("Failed to save assistant message: {}", e))?;
@@ -158,9 +208,263 @@ impl ChatService {
             answer,
             user_message,
             assistant_message,
+            citations,
+        })
+    }
+
+    /// Ask a question across the meeting library (all meetings, or a filtered set).
+    ///
+    /// Starts a new conversation when `conversation_id` is `None`. Passing
+    /// `filters` replaces the conversation's stored filters; otherwise the stored
+    /// ones are used. Without an explicit date range, phrases like "last month"
+    /// in the question narrow the meetings searched.
+    ///
+    /// The answer streams as `chat-stream` events with `request_id` as the meeting id,
+    /// and is cancelled by it. A cancelled answer keeps its partial text, as in
+    /// `ask_question`.
+    pub async fn ask_library_question<R: Runtime>(
+        app: AppHandle<R>,
+        pool: SqlitePool,
+        request_id: String,
+        conversation_id: Option<String>,
+        question: String,
+        filters: Option<ConversationFilters>,
+        model_provider: String,
+        model_name: String,
+    ) -> Result<AskLibraryQuestionResponse, String> {
+        if request_id.trim().is_empty() {
+            return Err("request_id cannot be empty".to_string());
+        }
+        log_info!("🚀 Starting library question (conversation: {:?})", conversation_id);
+
+        // 1. Validate provider, API key and Ollama endpoint
+        let provider = LLMProvider::from_str(&model_provider)?;
+        let api_key = Self::get_api_key(&pool, &provider).await?;
+        let ollama_endpoint = Self::get_ollama_endpoint(&pool, &provider).await;
+
+        let filters_json = filters
+            .as_ref()
+            .map(|f| serde_json::to_string(f).map_err(|e| format!("Invalid filters: {}", e)))
+            .transpose()?;
+
+        // 2. Get the conversation; a new one is only created once the question is answered
+        let existing = match conversation_id {
+            Some(id) => {
+                let mut conversation = ConversationsRepository::get_conversation(&pool, &id)
+                    .await
+                    .map_err(|e| format!("Failed to get conversation: {}", e))?
+                    .ok_or_else(|| format!("Conversation not found: {}", id))?;
+
+                if filters_json.is_some() && filters_json != conversation.filters {
+                    ConversationsRepository::update_filters(&pool, &id, filters_json.clone())
+                        .await
+                        .map_err(|e| format!("Failed to update conversation filters: {}", e))?;
+                    conversation.filters = filters_json.clone();
+                }
+                Some(conversation)
+            }
+            None => None,
+        };
+
+        let filters: ConversationFilters = existing
+            .as_ref()
+            .map_or(filters_json.as_deref(), |c| c.filters.as_deref())
+            .and_then(|f| serde_json::from_str(f).ok())
+            .unwrap_or_default();
+
+        // 3. Select the meetings to search
+        let mut range = filters.date_range()?;
+        if range.is_unbounded() {
+            if let Some(inferred) = library::infer_date_range(&question, Local::now().date_naive()) {
+                log_info!("📅 Question implies date range {:?} - {:?}", inferred.from, inferred.to);
+                range = inferred;
+            }
+        }
+
+        let all_meetings = MeetingsRepository::get_meetings(&pool)
+            .await
+            .map_err(|e| format!("Failed to get meetings: {}", e))?;
+        let meetings = library::filter_meetings(all_meetings, &filters, range);
+
+        // 4. Split the transcript of every selected meeting into chunks
+        let index = library::load_library_chunks(&pool, &meetings).await?;
+
+        // 5. Get recent conversation history
+        let history = match &existing {
+            Some(conversation) => ConversationsRepository::get_messages(&pool, &conversation.id)
+                .await
+                .map_err(|e| format!("Failed to get conversation history: {}", e))?,
+            None => Vec::new(),
+        };
+        let recent_history = &history[history.len().saturating_sub(MAX_HISTORY_MESSAGES)..];
+
+        // 6. Retrieve the most relevant chunks across the selected meetings
+        let retrieval_query = match recent_history.iter().rev().find(|m| m.role == "user") {
+            Some(previous) => format!("{}\n{}", previous.content, question),
+            None => question.clone(),
+        };
+        let meeting_ids: Vec<String> = meetings.iter().map(|m| m.id.clone()).collect();
+        let scores = retrieval::segment_scores(&pool, &retrieval_query, &meeting_ids).await?;
+        let meetings_by_id: HashMap<String, MeetingModel> =
+            meetings.into_iter().map(|m| (m.id.clone(), m)).collect();
+        // Excerpts read in the order the meetings happened
+        let retrieved = retrieval::retrieve_ordered_by(
+            &index,
+            &scores,
+            library::LIBRARY_TOP_K,
+            |meeting_id| meetings_by_id.get(meeting_id).map(|m| m.created_at.0),
+        );
+
+        log_info!(
+            "🔎 Retrieved {} of {} chunks from {} meetings",
+            retrieved.len(),
+            index.len(),
+            meetings_by_id.len()
+        );
+
+        let context = Self::build_library_context(
+            &meetings_by_id,
+            range,
+            &retrieved,
+            recent_history,
+            &question,
+        );
+
+        // 7. Call LLM, streaming tokens to the frontend
+        let language = Self::get_language(&pool).await.unwrap_or_else(|_| "pt-BR".to_string());
+        let system_prompt = Self::build_library_system_prompt(&language);
+
+        log_info!("📝 Context built, sending to LLM (provider: {}, model: {})", model_provider, model_name);
+
+        let stream_id = request_id.as_str();
+        let registration = streaming::register_stream(streaming::library_stream_key(stream_id));
+        let client = Client::new();
+        let outcome = llm_client::generate_summary_stream(
+            &client,
+            &provider,
+            &model_name,
+            &api_key,
+            &system_prompt,
+            &context,
+            ollama_endpoint.as_deref(),
+            registration.cancellation(),
+            |delta| {
+                let _ = app.emit(CHAT_STREAM_EVENT, StreamEvent::token(stream_id, delta));
+            },
+        )
+        .await
+        .map_err(|e| {
+            let _ = app.emit(CHAT_STREAM_EVENT, StreamEvent::failed(stream_id, &e));
+            e
+        })?;
+        drop(registration);
+
+        let (answer, cancelled) = match outcome {
+            StreamOutcome::Completed(answer) => {
+                log_info!("✅ Received answer from LLM");
+                let _ = app.emit(CHAT_STREAM_EVENT, StreamEvent::completed(stream_id, &answer));
+                (answer, false)
+            }
+            StreamOutcome::Cancelled(partial) => {
+                log_info!("⏹️ Answer cancelled after {} chars", partial.len());
+                let _ = app.emit(CHAT_STREAM_EVENT, StreamEvent::cancelled(stream_id, &partial));
+                if partial.is_empty() {
+                    return Err("Answer cancelled".to_string());
+                }
+                (partial, true)
+            }
+        };
+
+        let citations: Vec<ChatCitation> = retrieval::citations_for_answer(&retrieved, &answer)
+            .into_iter()
+            .map(|mut citation| {
+                citation.meeting_title = meetings_by_id
+                    .get(&citation.meeting_id)
+                    .map(|m| m.title.clone());
+                citation
+            })
+            .collect();
+        let mut metadata = serde_json::Map::new();
+        if !citations.is_empty() {
+            metadata.insert("citations".to_string(), serde_json::json!(&citations));
+        }
+        if cancelled {
+            metadata.insert("cancelled".to_string(), serde_json::Value::Bool(true));
+        }
+        let metadata = if metadata.is_empty() {
+            None
+        } else {
+            serde_json::to_string(&metadata).ok()
+        };
+
+        // 8. Save both messages, in a new conversation unless continuing one
+        let conversation = match existing {
+            Some(conversation) => conversation,
+            None => ConversationsRepository::create_conversation(
+                &pool,
+                &Self::conversation_title(&question),
+                filters_json,
+            )
+            .await
+            .map_err(|e| format!("Failed to create conversation: {}", e))?,
+        };
+
+        let user_message = ConversationsRepository::save_message(
+            &pool,
+            &conversation.id,
+            "user",
+            &question,
+            None,
+        )
+        .await
+        .map_err(|e| format!("Failed to save user message: {}", e))?;
+
+        let assistant_message = ConversationsRepository::save_message(
+            &pool,
+            &conversation.id,
+            "assistant",
+            &answer,
+            metadata,
+        )
+        .await
+        .map_err(|e| format!("Failed to save assistant message: {}", e))?;
+
+        log_info!("💾 Messages saved to conversation {}", conversation.id);
+
+        Ok(AskLibraryQuestionResponse {
+            request_id,
+            conversation,
+            answer,
+            user_message,
+            assistant_message,
+            citations,
         })
     }
 
+    /// Conversation title from its first question
+    fn conversation_title(question: &str) -> String {
+        const MAX_TITLE_CHARS: usize = 60;
+        let question = question.trim();
+        if question.chars().count() <= MAX_TITLE_CHARS {
+            question.to_string()
+        } else {
+            let truncated: String = question.chars().take(MAX_TITLE_CHARS).collect();
+            format!("{}…", truncated.trim_end())
+        }
+    }
+
+    /// Get the configured Ollama endpoint (only for the Ollama provider)
+    async fn get_ollama_endpoint(pool: &SqlitePool, provider: &LLMProvider) -> Option<String> {
+        if provider != &LLMProvider::Ollama {
+            return None;
+        }
+        SettingsRepository::get_model_config(pool)
+            .await
+            .ok()
+            .flatten()
+            .and_then(|s| s.ollama_endpoint)
+    }
+
     /// Get API key for the specified provider from settings
     async fn get_api_key(pool: &SqlitePool, provider: &LLMProvider) -> Result<String, String> {
         let settings = SettingsRepository::get_model_config(pool)
@@ -207,10 +511,11 @@ impl ChatService {
             "en" | "en-US" => {
                 format!(
                     "You are an AI assistant helping users understand their meeting notes. \
-                    Today's date is {}. You have access to the meeting transcript, summary, \
-                    and previous conversation history. Answer questions accurately based on the \
-                    context provided. If information is not in the context, say so clearly. \
-                    Be concise but comprehensive in your responses. Respond in English.",
+                    Today's date is {}. You have access to numbered excerpts of the meeting \
+                    transcript, the summary and the recent conversation. Answer questions accurately \
+                    based on the context provided. If information is not in the context, say so clearly. \
+                    When you use a transcript excerpt, cite it with its number in square brackets, \
+                    e.g. [2]. Be concise but comprehensive in your responses. Respond in English.",
                     current_date
                 )
             }
@@ -218,20 +523,21 @@ impl ChatService {
                 // Default to Portuguese Brazil
                 format!(
                     "Você é um assistente de IA ajudando usuários a entender suas anotações de reunião. \
-                    A data de hoje é {}. Você tem acesso à transcrição da reunião, ao resumo, \
-                    e ao histórico de conversas anteriores. Responda perguntas com precisão baseado no \
+                    A data de hoje é {}. Você tem acesso a trechos numerados da transcrição da reunião, \
+                    ao resumo e às mensagens recentes da conversa. Responda perguntas com precisão baseado no \
                     contexto fornecido. Se a informação não estiver no contexto, deixe isso claro. \
-                    Seja conciso mas abrangente em suas respostas. Responda em português do Brasil.",
+                    Ao usar um trecho da transcrição, cite-o com seu número entre colchetes, \
+                    por exemplo [2]. Seja conciso mas abrangente em suas respostas. Responda em português do Brasil.",
                     current_date
                 )
             }
         }
     }
 
-    /// Build context for LLM with all available information
+    /// Build context for LLM from the retrieved transcript excerpts
     fn build_context(
         meeting_title: &str,
-        transcript: &str,
+        retrieved: &[RetrievedChunk],
         summary: Option<&str>,
         chat_history: &[ChatMessage],
         current_question: &str,
@@ -241,9 +547,13 @@ impl ChatService {
         // Meeting title
         context.push_str(&format!("# Meeting Title\n{}\n\n", meeting_title));
 
-        // Transcript
-        context.push_str("# Transcript\n");
-        context.push_str(transcript);
+        // Relevant transcript excerpts, labeled [n] for citations
+        context.push_str("# Relevant Transcript Excerpts\n");
+        if retrieved.is_empty() {
+            context.push_str("(No transcript excerpts matched this question.)");
+        } else {
+            context.push_str(&retrieval::format_excerpts(retrieved));
+        }
         context.push_str("\n\n");
 
         // Summary if available
@@ -253,7 +563,7 @@ impl ChatService {
             context.push_str("\n\n");
         }
 
-        // Chat history if exists
+        // Recent chat history if exists
         if !chat_history.is_empty() {
             context.push_str("# Previous Conversation\n");
             for msg in chat_history {
@@ -267,4 +577,82 @@ impl ChatService {
 
         context
     }
+
+    /// Build system prompt for library-wide questions based on language
+    fn build_library_system_prompt(language: &str) -> String {
+        let current_date = Utc::now().format("%Y-%m-%d").to_string();
+
+        match language {
+            "en" | "en-US" => {
+                format!(
+                    "You are an AI assistant helping users find information across all of their \
+                    meetings. Today's date is {}. You have access to numbered transcript excerpts \
+                    from several meetings, each labeled with the meeting title and date, and the \
+                    recent conversation. Answer based only on that context and mention which meeting \
+                    and when something was said. If information is not in the context, say so clearly. \
+                    Cite every excerpt you use with its number in square brackets, e.g. [2]. \
+                    Be concise but comprehensive in your responses. Respond in English.",
+                    current_date
+                )
+            }
+            _ => {
+                // Default to Portuguese Brazil
+                format!(
+                    "Você é um assistente de IA ajudando usuários a encontrar informações em todas as \
+                    suas reuniões. A data de hoje é {}. Você tem acesso a trechos numerados de transcrições \
+                    de várias reuniões, cada um identificado com o título e a data da reunião, e às mensagens \
+                    recentes da conversa. Responda apenas com base nesse contexto e mencione em qual reunião \
+                    e quando algo foi dito. Se a informação não estiver no contexto, deixe isso claro. \
+                    Cite cada trecho usado com seu número entre colchetes, por exemplo [2]. \
+                    Seja conciso mas abrangente em suas respostas. Responda em português do Brasil.",
+                    current_date
+                )
+            }
+        }
+    }
+
+    /// Build context for library-wide questions from excerpts of several meetings
+    fn build_library_context(
+        meetings: &HashMap<String, MeetingModel>,
+        range: DateRange,
+        retrieved: &[RetrievedChunk],
+        history: &[ConversationMessage],
+        current_question: &str,
+    ) -> String {
+        let mut context = String::new();
+
+        // Which meetings were searched
+        context.push_str("# Meetings Searched\n");
+        context.push_str(&format!("{} meetings", meetings.len()));
+        match (range.from, range.to) {
+            (Some(from), Some(to)) => context.push_str(&format!(" from {} to {}", from, to)),
+            (Some(from), None) => context.push_str(&format!(" since {}", from)),
+            (None, Some(to)) => context.push_str(&format!(" until {}", to)),
+            (None, None) => {}
+        }
+        context.push_str("\n\n");
+
+        // Relevant transcript excerpts, labeled [n] with meeting title and date
+        context.push_str("# Relevant Transcript Excerpts\n");
+        if retrieved.is_empty() {
+            context.push_str("(No transcript excerpts matched this question.)");
+        } else {
+            context.push_str(&library::format_library_excerpts(retrieved, meetings));
+        }
+        context.push_str("\n\n");
+
+        // Recent conversation if exists
+        if !history.is_empty() {
+            context.push_str("# Previous Conversation\n");
+            for msg in history {
+                let role_label = if msg.role == "user" { "User" } else { "Assistant" };
+                context.push_str(&format!("{}: {}\n\n", role_label, msg.content));
+            }
+        }
+
+        // Current question
+        context.push_str(&format!("# Current Question\n{}", current_question));
+
+        context
+    }
 }
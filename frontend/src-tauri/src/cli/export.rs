@@ -0,0 +1,184 @@
+// cli/export.rs
+//
+// Rendering of a stored meeting for `meetily-cli export`.
+
+use crate::api::{MeetingDetails, MeetingTranscript};
+use crate::subtitles::{self, CueLimits, SubtitleFormat};
+use crate::utils::format_timestamp;
+use clap::ValueEnum;
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Meeting, transcript and summary as JSON
+    Json,
+    /// Summary and transcript as a markdown document
+    Markdown,
+    /// Transcript only, one line per segment
+    Text,
+    /// Captions as SubRip subtitles
+    Srt,
+    /// Captions as WebVTT subtitles
+    Vtt,
+}
+
+/// Render a meeting with its current summary (markdown, plus the validated JSON
+/// of structured summaries)
+pub fn render(
+    format: ExportFormat,
+    meeting: &MeetingDetails,
+    summary: Option<&str>,
+    structured: Option<&Value>,
+) -> Result<String, String> {
+    match format {
+        ExportFormat::Json => {
+            let export = json!({
+                "meeting": meeting,
+                "summary": summary.map(|markdown| json!({
+                    "markdown": markdown,
+                    "structured": structured,
+                })),
+            });
+            serde_json::to_string_pretty(&export)
+                .map_err(|e| format!("Failed to serialize meeting: {}", e))
+        }
+        ExportFormat::Markdown => Ok(render_markdown(meeting, summary)),
+        ExportFormat::Text => Ok(render_text(&meeting.transcripts)),
+        ExportFormat::Srt => {
+            subtitles::render_subtitles(&meeting.transcripts, SubtitleFormat::Srt, &CueLimits::default())
+        }
+        ExportFormat::Vtt => {
+            subtitles::render_subtitles(&meeting.transcripts, SubtitleFormat::Vtt, &CueLimits::default())
+        }
+    }
+}
+
+fn render_markdown(meeting: &MeetingDetails, summary: Option<&str>) -> String {
+    let mut out = format!("# {}\n\n_{}_\n\n", meeting.title, meeting.created_at);
+
+    if let Some(summary) = summary.map(str::trim).filter(|s| !s.is_empty()) {
+        out.push_str("## Summary\n\n");
+        out.push_str(summary);
+        out.push_str("\n\n");
+    }
+
+    out.push_str("## Transcript\n\n");
+    for segment in &meeting.transcripts {
+        match speaker_label(segment) {
+            Some(speaker) => out.push_str(&format!(
+                "**[{}] {}:** {}\n\n",
+                segment_time(segment),
+                speaker,
+                segment.text.trim()
+            )),
+            None => out.push_str(&format!(
+                "**[{}]** {}\n\n",
+                segment_time(segment),
+                segment.text.trim()
+            )),
+        }
+    }
+    out
+}
+
+fn render_text(transcripts: &[MeetingTranscript]) -> String {
+    transcripts
+        .iter()
+        .map(|segment| match speaker_label(segment) {
+            Some(speaker) => format!(
+                "[{}] {}: {}",
+                segment_time(segment),
+                speaker,
+                segment.text.trim()
+            ),
+            None => format!("[{}] {}", segment_time(segment), segment.text.trim()),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Recording-relative time when known, the stored display time otherwise
+fn segment_time(segment: &MeetingTranscript) -> String {
+    segment
+        .audio_start_time
+        .map(format_timestamp)
+        .unwrap_or_else(|| segment.timestamp.clone())
+}
+
+fn speaker_label(segment: &MeetingTranscript) -> Option<&str> {
+    segment
+        .speaker_name
+        .as_deref()
+        .or(segment.speaker.as_deref())
+        .filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, start: Option<f64>, speaker_name: Option<&str>) -> MeetingTranscript {
+        MeetingTranscript {
+            id: "t".to_string(),
+            text: text.to_string(),
+            timestamp: "10:15:00".to_string(),
+            audio_start_time: start,
+            audio_end_time: None,
+            duration: None,
+            words: Vec::new(),
+            speaker: None,
+            speaker_name: speaker_name.map(str::to_string),
+            source: None,
+            confidence: None,
+        }
+    }
+
+    fn meeting() -> MeetingDetails {
+        MeetingDetails {
+            id: "meeting-1".to_string(),
+            title: "Weekly sync".to_string(),
+            created_at: "2025-11-20T10:00:00Z".to_string(),
+            updated_at: "2025-11-20T11:00:00Z".to_string(),
+            status: "completed".to_string(),
+            transcripts: vec![
+                segment(" Hello everyone ", Some(65.2), Some("Alice")),
+                segment("Hi", None, None),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_render_text() {
+        let text = render(ExportFormat::Text, &meeting(), None, None).unwrap();
+        assert_eq!(text, "[00:01:05] Alice: Hello everyone\n[10:15:00] Hi");
+    }
+
+    #[test]
+    fn test_render_markdown_with_and_without_summary() {
+        let with_summary =
+            render(ExportFormat::Markdown, &meeting(), Some("- Shipped it\n"), None).unwrap();
+        assert!(with_summary.starts_with("# Weekly sync\n"));
+        assert!(with_summary.contains("## Summary\n\n- Shipped it\n\n## Transcript"));
+        assert!(with_summary.contains("**[00:01:05] Alice:** Hello everyone"));
+        assert!(with_summary.contains("**[10:15:00]** Hi"));
+
+        let without_summary = render(ExportFormat::Markdown, &meeting(), None, None).unwrap();
+        assert!(!without_summary.contains("## Summary"));
+    }
+
+    #[test]
+    fn test_render_json() {
+        let structured = json!({"decisions": ["Ship it"]});
+        let rendered =
+            render(ExportFormat::Json, &meeting(), Some("- Ship it"), Some(&structured)).unwrap();
+        let value: Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(value["meeting"]["id"], "meeting-1");
+        assert_eq!(value["meeting"]["transcripts"].as_array().unwrap().len(), 2);
+        assert_eq!(value["summary"]["markdown"], "- Ship it");
+        assert_eq!(value["summary"]["structured"], structured);
+
+        let rendered = render(ExportFormat::Json, &meeting(), None, None).unwrap();
+        let value: Value = serde_json::from_str(&rendered).unwrap();
+        assert!(value["summary"].is_null());
+    }
+}
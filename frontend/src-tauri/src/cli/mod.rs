@@ -0,0 +1,684 @@
+// cli/mod.rs
+//
+// Headless command line interface (`meetily-cli`) for scripting: transcribe a
+// file with Parakeet or import an exported transcript, summarize or export a
+// stored meeting, and list or search the library. It works on the app's own
+// database and models, without a window.
+//
+// Results are printed to stdout as JSON (exports print the rendered document),
+// errors to stderr as `{"error": "..."}`, and the outcome is reported through
+// the exit code, see `EXIT_*`.
+
+mod export;
+
+use crate::audio::import::{self, ImportStage, TranscribeOutcome};
+use crate::audio::recording_preferences::get_default_recordings_folder;
+use crate::audio::transcription::TranscriptionEngine;
+use crate::database::manager::DatabaseManager;
+use crate::database::repositories::{
+    meeting::MeetingsRepository, search::SearchRepository, speaker::SpeakersRepository,
+    summary::SummaryProcessesRepository, summary_version::NewSummaryVersion,
+    transcript::TranscriptsRepository,
+};
+use crate::parakeet_engine::{DecodingConfig, ModelStatus, ParakeetEngine};
+use crate::summary::processor::{generate_meeting_summary, generate_structured_meeting_summary};
+use crate::summary::streaming::{self, StreamCancellation};
+use crate::summary::{versions, SummaryService};
+use crate::transcript_import;
+use clap::{Parser, Subcommand};
+use export::ExportFormat;
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_FAILED: i32 = 1;
+/// Invalid arguments (reported by clap)
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_NOT_FOUND: i32 = 3;
+/// Interrupted with Ctrl-C
+pub const EXIT_CANCELLED: i32 = 130;
+
+/// Tauri identifier; the app keeps its data under `<data dir>/<identifier>`
+const APP_IDENTIFIER: &str = "com.meetily.ai";
+
+/// Preferred Parakeet model when none is given (same default as the app)
+const DEFAULT_PARAKEET_MODEL: &str = "parakeet-tdt-0.6b-v3-int8";
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "meetily-cli",
+    version,
+    about = "Transcribe, summarize and export Meetily meetings from the command line",
+    after_help = "Exit codes: 0 success, 1 failure, 2 invalid arguments, 3 meeting or model not found, 130 interrupted"
+)]
+struct Cli {
+    /// App data directory holding the database and models [default: the app's]
+    #[arg(long, global = true)]
+    data_dir: Option<PathBuf>,
+
+    /// Log progress to stderr
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Transcribe an audio or video file (mp4, m4a, wav, mp3) with Parakeet
+    Transcribe {
+        file: PathBuf,
+        /// Parakeet model to use [default: the app's default if downloaded, else any downloaded one]
+        #[arg(long)]
+        model: Option<String>,
+        /// Hypotheses kept while decoding; 1 decodes greedily, wider beams are slower
+        #[arg(long, default_value_t = 1)]
+        beam_width: usize,
+        /// Word or phrase to favour with beam search, e.g. a product name (repeatable)
+        #[arg(long = "hotword")]
+        hotwords: Vec<String>,
+        /// Log-probability bonus for each token of a hotword
+        #[arg(long, default_value_t = 2.0)]
+        hotword_boost: f32,
+        /// Save the transcript as a new meeting in the library
+        #[arg(long)]
+        save: bool,
+        /// Title of the saved meeting [default: the file name]
+        #[arg(long, requires = "save")]
+        title: Option<String>,
+        /// Folder to create the meeting folder in [default: the recordings folder]
+        #[arg(long, requires = "save")]
+        folder: Option<PathBuf>,
+    },
+    /// Import an exported transcript (WebVTT, SRT, Zoom text or Otter JSON) as a new meeting
+    ImportTranscript {
+        file: PathBuf,
+        /// Title of the meeting [default: the file name]
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Summarize a stored meeting and save the result as its current summary
+    Summarize {
+        meeting_id: String,
+        /// LLM provider, e.g. ollama, openai, claude, groq, openrouter
+        #[arg(long)]
+        provider: String,
+        /// Model name, e.g. llama3.2:latest
+        #[arg(long)]
+        model: String,
+        /// Summary template id (see `templates`)
+        #[arg(long, default_value = "daily_standup")]
+        template: String,
+        /// Additional context for the summary
+        #[arg(long)]
+        prompt: Option<String>,
+        /// Ask for JSON keyed by the template's sections instead of markdown
+        #[arg(long)]
+        structured: bool,
+    },
+    /// Export a stored meeting with its transcript and current summary
+    Export {
+        meeting_id: String,
+        #[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+        format: ExportFormat,
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// List the meetings in the library, newest first
+    List {
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+    /// Search transcripts, summaries and chats
+    Search {
+        query: String,
+        #[arg(long)]
+        limit: Option<i64>,
+    },
+    /// List the available summary templates
+    Templates,
+}
+
+#[derive(Debug)]
+enum CliError {
+    NotFound(String),
+    Cancelled,
+    Failed(String),
+}
+
+impl CliError {
+    fn exit_code(&self) -> i32 {
+        match self {
+            Self::NotFound(_) => EXIT_NOT_FOUND,
+            Self::Cancelled => EXIT_CANCELLED,
+            Self::Failed(_) => EXIT_FAILED,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            Self::NotFound(message) | Self::Failed(message) => message,
+            Self::Cancelled => "Interrupted",
+        }
+    }
+}
+
+impl From<String> for CliError {
+    fn from(message: String) -> Self {
+        Self::Failed(message)
+    }
+}
+
+type CliResult = Result<Value, CliError>;
+
+/// Parse the arguments, run the command and return the process exit code
+pub fn run() -> i32 {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => {
+            let _ = e.print();
+            return if e.use_stderr() { EXIT_USAGE } else { EXIT_OK };
+        }
+    };
+
+    // stdout is reserved for results; logs go to stderr
+    env_logger::Builder::new()
+        .filter_level(if cli.verbose {
+            log::LevelFilter::Info
+        } else {
+            log::LevelFilter::Warn
+        })
+        .target(env_logger::Target::Stderr)
+        .init();
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => return report(Err(CliError::Failed(format!("Failed to start runtime: {}", e)))),
+    };
+    report(runtime.block_on(execute(cli)))
+}
+
+/// Print the result and map it to an exit code
+fn report(result: Result<Option<Value>, CliError>) -> i32 {
+    match result {
+        Ok(output) => {
+            if let Some(output) = output {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&output).unwrap_or_else(|_| output.to_string())
+                );
+            }
+            EXIT_OK
+        }
+        Err(e) => {
+            eprintln!("{}", json!({ "error": e.message() }));
+            e.exit_code()
+        }
+    }
+}
+
+async fn execute(cli: Cli) -> Result<Option<Value>, CliError> {
+    let data_dir = match cli.data_dir {
+        Some(dir) => dir,
+        None => dirs::data_dir()
+            .map(|dir| dir.join(APP_IDENTIFIER))
+            .ok_or_else(|| "Could not find the app data directory, pass --data-dir".to_string())?,
+    };
+
+    match cli.command {
+        Command::Transcribe {
+            file,
+            model,
+            beam_width,
+            hotwords,
+            hotword_boost,
+            save,
+            title,
+            folder,
+        } => {
+            let pool = if save { Some(open_database(&data_dir).await?) } else { None };
+            let folder = folder.unwrap_or_else(get_default_recordings_folder);
+            let decoding = DecodingConfig {
+                beam_width,
+                hotword_boost,
+                hotwords,
+            }
+            .normalized();
+            transcribe(
+                &data_dir,
+                &file,
+                model,
+                decoding,
+                pool.as_ref().map(|pool| (pool, title, folder)),
+            )
+            .await
+            .map(Some)
+        }
+        Command::ImportTranscript { file, title } => {
+            if !file.is_file() {
+                return Err(CliError::NotFound(format!(
+                    "File not found: {}",
+                    file.display()
+                )));
+            }
+            let pool = open_database(&data_dir).await?;
+            let meeting_id = transcript_import::import_transcript_file(&pool, &file, title).await?;
+            Ok(Some(json!({ "file": file, "meeting_id": meeting_id })))
+        }
+        Command::Summarize {
+            meeting_id,
+            provider,
+            model,
+            template,
+            prompt,
+            structured,
+        } => {
+            let pool = open_database(&data_dir).await?;
+            summarize(
+                &pool,
+                &meeting_id,
+                &provider,
+                &model,
+                &template,
+                &prompt.unwrap_or_default(),
+                structured,
+            )
+            .await
+            .map(Some)
+        }
+        Command::Export {
+            meeting_id,
+            format,
+            output,
+        } => {
+            let pool = open_database(&data_dir).await?;
+            let rendered = export_meeting(&pool, &meeting_id, format).await?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, rendered)
+                        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+                    Ok(Some(json!({ "meeting_id": meeting_id, "path": path })))
+                }
+                None => {
+                    println!("{}", rendered);
+                    Ok(None)
+                }
+            }
+        }
+        Command::List { limit } => {
+            let pool = open_database(&data_dir).await?;
+            list(&pool, limit).await.map(Some)
+        }
+        Command::Search { query, limit } => {
+            let pool = open_database(&data_dir).await?;
+            search(&pool, &query, limit).await.map(Some)
+        }
+        Command::Templates => {
+            let templates: Vec<Value> = crate::summary::templates::list_templates()
+                .into_iter()
+                .map(|(id, name, description)| {
+                    json!({ "id": id, "name": name, "description": description })
+                })
+                .collect();
+            Ok(Some(json!(templates)))
+        }
+    }
+}
+
+/// Open the app's database (running its migrations), like the app does on startup
+async fn open_database(data_dir: &Path) -> Result<SqlitePool, CliError> {
+    let db_path = data_dir.join("meeting_minutes.sqlite");
+    let legacy_db_path = data_dir.join("meeting_minutes.db");
+    let manager = DatabaseManager::new(
+        &db_path.to_string_lossy(),
+        &legacy_db_path.to_string_lossy(),
+    )
+    .await
+    .map_err(|e| format!("Failed to open database {}: {}", db_path.display(), e))?;
+    Ok(manager.pool().clone())
+}
+
+/// Cancel `cancellation` when Ctrl-C is pressed
+fn cancel_on_ctrl_c(cancellation: Arc<StreamCancellation>) {
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            log::warn!("Interrupted, stopping...");
+            cancellation.cancel();
+        }
+    });
+}
+
+async fn transcribe(
+    data_dir: &Path,
+    file: &Path,
+    model: Option<String>,
+    decoding: DecodingConfig,
+    save_to: Option<(&SqlitePool, Option<String>, PathBuf)>,
+) -> CliResult {
+    if !file.is_file() {
+        return Err(CliError::NotFound(format!("File not found: {}", file.display())));
+    }
+
+    let engine = load_parakeet(data_dir, model, decoding).await?;
+
+    let registration = streaming::register_stream(format!("cli-transcribe:{}", file.display()));
+    cancel_on_ctrl_c(registration.shared_cancellation());
+
+    // Log each stage and every 10% of the overall progress
+    let last_logged = Arc::new(AtomicI32::new(-1));
+    let on_progress: import::ProgressCallback = Arc::new(move |stage: ImportStage, fraction| {
+        let percent = stage.overall_progress(fraction) as i32 / 10 * 10;
+        if last_logged.swap(percent, Ordering::Relaxed) != percent {
+            log::info!("{} ({}%)", stage.as_str(), percent);
+        }
+    });
+
+    let started = Instant::now();
+    let transcribed = match import::transcribe_file(
+        &engine,
+        file,
+        None,
+        registration.shared_cancellation(),
+        on_progress,
+    )
+    .await?
+    {
+        TranscribeOutcome::Completed(transcribed) => transcribed,
+        TranscribeOutcome::Cancelled => return Err(CliError::Cancelled),
+    };
+
+    let meeting_id = match save_to {
+        Some(_) if transcribed.segments.is_empty() => {
+            return Err(CliError::Failed("No speech was found in the file".to_string()))
+        }
+        Some((pool, title, folder)) => {
+            let title = title
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .or_else(|| file.file_stem().map(|s| s.to_string_lossy().to_string()))
+                .unwrap_or_else(|| "Imported meeting".to_string());
+            Some(import::save_as_meeting(pool, &folder, &title, file, &transcribed).await?)
+        }
+        None => None,
+    };
+
+    let text = transcribed
+        .segments
+        .iter()
+        .map(|segment| segment.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ok(json!({
+        "file": file,
+        "meeting_id": meeting_id,
+        "duration_seconds": transcribed.duration_seconds,
+        "processing_time": started.elapsed().as_secs_f64(),
+        "text": text,
+        "segments": transcribed.segments,
+    }))
+}
+
+/// Load a downloaded Parakeet model from the app's models directory
+async fn load_parakeet(
+    data_dir: &Path,
+    model: Option<String>,
+    decoding: DecodingConfig,
+) -> Result<TranscriptionEngine, CliError> {
+    let engine = ParakeetEngine::new_with_models_dir(Some(data_dir.join("models")))
+        .map_err(|e| format!("Failed to initialize Parakeet: {}", e))?;
+    engine.set_decoding_config(decoding).await;
+    let available: Vec<String> = engine
+        .discover_models()
+        .await
+        .map_err(|e| format!("Failed to discover Parakeet models: {}", e))?
+        .into_iter()
+        .filter(|model| matches!(model.status, ModelStatus::Available))
+        .map(|model| model.name)
+        .collect();
+
+    let model_name = match model {
+        Some(model) if available.contains(&model) => model,
+        Some(model) => {
+            return Err(CliError::NotFound(format!(
+                "Parakeet model '{}' is not downloaded (available: {})",
+                model,
+                available.join(", ")
+            )))
+        }
+        None => available
+            .iter()
+            .find(|name| name.as_str() == DEFAULT_PARAKEET_MODEL)
+            .or_else(|| available.first())
+            .cloned()
+            .ok_or_else(|| {
+                CliError::NotFound(
+                    "No Parakeet model is downloaded. Download one from the app's settings."
+                        .to_string(),
+                )
+            })?,
+    };
+
+    log::info!("Loading Parakeet model {}", model_name);
+    engine
+        .load_model(&model_name)
+        .await
+        .map_err(|e| format!("Failed to load Parakeet model {}: {}", model_name, e))?;
+    Ok(TranscriptionEngine::Parakeet(Arc::new(engine)))
+}
+
+async fn get_meeting(
+    pool: &SqlitePool,
+    meeting_id: &str,
+) -> Result<crate::api::MeetingDetails, CliError> {
+    match MeetingsRepository::get_meeting(pool, meeting_id).await {
+        Ok(Some(meeting)) => Ok(meeting),
+        Ok(None) | Err(sqlx::Error::RowNotFound) => {
+            Err(CliError::NotFound(format!("Meeting not found: {}", meeting_id)))
+        }
+        Err(e) => Err(CliError::Failed(format!("Failed to load meeting: {}", e))),
+    }
+}
+
+/// Generate a summary the same way the app does (minus action item extraction)
+/// and store it as the meeting's current summary and a new version
+async fn summarize(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    model_provider: &str,
+    model_name: &str,
+    template_id: &str,
+    custom_prompt: &str,
+    structured_output: bool,
+) -> CliResult {
+    let meeting = get_meeting(pool, meeting_id).await?;
+    crate::summary::templates::get_template(template_id).map_err(CliError::NotFound)?;
+
+    // Diarized or scored meetings: speaker names and low-confidence marks reach the LLM like in the app
+    let text = match SpeakersRepository::build_labeled_transcript(pool, meeting_id).await {
+        Ok(Some(labeled)) => labeled,
+        Ok(None) => join_transcript(&meeting),
+        Err(e) => {
+            log::warn!("Failed to build speaker-labeled transcript: {}", e);
+            join_transcript(&meeting)
+        }
+    };
+    if text.trim().is_empty() {
+        return Err(CliError::Failed(format!("Meeting {} has no transcript", meeting_id)));
+    }
+
+    let settings = SummaryService::resolve_model_settings(pool, model_provider).await?;
+    let token_threshold = SummaryService::token_threshold(
+        &settings.provider,
+        model_name,
+        settings.ollama_endpoint.as_deref(),
+    )
+    .await;
+
+    SummaryProcessesRepository::create_or_reset_process(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to initialize process: {}", e))?;
+
+    // Registered so Ctrl+C can cancel it. The registry is per process: this neither
+    // sees nor cancels a summary the app is generating for the same meeting.
+    let registration = streaming::register_stream(streaming::summary_stream_key(meeting_id));
+    cancel_on_ctrl_c(registration.shared_cancellation());
+
+    let started = Instant::now();
+    let client = reqwest::Client::new();
+    let result = if structured_output {
+        generate_structured_meeting_summary(
+            &client,
+            &settings.provider,
+            model_name,
+            &settings.api_key,
+            &text,
+            custom_prompt,
+            template_id,
+            token_threshold,
+            settings.ollama_endpoint.as_deref(),
+            &settings.language,
+            registration.cancellation(),
+        )
+        .await
+        .map(|(markdown, structured, num_chunks)| (markdown, Some(structured), num_chunks))
+    } else {
+        generate_meeting_summary(
+            &client,
+            &settings.provider,
+            model_name,
+            &settings.api_key,
+            &text,
+            custom_prompt,
+            template_id,
+            token_threshold,
+            settings.ollama_endpoint.as_deref(),
+            &settings.language,
+            registration.cancellation(),
+            |_| {},
+        )
+        .await
+        .map(|(markdown, num_chunks)| (markdown, None, num_chunks))
+    };
+    let duration = started.elapsed().as_secs_f64();
+
+    let (markdown, structured, num_chunks) = match result {
+        Ok((markdown, _, 0)) if markdown.is_empty() => {
+            let error = "Summary generation failed: No content was processed.";
+            SummaryService::update_process_failed(pool, meeting_id, error).await;
+            return Err(CliError::Failed(error.to_string()));
+        }
+        Ok(result) => result,
+        Err(_) if registration.cancellation().is_cancelled() => {
+            if let Err(e) = SummaryProcessesRepository::update_process_cancelled(pool, meeting_id).await
+            {
+                log::error!("Failed to update DB status to cancelled for {}: {}", meeting_id, e);
+            }
+            return Err(CliError::Cancelled);
+        }
+        Err(e) => {
+            SummaryService::update_process_failed(pool, meeting_id, &e).await;
+            return Err(CliError::Failed(e));
+        }
+    };
+
+    let markdown = SummaryService::apply_summary_title(pool, meeting_id, markdown).await;
+    let mut result_json = json!({ "markdown": markdown });
+    if let Some(structured) = &structured {
+        result_json["structured"] = structured.clone();
+    }
+
+    SummaryService::save_completed_run(
+        pool,
+        &NewSummaryVersion {
+            meeting_id,
+            template_id,
+            provider: model_provider,
+            model: model_name,
+            custom_prompt,
+            language: &settings.language,
+            processing_time: duration,
+            chunk_count: num_chunks,
+            result: &result_json,
+        },
+    )
+    .await;
+
+    Ok(json!({
+        "meeting_id": meeting_id,
+        "markdown": markdown,
+        "structured": structured,
+        "chunk_count": num_chunks,
+        "processing_time": duration,
+    }))
+}
+
+fn join_transcript(meeting: &crate::api::MeetingDetails) -> String {
+    meeting
+        .transcripts
+        .iter()
+        .map(|segment| segment.text.trim())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+async fn export_meeting(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    format: ExportFormat,
+) -> Result<String, CliError> {
+    let meeting = get_meeting(pool, meeting_id).await?;
+
+    let result = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to get summary: {}", e))?
+        .filter(|process| process.status.eq_ignore_ascii_case("completed"))
+        .and_then(|process| process.result);
+    let summary = result.as_deref().map(versions::summary_markdown);
+    let structured = result
+        .as_deref()
+        .and_then(|result| serde_json::from_str::<Value>(result).ok())
+        .and_then(|value| value.get("structured").cloned());
+
+    Ok(export::render(format, &meeting, summary.as_deref(), structured.as_ref())?)
+}
+
+async fn list(pool: &SqlitePool, limit: Option<usize>) -> CliResult {
+    let meetings = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| format!("Failed to list meetings: {}", e))?;
+    let meetings: Vec<Value> = meetings
+        .into_iter()
+        .take(limit.unwrap_or(usize::MAX))
+        .map(|meeting| {
+            json!({
+                "id": meeting.id,
+                "title": meeting.title,
+                "created_at": meeting.created_at.0.to_rfc3339(),
+                "updated_at": meeting.updated_at.0.to_rfc3339(),
+                "folder_path": meeting.folder_path,
+            })
+        })
+        .collect();
+    Ok(json!(meetings))
+}
+
+async fn search(pool: &SqlitePool, query: &str, limit: Option<i64>) -> CliResult {
+    // FTS5 (BM25-ranked) search; fall back to a plain scan if the query has no searchable terms
+    let results = match SearchRepository::search(pool, query, limit).await {
+        Ok(Some(results)) => Ok(results),
+        Ok(None) => TranscriptsRepository::search_transcripts(pool, query).await.map(|mut results| {
+            results.truncate(limit.map_or(usize::MAX, |limit| limit.max(0) as usize));
+            results
+        }),
+        Err(e) => Err(e),
+    }
+    .map_err(|e| format!("Failed to search transcripts: {}", e))?;
+    Ok(json!(results))
+}
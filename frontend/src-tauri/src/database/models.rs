@@ -9,8 +9,19 @@ pub struct MeetingModel {
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
     pub folder_path: Option<String>,
+    /// `MEETING_STATUS_RECORDING` while the meeting is being recorded
+    pub status: String,
 }
 
+/// `meetings.status` of a meeting whose recording is in progress (or was interrupted)
+pub const MEETING_STATUS_RECORDING: &str = "recording";
+
+/// `meetings.status` of a finished meeting
+pub const MEETING_STATUS_COMPLETED: &str = "completed";
+
+/// `meetings.status` of a meeting whose folder a library rescan did not find on disk
+pub const MEETING_STATUS_FOLDER_MISSING: &str = "folder_missing";
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(transparent)]
 pub struct DateTimeUtc(pub DateTime<Utc>);
@@ -35,6 +46,14 @@ pub struct Transcript {
     pub audio_start_time: Option<f64>,
     pub audio_end_time: Option<f64>,
     pub duration: Option<f64>,
+    // JSON array of recording-relative word timings
+    pub words: Option<String>,
+    // Diarization speaker ID ("speaker_1"); display names live in meeting_speakers
+    pub speaker: Option<String>,
+    // Mic/system attribution: "local" | "remote" | "overlap" | "unknown"
+    pub source: Option<String>,
+    // Mean word confidence (0.0 - 1.0), if the transcription model scores words
+    pub confidence: Option<f32>,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -52,6 +71,52 @@ pub struct SummaryProcess {
     pub metadata: Option<String>, // JSON
 }
 
+/// `summary_versions.source` of a generation run
+pub const SUMMARY_VERSION_SOURCE_GENERATED: &str = "generated";
+
+/// `summary_versions.source` of the user's edits, kept when an older version was restored over them
+pub const SUMMARY_VERSION_SOURCE_MANUAL: &str = "manual";
+
+fn default_summary_version_source() -> String {
+    SUMMARY_VERSION_SOURCE_GENERATED.to_string()
+}
+
+/// One summary generation run of a meeting, kept when the summary is regenerated
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SummaryVersion {
+    pub id: String,
+    pub meeting_id: String,
+    pub version: i64,
+    pub template_id: Option<String>,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub custom_prompt: Option<String>,
+    pub language: Option<String>,
+    pub processing_time: f64,
+    pub chunk_count: i64,
+    pub result: String, // JSON
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// `SUMMARY_VERSION_SOURCE_GENERATED` or `SUMMARY_VERSION_SOURCE_MANUAL`
+    #[serde(default = "default_summary_version_source")]
+    pub source: String,
+}
+
+/// An alternate transcript of a meeting: a re-transcription kept next to the
+/// current one, or a transcript that a re-transcription or restore replaced
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TranscriptVersion {
+    pub id: String,
+    pub meeting_id: String,
+    pub version: i64,
+    pub label: String,
+    pub model: Option<String>,
+    pub language: Option<String>,
+    pub vad_settings: Option<String>, // JSON
+    pub segment_count: i64,
+    pub segments: String, // JSON array of api::TranscriptSegment
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct TranscriptChunk {
     pub meeting_id: String,
@@ -123,3 +188,48 @@ pub struct ChatMessage {
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub metadata: Option<String>, // JSON for additional data if needed
 }
+
+/// A library-wide chat conversation (questions across all meetings)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ChatConversation {
+    pub id: String,
+    pub title: String,
+    pub filters: Option<String>, // JSON ConversationFilters
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ConversationMessage {
+    pub id: String,
+    pub conversation_id: String,
+    pub role: String, // 'user' or 'assistant'
+    pub content: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub metadata: Option<String>, // JSON: {"citations": [...]}
+}
+
+/// A diarized speaker in a meeting, with the user's display name if renamed
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingSpeaker {
+    pub speaker_id: String,
+    pub display_name: Option<String>,
+    pub segment_count: i64,
+}
+
+/// An action item raised in a meeting, tracked until it is done or cancelled
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ActionItem {
+    pub id: String,
+    pub meeting_id: String,
+    pub meeting_title: Option<String>, // joined from meetings
+    pub text: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub source_timestamp: Option<f64>, // seconds from recording start
+    pub status: String,                // 'open', 'done' or 'cancelled'
+    pub last_seen_meeting_id: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -0,0 +1,264 @@
+use crate::database::models::ActionItem;
+use chrono::Utc;
+use serde::Deserialize;
+use sqlx::{Error as SqlxError, SqliteConnection, SqlitePool};
+use tracing::info;
+use uuid::Uuid;
+
+/// Valid values of `action_items.status`
+pub const ACTION_ITEM_STATUSES: [&str; 3] = ["open", "done", "cancelled"];
+
+const SELECT_ACTION_ITEMS: &str = "SELECT a.*, m.title AS meeting_title
+     FROM action_items a
+     LEFT JOIN meetings m ON m.id = a.meeting_id";
+
+/// A new action item, extracted from a meeting or added by hand
+#[derive(Debug, Clone)]
+pub struct NewActionItem<'a> {
+    pub meeting_id: &'a str,
+    pub text: &'a str,
+    pub owner: Option<&'a str>,
+    pub due_date: Option<&'a str>,
+    pub source_timestamp: Option<f64>,
+    pub status: &'a str,
+}
+
+/// Fields to change on an action item. `None` leaves a field as is; an empty
+/// owner or due date clears it.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ActionItemUpdate {
+    pub text: Option<String>,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub status: Option<String>,
+}
+
+pub struct ActionItemsRepository;
+
+impl ActionItemsRepository {
+    /// Items raised in a meeting or carried over into it, in transcript order
+    pub async fn get_meeting_items(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<ActionItem>, SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol("meeting_id cannot be empty".to_string()));
+        }
+
+        sqlx::query_as::<_, ActionItem>(&format!(
+            "{} WHERE a.meeting_id = ? OR a.last_seen_meeting_id = ?
+             ORDER BY a.source_timestamp IS NULL, a.source_timestamp, a.created_at",
+            SELECT_ACTION_ITEMS
+        ))
+        .bind(meeting_id)
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Open items across all meetings, earliest due date first (undated last).
+    /// `owner` filters case-insensitively on a substring of the owner.
+    pub async fn get_open_items(
+        pool: &SqlitePool,
+        owner: Option<&str>,
+    ) -> Result<Vec<ActionItem>, SqlxError> {
+        let owner = owner.map(str::trim).filter(|o| !o.is_empty());
+
+        sqlx::query_as::<_, ActionItem>(&format!(
+            "{} WHERE a.status = 'open' AND (? IS NULL OR a.owner LIKE '%' || ? || '%')
+             ORDER BY a.due_date IS NULL, a.due_date, a.created_at",
+            SELECT_ACTION_ITEMS
+        ))
+        .bind(owner)
+        .bind(owner)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Get an action item by id
+    pub async fn get_item(pool: &SqlitePool, item_id: &str) -> Result<Option<ActionItem>, SqlxError> {
+        sqlx::query_as::<_, ActionItem>(&format!("{} WHERE a.id = ?", SELECT_ACTION_ITEMS))
+            .bind(item_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Create an action item
+    pub async fn create_item(
+        pool: &SqlitePool,
+        new_item: &NewActionItem<'_>,
+    ) -> Result<ActionItem, SqlxError> {
+        let mut transaction = pool.begin().await?;
+        let id = insert_item(&mut transaction, new_item).await?;
+        transaction.commit().await?;
+
+        info!("Created action item {} for meeting_id: {}", id, new_item.meeting_id);
+
+        Self::get_item(pool, &id).await?.ok_or(SqlxError::RowNotFound)
+    }
+
+    /// Update an action item. Returns `None` if it does not exist.
+    pub async fn update_item(
+        pool: &SqlitePool,
+        item_id: &str,
+        update: &ActionItemUpdate,
+    ) -> Result<Option<ActionItem>, SqlxError> {
+        let mut transaction = pool.begin().await?;
+        let updated = apply_update(&mut transaction, item_id, update, None).await?;
+        transaction.commit().await?;
+
+        if !updated {
+            return Ok(None);
+        }
+        Self::get_item(pool, item_id).await
+    }
+
+    /// Delete an action item
+    pub async fn delete_item(pool: &SqlitePool, item_id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM action_items WHERE id = ?")
+            .bind(item_id)
+            .execute(pool)
+            .await?;
+
+        let deleted = result.rows_affected() > 0;
+        if deleted {
+            info!("Deleted action item: {}", item_id);
+        }
+        Ok(deleted)
+    }
+
+    /// Save the result of an extraction pass over a meeting in one transaction:
+    /// new items are inserted, and items carried over from earlier meetings are
+    /// updated and marked as seen in this meeting.
+    pub async fn save_extracted_items(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        new_items: &[NewActionItem<'_>],
+        carried_over: &[(String, ActionItemUpdate)],
+    ) -> Result<(), SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        for new_item in new_items {
+            insert_item(&mut transaction, new_item).await?;
+        }
+        for (item_id, update) in carried_over {
+            apply_update(&mut transaction, item_id, update, Some(meeting_id)).await?;
+        }
+
+        transaction.commit().await?;
+
+        info!(
+            "Saved {} new and {} carried-over action items for meeting_id: {}",
+            new_items.len(),
+            carried_over.len(),
+            meeting_id
+        );
+        Ok(())
+    }
+}
+
+fn validate_status(status: &str) -> Result<(), SqlxError> {
+    if ACTION_ITEM_STATUSES.contains(&status) {
+        Ok(())
+    } else {
+        Err(SqlxError::Protocol(format!("Invalid action item status: {}", status)))
+    }
+}
+
+/// Empty strings clear optional fields
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+async fn insert_item(
+    transaction: &mut SqliteConnection,
+    new_item: &NewActionItem<'_>,
+) -> Result<String, SqlxError> {
+    if new_item.meeting_id.trim().is_empty() {
+        return Err(SqlxError::Protocol("meeting_id cannot be empty".to_string()));
+    }
+    if new_item.text.trim().is_empty() {
+        return Err(SqlxError::Protocol("text cannot be empty".to_string()));
+    }
+    validate_status(new_item.status)?;
+
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+    let completed_at = (new_item.status != "open").then_some(now);
+
+    sqlx::query(
+        "INSERT INTO action_items (
+            id, meeting_id, text, owner, due_date, source_timestamp, status,
+            created_at, updated_at, completed_at
+         ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&id)
+    .bind(new_item.meeting_id)
+    .bind(new_item.text.trim())
+    .bind(non_empty(new_item.owner))
+    .bind(non_empty(new_item.due_date))
+    .bind(new_item.source_timestamp)
+    .bind(new_item.status)
+    .bind(now)
+    .bind(now)
+    .bind(completed_at)
+    .execute(&mut *transaction)
+    .await?;
+
+    Ok(id)
+}
+
+/// Apply `update` to an item; `seen_in` also records the meeting that mentioned it
+/// again (ignored for the meeting the item was raised in). Returns whether the item exists.
+async fn apply_update(
+    transaction: &mut SqliteConnection,
+    item_id: &str,
+    update: &ActionItemUpdate,
+    seen_in: Option<&str>,
+) -> Result<bool, SqlxError> {
+    if let Some(status) = &update.status {
+        validate_status(status)?;
+    }
+    if update.text.as_deref().is_some_and(|t| t.trim().is_empty()) {
+        return Err(SqlxError::Protocol("text cannot be empty".to_string()));
+    }
+
+    let now = Utc::now();
+    let result = sqlx::query(
+        "UPDATE action_items SET
+            text = COALESCE(?, text),
+            owner = CASE WHEN ? THEN ? ELSE owner END,
+            due_date = CASE WHEN ? THEN ? ELSE due_date END,
+            completed_at = CASE
+                WHEN ? IS NULL OR ? = status THEN completed_at
+                WHEN ? = 'open' THEN NULL
+                ELSE ?
+            END,
+            status = COALESCE(?, status),
+            last_seen_meeting_id = CASE
+                WHEN ? IS NOT NULL AND ? != meeting_id THEN ?
+                ELSE last_seen_meeting_id
+            END,
+            updated_at = ?
+         WHERE id = ?",
+    )
+    .bind(update.text.as_deref().map(str::trim))
+    .bind(update.owner.is_some())
+    .bind(non_empty(update.owner.as_deref()))
+    .bind(update.due_date.is_some())
+    .bind(non_empty(update.due_date.as_deref()))
+    .bind(&update.status)
+    .bind(&update.status)
+    .bind(&update.status)
+    .bind(now)
+    .bind(&update.status)
+    .bind(seen_in)
+    .bind(seen_in)
+    .bind(seen_in)
+    .bind(now)
+    .bind(item_id)
+    .execute(&mut *transaction)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -79,6 +79,41 @@ impl ChatMessagesRepository {
         })
     }
 
+    /// Add a message carried over from another library (a meeting archive) to a
+    /// meeting, keeping its date under a new id
+    pub async fn import_message(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        message: &ChatMessage,
+    ) -> Result<ChatMessage, SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        let imported = ChatMessage {
+            id: Uuid::new_v4().to_string(),
+            meeting_id: meeting_id.to_string(),
+            ..message.clone()
+        };
+
+        sqlx::query(
+            "INSERT INTO chat_messages (id, meeting_id, role, content, created_at, metadata)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&imported.id)
+        .bind(&imported.meeting_id)
+        .bind(&imported.role)
+        .bind(&imported.content)
+        .bind(imported.created_at)
+        .bind(&imported.metadata)
+        .execute(pool)
+        .await?;
+
+        Ok(imported)
+    }
+
     /// Delete all chat messages for a meeting
     pub async fn delete_messages(
         pool: &SqlitePool,
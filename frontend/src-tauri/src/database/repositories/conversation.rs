@@ -0,0 +1,225 @@
+use crate::database::models::{ChatConversation, ConversationMessage};
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqlitePool};
+use tracing::info;
+use uuid::Uuid;
+
+pub struct ConversationsRepository;
+
+impl ConversationsRepository {
+    /// List all library conversations, most recently active first
+    pub async fn get_conversations(pool: &SqlitePool) -> Result<Vec<ChatConversation>, SqlxError> {
+        let conversations = sqlx::query_as::<_, ChatConversation>(
+            "SELECT * FROM chat_conversations ORDER BY updated_at DESC",
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(conversations)
+    }
+
+    /// Get a conversation by id
+    pub async fn get_conversation(
+        pool: &SqlitePool,
+        conversation_id: &str,
+    ) -> Result<Option<ChatConversation>, SqlxError> {
+        if conversation_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "conversation_id cannot be empty".to_string(),
+            ));
+        }
+
+        let conversation = sqlx::query_as::<_, ChatConversation>(
+            "SELECT * FROM chat_conversations WHERE id = ?",
+        )
+        .bind(conversation_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(conversation)
+    }
+
+    /// Create a new conversation
+    pub async fn create_conversation(
+        pool: &SqlitePool,
+        title: &str,
+        filters: Option<String>,
+    ) -> Result<ChatConversation, SqlxError> {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now();
+
+        sqlx::query(
+            "INSERT INTO chat_conversations (id, title, filters, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(title)
+        .bind(&filters)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await?;
+
+        info!("Created chat conversation {}", id);
+
+        Ok(ChatConversation {
+            id,
+            title: title.to_string(),
+            filters,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Update the filters of a conversation (also bumps updated_at)
+    pub async fn update_filters(
+        pool: &SqlitePool,
+        conversation_id: &str,
+        filters: Option<String>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE chat_conversations SET filters = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(&filters)
+        .bind(Utc::now())
+        .bind(conversation_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Rename a conversation
+    pub async fn rename_conversation(
+        pool: &SqlitePool,
+        conversation_id: &str,
+        title: &str,
+    ) -> Result<bool, SqlxError> {
+        if title.trim().is_empty() {
+            return Err(SqlxError::Protocol("title cannot be empty".to_string()));
+        }
+
+        let result = sqlx::query("UPDATE chat_conversations SET title = ? WHERE id = ?")
+            .bind(title.trim())
+            .bind(conversation_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a conversation and its messages
+    pub async fn delete_conversation(
+        pool: &SqlitePool,
+        conversation_id: &str,
+    ) -> Result<bool, SqlxError> {
+        if conversation_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "conversation_id cannot be empty".to_string(),
+            ));
+        }
+
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query("DELETE FROM conversation_messages WHERE conversation_id = ?")
+            .bind(conversation_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let result = sqlx::query("DELETE FROM chat_conversations WHERE id = ?")
+            .bind(conversation_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        let deleted = result.rows_affected() > 0;
+        if deleted {
+            info!("Deleted chat conversation: {}", conversation_id);
+        }
+
+        Ok(deleted)
+    }
+
+    /// Get all messages of a conversation, ordered by created_at
+    pub async fn get_messages(
+        pool: &SqlitePool,
+        conversation_id: &str,
+    ) -> Result<Vec<ConversationMessage>, SqlxError> {
+        if conversation_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "conversation_id cannot be empty".to_string(),
+            ));
+        }
+
+        let messages = sqlx::query_as::<_, ConversationMessage>(
+            "SELECT * FROM conversation_messages WHERE conversation_id = ? ORDER BY created_at ASC",
+        )
+        .bind(conversation_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(messages)
+    }
+
+    /// Save a new message and bump the conversation's updated_at
+    pub async fn save_message(
+        pool: &SqlitePool,
+        conversation_id: &str,
+        role: &str,
+        content: &str,
+        metadata: Option<String>,
+    ) -> Result<ConversationMessage, SqlxError> {
+        if conversation_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "conversation_id cannot be empty".to_string(),
+            ));
+        }
+
+        if role != "user" && role != "assistant" {
+            return Err(SqlxError::Protocol(
+                "role must be 'user' or 'assistant'".to_string(),
+            ));
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let created_at = Utc::now();
+
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query(
+            "INSERT INTO conversation_messages (id, conversation_id, role, content, created_at, metadata)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(conversation_id)
+        .bind(role)
+        .bind(content)
+        .bind(created_at)
+        .bind(&metadata)
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query("UPDATE chat_conversations SET updated_at = ? WHERE id = ?")
+            .bind(created_at)
+            .bind(conversation_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        info!(
+            "Saved conversation message (role: {}) for conversation_id: {}",
+            role, conversation_id
+        );
+
+        Ok(ConversationMessage {
+            id,
+            conversation_id: conversation_id.to_string(),
+            role: role.to_string(),
+            content: content.to_string(),
+            created_at,
+            metadata,
+        })
+    }
+}
@@ -1,7 +1,9 @@
 use crate::api::{MeetingDetails, MeetingTranscript};
 use crate::database::models::{MeetingModel, Transcript};
-use chrono::Utc;
+use crate::database::repositories::speaker::SpeakersRepository;
+use chrono::{DateTime, Utc};
 use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
+use std::collections::HashMap;
 use tracing::{error, info};
 
 pub struct MeetingsRepository;
@@ -15,6 +17,32 @@ impl MeetingsRepository {
         Ok(meetings)
     }
 
+    /// The meeting row alone (with its recording folder), without transcripts
+    pub async fn get_meeting_model(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Option<MeetingModel>, SqlxError> {
+        sqlx::query_as::<_, MeetingModel>(
+            "SELECT id, title, created_at, updated_at, folder_path, status FROM meetings WHERE id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// The meeting saved from a recording folder, if any
+    pub async fn get_meeting_model_by_folder(
+        pool: &SqlitePool,
+        folder_path: &str,
+    ) -> Result<Option<MeetingModel>, SqlxError> {
+        sqlx::query_as::<_, MeetingModel>(
+            "SELECT id, title, created_at, updated_at, folder_path, status FROM meetings WHERE folder_path = ?",
+        )
+        .bind(folder_path)
+        .fetch_optional(pool)
+        .await
+    }
+
     pub async fn delete_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<bool, SqlxError> {
         if meeting_id.trim().is_empty() {
             return Err(SqlxError::Protocol(
@@ -62,7 +90,7 @@ impl MeetingsRepository {
 
         // Get meeting details
         let meeting: Option<MeetingModel> =
-            sqlx::query_as("SELECT id, title, created_at, updated_at, folder_path FROM meetings WHERE id = ?")
+            sqlx::query_as("SELECT id, title, created_at, updated_at, folder_path, status FROM meetings WHERE id = ?")
                 .bind(meeting_id)
                 .fetch_optional(&mut *transaction)
                 .await?;
@@ -80,6 +108,16 @@ impl MeetingsRepository {
                     .fetch_all(&mut *transaction)
                     .await?;
 
+            // Speaker display names chosen by the user for this meeting
+            let speaker_names: HashMap<String, String> = sqlx::query_as::<_, (String, String)>(
+                "SELECT speaker_id, display_name FROM meeting_speakers WHERE meeting_id = ?",
+            )
+            .bind(meeting_id)
+            .fetch_all(&mut *transaction)
+            .await?
+            .into_iter()
+            .collect();
+
             transaction.commit().await?;
 
             // Convert Transcript to MeetingTranscript
@@ -92,6 +130,18 @@ impl MeetingsRepository {
                     audio_start_time: t.audio_start_time,
                     audio_end_time: t.audio_end_time,
                     duration: t.duration,
+                    words: t
+                        .words
+                        .as_deref()
+                        .and_then(|w| serde_json::from_str(w).ok())
+                        .unwrap_or_default(),
+                    speaker_name: t
+                        .speaker
+                        .as_deref()
+                        .map(|id| SpeakersRepository::resolve_name(&speaker_names, id)),
+                    speaker: t.speaker,
+                    source: t.source,
+                    confidence: t.confidence,
                 })
                 .collect::<Vec<_>>();
 
@@ -100,6 +150,7 @@ impl MeetingsRepository {
                 title: meeting.title,
                 created_at: meeting.created_at.0.to_rfc3339(),
                 updated_at: meeting.updated_at.0.to_rfc3339(),
+                status: meeting.status,
                 transcripts: meeting_transcripts,
             }))
         } else {
@@ -139,6 +190,54 @@ impl MeetingsRepository {
         Ok(true)
     }
 
+    /// Set a meeting's status (`MEETING_STATUS_*`). Returns false if the meeting
+    /// does not exist.
+    pub async fn set_meeting_status(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        status: &str,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET status = ?, updated_at = ? WHERE id = ?")
+            .bind(status)
+            .bind(Utc::now())
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Point a meeting at the folder its recording now lives in. Returns false if
+    /// the meeting does not exist.
+    pub async fn set_folder_path(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        folder_path: &str,
+    ) -> Result<bool, SqlxError> {
+        let result =
+            sqlx::query("UPDATE meetings SET folder_path = ?, updated_at = ? WHERE id = ?")
+                .bind(folder_path)
+                .bind(Utc::now())
+                .bind(meeting_id)
+                .execute(pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Date a meeting back to when it was recorded, for meetings saved after the fact.
+    /// Returns false if the meeting does not exist.
+    pub async fn set_created_at(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        created_at: DateTime<Utc>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET created_at = ? WHERE id = ?")
+            .bind(created_at)
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     pub async fn update_meeting_name(
         pool: &SqlitePool,
         meeting_id: &str,
@@ -201,13 +300,29 @@ async fn delete_meeting_with_transaction(
         .execute(&mut *transaction)
         .await?;
 
-    // 3. Delete from transcripts
+    // 3. Delete from summary_versions
+    sqlx::query("DELETE FROM summary_versions WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 4. Delete from action_items, and forget this meeting on items carried over into it
+    sqlx::query("DELETE FROM action_items WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+    sqlx::query("UPDATE action_items SET last_seen_meeting_id = NULL WHERE last_seen_meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 5. Delete from transcripts
     sqlx::query("DELETE FROM transcripts WHERE meeting_id = ?")
         .bind(meeting_id)
         .execute(&mut *transaction)
         .await?;
 
-    // 4. Finally, delete the meeting
+    // 6. Finally, delete the meeting
     let result = sqlx::query("DELETE FROM meetings WHERE id = ?")
         .bind(meeting_id)
         .execute(&mut *transaction)
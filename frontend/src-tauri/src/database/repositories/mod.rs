@@ -1,6 +1,12 @@
+pub mod action_item;
 pub mod chat_message;
+pub mod conversation;
 pub mod meeting;
+pub mod search;
 pub mod setting;
+pub mod speaker;
 pub mod summary;
+pub mod summary_version;
 pub mod transcript;
 pub mod transcript_chunk;
+pub mod transcript_version;
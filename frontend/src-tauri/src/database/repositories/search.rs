@@ -0,0 +1,222 @@
+use crate::api::TranscriptSearchResult;
+use sqlx::{Error as SqlxError, FromRow, SqlitePool};
+use tracing::info;
+
+/// Highlight markers wrapped around matched terms in `highlighted_context`
+pub const HIGHLIGHT_START: &str = "<mark>";
+pub const HIGHLIGHT_END: &str = "</mark>";
+
+const DEFAULT_SEARCH_LIMIT: i64 = 100;
+
+#[derive(Debug, FromRow)]
+struct SearchRow {
+    meeting_id: String,
+    title: String,
+    source_type: String,
+    source_id: String,
+    snippet: String,
+    audio_start_time: Option<f64>,
+    rank: f64,
+    timestamp: String,
+}
+
+pub struct SearchRepository;
+
+impl SearchRepository {
+    /// Full-text search over transcripts, summaries and chat messages (FTS5, BM25-ranked).
+    ///
+    /// Supports quoted phrases (`"next sprint"`), prefixes (`deploy*`) and
+    /// `AND` / `OR` / `NOT` operators. Returns `Ok(None)` if the query has no
+    /// searchable terms so callers can fall back to a plain scan.
+    pub async fn search(
+        pool: &SqlitePool,
+        query: &str,
+        limit: Option<i64>,
+    ) -> Result<Option<Vec<TranscriptSearchResult>>, SqlxError> {
+        let Some(fts_query) = build_fts_query(query) else {
+            return Ok(None);
+        };
+
+        let rows = sqlx::query_as::<_, SearchRow>(
+            "SELECT search_index.meeting_id AS meeting_id,
+                    m.title AS title,
+                    search_index.source_type AS source_type,
+                    search_index.source_id AS source_id,
+                    snippet(search_index, 0, '<mark>', '</mark>', '…', 24) AS snippet,
+                    search_index.audio_start_time AS audio_start_time,
+                    bm25(search_index) AS rank,
+                    COALESCE(t.timestamp, c.created_at, m.created_at) AS timestamp
+             FROM search_index
+             JOIN meetings m ON m.id = search_index.meeting_id
+             LEFT JOIN transcripts t
+                ON search_index.source_type = 'transcript' AND t.id = search_index.source_id
+             LEFT JOIN chat_messages c
+                ON search_index.source_type = 'chat' AND c.id = search_index.source_id
+             WHERE search_index MATCH ?
+             ORDER BY rank
+             LIMIT ?",
+        )
+        .bind(&fts_query)
+        .bind(limit.unwrap_or(DEFAULT_SEARCH_LIMIT))
+        .fetch_all(pool)
+        .await?;
+
+        info!("FTS search '{}' returned {} hits", fts_query, rows.len());
+
+        let results = rows
+            .into_iter()
+            .map(|row| TranscriptSearchResult {
+                id: row.meeting_id,
+                title: row.title,
+                match_context: strip_highlights(&row.snippet),
+                timestamp: row.timestamp,
+                highlighted_context: Some(row.snippet),
+                source_type: Some(row.source_type),
+                source_id: Some(row.source_id),
+                audio_start_time: row.audio_start_time,
+                rank: Some(row.rank),
+            })
+            .collect();
+
+        Ok(Some(results))
+    }
+
+    /// BM25 rank of every transcript segment of the given meetings matching
+    /// `fts_query`, an FTS5 MATCH expression, as (transcript id, rank). Lower ranks
+    /// are better matches.
+    pub async fn transcript_ranks(
+        pool: &SqlitePool,
+        fts_query: &str,
+        meeting_ids: &[String],
+    ) -> Result<Vec<(String, f64)>, SqlxError> {
+        if meeting_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let meeting_ids = serde_json::to_string(meeting_ids)
+            .map_err(|e| SqlxError::Protocol(format!("Invalid meeting ids: {}", e)))?;
+
+        sqlx::query_as::<_, (String, f64)>(
+            "SELECT source_id, bm25(search_index) AS rank
+             FROM search_index
+             WHERE search_index MATCH ?
+               AND source_type = 'transcript'
+               AND meeting_id IN (SELECT value FROM json_each(?))",
+        )
+        .bind(fts_query)
+        .bind(meeting_ids)
+        .fetch_all(pool)
+        .await
+    }
+}
+
+/// Translate user input into a safe FTS5 MATCH expression.
+///
+/// Every term is quoted so punctuation (`o'clock`, `follow-up`) can't break
+/// the FTS5 grammar; a trailing `*` keeps prefix search, `"..."` keeps phrases
+/// and upper-case `AND` / `OR` / `NOT` are passed through as operators.
+pub fn build_fts_query(input: &str) -> Option<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut chars = input.chars().peekable();
+    let mut last_was_operator = true; // no operator allowed at the start
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let (term, quoted) = if c == '"' {
+            chars.next();
+            let mut phrase = String::new();
+            while let Some(ch) = chars.next() {
+                if ch == '"' {
+                    break;
+                }
+                phrase.push(ch);
+            }
+            (phrase, true)
+        } else {
+            let mut word = String::new();
+            while let Some(&ch) = chars.peek() {
+                if ch.is_whitespace() || ch == '"' {
+                    break;
+                }
+                word.push(ch);
+                chars.next();
+            }
+            (word, false)
+        };
+
+        if !quoted && matches!(term.as_str(), "AND" | "OR" | "NOT") {
+            if !last_was_operator {
+                parts.push(term);
+                last_was_operator = true;
+            }
+            continue;
+        }
+
+        let prefix = !quoted && term.ends_with('*');
+        let cleaned = term.trim_end_matches('*').trim();
+        if !cleaned.chars().any(|ch| ch.is_alphanumeric()) {
+            continue;
+        }
+
+        let mut part = format!("\"{}\"", cleaned.replace('"', "\"\""));
+        if prefix {
+            part.push('*');
+        }
+        parts.push(part);
+        last_was_operator = false;
+    }
+
+    // Drop a dangling trailing operator
+    if last_was_operator {
+        parts.pop();
+    }
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" "))
+    }
+}
+
+fn strip_highlights(snippet: &str) -> String {
+    snippet.replace(HIGHLIGHT_START, "").replace(HIGHLIGHT_END, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_terms_are_quoted() {
+        assert_eq!(
+            build_fts_query("budget review").as_deref(),
+            Some("\"budget\" \"review\"")
+        );
+    }
+
+    #[test]
+    fn test_phrase_prefix_and_operators() {
+        assert_eq!(
+            build_fts_query("\"next sprint\" AND deploy* NOT staging").as_deref(),
+            Some("\"next sprint\" AND \"deploy\"* NOT \"staging\"")
+        );
+    }
+
+    #[test]
+    fn test_punctuation_and_dangling_operators() {
+        assert_eq!(
+            build_fts_query("OR follow-up AND").as_deref(),
+            Some("\"follow-up\"")
+        );
+        assert_eq!(build_fts_query("decisão").as_deref(), Some("\"decisão\""));
+        assert_eq!(build_fts_query("  ?! * "), None);
+    }
+
+    #[test]
+    fn test_strip_highlights() {
+        assert_eq!(strip_highlights("a <mark>b</mark> c"), "a b c");
+    }
+}
@@ -0,0 +1,189 @@
+use crate::audio::transcription::{mark_low_confidence_words, WordTimestamp};
+use crate::database::models::{MeetingSpeaker, Transcript};
+use crate::diarization::default_speaker_name;
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqlitePool};
+use std::collections::HashMap;
+use tracing::info;
+
+pub struct SpeakersRepository;
+
+impl SpeakersRepository {
+    /// List the diarized speakers of a meeting in order of first appearance
+    pub async fn get_speakers(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingSpeaker>, SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        let speakers = sqlx::query_as::<_, MeetingSpeaker>(
+            "SELECT t.speaker AS speaker_id, ms.display_name, COUNT(*) AS segment_count
+             FROM transcripts t
+             LEFT JOIN meeting_speakers ms
+                ON ms.meeting_id = t.meeting_id AND ms.speaker_id = t.speaker
+             WHERE t.meeting_id = ? AND t.speaker IS NOT NULL
+             GROUP BY t.speaker, ms.display_name
+             ORDER BY MIN(COALESCE(t.audio_start_time, 0)) ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(speakers)
+    }
+
+    /// Rename a speaker for one meeting. An empty name restores the default label.
+    pub async fn rename_speaker(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        speaker_id: &str,
+        display_name: &str,
+    ) -> Result<(), SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        if speaker_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "speaker_id cannot be empty".to_string(),
+            ));
+        }
+
+        let display_name = display_name.trim();
+
+        if display_name.is_empty() {
+            sqlx::query("DELETE FROM meeting_speakers WHERE meeting_id = ? AND speaker_id = ?")
+                .bind(meeting_id)
+                .bind(speaker_id)
+                .execute(pool)
+                .await?;
+
+            info!("Reset speaker {} name for meeting_id: {}", speaker_id, meeting_id);
+            return Ok(());
+        }
+
+        sqlx::query(
+            "INSERT INTO meeting_speakers (meeting_id, speaker_id, display_name, updated_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(meeting_id, speaker_id) DO UPDATE SET
+                display_name = excluded.display_name,
+                updated_at = excluded.updated_at",
+        )
+        .bind(meeting_id)
+        .bind(speaker_id)
+        .bind(display_name)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+
+        info!(
+            "Renamed speaker {} to '{}' for meeting_id: {}",
+            speaker_id, display_name, meeting_id
+        );
+
+        Ok(())
+    }
+
+    /// Map of speaker ID -> user-chosen display name (renamed speakers only)
+    pub async fn get_speaker_names(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<HashMap<String, String>, SqlxError> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT speaker_id, display_name FROM meeting_speakers WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Build the meeting transcript as "Name: text" lines for LLM context.
+    ///
+    /// Diarized speakers take precedence; otherwise the mic/system source
+    /// ("Me" / "Others") is used. Low-confidence words are marked as `[word?]`.
+    /// Returns `None` if no segment carries a label or a marked word, so
+    /// callers can keep their existing plain text.
+    pub async fn build_labeled_transcript(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Option<String>, SqlxError> {
+        let transcripts = sqlx::query_as::<_, Transcript>(
+            "SELECT * FROM transcripts WHERE meeting_id = ?
+             ORDER BY COALESCE(audio_start_time, 0) ASC, timestamp ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?;
+
+        let marked: Vec<Option<String>> = transcripts
+            .iter()
+            .map(|t| {
+                let words: Vec<WordTimestamp> = t
+                    .words
+                    .as_deref()
+                    .and_then(|words| serde_json::from_str(words).ok())
+                    .unwrap_or_default();
+                mark_low_confidence_words(&words)
+            })
+            .collect();
+
+        if marked.iter().all(Option::is_none)
+            && transcripts
+                .iter()
+                .all(|t| t.speaker.is_none() && source_label(t.source.as_deref()).is_none())
+        {
+            return Ok(None);
+        }
+
+        let names = Self::get_speaker_names(pool, meeting_id).await?;
+
+        let text = transcripts
+            .iter()
+            .zip(&marked)
+            .map(|(t, marked)| {
+                let text = marked.as_deref().unwrap_or(t.transcript.trim());
+                match Self::segment_label(&names, t) {
+                    Some(label) => format!("{}: {}", label, text),
+                    None => text.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(Some(text))
+    }
+
+    /// Label for one segment: the speaker's display name, else "Me" / "Others"
+    pub fn segment_label(names: &HashMap<String, String>, transcript: &Transcript) -> Option<String> {
+        match &transcript.speaker {
+            Some(speaker) => Some(Self::resolve_name(names, speaker)),
+            None => source_label(transcript.source.as_deref()).map(str::to_string),
+        }
+    }
+
+    /// Display name for a speaker ID, falling back to "Speaker N"
+    pub fn resolve_name(names: &HashMap<String, String>, speaker_id: &str) -> String {
+        names
+            .get(speaker_id)
+            .cloned()
+            .unwrap_or_else(|| default_speaker_name(speaker_id))
+    }
+}
+
+/// Transcript label for a mic/system source tag (None when unattributed)
+fn source_label(source: Option<&str>) -> Option<&'static str> {
+    match source {
+        Some("local") => Some("Me"),
+        Some("remote") => Some("Others"),
+        Some("overlap") => Some("Me + Others"),
+        _ => None,
+    }
+}
@@ -162,4 +162,25 @@ impl SummaryProcessesRepository {
         .await?;
         Ok(())
     }
+
+    /// Marks a summary process as cancelled by the user
+    pub async fn update_process_cancelled(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        sqlx::query(
+            r#"
+            UPDATE summary_processes
+            SET status = 'cancelled', error = NULL, updated_at = ?, end_time = ?
+            WHERE meeting_id = ?
+            "#,
+        )
+        .bind(now)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,292 @@
+use crate::database::models::{
+    SummaryVersion, SUMMARY_VERSION_SOURCE_GENERATED, SUMMARY_VERSION_SOURCE_MANUAL,
+};
+use chrono::Utc;
+use serde_json::Value;
+use sqlx::{Error as SqlxError, Sqlite, SqlitePool, Transaction};
+use tracing::info;
+use uuid::Uuid;
+
+/// Settings and result of a finished summary generation run
+#[derive(Debug, Clone)]
+pub struct NewSummaryVersion<'a> {
+    pub meeting_id: &'a str,
+    pub template_id: &'a str,
+    pub provider: &'a str,
+    pub model: &'a str,
+    pub custom_prompt: &'a str,
+    pub language: &'a str,
+    pub processing_time: f64,
+    pub chunk_count: i64,
+    pub result: &'a Value,
+}
+
+pub struct SummaryVersionsRepository;
+
+impl SummaryVersionsRepository {
+    /// Record a generation run as the meeting's next version
+    pub async fn create_version(
+        pool: &SqlitePool,
+        new_version: &NewSummaryVersion<'_>,
+    ) -> Result<SummaryVersion, SqlxError> {
+        if new_version.meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol("meeting_id cannot be empty".to_string()));
+        }
+
+        let result = serde_json::to_string(new_version.result)
+            .map_err(|e| SqlxError::Protocol(format!("Failed to serialize result: {}", e)))?;
+        let custom_prompt = Some(new_version.custom_prompt).filter(|p| !p.trim().is_empty());
+        let id = Uuid::new_v4().to_string();
+        let created_at = Utc::now();
+
+        let mut transaction = pool.begin().await?;
+
+        let (version,): (i64,) = sqlx::query_as(
+            "SELECT COALESCE(MAX(version), 0) + 1 FROM summary_versions WHERE meeting_id = ?",
+        )
+        .bind(new_version.meeting_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO summary_versions (
+                id, meeting_id, version, template_id, provider, model, custom_prompt,
+                language, processing_time, chunk_count, result, created_at
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(new_version.meeting_id)
+        .bind(version)
+        .bind(new_version.template_id)
+        .bind(new_version.provider)
+        .bind(new_version.model)
+        .bind(custom_prompt)
+        .bind(new_version.language)
+        .bind(new_version.processing_time)
+        .bind(new_version.chunk_count)
+        .bind(&result)
+        .bind(created_at)
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        info!(
+            "Saved summary version {} for meeting_id: {}",
+            version, new_version.meeting_id
+        );
+
+        Ok(SummaryVersion {
+            id,
+            meeting_id: new_version.meeting_id.to_string(),
+            version,
+            template_id: Some(new_version.template_id.to_string()),
+            provider: Some(new_version.provider.to_string()),
+            model: Some(new_version.model.to_string()),
+            custom_prompt: custom_prompt.map(str::to_string),
+            language: Some(new_version.language.to_string()),
+            processing_time: new_version.processing_time,
+            chunk_count: new_version.chunk_count,
+            result,
+            created_at,
+            source: SUMMARY_VERSION_SOURCE_GENERATED.to_string(),
+        })
+    }
+
+    /// Add a version carried over from another library (a meeting archive) to a
+    /// meeting, keeping its number and date under a new id
+    pub async fn import_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version: &SummaryVersion,
+    ) -> Result<SummaryVersion, SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol("meeting_id cannot be empty".to_string()));
+        }
+
+        let imported = SummaryVersion {
+            id: Uuid::new_v4().to_string(),
+            meeting_id: meeting_id.to_string(),
+            ..version.clone()
+        };
+
+        sqlx::query(
+            "INSERT INTO summary_versions (
+                id, meeting_id, version, template_id, provider, model, custom_prompt,
+                language, processing_time, chunk_count, result, created_at, source
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&imported.id)
+        .bind(&imported.meeting_id)
+        .bind(imported.version)
+        .bind(&imported.template_id)
+        .bind(&imported.provider)
+        .bind(&imported.model)
+        .bind(&imported.custom_prompt)
+        .bind(&imported.language)
+        .bind(imported.processing_time)
+        .bind(imported.chunk_count)
+        .bind(&imported.result)
+        .bind(imported.created_at)
+        .bind(&imported.source)
+        .execute(pool)
+        .await?;
+
+        Ok(imported)
+    }
+
+    /// All versions of a meeting's summary, newest first
+    pub async fn get_versions(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<SummaryVersion>, SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol("meeting_id cannot be empty".to_string()));
+        }
+
+        sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? ORDER BY version DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Get a version by id
+    pub async fn get_version(
+        pool: &SqlitePool,
+        version_id: &str,
+    ) -> Result<Option<SummaryVersion>, SqlxError> {
+        sqlx::query_as::<_, SummaryVersion>("SELECT * FROM summary_versions WHERE id = ?")
+            .bind(version_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Make a version the meeting's current summary. Manual edits are only saved to
+    /// summary_processes.result, so a current summary that is not a version yet is
+    /// kept as a "manual" version first. Returns `None` if the version does not
+    /// belong to the meeting.
+    pub async fn restore_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version_id: &str,
+    ) -> Result<Option<SummaryVersion>, SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        let version = sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE id = ? AND meeting_id = ?",
+        )
+        .bind(version_id)
+        .bind(meeting_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        let Some(version) = version else {
+            transaction.rollback().await?;
+            return Ok(None);
+        };
+
+        Self::keep_manual_edits(&mut transaction, meeting_id, &version.result).await?;
+
+        let now = Utc::now();
+        sqlx::query(
+            r#"
+            INSERT INTO summary_processes (meeting_id, status, created_at, updated_at, end_time, result, chunk_count, processing_time)
+            VALUES (?, 'completed', ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(meeting_id) DO UPDATE SET
+                status = 'completed',
+                updated_at = excluded.updated_at,
+                end_time = excluded.end_time,
+                result = excluded.result,
+                chunk_count = excluded.chunk_count,
+                processing_time = excluded.processing_time,
+                error = NULL
+            "#,
+        )
+        .bind(meeting_id)
+        .bind(now)
+        .bind(now)
+        .bind(now)
+        .bind(&version.result)
+        .bind(version.chunk_count)
+        .bind(version.processing_time)
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        info!(
+            "Restored summary version {} for meeting_id: {}",
+            version.version, meeting_id
+        );
+
+        Ok(Some(version))
+    }
+
+    /// Keep the meeting's current summary as a "manual" version if it is about to
+    /// be replaced by `replacement` and no version holds it (it has the user's edits)
+    async fn keep_manual_edits(
+        transaction: &mut Transaction<'_, Sqlite>,
+        meeting_id: &str,
+        replacement: &str,
+    ) -> Result<(), SqlxError> {
+        let current: Option<(Option<String>, i64)> = sqlx::query_as(
+            "SELECT result, COALESCE(chunk_count, 0) FROM summary_processes WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_optional(&mut **transaction)
+        .await?;
+        let Some((Some(current), chunk_count)) = current else {
+            return Ok(());
+        };
+        if current == replacement {
+            return Ok(());
+        }
+
+        let (versioned,): (bool,) = sqlx::query_as(
+            "SELECT EXISTS(SELECT 1 FROM summary_versions WHERE meeting_id = ? AND result = ?)",
+        )
+        .bind(meeting_id)
+        .bind(&current)
+        .fetch_one(&mut **transaction)
+        .await?;
+        if versioned {
+            return Ok(());
+        }
+
+        let (version,): (i64,) = sqlx::query_as(
+            "SELECT COALESCE(MAX(version), 0) + 1 FROM summary_versions WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_one(&mut **transaction)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO summary_versions (
+                id, meeting_id, version, chunk_count, result, created_at, source
+             ) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(meeting_id)
+        .bind(version)
+        .bind(chunk_count)
+        .bind(&current)
+        .bind(Utc::now())
+        .bind(SUMMARY_VERSION_SOURCE_MANUAL)
+        .execute(&mut **transaction)
+        .await?;
+
+        info!(
+            "Kept the edited summary of meeting_id {} as version {}",
+            meeting_id, version
+        );
+        Ok(())
+    }
+}
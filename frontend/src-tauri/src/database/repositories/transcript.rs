@@ -1,4 +1,5 @@
 use crate::api::{TranscriptSearchResult, TranscriptSegment};
+use crate::database::models::{Transcript, MEETING_STATUS_RECORDING};
 use chrono::Utc;
 use sqlx::{Connection, Error as SqlxError, SqlitePool};
 use tracing::{error, info};
@@ -46,9 +47,14 @@ impl TranscriptsRepository {
         // 2. Save each transcript segment with audio timing fields
         for segment in transcripts {
             let transcript_id = format!("transcript-{}", Uuid::new_v4());
+            let words_json = if segment.words.is_empty() {
+                None
+            } else {
+                serde_json::to_string(&segment.words).ok()
+            };
             let result = sqlx::query(
-                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration)
-                 VALUES (?, ?, ?, ?, ?, ?, ?)"
+                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, words, speaker, source, confidence)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
             )
             .bind(&transcript_id)
             .bind(&meeting_id)
@@ -57,6 +63,10 @@ impl TranscriptsRepository {
             .bind(segment.audio_start_time)
             .bind(segment.audio_end_time)
             .bind(segment.duration)
+            .bind(words_json)
+            .bind(&segment.speaker)
+            .bind(&segment.source)
+            .bind(segment.confidence)
             .execute(&mut *transaction)
             .await;
 
@@ -82,6 +92,110 @@ impl TranscriptsRepository {
         Ok(meeting_id)
     }
 
+    /// Creates the meeting of a recording that is starting, in the "recording"
+    /// status. Its segments are added with `upsert_segment` as they are transcribed.
+    pub async fn create_live_meeting(
+        pool: &SqlitePool,
+        meeting_title: &str,
+        folder_path: Option<String>,
+    ) -> Result<String, SqlxError> {
+        let meeting_id = format!("meeting-{}", Uuid::new_v4());
+        let now = Utc::now();
+
+        sqlx::query(
+            "INSERT INTO meetings (id, title, created_at, updated_at, folder_path, status) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&meeting_id)
+        .bind(meeting_title)
+        .bind(now)
+        .bind(now)
+        .bind(&folder_path)
+        .bind(MEETING_STATUS_RECORDING)
+        .execute(pool)
+        .await?;
+
+        info!("Created live meeting {} for recording '{}'", meeting_id, meeting_title);
+        Ok(meeting_id)
+    }
+
+    /// Saves one segment of a meeting under `segment.id`, replacing the segment
+    /// saved earlier under the same id (live segments can be revised).
+    pub async fn upsert_segment(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        segment: &TranscriptSegment,
+    ) -> Result<(), SqlxError> {
+        let words_json = if segment.words.is_empty() {
+            None
+        } else {
+            serde_json::to_string(&segment.words).ok()
+        };
+
+        sqlx::query(
+            "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, words, speaker, source, confidence)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET
+                transcript = excluded.transcript,
+                timestamp = excluded.timestamp,
+                audio_start_time = excluded.audio_start_time,
+                audio_end_time = excluded.audio_end_time,
+                duration = excluded.duration,
+                words = excluded.words,
+                speaker = excluded.speaker,
+                source = excluded.source,
+                confidence = excluded.confidence",
+        )
+        .bind(&segment.id)
+        .bind(meeting_id)
+        .bind(&segment.text)
+        .bind(&segment.timestamp)
+        .bind(segment.audio_start_time)
+        .bind(segment.audio_end_time)
+        .bind(segment.duration)
+        .bind(words_json)
+        .bind(&segment.speaker)
+        .bind(&segment.source)
+        .bind(segment.confidence)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// A meeting's transcript segments, in audio order
+    pub async fn get_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<TranscriptSegment>, SqlxError> {
+        let rows = sqlx::query_as::<_, Transcript>(
+            "SELECT * FROM transcripts WHERE meeting_id = ?
+             ORDER BY COALESCE(audio_start_time, 0) ASC, timestamp ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Self::segment_from_row).collect())
+    }
+
+    pub fn segment_from_row(row: Transcript) -> TranscriptSegment {
+        TranscriptSegment {
+            id: row.id,
+            text: row.transcript,
+            timestamp: row.timestamp,
+            audio_start_time: row.audio_start_time,
+            audio_end_time: row.audio_end_time,
+            duration: row.duration,
+            words: row
+                .words
+                .and_then(|words| serde_json::from_str(&words).ok())
+                .unwrap_or_default(),
+            speaker: row.speaker,
+            source: row.source,
+            confidence: row.confidence,
+        }
+    }
+
     /// Searches for a query string within the transcripts.
     /// It returns a list of matching transcripts with context.
     pub async fn search_transcripts(
@@ -113,6 +227,11 @@ impl TranscriptsRepository {
                     title,
                     match_context,
                     timestamp,
+                    highlighted_context: None,
+                    source_type: Some("transcript".to_string()),
+                    source_id: None,
+                    audio_start_time: None,
+                    rank: None,
                 }
             })
             .collect();
@@ -121,21 +240,26 @@ impl TranscriptsRepository {
     }
 
     /// Helper function to extract a snippet of text around the first match of a query.
+    /// Works in characters, not bytes, so multibyte text (e.g. "ação") can't split a char.
     fn get_match_context(transcript: &str, query: &str) -> String {
         let transcript_lower = transcript.to_lowercase();
         let query_lower = query.to_lowercase();
 
         match transcript_lower.find(&query_lower) {
             Some(match_index) => {
-                let start_index = match_index.saturating_sub(100);
-                let end_index = (match_index + query.len() + 100).min(transcript.len());
+                let chars: Vec<char> = transcript.chars().collect();
+                let match_char = transcript_lower[..match_index].chars().count().min(chars.len());
+                let query_chars = query_lower.chars().count();
+
+                let start_index = match_char.saturating_sub(100);
+                let end_index = (match_char + query_chars + 100).min(chars.len());
 
                 let mut context = String::new();
                 if start_index > 0 {
                     context.push_str("...");
                 }
-                context.push_str(&transcript[start_index..end_index]);
-                if end_index < transcript.len() {
+                context.extend(&chars[start_index..end_index]);
+                if end_index < chars.len() {
                     context.push_str("...");
                 }
                 context
@@ -144,3 +268,18 @@ impl TranscriptsRepository {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_context_multibyte_text() {
+        // Multibyte characters around the 100-char window edges used to panic on byte slicing
+        let transcript = format!("{}decisão final{}", "ç".repeat(150), "ã".repeat(150));
+        let context = TranscriptsRepository::get_match_context(&transcript, "DECISÃO");
+        assert!(context.contains("decisão final"));
+        assert!(context.starts_with("..."));
+        assert!(context.ends_with("..."));
+    }
+}
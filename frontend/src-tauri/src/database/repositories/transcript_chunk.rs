@@ -1,11 +1,23 @@
 // src/database/repo/transcript_chunks.rs
 
+use crate::database::models::TranscriptChunk;
 use chrono::Utc;
 use log::info as log_info;
 use sqlx::SqlitePool;
 pub struct TranscriptChunksRepository;
 
 impl TranscriptChunksRepository {
+    /// The transcript text and processing parameters saved for a meeting's summary
+    pub async fn get_transcript_data(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Option<TranscriptChunk>, sqlx::Error> {
+        sqlx::query_as::<_, TranscriptChunk>("SELECT * FROM transcript_chunks WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .fetch_optional(pool)
+            .await
+    }
+
     /// Saves the full transcript text and processing parameters.
     pub async fn save_transcript_data(
         pool: &SqlitePool,
@@ -0,0 +1,263 @@
+use crate::api::TranscriptSegment;
+use crate::database::models::{Transcript, TranscriptVersion};
+use crate::database::repositories::transcript::TranscriptsRepository;
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqliteConnection, SqlitePool};
+use tracing::info;
+use uuid::Uuid;
+
+/// How an alternate transcript was produced
+#[derive(Debug, Clone, Default)]
+pub struct NewTranscriptVersion<'a> {
+    pub meeting_id: &'a str,
+    pub label: &'a str,
+    pub model: Option<&'a str>,
+    pub language: Option<&'a str>,
+    pub vad_settings: Option<&'a str>, // JSON
+}
+
+pub struct TranscriptVersionsRepository;
+
+impl TranscriptVersionsRepository {
+    /// Keep a transcript as the meeting's next version, next to the current one
+    pub async fn create_version(
+        pool: &SqlitePool,
+        new_version: &NewTranscriptVersion<'_>,
+        segments: &[TranscriptSegment],
+    ) -> Result<TranscriptVersion, SqlxError> {
+        if new_version.meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        let mut transaction = pool.begin().await?;
+        let version = insert_version(&mut transaction, new_version, segments).await?;
+        transaction.commit().await?;
+
+        info!(
+            "Saved transcript version {} for meeting_id: {}",
+            version.version, new_version.meeting_id
+        );
+        Ok(version)
+    }
+
+    /// All versions of a meeting's transcript, newest first
+    pub async fn get_versions(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<TranscriptVersion>, SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        sqlx::query_as::<_, TranscriptVersion>(
+            "SELECT * FROM transcript_versions WHERE meeting_id = ? ORDER BY version DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Get a version by id
+    pub async fn get_version(
+        pool: &SqlitePool,
+        version_id: &str,
+    ) -> Result<Option<TranscriptVersion>, SqlxError> {
+        sqlx::query_as::<_, TranscriptVersion>("SELECT * FROM transcript_versions WHERE id = ?")
+            .bind(version_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Replace the meeting's current transcript with `segments`. The replaced
+    /// transcript is kept as a new version, described by `backup`, which is returned.
+    pub async fn replace_transcript(
+        pool: &SqlitePool,
+        backup: &NewTranscriptVersion<'_>,
+        segments: &[TranscriptSegment],
+    ) -> Result<TranscriptVersion, SqlxError> {
+        if backup.meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        let mut transaction = pool.begin().await?;
+        let backup_version = replace_current(&mut transaction, backup, segments).await?;
+        transaction.commit().await?;
+
+        info!(
+            "Replaced transcript of meeting_id: {} ({} segments), previous kept as version {}",
+            backup.meeting_id,
+            segments.len(),
+            backup_version.version
+        );
+        Ok(backup_version)
+    }
+
+    /// Make a version the meeting's current transcript. The current transcript
+    /// is kept as a new version first. Returns `None` if the version does not
+    /// belong to the meeting.
+    pub async fn restore_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version_id: &str,
+    ) -> Result<Option<TranscriptVersion>, SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        let version = sqlx::query_as::<_, TranscriptVersion>(
+            "SELECT * FROM transcript_versions WHERE id = ? AND meeting_id = ?",
+        )
+        .bind(version_id)
+        .bind(meeting_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        let Some(version) = version else {
+            transaction.rollback().await?;
+            return Ok(None);
+        };
+
+        let segments: Vec<TranscriptSegment> = serde_json::from_str(&version.segments)
+            .map_err(|e| SqlxError::Protocol(format!("Invalid transcript version: {}", e)))?;
+        let label = format!("Before restoring version {}", version.version);
+        let backup = NewTranscriptVersion {
+            meeting_id,
+            label: &label,
+            ..Default::default()
+        };
+        replace_current(&mut transaction, &backup, &segments).await?;
+
+        transaction.commit().await?;
+
+        info!(
+            "Restored transcript version {} for meeting_id: {}",
+            version.version, meeting_id
+        );
+        Ok(Some(version))
+    }
+
+    /// Delete a version. Returns false if it does not belong to the meeting.
+    pub async fn delete_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version_id: &str,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM transcript_versions WHERE id = ? AND meeting_id = ?")
+            .bind(version_id)
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+async fn insert_version(
+    conn: &mut SqliteConnection,
+    new_version: &NewTranscriptVersion<'_>,
+    segments: &[TranscriptSegment],
+) -> Result<TranscriptVersion, SqlxError> {
+    let segments_json = serde_json::to_string(segments)
+        .map_err(|e| SqlxError::Protocol(format!("Failed to serialize segments: {}", e)))?;
+    let id = Uuid::new_v4().to_string();
+    let created_at = Utc::now();
+
+    let (version,): (i64,) = sqlx::query_as(
+        "SELECT COALESCE(MAX(version), 0) + 1 FROM transcript_versions WHERE meeting_id = ?",
+    )
+    .bind(new_version.meeting_id)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO transcript_versions (
+            id, meeting_id, version, label, model, language, vad_settings,
+            segment_count, segments, created_at
+         ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&id)
+    .bind(new_version.meeting_id)
+    .bind(version)
+    .bind(new_version.label)
+    .bind(new_version.model)
+    .bind(new_version.language)
+    .bind(new_version.vad_settings)
+    .bind(segments.len() as i64)
+    .bind(&segments_json)
+    .bind(created_at)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(TranscriptVersion {
+        id,
+        meeting_id: new_version.meeting_id.to_string(),
+        version,
+        label: new_version.label.to_string(),
+        model: new_version.model.map(str::to_string),
+        language: new_version.language.map(str::to_string),
+        vad_settings: new_version.vad_settings.map(str::to_string),
+        segment_count: segments.len() as i64,
+        segments: segments_json,
+        created_at,
+    })
+}
+
+/// Keep the current transcript as a version, then replace it with `segments`
+async fn replace_current(
+    conn: &mut SqliteConnection,
+    backup: &NewTranscriptVersion<'_>,
+    segments: &[TranscriptSegment],
+) -> Result<TranscriptVersion, SqlxError> {
+    let current: Vec<TranscriptSegment> = sqlx::query_as::<_, Transcript>(
+        "SELECT * FROM transcripts WHERE meeting_id = ?
+         ORDER BY COALESCE(audio_start_time, 0) ASC, timestamp ASC",
+    )
+    .bind(backup.meeting_id)
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(TranscriptsRepository::segment_from_row)
+    .collect();
+    let backup_version = insert_version(conn, backup, &current).await?;
+
+    sqlx::query("DELETE FROM transcripts WHERE meeting_id = ?")
+        .bind(backup.meeting_id)
+        .execute(&mut *conn)
+        .await?;
+
+    for segment in segments {
+        let words_json = if segment.words.is_empty() {
+            None
+        } else {
+            serde_json::to_string(&segment.words).ok()
+        };
+        sqlx::query(
+            "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, words, speaker, source, confidence)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(format!("transcript-{}", Uuid::new_v4()))
+        .bind(backup.meeting_id)
+        .bind(&segment.text)
+        .bind(&segment.timestamp)
+        .bind(segment.audio_start_time)
+        .bind(segment.audio_end_time)
+        .bind(segment.duration)
+        .bind(words_json)
+        .bind(&segment.speaker)
+        .bind(&segment.source)
+        .bind(segment.confidence)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(backup.meeting_id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(backup_version)
+}
@@ -0,0 +1,129 @@
+// diarization/clustering.rs
+//
+// Online (streaming) speaker clustering over L2-normalized embeddings.
+// Each new segment is assigned to the closest speaker centroid by cosine
+// similarity, or opens a new speaker when nothing is close enough.
+
+/// Cosine similarity above which a segment joins an existing speaker
+pub const DEFAULT_SIMILARITY_THRESHOLD: f32 = 0.5;
+
+/// Upper bound on distinct speakers per meeting; extra speakers fold into the closest one
+pub const DEFAULT_MAX_SPEAKERS: usize = 8;
+
+#[derive(Debug, Clone)]
+struct SpeakerCluster {
+    centroid: Vec<f32>,
+    segment_count: usize,
+}
+
+#[derive(Debug)]
+pub struct OnlineSpeakerClusterer {
+    clusters: Vec<SpeakerCluster>,
+    similarity_threshold: f32,
+    max_speakers: usize,
+}
+
+impl Default for OnlineSpeakerClusterer {
+    fn default() -> Self {
+        Self::new(DEFAULT_SIMILARITY_THRESHOLD, DEFAULT_MAX_SPEAKERS)
+    }
+}
+
+impl OnlineSpeakerClusterer {
+    pub fn new(similarity_threshold: f32, max_speakers: usize) -> Self {
+        Self {
+            clusters: Vec::new(),
+            similarity_threshold,
+            max_speakers: max_speakers.max(1),
+        }
+    }
+
+    /// Assign an embedding to a speaker and return its zero-based index
+    pub fn assign(&mut self, embedding: &[f32]) -> usize {
+        let best = self
+            .clusters
+            .iter()
+            .enumerate()
+            .map(|(idx, cluster)| (idx, cosine_similarity(&cluster.centroid, embedding)))
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        match best {
+            Some((idx, similarity))
+                if similarity >= self.similarity_threshold
+                    || self.clusters.len() >= self.max_speakers =>
+            {
+                self.update_centroid(idx, embedding);
+                idx
+            }
+            _ => {
+                self.clusters.push(SpeakerCluster {
+                    centroid: embedding.to_vec(),
+                    segment_count: 1,
+                });
+                self.clusters.len() - 1
+            }
+        }
+    }
+
+    /// Number of speakers seen so far
+    pub fn speaker_count(&self) -> usize {
+        self.clusters.len()
+    }
+
+    /// Forget all speakers (start of a new recording)
+    pub fn reset(&mut self) {
+        self.clusters.clear();
+    }
+
+    fn update_centroid(&mut self, idx: usize, embedding: &[f32]) {
+        let cluster = &mut self.clusters[idx];
+        let n = cluster.segment_count as f32;
+        for (c, e) in cluster.centroid.iter_mut().zip(embedding.iter()) {
+            *c = (*c * n + e) / (n + 1.0);
+        }
+        cluster.segment_count += 1;
+    }
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a <= f32::EPSILON || norm_b <= f32::EPSILON {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_similar_embeddings_share_speaker() {
+        let mut clusterer = OnlineSpeakerClusterer::default();
+        assert_eq!(clusterer.assign(&[1.0, 0.0, 0.0]), 0);
+        assert_eq!(clusterer.assign(&[0.95, 0.1, 0.0]), 0);
+        assert_eq!(clusterer.assign(&[0.0, 1.0, 0.0]), 1);
+        assert_eq!(clusterer.assign(&[0.1, 0.9, 0.0]), 1);
+        assert_eq!(clusterer.speaker_count(), 2);
+    }
+
+    #[test]
+    fn test_max_speakers_folds_into_closest() {
+        let mut clusterer = OnlineSpeakerClusterer::new(0.9, 2);
+        assert_eq!(clusterer.assign(&[1.0, 0.0, 0.0]), 0);
+        assert_eq!(clusterer.assign(&[0.0, 1.0, 0.0]), 1);
+        // Third distinct voice is capped and folded into the nearest speaker
+        assert_eq!(clusterer.assign(&[0.2, 0.0, 1.0]), 0);
+        assert_eq!(clusterer.speaker_count(), 2);
+    }
+
+    #[test]
+    fn test_reset_clears_speakers() {
+        let mut clusterer = OnlineSpeakerClusterer::default();
+        clusterer.assign(&[1.0, 0.0]);
+        clusterer.reset();
+        assert_eq!(clusterer.speaker_count(), 0);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::diarization::{SpeakerDiarizer, EMBEDDING_MODEL_FILE};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::{command, AppHandle, Manager, Runtime};
+
+// Global diarizer, loaded lazily the first time a recording starts with the model present
+static DIARIZER: Mutex<Option<Arc<SpeakerDiarizer>>> = Mutex::new(None);
+
+// Global diarization models directory (set during app initialization)
+static MODELS_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Initialize the diarization models directory (`<app_data>/models/diarization`)
+pub fn set_models_directory<R: Runtime>(app: &AppHandle<R>) {
+    let app_data_dir = app.path().app_data_dir()
+        .expect("Failed to get app data dir");
+
+    let models_dir = app_data_dir.join("models").join("diarization");
+
+    if !models_dir.exists() {
+        if let Err(e) = std::fs::create_dir_all(&models_dir) {
+            log::error!("Failed to create diarization models directory: {}", e);
+            return;
+        }
+    }
+
+    log::info!("Diarization models directory set to: {}", models_dir.display());
+
+    let mut guard = MODELS_DIR.lock().unwrap();
+    *guard = Some(models_dir);
+}
+
+fn get_models_directory() -> Option<PathBuf> {
+    MODELS_DIR.lock().unwrap().clone()
+}
+
+fn is_model_available() -> bool {
+    get_models_directory()
+        .map(|dir| dir.join(EMBEDDING_MODEL_FILE).exists())
+        .unwrap_or(false)
+}
+
+/// Get a diarizer with cleared speaker state for a new recording.
+///
+/// Returns `None` when no speaker embedding model is installed, in which case
+/// transcripts are saved without speaker labels.
+pub fn prepare_for_recording() -> Option<Arc<SpeakerDiarizer>> {
+    let mut guard = DIARIZER.lock().unwrap();
+
+    if guard.is_none() {
+        if !is_model_available() {
+            log::info!("No speaker embedding model installed - diarization disabled");
+            return None;
+        }
+
+        let models_dir = get_models_directory()?;
+        match SpeakerDiarizer::new(&models_dir) {
+            Ok(diarizer) => *guard = Some(Arc::new(diarizer)),
+            Err(e) => {
+                log::error!("Failed to load speaker embedding model: {}", e);
+                return None;
+            }
+        }
+    }
+
+    let diarizer = guard.as_ref().cloned()?;
+    diarizer.reset();
+    log::info!("🗣️ Speaker diarization enabled for this recording");
+    Some(diarizer)
+}
+
+#[command]
+pub async fn diarization_is_available() -> Result<bool, String> {
+    Ok(is_model_available())
+}
+
+#[command]
+pub async fn diarization_get_models_directory() -> Result<String, String> {
+    get_models_directory()
+        .map(|dir| dir.to_string_lossy().to_string())
+        .ok_or_else(|| "Diarization models directory not initialized".to_string())
+}
@@ -0,0 +1,78 @@
+// diarization/diarizer.rs
+//
+// Combines the embedding model and online clustering into a per-recording
+// speaker labeler used by the transcription worker.
+
+use super::clustering::OnlineSpeakerClusterer;
+use super::embedding::{DiarizationError, SpeakerEmbeddingModel};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Segments shorter than this (16kHz samples) give unreliable embeddings and stay unlabeled
+pub const MIN_DIARIZATION_SAMPLES: usize = 16000; // 1 second
+
+pub struct SpeakerDiarizer {
+    model: Mutex<SpeakerEmbeddingModel>,
+    clusterer: Mutex<OnlineSpeakerClusterer>,
+}
+
+impl SpeakerDiarizer {
+    pub fn new<P: AsRef<Path>>(model_dir: P) -> Result<Self, DiarizationError> {
+        Ok(Self {
+            model: Mutex::new(SpeakerEmbeddingModel::new(model_dir)?),
+            clusterer: Mutex::new(OnlineSpeakerClusterer::default()),
+        })
+    }
+
+    /// Label a speech segment (16kHz mono) with a speaker ID like "speaker_1".
+    ///
+    /// CPU-bound; call from a blocking context. Returns `Ok(None)` for
+    /// segments too short to identify.
+    pub fn identify_speaker(&self, samples: &[f32]) -> Result<Option<String>, DiarizationError> {
+        if samples.len() < MIN_DIARIZATION_SAMPLES {
+            return Ok(None);
+        }
+
+        let embedding = {
+            let mut model = self.model.lock().unwrap();
+            model.embed(samples)?
+        };
+
+        let Some(embedding) = embedding else {
+            return Ok(None);
+        };
+
+        let index = self.clusterer.lock().unwrap().assign(&embedding);
+        Ok(Some(speaker_id(index)))
+    }
+
+    /// Forget speakers from the previous recording
+    pub fn reset(&self) {
+        self.clusterer.lock().unwrap().reset();
+    }
+}
+
+/// Stable speaker ID stored in the database for a zero-based cluster index
+pub fn speaker_id(index: usize) -> String {
+    format!("speaker_{}", index + 1)
+}
+
+/// Display name used until the user renames a speaker ("speaker_2" -> "Speaker 2")
+pub fn default_speaker_name(speaker_id: &str) -> String {
+    match speaker_id.strip_prefix("speaker_") {
+        Some(number) => format!("Speaker {}", number),
+        None => speaker_id.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speaker_id_round_trip() {
+        assert_eq!(speaker_id(0), "speaker_1");
+        assert_eq!(default_speaker_name(&speaker_id(1)), "Speaker 2");
+        assert_eq!(default_speaker_name("custom"), "custom");
+    }
+}
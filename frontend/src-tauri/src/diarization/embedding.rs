@@ -0,0 +1,107 @@
+// diarization/embedding.rs
+//
+// Speaker embedding extraction with an ONNX model (WeSpeaker / 3D-Speaker style
+// exports taking fbank features [1, T, 80] and returning an embedding [1, D]).
+
+use super::features::{compute_fbank, NUM_MEL_BINS};
+use ndarray::Array3;
+use ort::execution_providers::CPUExecutionProvider;
+use ort::inputs;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use ort::value::TensorRef;
+use std::path::Path;
+
+/// File name of the speaker embedding model inside the diarization models directory
+pub const EMBEDDING_MODEL_FILE: &str = "speaker-embedding.onnx";
+
+#[derive(thiserror::Error, Debug)]
+pub enum DiarizationError {
+    #[error("ORT error")]
+    Ort(#[from] ort::Error),
+    #[error("ndarray shape error")]
+    Shape(#[from] ndarray::ShapeError),
+    #[error("Speaker embedding model not found: {0}")]
+    ModelNotFound(String),
+    #[error("Model input not found: {0}")]
+    InputNotFound(String),
+    #[error("Model output not found: {0}")]
+    OutputNotFound(String),
+}
+
+pub struct SpeakerEmbeddingModel {
+    session: Session,
+    input_name: String,
+    output_name: String,
+}
+
+impl SpeakerEmbeddingModel {
+    pub fn new<P: AsRef<Path>>(model_dir: P) -> Result<Self, DiarizationError> {
+        let model_path = model_dir.as_ref().join(EMBEDDING_MODEL_FILE);
+        if !model_path.exists() {
+            return Err(DiarizationError::ModelNotFound(model_path.display().to_string()));
+        }
+
+        // CPU only, single thread: runs alongside live transcription
+        let session = Session::builder()?
+            .with_optimization_level(GraphOptimizationLevel::Level3)?
+            .with_execution_providers(vec![CPUExecutionProvider::default().build()])?
+            .with_intra_threads(1)?
+            .commit_from_file(&model_path)?;
+
+        let input_name = session
+            .inputs
+            .first()
+            .map(|input| input.name.clone())
+            .ok_or_else(|| DiarizationError::InputNotFound("fbank".to_string()))?;
+        let output_name = session
+            .outputs
+            .first()
+            .map(|output| output.name.clone())
+            .ok_or_else(|| DiarizationError::OutputNotFound("embedding".to_string()))?;
+
+        log::info!(
+            "Loaded speaker embedding model from {} (input={}, output={})",
+            model_path.display(),
+            input_name,
+            output_name
+        );
+
+        Ok(Self {
+            session,
+            input_name,
+            output_name,
+        })
+    }
+
+    /// Compute an L2-normalized speaker embedding for 16kHz mono audio.
+    ///
+    /// Returns `Ok(None)` when the audio is too short to produce features.
+    pub fn embed(&mut self, samples: &[f32]) -> Result<Option<Vec<f32>>, DiarizationError> {
+        let features = compute_fbank(samples);
+        if features.is_empty() {
+            return Ok(None);
+        }
+
+        let num_frames = features.len();
+        let flat: Vec<f32> = features.into_iter().flatten().collect();
+        let input = Array3::from_shape_vec((1, num_frames, NUM_MEL_BINS), flat)?;
+
+        let outputs = self.session.run(inputs![
+            self.input_name.as_str() => TensorRef::from_array_view(input.view())?,
+        ])?;
+
+        let embedding = outputs
+            .get(self.output_name.as_str())
+            .ok_or_else(|| DiarizationError::OutputNotFound(self.output_name.clone()))?
+            .try_extract_array::<f32>()?;
+
+        let mut embedding: Vec<f32> = embedding.iter().copied().collect();
+        let norm = embedding.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > f32::EPSILON {
+            embedding.iter_mut().for_each(|v| *v /= norm);
+        }
+
+        Ok(Some(embedding))
+    }
+}
@@ -0,0 +1,153 @@
+// diarization/features.rs
+//
+// Kaldi-compatible log-mel filterbank features for speaker embedding models.
+// WeSpeaker / 3D-Speaker ONNX exports expect 80-dim fbank frames
+// (25ms window, 10ms shift, povey window) with per-utterance mean normalization.
+
+use realfft::RealFftPlanner;
+
+pub const SAMPLE_RATE: usize = 16000;
+pub const NUM_MEL_BINS: usize = 80;
+
+const FRAME_LENGTH: usize = 400; // 25ms at 16kHz
+const FRAME_SHIFT: usize = 160; // 10ms at 16kHz
+const FFT_SIZE: usize = 512;
+const PREEMPHASIS: f32 = 0.97;
+const LOW_FREQ: f32 = 20.0;
+const HIGH_FREQ: f32 = 8000.0;
+
+fn mel_scale(freq: f32) -> f32 {
+    1127.0 * (1.0 + freq / 700.0).ln()
+}
+
+/// Triangular mel filters over the positive FFT bins (Kaldi layout)
+fn mel_filterbank() -> Vec<Vec<f32>> {
+    let num_fft_bins = FFT_SIZE / 2;
+    let fft_bin_width = SAMPLE_RATE as f32 / FFT_SIZE as f32;
+    let mel_low = mel_scale(LOW_FREQ);
+    let mel_high = mel_scale(HIGH_FREQ);
+    let mel_delta = (mel_high - mel_low) / (NUM_MEL_BINS + 1) as f32;
+
+    (0..NUM_MEL_BINS)
+        .map(|bin| {
+            let left = mel_low + bin as f32 * mel_delta;
+            let center = left + mel_delta;
+            let right = center + mel_delta;
+
+            (0..num_fft_bins)
+                .map(|i| {
+                    let mel = mel_scale(fft_bin_width * i as f32);
+                    if mel > left && mel < right {
+                        if mel <= center {
+                            (mel - left) / (center - left)
+                        } else {
+                            (right - mel) / (right - center)
+                        }
+                    } else {
+                        0.0
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Compute mean-normalized log-mel fbank features for 16kHz mono audio.
+///
+/// Returns one `NUM_MEL_BINS`-wide row per 10ms frame; empty if the audio is
+/// shorter than a single 25ms frame.
+pub fn compute_fbank(samples: &[f32]) -> Vec<[f32; NUM_MEL_BINS]> {
+    if samples.len() < FRAME_LENGTH {
+        return Vec::new();
+    }
+
+    let num_frames = 1 + (samples.len() - FRAME_LENGTH) / FRAME_SHIFT;
+    let filters = mel_filterbank();
+
+    // Povey window: hann raised to 0.85
+    let window: Vec<f32> = (0..FRAME_LENGTH)
+        .map(|i| {
+            let hann = 0.5
+                - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (FRAME_LENGTH - 1) as f32).cos();
+            hann.powf(0.85)
+        })
+        .collect();
+
+    let mut planner = RealFftPlanner::<f32>::new();
+    let r2c = planner.plan_fft_forward(FFT_SIZE);
+    let mut frame_buf = r2c.make_input_vec();
+    let mut spectrum = r2c.make_output_vec();
+
+    // Kaldi features are computed on int16-scaled audio
+    let scale = 32768.0;
+    let mut features = Vec::with_capacity(num_frames);
+
+    for frame_idx in 0..num_frames {
+        let start = frame_idx * FRAME_SHIFT;
+        let frame = &samples[start..start + FRAME_LENGTH];
+
+        let mean = frame.iter().sum::<f32>() / FRAME_LENGTH as f32;
+
+        frame_buf.iter_mut().for_each(|x| *x = 0.0);
+        for i in (0..FRAME_LENGTH).rev() {
+            let current = (frame[i] - mean) * scale;
+            let previous = if i > 0 { (frame[i - 1] - mean) * scale } else { current };
+            frame_buf[i] = (current - PREEMPHASIS * previous) * window[i];
+        }
+
+        if r2c.process(&mut frame_buf, &mut spectrum).is_err() {
+            continue;
+        }
+
+        let power: Vec<f32> = spectrum[..FFT_SIZE / 2].iter().map(|c| c.norm_sqr()).collect();
+
+        let mut row = [0.0f32; NUM_MEL_BINS];
+        for (bin, filter) in filters.iter().enumerate() {
+            let energy: f32 = filter.iter().zip(power.iter()).map(|(w, p)| w * p).sum();
+            row[bin] = energy.max(f32::EPSILON).ln();
+        }
+        features.push(row);
+    }
+
+    // Cepstral mean normalization over the whole segment
+    if !features.is_empty() {
+        let mut means = [0.0f32; NUM_MEL_BINS];
+        for row in &features {
+            for (m, v) in means.iter_mut().zip(row.iter()) {
+                *m += v;
+            }
+        }
+        let count = features.len() as f32;
+        for m in means.iter_mut() {
+            *m /= count;
+        }
+        for row in features.iter_mut() {
+            for (v, m) in row.iter_mut().zip(means.iter()) {
+                *v -= m;
+            }
+        }
+    }
+
+    features
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fbank_frame_count() {
+        // 1 second of audio -> (16000 - 400) / 160 + 1 = 98 frames
+        let samples: Vec<f32> = (0..SAMPLE_RATE)
+            .map(|i| (i as f32 * 440.0 * 2.0 * std::f32::consts::PI / SAMPLE_RATE as f32).sin() * 0.1)
+            .collect();
+        let features = compute_fbank(&samples);
+        assert_eq!(features.len(), 98);
+        assert!(features.iter().all(|row| row.iter().all(|v| v.is_finite())));
+    }
+
+    #[test]
+    fn test_fbank_too_short() {
+        assert!(compute_fbank(&[0.0; 100]).is_empty());
+    }
+}
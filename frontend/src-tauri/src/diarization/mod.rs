@@ -0,0 +1,19 @@
+// diarization/mod.rs
+//
+// Local, CPU-only speaker diarization: an ONNX speaker-embedding model
+// (run through ort) plus online clustering labels each transcript segment
+// with a per-meeting speaker ID ("speaker_1", "speaker_2", ...).
+//
+// The model is optional: drop a WeSpeaker / 3D-Speaker style export named
+// `speaker-embedding.onnx` into `<app_data>/models/diarization/` to enable it.
+
+pub mod clustering;
+pub mod commands;
+pub mod diarizer;
+pub mod embedding;
+pub mod features;
+
+pub use clustering::OnlineSpeakerClusterer;
+pub use diarizer::{default_speaker_name, speaker_id, SpeakerDiarizer};
+pub use embedding::{DiarizationError, SpeakerEmbeddingModel, EMBEDDING_MODEL_FILE};
+pub use commands::*;
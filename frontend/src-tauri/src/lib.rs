@@ -35,18 +35,24 @@ pub(crate) use perf_trace;
 // Re-export async logging macros for external use (removed due to macro conflicts)
 
 // Declare audio module
+pub mod action_items;
 pub mod analytics;
 pub mod api;
 pub mod audio;
 pub mod chat;
+pub mod cli;
 pub mod console_utils;
 pub mod database;
+pub mod diarization;
+pub mod meeting_archive;
 pub mod notifications;
 pub mod ollama;
 pub mod openrouter;
 pub mod parakeet_engine;
 pub mod state;
+pub mod subtitles;
 pub mod summary;
+pub mod transcript_import;
 pub mod tray;
 pub mod utils;
 // NOTE: whisper_engine module disabled - Meetily now uses Parakeet exclusively
@@ -449,13 +455,19 @@ pub fn run() {
             // Set Parakeet models directory
             parakeet_engine::commands::set_models_directory(&_app.handle());
 
-            // Initialize Parakeet engine on startup
-            tauri::async_runtime::spawn(async {
+            // Initialize Parakeet engine on startup, with the saved decoding settings
+            let parakeet_app = _app.handle().clone();
+            tauri::async_runtime::spawn(async move {
                 if let Err(e) = parakeet_engine::commands::parakeet_init().await {
                     log::error!("Failed to initialize Parakeet engine on startup: {}", e);
+                    return;
                 }
+                parakeet_engine::commands::apply_saved_decoding_config(&parakeet_app).await;
             });
 
+            // Set speaker diarization models directory (model is loaded lazily per recording)
+            diarization::commands::set_models_directory(&_app.handle());
+
             // Trigger system audio permission request on startup (similar to microphone permission)
             // #[cfg(target_os = "macos")]
             // {
@@ -542,7 +554,12 @@ pub fn run() {
             parakeet_engine::commands::parakeet_download_model,
             parakeet_engine::commands::parakeet_cancel_download,
             parakeet_engine::commands::parakeet_delete_corrupted_model,
+            parakeet_engine::commands::parakeet_get_decoding_config,
+            parakeet_engine::commands::parakeet_set_decoding_config,
             parakeet_engine::commands::open_parakeet_models_folder,
+            // Speaker diarization commands
+            diarization::commands::diarization_is_available,
+            diarization::commands::diarization_get_models_directory,
             // NOTE: Whisper parallel processing commands disabled - using Parakeet exclusively
             // whisper_engine::parallel_commands::initialize_parallel_processor,
             // whisper_engine::parallel_commands::start_parallel_processing,
@@ -603,6 +620,8 @@ pub fn run() {
             api::api_delete_meeting,
             api::api_get_meeting,
             api::api_save_meeting_title,
+            api::api_get_meeting_speakers,
+            api::api_rename_speaker,
             api::api_save_transcript,
             api::open_meeting_folder,
             api::test_backend_connection,
@@ -612,6 +631,10 @@ pub fn run() {
             summary::api_process_transcript,
             summary::api_get_summary,
             summary::api_save_meeting_summary,
+            summary::api_cancel_summary,
+            summary::api_list_summary_versions,
+            summary::api_restore_summary_version,
+            summary::api_diff_summary_versions,
             // Template commands
             summary::api_list_templates,
             summary::api_get_template_details,
@@ -621,6 +644,20 @@ pub fn run() {
             chat::api_save_chat_message,
             chat::api_delete_chat_messages,
             chat::api_ask_question,
+            chat::api_cancel_chat_answer,
+            chat::api_ask_library_question,
+            chat::api_cancel_library_answer,
+            chat::api_get_conversations,
+            chat::api_get_conversation_messages,
+            chat::api_rename_conversation,
+            chat::api_delete_conversation,
+            action_items::api_get_action_items,
+            action_items::api_get_open_action_items,
+            action_items::api_create_action_item,
+            action_items::api_update_action_item,
+            action_items::api_delete_action_item,
+            action_items::api_extract_action_items,
+            subtitles::api_export_subtitles,
             openrouter::get_openrouter_models,
             audio::recording_preferences::get_recording_preferences,
             audio::recording_preferences::set_recording_preferences,
@@ -631,6 +668,33 @@ pub fn run() {
             audio::recording_preferences::get_current_audio_backend,
             audio::recording_preferences::set_audio_backend,
             audio::recording_preferences::get_audio_backend_info,
+            // Audio/video file import commands
+            audio::import_commands::select_import_file,
+            audio::import_commands::api_import_audio_file,
+            audio::import_commands::api_cancel_import,
+            // Interrupted recording recovery commands
+            audio::recovery_commands::api_find_interrupted_recordings,
+            audio::recovery_commands::api_recover_recording,
+            audio::recovery_commands::api_cancel_recovery,
+            audio::recovery_commands::api_dismiss_interrupted_recording,
+            // Library rescan commands
+            audio::library_scan_commands::api_scan_library,
+            audio::library_scan_commands::api_apply_library_scan,
+            // Re-transcription and transcript version commands
+            audio::retranscribe_commands::api_retranscribe_meeting,
+            audio::retranscribe_commands::api_cancel_retranscribe,
+            audio::retranscribe_commands::api_list_transcript_versions,
+            audio::retranscribe_commands::api_restore_transcript_version,
+            audio::retranscribe_commands::api_delete_transcript_version,
+            audio::refine_commands::api_cancel_transcript_refinement,
+            // External transcript import commands
+            transcript_import::select_transcript_file,
+            transcript_import::api_import_transcript_file,
+            // Meeting archive commands
+            meeting_archive::select_meeting_archive_destination,
+            meeting_archive::select_meeting_archive_file,
+            meeting_archive::api_export_meeting_archive,
+            meeting_archive::api_import_meeting_archive,
             // Language preference commands
             get_language_preference,
             set_language_preference,
@@ -0,0 +1,110 @@
+use super::{export_meeting, import_meeting, ArchiveManifest, ImportedMeeting};
+use crate::audio::audio_processing::sanitize_filename;
+use crate::audio::recording_preferences::{
+    get_default_recordings_folder, load_recording_preferences,
+};
+use crate::state::AppState;
+use log::{error as log_error, info as log_info};
+use std::path::Path;
+use tauri::{AppHandle, Runtime};
+
+/// Ask where to save a meeting archive. Returns `None` if the user cancelled.
+#[tauri::command]
+pub async fn select_meeting_archive_destination<R: Runtime>(
+    app: AppHandle<R>,
+    title: String,
+) -> Result<Option<String>, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let file_path = app
+        .dialog()
+        .file()
+        .add_filter("Meeting archive", &["zip"])
+        .set_file_name(format!("{}.zip", sanitize_filename(&title)))
+        .blocking_save_file();
+
+    Ok(file_path.map(|path| path.to_string()))
+}
+
+/// Ask for a meeting archive to import. Returns `None` if the user cancelled.
+#[tauri::command]
+pub async fn select_meeting_archive_file<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Option<String>, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let file_path = app
+        .dialog()
+        .file()
+        .add_filter("Meeting archive", &["zip"])
+        .blocking_pick_file();
+
+    Ok(file_path.map(|path| path.to_string()))
+}
+
+/// Export a meeting with its audio, transcript, summaries and chat as a zip archive
+#[tauri::command]
+pub async fn api_export_meeting_archive<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    destination: String,
+) -> Result<ArchiveManifest, String> {
+    log_info!(
+        "api_export_meeting_archive called for meeting_id: {}, destination: {}",
+        meeting_id,
+        destination
+    );
+
+    match export_meeting(
+        state.db_manager.pool(),
+        &meeting_id,
+        Path::new(&destination),
+    )
+    .await
+    {
+        Ok(manifest) => {
+            log_info!(
+                "✅ Exported meeting {} to {} ({} files)",
+                meeting_id,
+                destination,
+                manifest.files.len()
+            );
+            Ok(manifest)
+        }
+        Err(e) => {
+            log_error!("❌ Failed to export meeting {}: {}", meeting_id, e);
+            Err(e)
+        }
+    }
+}
+
+/// Import a meeting archive as a new meeting, into a new folder in the recordings folder
+#[tauri::command]
+pub async fn api_import_meeting_archive<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    file_path: String,
+) -> Result<ImportedMeeting, String> {
+    log_info!("api_import_meeting_archive called for: {}", file_path);
+
+    let base_folder = load_recording_preferences(&app)
+        .await
+        .map(|prefs| prefs.save_folder)
+        .unwrap_or_else(|_| get_default_recordings_folder());
+
+    match import_meeting(state.db_manager.pool(), Path::new(&file_path), &base_folder).await {
+        Ok(imported) => {
+            log_info!(
+                "✅ Imported archive {} as meeting {}",
+                file_path,
+                imported.meeting_id
+            );
+            Ok(imported)
+        }
+        Err(e) => {
+            log_error!("❌ Failed to import archive {}: {}", file_path, e);
+            Err(e)
+        }
+    }
+}
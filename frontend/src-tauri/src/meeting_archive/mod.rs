@@ -0,0 +1,605 @@
+// meeting_archive/mod.rs
+//
+// Portable meeting archives, to move a meeting between machines or hand it to
+// a colleague. An archive is a zip with a versioned `manifest.json`, the
+// meeting's database content in `meeting.json` (transcript segments with their
+// timings, speaker names, the current summary, every summary version and the
+// chat history) and the files of its meeting folder under `files/`. Importing
+// saves it as a new meeting under new ids, with its files in a new meeting
+// folder.
+
+pub mod commands;
+
+pub use commands::*;
+
+use crate::api::TranscriptSegment;
+use crate::audio::audio_processing::create_meeting_folder;
+use crate::database::models::{ChatMessage, SummaryProcess, SummaryVersion, TranscriptChunk};
+use crate::database::repositories::{
+    chat_message::ChatMessagesRepository, meeting::MeetingsRepository, speaker::SpeakersRepository,
+    summary::SummaryProcessesRepository, summary_version::SummaryVersionsRepository,
+    transcript::TranscriptsRepository, transcript_chunk::TranscriptChunksRepository,
+};
+use crate::summary::templates::{self, Template};
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// Identifies a meeting archive's manifest
+pub const ARCHIVE_FORMAT: &str = "meetily-meeting-archive";
+
+/// Version of the archive layout written by this build. Archives with a newer
+/// version are refused; older ones are read with the fields they have.
+pub const ARCHIVE_VERSION: u32 = 1;
+
+const MANIFEST_ENTRY: &str = "manifest.json";
+const MEETING_ENTRY: &str = "meeting.json";
+const FILES_PREFIX: &str = "files/";
+
+/// What an archive holds, read first on import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub format: String,
+    pub version: u32,
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    /// Id of the meeting in the library it was exported from
+    pub meeting_id: String,
+    pub title: String,
+    /// Meeting folder files under `files/`
+    #[serde(default)]
+    pub files: Vec<ArchivedFile>,
+    /// Templates the summary versions were generated with
+    #[serde(default)]
+    pub templates: Vec<ArchivedTemplate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedFile {
+    pub name: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedTemplate {
+    pub id: String,
+    pub template: Template,
+}
+
+/// The database content of an archived meeting
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ArchivedMeeting {
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub segments: Vec<TranscriptSegment>,
+    /// Speaker ID -> name, for renamed speakers
+    #[serde(default)]
+    pub speaker_names: HashMap<String, String>,
+    #[serde(default)]
+    pub summary: Option<SummaryProcess>,
+    /// Transcript and model the summary was generated from
+    #[serde(default)]
+    pub transcript_chunk: Option<TranscriptChunk>,
+    #[serde(default)]
+    pub summary_versions: Vec<SummaryVersion>,
+    #[serde(default)]
+    pub chat_messages: Vec<ChatMessage>,
+}
+
+/// A meeting imported from an archive
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedMeeting {
+    pub meeting_id: String,
+    pub title: String,
+    pub folder_path: String,
+    /// Templates of the archive that were missing here and were added as custom templates
+    pub installed_templates: Vec<String>,
+}
+
+/// Write a meeting with its folder's files into an archive at `destination`
+pub async fn export_meeting(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    destination: &Path,
+) -> Result<ArchiveManifest, String> {
+    let meeting = MeetingsRepository::get_meeting_model(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| "Meeting not found".to_string())?;
+    let db_error = |e: sqlx::Error| format!("Failed to load meeting: {}", e);
+
+    let archived = ArchivedMeeting {
+        title: meeting.title.clone(),
+        created_at: meeting.created_at.0,
+        segments: TranscriptsRepository::get_segments(pool, meeting_id)
+            .await
+            .map_err(db_error)?,
+        speaker_names: SpeakersRepository::get_speaker_names(pool, meeting_id)
+            .await
+            .map_err(db_error)?,
+        // Only a finished summary is worth carrying over
+        summary: SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+            .await
+            .map_err(db_error)?
+            .filter(|process| process.status == "completed" && process.result.is_some()),
+        transcript_chunk: TranscriptChunksRepository::get_transcript_data(pool, meeting_id)
+            .await
+            .map_err(db_error)?,
+        summary_versions: SummaryVersionsRepository::get_versions(pool, meeting_id)
+            .await
+            .map_err(db_error)?,
+        chat_messages: ChatMessagesRepository::get_messages(pool, meeting_id)
+            .await
+            .map_err(db_error)?,
+    };
+
+    let mut template_ids: Vec<&str> = archived
+        .summary_versions
+        .iter()
+        .filter_map(|version| version.template_id.as_deref())
+        .filter(|id| !id.is_empty())
+        .collect();
+    template_ids.sort_unstable();
+    template_ids.dedup();
+    let templates = template_ids
+        .into_iter()
+        .filter_map(|id| match templates::get_template(id) {
+            Ok(template) => Some(ArchivedTemplate {
+                id: id.to_string(),
+                template,
+            }),
+            Err(e) => {
+                warn!("Template '{}' not included in the archive: {}", id, e);
+                None
+            }
+        })
+        .collect();
+
+    let folder = meeting
+        .folder_path
+        .map(PathBuf::from)
+        .filter(|folder| folder.is_dir());
+    if folder.is_none() {
+        info!(
+            "Meeting {} has no folder on disk, archiving it without files",
+            meeting_id
+        );
+    }
+
+    let manifest = ArchiveManifest {
+        format: ARCHIVE_FORMAT.to_string(),
+        version: ARCHIVE_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: Utc::now(),
+        meeting_id: meeting_id.to_string(),
+        title: meeting.title,
+        files: Vec::new(),
+        templates,
+    };
+
+    let destination = destination.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        write_archive(&destination, manifest, &archived, folder.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Archive export failed: {}", e))?
+}
+
+/// Save the meeting of an archive as a new meeting, with its files in a new
+/// meeting folder under `base_folder`
+pub async fn import_meeting(
+    pool: &SqlitePool,
+    archive: &Path,
+    base_folder: &Path,
+) -> Result<ImportedMeeting, String> {
+    let (manifest, archived) = {
+        let archive = archive.to_path_buf();
+        tokio::task::spawn_blocking(move || read_archive(&archive))
+            .await
+            .map_err(|e| format!("Archive import failed: {}", e))??
+    };
+
+    let folder = create_meeting_folder(&base_folder.to_path_buf(), &archived.title)
+        .map_err(|e| format!("Failed to create meeting folder: {}", e))?;
+    // There are no recording checkpoints in an archive
+    let _ = std::fs::remove_dir(folder.join(".checkpoints"));
+
+    let extracted = {
+        let archive = archive.to_path_buf();
+        let folder = folder.clone();
+        tokio::task::spawn_blocking(move || extract_files(&archive, &folder))
+            .await
+            .map_err(|e| format!("Archive import failed: {}", e))?
+    };
+    if let Err(e) = extracted {
+        let _ = std::fs::remove_dir_all(&folder);
+        return Err(e);
+    }
+
+    let folder_path = folder.to_string_lossy().to_string();
+    let meeting_id = match TranscriptsRepository::save_transcript(
+        pool,
+        &archived.title,
+        &archived.segments,
+        Some(folder_path.clone()),
+    )
+    .await
+    {
+        Ok(meeting_id) => meeting_id,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&folder);
+            return Err(format!("Failed to save meeting: {}", e));
+        }
+    };
+
+    // A meeting left half imported is removed with its folder
+    if let Err(e) = save_meeting_content(pool, &meeting_id, &manifest.meeting_id, &archived).await {
+        if let Err(delete_error) = MeetingsRepository::delete_meeting(pool, &meeting_id).await {
+            warn!(
+                "Failed to remove partially imported meeting {}: {}",
+                meeting_id, delete_error
+            );
+        }
+        let _ = std::fs::remove_dir_all(&folder);
+        return Err(e);
+    }
+
+    if let Err(e) = set_metadata_meeting_id(&folder, &meeting_id) {
+        warn!("Failed to record meeting id in {}: {}", folder.display(), e);
+    }
+
+    Ok(ImportedMeeting {
+        meeting_id,
+        title: archived.title,
+        folder_path,
+        installed_templates: install_missing_templates(&manifest.templates),
+    })
+}
+
+/// Everything but the transcript, which is saved with the meeting
+async fn save_meeting_content(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    archived_meeting_id: &str,
+    archived: &ArchivedMeeting,
+) -> Result<(), String> {
+    let db_error = |e: sqlx::Error| format!("Failed to save meeting: {}", e);
+
+    MeetingsRepository::set_created_at(pool, meeting_id, archived.created_at)
+        .await
+        .map_err(db_error)?;
+
+    for (speaker_id, display_name) in &archived.speaker_names {
+        SpeakersRepository::rename_speaker(pool, meeting_id, speaker_id, display_name)
+            .await
+            .map_err(db_error)?;
+    }
+
+    if let Some(chunk) = &archived.transcript_chunk {
+        TranscriptChunksRepository::save_transcript_data(
+            pool,
+            meeting_id,
+            &chunk.transcript_text,
+            &chunk.model,
+            &chunk.model_name,
+            chunk.chunk_size.unwrap_or_default() as i32,
+            chunk.overlap.unwrap_or_default() as i32,
+        )
+        .await
+        .map_err(db_error)?;
+    }
+
+    if let Some(summary) = &archived.summary {
+        let result = summary
+            .result
+            .as_deref()
+            .map(serde_json::from_str::<serde_json::Value>)
+            .transpose()
+            .map_err(|e| format!("Invalid summary in archive: {}", e))?;
+        if let Some(result) = result {
+            SummaryProcessesRepository::create_or_reset_process(pool, meeting_id)
+                .await
+                .map_err(db_error)?;
+            SummaryProcessesRepository::update_process_completed(
+                pool,
+                meeting_id,
+                result,
+                summary.chunk_count,
+                summary.processing_time,
+            )
+            .await
+            .map_err(db_error)?;
+        }
+    }
+
+    let mut versions: Vec<&SummaryVersion> = archived.summary_versions.iter().collect();
+    versions.sort_by_key(|version| version.version);
+    for version in versions {
+        SummaryVersionsRepository::import_version(pool, meeting_id, version)
+            .await
+            .map_err(db_error)?;
+    }
+
+    for message in &archived.chat_messages {
+        // Citations refer to the meeting by id
+        let message = ChatMessage {
+            metadata: message
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.replace(archived_meeting_id, meeting_id)),
+            ..message.clone()
+        };
+        ChatMessagesRepository::import_message(pool, meeting_id, &message)
+            .await
+            .map_err(db_error)?;
+    }
+
+    Ok(())
+}
+
+/// Add the archive's templates that are not available here as custom templates.
+/// Returns the ids of the added templates.
+fn install_missing_templates(archived_templates: &[ArchivedTemplate]) -> Vec<String> {
+    let mut installed = Vec::new();
+    for archived in archived_templates {
+        if templates::get_template(&archived.id).is_ok() {
+            continue;
+        }
+        match templates::save_custom_template(&archived.id, &archived.template) {
+            Ok(_) => installed.push(archived.id.clone()),
+            Err(e) => warn!("Failed to add template '{}': {}", archived.id, e),
+        }
+    }
+    installed
+}
+
+/// Write the archive. Files of the meeting folder are added flat; hidden ones,
+/// such as recording checkpoints, are left out. Returns the written manifest.
+fn write_archive(
+    destination: &Path,
+    mut manifest: ArchiveManifest,
+    archived: &ArchivedMeeting,
+    folder: Option<&Path>,
+) -> Result<ArchiveManifest, String> {
+    let mut files: Vec<PathBuf> = match folder {
+        Some(folder) => std::fs::read_dir(folder)
+            .map_err(|e| format!("Failed to read meeting folder: {}", e))?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .filter(|path| {
+                path.file_name()
+                    .is_some_and(|name| !name.to_string_lossy().starts_with('.'))
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    files.sort();
+
+    manifest.files = files
+        .iter()
+        .map(|path| ArchivedFile {
+            name: path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string(),
+            size: std::fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        })
+        .collect();
+
+    let write = || -> anyhow::Result<()> {
+        let mut zip = ZipWriter::new(BufWriter::new(File::create(destination)?));
+        let json_options =
+            SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        zip.start_file(MANIFEST_ENTRY, json_options)?;
+        zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+        zip.start_file(MEETING_ENTRY, json_options)?;
+        zip.write_all(serde_json::to_string(archived)?.as_bytes())?;
+
+        for (path, file) in files.iter().zip(&manifest.files) {
+            // Audio is already compressed
+            let options = SimpleFileOptions::default()
+                .compression_method(CompressionMethod::Stored)
+                .large_file(file.size >= u32::MAX as u64);
+            zip.start_file(format!("{}{}", FILES_PREFIX, file.name), options)?;
+            std::io::copy(&mut BufReader::new(File::open(path)?), &mut zip)?;
+        }
+
+        zip.finish()?.flush()?;
+        Ok(())
+    };
+
+    if let Err(e) = write() {
+        let _ = std::fs::remove_file(destination);
+        return Err(format!("Failed to write archive: {}", e));
+    }
+    Ok(manifest)
+}
+
+/// Read the manifest and meeting of an archive, refusing archives written by a
+/// newer version of the app
+fn read_archive(archive: &Path) -> Result<(ArchiveManifest, ArchivedMeeting), String> {
+    let file = File::open(archive).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let mut zip = ZipArchive::new(BufReader::new(file))
+        .map_err(|e| format!("Not a meeting archive: {}", e))?;
+
+    let manifest: ArchiveManifest = read_json_entry(&mut zip, MANIFEST_ENTRY)?;
+    if manifest.format != ARCHIVE_FORMAT {
+        return Err("Not a meeting archive".to_string());
+    }
+    if manifest.version > ARCHIVE_VERSION {
+        return Err(format!(
+            "This archive needs a newer version of the app (archive version {}, supported up to {})",
+            manifest.version, ARCHIVE_VERSION
+        ));
+    }
+
+    let meeting = read_json_entry(&mut zip, MEETING_ENTRY)?;
+    Ok((manifest, meeting))
+}
+
+fn read_json_entry<T: serde::de::DeserializeOwned>(
+    zip: &mut ZipArchive<BufReader<File>>,
+    name: &str,
+) -> Result<T, String> {
+    let mut entry = zip
+        .by_name(name)
+        .map_err(|_| format!("Archive is missing {}", name))?;
+    let mut content = String::new();
+    entry
+        .read_to_string(&mut content)
+        .map_err(|e| format!("Failed to read {}: {}", name, e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid {}: {}", name, e))
+}
+
+/// Extract the archive's meeting folder files into `folder`
+fn extract_files(archive: &Path, folder: &Path) -> Result<(), String> {
+    let file = File::open(archive).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let mut zip = ZipArchive::new(BufReader::new(file))
+        .map_err(|e| format!("Not a meeting archive: {}", e))?;
+
+    for index in 0..zip.len() {
+        let mut entry = zip
+            .by_index(index)
+            .map_err(|e| format!("Failed to read archive: {}", e))?;
+        let Some(name) = entry.name().strip_prefix(FILES_PREFIX).map(str::to_string) else {
+            continue;
+        };
+        // Files are stored flat; anything else could write outside the folder
+        if name.is_empty() || name.contains(['/', '\\']) || name == ".." {
+            warn!("Skipping archive entry {}", entry.name());
+            continue;
+        }
+        let target = folder.join(&name);
+        let mut output = BufWriter::new(
+            File::create(&target).map_err(|e| format!("Failed to extract {}: {}", name, e))?,
+        );
+        std::io::copy(&mut entry, &mut output)
+            .and_then(|_| output.flush())
+            .map_err(|e| format!("Failed to extract {}: {}", name, e))?;
+    }
+    Ok(())
+}
+
+/// Point the folder's `metadata.json` at the imported meeting, so a library
+/// rescan matches them
+fn set_metadata_meeting_id(folder: &Path, meeting_id: &str) -> anyhow::Result<()> {
+    let path = folder.join("metadata.json");
+    if !path.is_file() {
+        return Ok(());
+    }
+    let mut metadata: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+    if let Some(object) = metadata.as_object_mut() {
+        object.insert("meeting_id".to_string(), meeting_id.into());
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(&metadata)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn archived_meeting() -> ArchivedMeeting {
+        ArchivedMeeting {
+            title: "Weekly sync".to_string(),
+            created_at: Utc::now(),
+            segments: vec![TranscriptSegment {
+                id: "transcript-1".to_string(),
+                text: "Hello everyone".to_string(),
+                timestamp: "00:00:01".to_string(),
+                audio_start_time: Some(1.0),
+                audio_end_time: Some(2.5),
+                duration: Some(1.5),
+                words: Vec::new(),
+                speaker: Some("speaker_1".to_string()),
+                source: None,
+                confidence: None,
+            }],
+            speaker_names: HashMap::from([("speaker_1".to_string(), "Ana".to_string())]),
+            summary: None,
+            transcript_chunk: None,
+            summary_versions: Vec::new(),
+            chat_messages: Vec::new(),
+        }
+    }
+
+    fn manifest(version: u32) -> ArchiveManifest {
+        ArchiveManifest {
+            format: ARCHIVE_FORMAT.to_string(),
+            version,
+            app_version: "0.0.0".to_string(),
+            exported_at: Utc::now(),
+            meeting_id: "meeting-1".to_string(),
+            title: "Weekly sync".to_string(),
+            files: Vec::new(),
+            templates: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_archive_round_trip() {
+        let source = tempdir().unwrap();
+        std::fs::write(source.path().join("audio.mp4"), b"audio").unwrap();
+        std::fs::write(
+            source.path().join("metadata.json"),
+            r#"{"meeting_id":"meeting-1"}"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(source.path().join(".checkpoints")).unwrap();
+        std::fs::write(source.path().join(".metadata.json.tmp"), b"{}").unwrap();
+
+        let output = tempdir().unwrap();
+        let archive = output.path().join("meeting.zip");
+        let written = write_archive(
+            &archive,
+            manifest(ARCHIVE_VERSION),
+            &archived_meeting(),
+            Some(source.path()),
+        )
+        .unwrap();
+        let names: Vec<_> = written.files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["audio.mp4", "metadata.json"]);
+
+        let (manifest, meeting) = read_archive(&archive).unwrap();
+        assert_eq!(manifest.meeting_id, "meeting-1");
+        assert_eq!(meeting.segments[0].audio_start_time, Some(1.0));
+        assert_eq!(meeting.speaker_names["speaker_1"], "Ana");
+
+        let folder = tempdir().unwrap();
+        extract_files(&archive, folder.path()).unwrap();
+        assert_eq!(
+            std::fs::read(folder.path().join("audio.mp4")).unwrap(),
+            b"audio"
+        );
+        set_metadata_meeting_id(folder.path(), "meeting-2").unwrap();
+        let metadata = std::fs::read_to_string(folder.path().join("metadata.json")).unwrap();
+        assert!(metadata.contains("meeting-2"));
+    }
+
+    #[test]
+    fn test_newer_archive_is_refused() {
+        let output = tempdir().unwrap();
+        let archive = output.path().join("meeting.zip");
+        write_archive(
+            &archive,
+            manifest(ARCHIVE_VERSION + 1),
+            &archived_meeting(),
+            None,
+        )
+        .unwrap();
+
+        let error = read_archive(&archive).unwrap_err();
+        assert!(error.contains("newer version"), "{}", error);
+    }
+}
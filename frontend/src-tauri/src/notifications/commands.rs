@@ -444,6 +444,20 @@ pub async fn show_transcription_complete_notification(
     }
 }
 
+/// Show transcript refined notification (internal use)
+pub async fn show_transcript_refined_notification<R: Runtime>(
+    manager_state: &NotificationManagerState<R>,
+    meeting_title: Option<String>,
+) -> Result<()> {
+    let manager_lock = manager_state.read().await;
+    if let Some(manager) = manager_lock.as_ref() {
+        manager.show_transcript_refined(meeting_title).await
+    } else {
+        log_error!("Cannot show transcript refined notification: manager not initialized");
+        Ok(())
+    }
+}
+
 /// Show system error notification (internal use)
 pub async fn show_system_error_notification(
     manager_state: &NotificationManagerState<Wry>,
@@ -156,6 +156,17 @@ impl<R: Runtime> NotificationManager<R> {
         self.show_notification(notification).await
     }
 
+    /// Show a notification that a refined transcript replaced the live one
+    pub async fn show_transcript_refined(&self, meeting_title: Option<String>) -> Result<()> {
+        let settings = self.settings.read().await;
+        if !settings.notification_preferences.show_transcription_complete {
+            return Ok(());
+        }
+
+        let notification = Notification::transcript_refined(meeting_title);
+        self.show_notification(notification).await
+    }
+
     /// Show a meeting reminder notification
     pub async fn show_meeting_reminder(&self, minutes_until: u64, meeting_title: Option<String>) -> Result<()> {
         let settings = self.settings.read().await;
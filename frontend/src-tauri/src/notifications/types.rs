@@ -165,6 +165,17 @@ impl Notification {
             .with_timeout(NotificationTimeout::Seconds(5))
     }
 
+    pub fn transcript_refined(meeting_title: Option<String>) -> Self {
+        let body = match meeting_title {
+            Some(title) => format!("The transcript of '{}' has been improved", title),
+            None => "The meeting transcript has been improved".to_string(),
+        };
+
+        Notification::new("Meetily", body, NotificationType::TranscriptionComplete)
+            .with_priority(NotificationPriority::Normal)
+            .with_timeout(NotificationTimeout::Seconds(5))
+    }
+
     pub fn meeting_reminder(minutes_until: u64, meeting_title: Option<String>) -> Self {
         let body = match meeting_title {
             Some(title) => format!("Meeting '{}' starts in {} minutes", title, minutes_until),
@@ -0,0 +1,374 @@
+//! Beam search decoding for Parakeet TDT models, with optional hotword boosting.
+//!
+//! Greedy decoding keeps the single most likely token at every step. Beam search
+//! keeps the `beam_width` best hypotheses instead, so a word that starts out less
+//! likely, such as a product name boosted as a hotword, can still win.
+
+use super::hotwords::{HotwordTrie, ROOT};
+use serde::{Deserialize, Serialize};
+
+/// Widest beam the settings accept; every hypothesis costs a decoder run per step
+pub const MAX_BEAM_WIDTH: usize = 16;
+/// Largest per-token hotword bonus the settings accept, in log-probability
+pub const MAX_HOTWORD_BOOST: f32 = 10.0;
+
+/// Decoded tokens, the encoder frame each was emitted at and its probability
+pub type DecodedTokens = (Vec<i32>, Vec<usize>, Vec<f32>);
+
+/// How transcripts are decoded from the model output
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct DecodingConfig {
+    /// Hypotheses kept at each step; 1 decodes greedily
+    pub beam_width: usize,
+    /// Log-probability bonus for each token of a hotword
+    pub hotword_boost: f32,
+    /// Words and phrases to favour, such as product names and acronyms.
+    /// Only used by beam search.
+    pub hotwords: Vec<String>,
+}
+
+impl Default for DecodingConfig {
+    fn default() -> Self {
+        Self {
+            beam_width: 1, // Greedy decoding is the fastest
+            hotword_boost: 2.0,
+            hotwords: Vec::new(),
+        }
+    }
+}
+
+impl DecodingConfig {
+    pub fn uses_beam_search(&self) -> bool {
+        self.beam_width > 1
+    }
+
+    /// Clamp settings to supported ranges and drop blank or repeated hotwords
+    pub fn normalized(mut self) -> Self {
+        self.beam_width = self.beam_width.clamp(1, MAX_BEAM_WIDTH);
+        self.hotword_boost = if self.hotword_boost.is_finite() {
+            self.hotword_boost.clamp(0.0, MAX_HOTWORD_BOOST)
+        } else {
+            DecodingConfig::default().hotword_boost
+        };
+
+        let mut hotwords: Vec<String> = Vec::new();
+        for hotword in self.hotwords {
+            let hotword = hotword.split_whitespace().collect::<Vec<_>>().join(" ");
+            if !hotword.is_empty() && !hotwords.contains(&hotword) {
+                hotwords.push(hotword);
+            }
+        }
+        self.hotwords = hotwords;
+        self
+    }
+}
+
+#[derive(Clone)]
+struct Hypothesis<S> {
+    tokens: Vec<i32>,
+    timestamps: Vec<usize>,
+    /// Probability of each token when it was emitted
+    probs: Vec<f32>,
+    /// Decoder state to feed together with the last token
+    state: S,
+    /// Sum of token log-probabilities
+    log_prob: f32,
+    /// Hotword bonus so far, including a partial match
+    bonus: f32,
+    /// Position in the hotword trie
+    node: usize,
+    /// Tokens emitted at the current frame
+    emitted: usize,
+}
+
+impl<S> Hypothesis<S> {
+    fn score(&self) -> f32 {
+        self.log_prob + self.bonus
+    }
+}
+
+/// Decode `frames` encoder frames with beam search.
+///
+/// `step` runs the decoder and joint network for a frame, the tokens so far and
+/// a decoder state, returning the vocabulary logits and the state after the last
+/// token. Like greedy decoding, a blank moves a hypothesis on to the next frame,
+/// and at most `max_tokens_per_step` tokens are emitted per frame.
+///
+/// Returns the tokens of the best hypothesis, the frame each was emitted at and
+/// its probability.
+pub fn beam_search<S, E, F>(
+    frames: usize,
+    initial_state: S,
+    blank_idx: i32,
+    beam_width: usize,
+    max_tokens_per_step: usize,
+    hotwords: Option<&HotwordTrie>,
+    mut step: F,
+) -> Result<DecodedTokens, E>
+where
+    S: Clone,
+    F: FnMut(usize, &[i32], &S) -> Result<(Vec<f32>, S), E>,
+{
+    let beam_width = beam_width.max(1);
+    let mut beam = vec![Hypothesis {
+        tokens: Vec::new(),
+        timestamps: Vec::new(),
+        probs: Vec::new(),
+        state: initial_state,
+        log_prob: 0.0,
+        bonus: 0.0,
+        node: ROOT,
+        emitted: 0,
+    }];
+
+    for t in 0..frames {
+        // Hypotheses done with this frame, and ones that may emit another token at it
+        let mut next: Vec<Hypothesis<S>> = Vec::new();
+        let mut active = beam;
+
+        while !active.is_empty() {
+            let mut expanded = Vec::new();
+
+            for hyp in active {
+                let (logits, new_state) = step(t, &hyp.tokens, &hyp.state)?;
+                let log_probs = log_softmax(&logits);
+
+                for token in candidate_tokens(&log_probs, blank_idx, beam_width, hotwords, hyp.node)
+                {
+                    let (node, delta) = match hotwords {
+                        Some(trie) => trie.advance(hyp.node, token),
+                        None => (ROOT, 0.0),
+                    };
+                    let mut tokens = hyp.tokens.clone();
+                    tokens.push(token);
+                    let mut timestamps = hyp.timestamps.clone();
+                    timestamps.push(t);
+                    let mut probs = hyp.probs.clone();
+                    probs.push(log_probs[token as usize].exp());
+
+                    let extended = Hypothesis {
+                        tokens,
+                        timestamps,
+                        probs,
+                        state: new_state.clone(),
+                        log_prob: hyp.log_prob + log_probs[token as usize],
+                        bonus: hyp.bonus + delta,
+                        node,
+                        emitted: hyp.emitted + 1,
+                    };
+                    if extended.emitted == max_tokens_per_step {
+                        push_merged(
+                            &mut next,
+                            Hypothesis {
+                                emitted: 0,
+                                ..extended
+                            },
+                        );
+                    } else {
+                        expanded.push(extended);
+                    }
+                }
+
+                let blank_log_prob = log_probs.get(blank_idx as usize).copied().unwrap_or(0.0);
+                push_merged(
+                    &mut next,
+                    Hypothesis {
+                        log_prob: hyp.log_prob + blank_log_prob,
+                        emitted: 0,
+                        ..hyp
+                    },
+                );
+            }
+
+            // Keep the best hypotheses, whether they are done with the frame or not
+            let mut pool: Vec<(Hypothesis<S>, bool)> = next
+                .into_iter()
+                .map(|hyp| (hyp, false))
+                .chain(expanded.into_iter().map(|hyp| (hyp, true)))
+                .collect();
+            pool.sort_by(|(a, _), (b, _)| b.score().total_cmp(&a.score()));
+            pool.truncate(beam_width);
+
+            let (still_active, done): (Vec<_>, Vec<_>) =
+                pool.into_iter().partition(|(_, active)| *active);
+            next = done.into_iter().map(|(hyp, _)| hyp).collect();
+            active = still_active.into_iter().map(|(hyp, _)| hyp).collect();
+        }
+
+        beam = next;
+    }
+
+    // A hotword left unfinished at the end doesn't count
+    let best = beam
+        .into_iter()
+        .max_by(|a, b| final_score(a, hotwords).total_cmp(&final_score(b, hotwords)));
+
+    Ok(best
+        .map(|hyp| (hyp.tokens, hyp.timestamps, hyp.probs))
+        .unwrap_or_default())
+}
+
+fn final_score<S>(hyp: &Hypothesis<S>, hotwords: Option<&HotwordTrie>) -> f32 {
+    hyp.score() - hotwords.map_or(0.0, |trie| trie.pending_bonus(hyp.node))
+}
+
+/// Hypotheses with the same tokens reached along different frames are one
+/// transcript; keep a single one with the probabilities of both added up
+fn push_merged<S>(hyps: &mut Vec<Hypothesis<S>>, hyp: Hypothesis<S>) {
+    match hyps
+        .iter_mut()
+        .find(|existing| existing.tokens == hyp.tokens)
+    {
+        Some(existing) => {
+            let log_prob = log_add(existing.log_prob, hyp.log_prob);
+            if hyp.log_prob > existing.log_prob {
+                *existing = hyp;
+            }
+            existing.log_prob = log_prob;
+        }
+        None => hyps.push(hyp),
+    }
+}
+
+/// Non-blank tokens worth extending a hypothesis with: the `count` most likely,
+/// plus any token that would continue or start a hotword
+fn candidate_tokens(
+    log_probs: &[f32],
+    blank_idx: i32,
+    count: usize,
+    hotwords: Option<&HotwordTrie>,
+    node: usize,
+) -> Vec<i32> {
+    let mut best: Vec<(i32, f32)> = Vec::with_capacity(count + 1);
+    for (token, &log_prob) in log_probs.iter().enumerate() {
+        let token = token as i32;
+        if token == blank_idx {
+            continue;
+        }
+        if best.len() == count && best[count - 1].1 >= log_prob {
+            continue;
+        }
+        let position = best.partition_point(|&(_, p)| p >= log_prob);
+        best.insert(position, (token, log_prob));
+        best.truncate(count);
+    }
+
+    let mut tokens: Vec<i32> = best.into_iter().map(|(token, _)| token).collect();
+    if let Some(trie) = hotwords {
+        for token in trie.boosted_tokens(node) {
+            if token != blank_idx && (token as usize) < log_probs.len() && !tokens.contains(&token)
+            {
+                tokens.push(token);
+            }
+        }
+    }
+    tokens
+}
+
+fn log_softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let log_sum = logits.iter().map(|&x| (x - max).exp()).sum::<f32>().ln() + max;
+    logits.iter().map(|&x| x - log_sum).collect()
+}
+
+fn log_add(a: f32, b: f32) -> f32 {
+    let max = a.max(b);
+    if max == f32::NEG_INFINITY {
+        return max;
+    }
+    max + ((a - max).exp() + (b - max).exp()).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    const BLANK: i32 = 0;
+
+    /// A decoder whose logits depend only on the frame and the previous token
+    fn decode(
+        table: &[Vec<(i32, Vec<f32>)>],
+        hotwords: Option<&HotwordTrie>,
+        beam_width: usize,
+    ) -> Vec<i32> {
+        let (tokens, timestamps, probs) = beam_search(
+            table.len(),
+            (),
+            BLANK,
+            beam_width,
+            10,
+            hotwords,
+            |t, tokens: &[i32], _: &()| {
+                let last = tokens.last().copied().unwrap_or(BLANK);
+                let logits = table[t]
+                    .iter()
+                    .find(|(prev, _)| *prev == last)
+                    .or_else(|| table[t].iter().find(|(prev, _)| *prev == -1))
+                    .map(|(_, logits)| logits.clone())
+                    .unwrap();
+                Ok::<_, Infallible>((logits, ()))
+            },
+        )
+        .unwrap();
+        assert_eq!(tokens.len(), timestamps.len());
+        assert_eq!(tokens.len(), probs.len());
+        assert!(probs.iter().all(|&p| p > 0.0 && p <= 1.0));
+        tokens
+    }
+
+    #[test]
+    fn test_beam_search_with_hotwords() {
+        // Tokens: 0 blank, 1 " A", 2 "c", 3 "me", 4 " a", 5 "k"
+        let vocab: Vec<String> = ["<blk>", " A", "c", "me", " a", "k"]
+            .iter()
+            .map(|token| token.to_string())
+            .collect();
+        let blank_after = |token: i32| (token, vec![5.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        // " a" is a bit more likely than " A" at frame 0, then either spells on
+        let table = vec![
+            vec![(BLANK, vec![0.0, 2.0, 0.0, 0.0, 2.5, 0.0]), blank_after(-1)],
+            vec![
+                (1, vec![0.0, 0.0, 3.0, 0.0, 0.0, 0.0]),
+                (4, vec![0.0, 0.0, 3.0, 0.0, 0.0, 0.0]),
+                blank_after(-1),
+            ],
+            vec![(2, vec![0.0, 0.0, 0.0, 3.0, 0.0, 2.8]), blank_after(-1)],
+            vec![blank_after(-1)],
+        ];
+
+        // Without hotwords, beam search agrees with greedy decoding
+        assert_eq!(decode(&table, None, 1), vec![4, 2, 3]);
+        assert_eq!(decode(&table, None, 4), vec![4, 2, 3]);
+
+        // The hotword tips the first frame towards " A"
+        let trie = HotwordTrie::new(&["Acme".to_string()], &vocab, 1.0);
+        assert_eq!(decode(&table, Some(&trie), 4), vec![1, 2, 3]);
+
+        // A hotword that isn't said doesn't change the transcript
+        let trie = HotwordTrie::new(&["Ak".to_string()], &vocab, 1.0);
+        assert_eq!(decode(&table, Some(&trie), 4), vec![4, 2, 3]);
+    }
+
+    #[test]
+    fn test_decoding_config_normalized() {
+        let config = DecodingConfig {
+            beam_width: 64,
+            hotword_boost: f32::NAN,
+            hotwords: vec![
+                " Acme  Cloud ".to_string(),
+                "".to_string(),
+                "Acme Cloud".to_string(),
+            ],
+        }
+        .normalized();
+        assert_eq!(config.beam_width, MAX_BEAM_WIDTH);
+        assert_eq!(
+            config.hotword_boost,
+            DecodingConfig::default().hotword_boost
+        );
+        assert_eq!(config.hotwords, vec!["Acme Cloud".to_string()]);
+        assert!(!DecodingConfig::default().uses_beam_search());
+    }
+}
@@ -1,8 +1,9 @@
-use crate::parakeet_engine::{ModelInfo, ParakeetEngine};
+use crate::parakeet_engine::{DecodingConfig, ModelInfo, ParakeetEngine};
 use std::path::PathBuf;
 use std::sync::Mutex;
 use std::sync::Arc;
 use tauri::{command, Emitter, AppHandle, Manager, Runtime};
+use tauri_plugin_store::StoreExt;
 
 // Global parakeet engine
 pub static PARAKEET_ENGINE: Mutex<Option<Arc<ParakeetEngine>>> = Mutex::new(None);
@@ -10,6 +11,10 @@ pub static PARAKEET_ENGINE: Mutex<Option<Arc<ParakeetEngine>>> = Mutex::new(None
 // Global models directory path (set during app initialization)
 static MODELS_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
 
+/// Store and key of the decoding settings (beam width, hotword boost and hotwords)
+const PREFERENCES_STORE: &str = "preferences.json";
+const DECODING_PREFERENCE_KEY: &str = "parakeet_decoding";
+
 /// Initialize the models directory path using app_data_dir
 /// This should be called during app setup before parakeet_init
 pub fn set_models_directory<R: Runtime>(app: &AppHandle<R>) {
@@ -33,7 +38,7 @@ pub fn set_models_directory<R: Runtime>(app: &AppHandle<R>) {
 }
 
 /// Get the configured models directory
-fn get_models_directory() -> Option<PathBuf> {
+pub(crate) fn get_models_directory() -> Option<PathBuf> {
     MODELS_DIR.lock().unwrap().clone()
 }
 
@@ -360,6 +365,92 @@ pub async fn parakeet_transcribe_audio(audio_data: Vec<f32>) -> Result<String, S
     }
 }
 
+/// The saved decoding settings, or greedy decoding if none were saved
+pub fn load_decoding_config<R: Runtime>(app: &AppHandle<R>) -> DecodingConfig {
+    app.store(PREFERENCES_STORE)
+        .ok()
+        .and_then(|store| store.get(DECODING_PREFERENCE_KEY))
+        .and_then(|value| serde_json::from_value::<DecodingConfig>(value).ok())
+        .map(DecodingConfig::normalized)
+        .unwrap_or_default()
+}
+
+/// A Parakeet engine separate from the live one with `model` loaded, for
+/// transcribing recordings without changing the model the next recording uses.
+/// It decodes with the user's decoding settings, like the live engine.
+pub async fn standalone_engine<R: Runtime>(
+    app: &AppHandle<R>,
+    model: &str,
+) -> Result<ParakeetEngine, String> {
+    let engine = ParakeetEngine::new_with_models_dir(get_models_directory())
+        .map_err(|e| format!("Failed to initialize Parakeet engine: {}", e))?;
+    engine.set_decoding_config(load_decoding_config(app)).await;
+    engine
+        .discover_models()
+        .await
+        .map_err(|e| format!("Failed to discover Parakeet models: {}", e))?;
+    engine
+        .load_model(model)
+        .await
+        .map_err(|e| format!("Failed to load Parakeet model {}: {}", model, e))?;
+    Ok(engine)
+}
+
+/// Apply the saved decoding settings to the engine, called on startup
+pub async fn apply_saved_decoding_config<R: Runtime>(app: &AppHandle<R>) {
+    let engine = {
+        let guard = PARAKEET_ENGINE.lock().unwrap();
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine.set_decoding_config(load_decoding_config(app)).await;
+    }
+}
+
+#[command]
+pub async fn parakeet_get_decoding_config<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<DecodingConfig, String> {
+    Ok(load_decoding_config(&app))
+}
+
+/// Save the decoding settings and apply them to the engine.
+/// Returns the settings as saved, clamped to supported ranges.
+#[command]
+pub async fn parakeet_set_decoding_config<R: Runtime>(
+    app: AppHandle<R>,
+    config: DecodingConfig,
+) -> Result<DecodingConfig, String> {
+    let config = config.normalized();
+
+    let store = app
+        .store(PREFERENCES_STORE)
+        .map_err(|e| format!("Failed to open preferences: {}", e))?;
+    let value = serde_json::to_value(&config)
+        .map_err(|e| format!("Failed to serialize decoding settings: {}", e))?;
+    store.set(DECODING_PREFERENCE_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save decoding settings: {}", e))?;
+
+    let engine = {
+        let guard = PARAKEET_ENGINE.lock().unwrap();
+        guard.as_ref().cloned()
+    };
+    if let Some(engine) = engine {
+        engine.set_decoding_config(config.clone()).await;
+    }
+
+    log::info!(
+        "Saved Parakeet decoding settings: beam width {}, boost {}, {} hotwords",
+        config.beam_width,
+        config.hotword_boost,
+        config.hotwords.len()
+    );
+    Ok(config)
+}
+
 #[command]
 pub async fn parakeet_get_models_directory() -> Result<String, String> {
     let engine = {
@@ -0,0 +1,229 @@
+//! Hotword boosting for beam search decoding.
+//!
+//! Hotwords are split into vocabulary tokens and stored in a prefix trie. Every
+//! token a hypothesis emits along a trie path earns a bonus; if the path breaks
+//! off before a hotword is complete, the bonus it earned so far is taken back.
+
+use std::collections::HashMap;
+
+/// Trie node a hypothesis sits at when it is not inside a hotword
+pub const ROOT: usize = 0;
+
+struct TrieNode {
+    children: HashMap<i32, usize>,
+    /// Tokens from the root to this node
+    depth: usize,
+    /// Depth of the deepest complete hotword on the path to this node, 0 if none
+    matched_depth: usize,
+}
+
+impl TrieNode {
+    fn new(depth: usize, matched_depth: usize) -> Self {
+        Self {
+            children: HashMap::new(),
+            depth,
+            matched_depth,
+        }
+    }
+}
+
+/// Prefix trie of hotwords over vocabulary token ids
+pub struct HotwordTrie {
+    nodes: Vec<TrieNode>,
+    boost: f32,
+    /// Hotwords that could be split into vocabulary tokens
+    words: usize,
+}
+
+impl HotwordTrie {
+    /// Build the trie from hotwords, splitting each into the longest matching
+    /// vocabulary tokens. `vocab` uses a leading space where SentencePiece has `▁`.
+    /// Hotwords with characters the vocabulary can't spell are skipped.
+    pub fn new(hotwords: &[String], vocab: &[String], boost: f32) -> Self {
+        let index: HashMap<&str, i32> = vocab
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| !token.is_empty() && !is_special_token(token))
+            .map(|(id, token)| (token.as_str(), id as i32))
+            .collect();
+        let longest_token = index
+            .keys()
+            .map(|token| token.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut trie = Self {
+            nodes: vec![TrieNode::new(0, 0)],
+            boost,
+            words: 0,
+        };
+
+        for hotword in hotwords {
+            match tokenize(hotword, &index, longest_token) {
+                Some(tokens) if !tokens.is_empty() => {
+                    trie.insert(&tokens);
+                    trie.words += 1;
+                }
+                _ => log::warn!(
+                    "Hotword '{}' can't be spelled with the model vocabulary, ignoring it",
+                    hotword
+                ),
+            }
+        }
+
+        trie
+    }
+
+    fn insert(&mut self, tokens: &[i32]) {
+        let mut node = ROOT;
+        for &token in tokens {
+            node = match self.nodes[node].children.get(&token) {
+                Some(&child) => child,
+                None => {
+                    let parent = &self.nodes[node];
+                    let child = TrieNode::new(parent.depth + 1, parent.matched_depth);
+                    let id = self.nodes.len();
+                    self.nodes.push(child);
+                    self.nodes[node].children.insert(token, id);
+                    id
+                }
+            };
+        }
+
+        // Longer hotwords sharing this prefix keep the bonus for it
+        let depth = self.nodes[node].depth;
+        let mut pending = vec![node];
+        while let Some(id) = pending.pop() {
+            let child = &mut self.nodes[id];
+            child.matched_depth = child.matched_depth.max(depth);
+            pending.extend(child.children.values().copied());
+        }
+    }
+
+    /// Number of hotwords in the trie
+    pub fn len(&self) -> usize {
+        self.words
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words == 0
+    }
+
+    /// Follow `token` from `node`. Returns the next node and the change in bonus:
+    /// the boost for a token that continues or starts a hotword, less the bonus
+    /// of a partial match that broke off.
+    pub fn advance(&self, node: usize, token: i32) -> (usize, f32) {
+        if let Some(&next) = self.nodes[node].children.get(&token) {
+            return (self.settle(next), self.boost);
+        }
+
+        let refund = -self.pending_bonus(node);
+        match self.nodes[ROOT].children.get(&token) {
+            Some(&next) => (self.settle(next), refund + self.boost),
+            None => (ROOT, refund),
+        }
+    }
+
+    /// Bonus earned by the partial match at `node`, taken back if it breaks off
+    pub fn pending_bonus(&self, node: usize) -> f32 {
+        let node = &self.nodes[node];
+        (node.depth - node.matched_depth) as f32 * self.boost
+    }
+
+    /// Tokens that would earn a bonus from `node`: ones continuing the current
+    /// match and ones starting a new hotword
+    pub fn boosted_tokens(&self, node: usize) -> impl Iterator<Item = i32> + '_ {
+        let continuing = if node == ROOT {
+            None
+        } else {
+            Some(self.nodes[node].children.keys())
+        };
+        continuing
+            .into_iter()
+            .flatten()
+            .chain(self.nodes[ROOT].children.keys())
+            .copied()
+    }
+
+    /// Start over at the root once a hotword is complete and no longer one extends it
+    fn settle(&self, node: usize) -> usize {
+        if self.nodes[node].children.is_empty() {
+            ROOT
+        } else {
+            node
+        }
+    }
+}
+
+/// Control tokens such as `<blk>` and `<unk>` never spell out a hotword
+fn is_special_token(token: &str) -> bool {
+    token.len() > 2 && token.starts_with('<') && token.ends_with('>')
+}
+
+/// Split a hotword into vocabulary tokens, taking the longest match at each
+/// position. Each word starts with a space, like words in the vocabulary.
+fn tokenize(hotword: &str, index: &HashMap<&str, i32>, longest_token: usize) -> Option<Vec<i32>> {
+    let text: String = hotword
+        .split_whitespace()
+        .map(|word| format!(" {}", word))
+        .collect();
+    let chars: Vec<char> = text.chars().collect();
+
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let end = (start + longest_token).min(chars.len());
+        let (token, len) = (start + 1..=end).rev().find_map(|stop| {
+            let piece: String = chars[start..stop].iter().collect();
+            index.get(piece.as_str()).map(|&id| (id, stop - start))
+        })?;
+        tokens.push(token);
+        start += len;
+    }
+
+    Some(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocab() -> Vec<String> {
+        ["<blk>", " ", " A", "c", "me", " Cl", "oud", " the", "<unk>"]
+            .iter()
+            .map(|token| token.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_hotword_trie_boosts_and_refunds() {
+        let trie = HotwordTrie::new(
+            &[
+                "Acme".to_string(),
+                "Acme Cloud".to_string(),
+                "<unk>".to_string(),
+            ],
+            &vocab(),
+            1.0,
+        );
+        assert_eq!(trie.len(), 2);
+
+        // " A" "c" "me" completes "Acme"; " Cl" "oud" extends it to "Acme Cloud"
+        let (node, bonus) = trie.advance(ROOT, 2);
+        assert_eq!(bonus, 1.0);
+        let (node, _) = trie.advance(node, 3);
+        let (node, _) = trie.advance(node, 4);
+        assert_eq!(trie.pending_bonus(node), 0.0);
+        let (node, _) = trie.advance(node, 5);
+        assert_eq!(trie.pending_bonus(node), 1.0);
+        let (node, bonus) = trie.advance(node, 6);
+        assert_eq!((node, bonus), (ROOT, 1.0));
+
+        // Breaking off after " A" "c" takes back both bonuses
+        let (node, _) = trie.advance(ROOT, 2);
+        let (node, _) = trie.advance(node, 3);
+        assert!(trie.boosted_tokens(node).any(|token| token == 4));
+        let (node, bonus) = trie.advance(node, 7);
+        assert_eq!((node, bonus), (ROOT, -2.0));
+    }
+}
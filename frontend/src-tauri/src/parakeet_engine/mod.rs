@@ -15,12 +15,17 @@
 //!
 //! - `parakeet_engine`: Main engine implementation
 //! - `model`: ONNX model wrapper and inference logic
+//! - `beam_search`: Optional beam search decoding with hotword boosting
+//! - `hotwords`: Prefix trie of hotwords over vocabulary tokens
 //! - `commands`: Tauri command interface for frontend integration
 
 pub mod parakeet_engine;
 pub mod model;
+pub mod beam_search;
+pub mod hotwords;
 pub mod commands;
 
 pub use parakeet_engine::{ParakeetEngine, ParakeetEngineError, QuantizationType, ModelInfo, ModelStatus};
-pub use model::{ParakeetModel, ParakeetError, TimestampedResult};
+pub use beam_search::DecodingConfig;
+pub use model::{ParakeetModel, ParakeetError, TimestampedResult, TimestampedWord, MAX_BATCH_SIZE};
 pub use commands::*;
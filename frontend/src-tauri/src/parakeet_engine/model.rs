@@ -9,6 +9,10 @@ use regex::Regex;
 
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
+
+use super::beam_search::{beam_search, DecodedTokens, DecodingConfig};
+use super::hotwords::HotwordTrie;
 
 pub type DecoderState = (Array3<f32>, Array3<f32>);
 
@@ -16,6 +20,13 @@ const SUBSAMPLING_FACTOR: usize = 8;
 const WINDOW_SIZE: f32 = 0.01;
 const MAX_TOKENS_PER_STEP: usize = 10;
 
+/// Most utterances encoded together in one batch
+pub const MAX_BATCH_SIZE: usize = 8;
+/// Utterances are only batched with others at most this much longer, so padding stays cheap
+const MAX_BATCH_LENGTH_RATIO: f32 = 1.5;
+/// Most padded samples in one batch (2 minutes at 16kHz)
+const MAX_BATCH_SAMPLES: usize = 120 * 16000;
+
 static DECODE_SPACE_RE: Lazy<Result<Regex, regex::Error>> =
     Lazy::new(|| Regex::new(r"\A\s|\s\B|(\s)\b"));
 
@@ -24,6 +35,58 @@ pub struct TimestampedResult {
     pub text: String,
     pub timestamps: Vec<f32>,
     pub tokens: Vec<String>,
+    /// Softmax probability of each token when it was emitted
+    pub confidences: Vec<f32>,
+}
+
+/// A word assembled from consecutive sub-word tokens, with times in seconds
+/// relative to the start of the transcribed audio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimestampedWord {
+    pub text: String,
+    pub start: f32,
+    pub end: f32,
+    /// Probability of the least likely token of the word
+    pub confidence: f32,
+}
+
+impl TimestampedResult {
+    /// Group sub-word tokens into words.
+    ///
+    /// SentencePiece tokens that start with a space (the `▁` marker, already
+    /// replaced when the vocab is loaded) begin a new word; other tokens are
+    /// appended to the current word. A word ends one encoder frame after its
+    /// last token, and is only as confident as its least likely token.
+    pub fn words(&self) -> Vec<TimestampedWord> {
+        let frame_duration = WINDOW_SIZE * SUBSAMPLING_FACTOR as f32;
+        let mut words: Vec<TimestampedWord> = Vec::new();
+
+        for (index, (token, &time)) in self.tokens.iter().zip(self.timestamps.iter()).enumerate() {
+            let starts_word = token.starts_with(char::is_whitespace);
+            let piece = token.trim();
+            let confidence = self.confidences.get(index).copied().unwrap_or(1.0);
+
+            if piece.is_empty() {
+                continue;
+            }
+
+            match words.last_mut() {
+                Some(word) if !starts_word => {
+                    word.text.push_str(piece);
+                    word.end = time + frame_duration;
+                    word.confidence = word.confidence.min(confidence);
+                }
+                _ => words.push(TimestampedWord {
+                    text: piece.to_string(),
+                    start: time,
+                    end: time + frame_duration,
+                    confidence,
+                }),
+            }
+        }
+
+        words
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -49,6 +112,10 @@ pub struct ParakeetModel {
     vocab: Vec<String>,
     blank_idx: i32,
     vocab_size: usize,
+    /// Hypotheses kept while decoding; 1 decodes greedily
+    beam_width: usize,
+    /// Hotwords boosted by beam search, built over `vocab`
+    hotwords: Option<Arc<HotwordTrie>>,
 }
 
 impl Drop for ParakeetModel {
@@ -79,9 +146,34 @@ impl ParakeetModel {
             vocab,
             blank_idx,
             vocab_size,
+            beam_width: 1,
+            hotwords: None,
         })
     }
 
+    /// Switch between greedy and beam search decoding, and set the hotwords beam search boosts
+    pub fn set_decoding_config(&mut self, config: &DecodingConfig) {
+        self.beam_width = config.beam_width.max(1);
+        self.hotwords = None;
+
+        if config.uses_beam_search() && !config.hotwords.is_empty() {
+            let trie = HotwordTrie::new(&config.hotwords, &self.vocab, config.hotword_boost);
+            if !trie.is_empty() {
+                self.hotwords = Some(Arc::new(trie));
+            }
+        }
+
+        log::info!(
+            "Parakeet decoding: {}, {} hotwords",
+            if self.beam_width > 1 {
+                format!("beam search (width {})", self.beam_width)
+            } else {
+                "greedy".to_string()
+            },
+            self.hotwords.as_ref().map_or(0, |trie| trie.len())
+        );
+    }
+
     fn init_session<P: AsRef<Path>>(
         model_dir: P,
         model_name: &str,
@@ -331,9 +423,12 @@ impl ParakeetModel {
         // Decode for each batch item
         let mut results = Vec::new();
         for (encodings, &encodings_len) in encoder_out.outer_iter().zip(encoder_out_lens.iter()) {
-            let (tokens, timestamps) =
-                self.decode_sequence(&encodings.view(), encodings_len as usize)?;
-            let result = self.decode_tokens(tokens, timestamps);
+            let (tokens, timestamps, confidences) = if self.beam_width > 1 {
+                self.beam_search_sequence(&encodings.view(), encodings_len as usize)?
+            } else {
+                self.decode_sequence(&encodings.view(), encodings_len as usize)?
+            };
+            let result = self.decode_tokens(tokens, timestamps, confidences);
             results.push(result);
         }
 
@@ -344,10 +439,11 @@ impl ParakeetModel {
         &mut self,
         encodings: &ArrayViewD<f32>, // [time_steps, 1024]
         encodings_len: usize,
-    ) -> Result<(Vec<i32>, Vec<usize>), ParakeetError> {
+    ) -> Result<DecodedTokens, ParakeetError> {
         let mut prev_state = self.create_decoder_state()?;
         let mut tokens = Vec::new();
         let mut timestamps = Vec::new();
+        let mut confidences = Vec::new();
 
         let mut t = 0;
         let mut emitted_tokens = 0;
@@ -359,27 +455,7 @@ impl ParakeetModel {
             let (probs, new_state) =
                 self.decode_step(&tokens, &prev_state, &encoder_step_dyn.view())?;
 
-            // For TDT models, split output into vocab logits and duration logits
-            // output[:vocab_size] = vocabulary logits
-            // output[vocab_size:] = duration logits
-            let vocab_logits_slice = probs.as_slice().ok_or_else(|| {
-                ParakeetError::Shape(ndarray::ShapeError::from_kind(
-                    ndarray::ErrorKind::IncompatibleShape,
-                ))
-            })?;
-
-            let vocab_logits = if probs.len() > self.vocab_size {
-                // TDT model - extract only vocabulary logits
-                log::trace!(
-                    "TDT model detected: splitting {} logits into vocab({}) + duration",
-                    probs.len(),
-                    self.vocab_size
-                );
-                &vocab_logits_slice[..self.vocab_size]
-            } else {
-                // Regular RNN-T model
-                vocab_logits_slice
-            };
+            let vocab_logits = Self::vocab_logits(&probs, self.vocab_size)?;
 
             // Get argmax token from vocabulary logits only
             let token = vocab_logits
@@ -390,6 +466,7 @@ impl ParakeetModel {
                 .unwrap_or(self.blank_idx);
 
             if token != self.blank_idx {
+                confidences.push(token_probability(vocab_logits, token as usize));
                 prev_state = new_state;
                 tokens.push(token);
                 timestamps.push(t);
@@ -411,21 +488,88 @@ impl ParakeetModel {
             );
         }
 
-        Ok((tokens, timestamps))
+        Ok((tokens, timestamps, confidences))
+    }
+
+    /// Decode with beam search, boosting hotwords if there are any.
+    /// Slower than greedy decoding by roughly the beam width.
+    fn beam_search_sequence(
+        &mut self,
+        encodings: &ArrayViewD<f32>, // [time_steps, 1024]
+        encodings_len: usize,
+    ) -> Result<DecodedTokens, ParakeetError> {
+        let initial_state = self.create_decoder_state()?;
+        let hotwords = self.hotwords.clone();
+        let (blank_idx, beam_width, vocab_size) = (self.blank_idx, self.beam_width, self.vocab_size);
+
+        let (tokens, timestamps, confidences) = beam_search(
+            encodings_len,
+            initial_state,
+            blank_idx,
+            beam_width,
+            MAX_TOKENS_PER_STEP,
+            hotwords.as_deref(),
+            |t, tokens, state| {
+                let encoder_step = encodings.slice(ndarray::s![t, ..]).to_owned().into_dyn();
+                let (probs, new_state) = self.decode_step(tokens, state, &encoder_step.view())?;
+                let logits = Self::vocab_logits(&probs, vocab_size)?.to_vec();
+                Ok::<_, ParakeetError>((logits, new_state))
+            },
+        )?;
+
+        if tokens.is_empty() {
+            log::debug!(
+                "Parakeet beam search decoded zero tokens for audio with {} encoding timesteps",
+                encodings_len
+            );
+        }
+
+        Ok((tokens, timestamps, confidences))
+    }
+
+    /// The vocabulary logits of a decoder output
+    fn vocab_logits(probs: &ArrayD<f32>, vocab_size: usize) -> Result<&[f32], ParakeetError> {
+        // For TDT models, split output into vocab logits and duration logits
+        // output[:vocab_size] = vocabulary logits
+        // output[vocab_size:] = duration logits
+        let vocab_logits_slice = probs.as_slice().ok_or_else(|| {
+            ParakeetError::Shape(ndarray::ShapeError::from_kind(
+                ndarray::ErrorKind::IncompatibleShape,
+            ))
+        })?;
+
+        if probs.len() > vocab_size {
+            // TDT model - extract only vocabulary logits
+            log::trace!(
+                "TDT model detected: splitting {} logits into vocab({}) + duration",
+                probs.len(),
+                vocab_size
+            );
+            Ok(&vocab_logits_slice[..vocab_size])
+        } else {
+            // Regular RNN-T model
+            Ok(vocab_logits_slice)
+        }
     }
 
-    fn decode_tokens(&self, ids: Vec<i32>, timestamps: Vec<usize>) -> TimestampedResult {
-        let tokens: Vec<String> = ids
+    fn decode_tokens(
+        &self,
+        ids: Vec<i32>,
+        timestamps: Vec<usize>,
+        confidences: Vec<f32>,
+    ) -> TimestampedResult {
+        let (tokens, confidences): (Vec<String>, Vec<f32>) = ids
             .iter()
-            .filter_map(|&id| {
+            .zip(confidences)
+            .filter_map(|(&id, confidence)| {
                 let idx = id as usize;
                 if idx < self.vocab.len() {
-                    Some(self.vocab[idx].clone())
+                    Some((self.vocab[idx].clone(), confidence))
                 } else {
                     None
                 }
             })
-            .collect();
+            .unzip();
 
         let text = match &*DECODE_SPACE_RE {
             Ok(regex) => regex
@@ -449,9 +593,49 @@ impl ParakeetModel {
             text,
             timestamps: float_timestamps,
             tokens,
+            confidences,
         }
     }
 
+    /// Transcribe several utterances, batching those of similar length into one
+    /// run of the preprocessor and encoder each. Results are in input order.
+    pub fn transcribe_batch(
+        &mut self,
+        utterances: Vec<Vec<f32>>,
+    ) -> Result<Vec<TimestampedResult>, ParakeetError> {
+        let lengths: Vec<usize> = utterances.iter().map(Vec::len).collect();
+        let mut results: Vec<Option<TimestampedResult>> = vec![None; utterances.len()];
+
+        for group in batch_groups(&lengths) {
+            let longest = group.iter().map(|&index| lengths[index]).max().unwrap_or(0);
+
+            // Pad with silence to the longest; the lengths keep the padding out of the results
+            let mut waveforms = Array2::<f32>::zeros((group.len(), longest));
+            for (row, &index) in group.iter().enumerate() {
+                waveforms
+                    .row_mut(row)
+                    .slice_mut(ndarray::s![..lengths[index]])
+                    .assign(&ndarray::aview1(&utterances[index]));
+            }
+            let waveforms_lens =
+                Array1::from_iter(group.iter().map(|&index| lengths[index] as i64)).into_dyn();
+
+            let batch_results =
+                self.recognize_batch(&waveforms.into_dyn().view(), &waveforms_lens.view())?;
+            for (&index, result) in group.iter().zip(batch_results) {
+                results[index] = Some(result);
+            }
+        }
+
+        // Empty utterances are not run at all
+        Ok(results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| self.decode_tokens(Vec::new(), Vec::new(), Vec::new()))
+            })
+            .collect())
+    }
+
     pub fn transcribe_samples(
         &mut self,
         samples: Vec<f32>,
@@ -479,3 +663,150 @@ impl ParakeetModel {
         Ok(timestamped_result)
     }
 }
+
+/// Group utterances of these lengths (in samples) into batches, by index.
+/// Utterances are batched with others of similar length; empty ones are left out.
+fn batch_groups(lengths: &[usize]) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..lengths.len()).filter(|&index| lengths[index] > 0).collect();
+    order.sort_by_key(|&index| lengths[index]);
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut shortest = 0;
+    for index in order {
+        let len = lengths[index];
+        match groups.last_mut() {
+            // Sorted by length, so `len` is the longest of the group
+            Some(group)
+                if group.len() < MAX_BATCH_SIZE
+                    && len as f32 <= shortest as f32 * MAX_BATCH_LENGTH_RATIO
+                    && len * (group.len() + 1) <= MAX_BATCH_SAMPLES =>
+            {
+                group.push(index);
+            }
+            _ => {
+                groups.push(vec![index]);
+                shortest = len;
+            }
+        }
+    }
+    groups
+}
+
+/// Softmax probability of `token` among the vocabulary logits
+fn token_probability(vocab_logits: &[f32], token: usize) -> f32 {
+    let max = vocab_logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let sum: f32 = vocab_logits.iter().map(|&logit| (logit - max).exp()).sum();
+    vocab_logits
+        .get(token)
+        .map_or(0.0, |&logit| (logit - max).exp() / sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words_groups_subword_tokens() {
+        let result = TimestampedResult {
+            text: "Hello world.".to_string(),
+            timestamps: vec![0.0, 0.08, 0.4, 0.48, 0.56],
+            tokens: vec![
+                " Hel".to_string(),
+                "lo".to_string(),
+                " wor".to_string(),
+                "ld".to_string(),
+                ".".to_string(),
+            ],
+            confidences: vec![0.9, 0.6, 0.99, 0.95, 0.97],
+        };
+
+        let words = result.words();
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].text, "Hello");
+        assert!((words[0].start - 0.0).abs() < 1e-6);
+        assert!((words[0].end - 0.16).abs() < 1e-6);
+        assert!((words[0].confidence - 0.6).abs() < 1e-6);
+        assert_eq!(words[1].text, "world.");
+        assert!((words[1].start - 0.4).abs() < 1e-6);
+        assert!((words[1].end - 0.64).abs() < 1e-6);
+        assert!((words[1].confidence - 0.95).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_token_probability() {
+        let logits = [0.0, (3.0f32).ln(), f32::NEG_INFINITY];
+        assert!((token_probability(&logits, 1) - 0.75).abs() < 1e-6);
+        assert_eq!(token_probability(&logits, 2), 0.0);
+        assert_eq!(token_probability(&logits, 5), 0.0);
+    }
+
+    #[test]
+    fn test_batch_groups() {
+        let second = 16000;
+        let lengths = [4 * second, 0, 10 * second, 5 * second, 3 * second, 11 * second];
+        assert_eq!(batch_groups(&lengths), vec![vec![4, 0], vec![3], vec![2, 5]]);
+
+        // Full batches start a new one
+        let same = vec![second; MAX_BATCH_SIZE + 1];
+        let groups = batch_groups(&same);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].len(), MAX_BATCH_SIZE);
+
+        // As do batches over the sample limit
+        let long = vec![MAX_BATCH_SAMPLES / 2 + 1; 2];
+        assert_eq!(batch_groups(&long).len(), 2);
+    }
+
+    #[test]
+    #[ignore] // Only run manually: needs a Parakeet model in PARAKEET_MODEL_DIR
+    fn test_batched_throughput() {
+        let model_dir = std::env::var("PARAKEET_MODEL_DIR").expect("PARAKEET_MODEL_DIR not set");
+        let mut model = ParakeetModel::new(&model_dir, model_dir.contains("int8")).unwrap();
+
+        // A backed-up queue of VAD segments, 3 to 12s long
+        let segments: Vec<Vec<f32>> = (0..24)
+            .map(|i| {
+                let len = (3 + i % 10) * 16000;
+                (0..len).map(|n| (n as f32 * 0.03).sin() * 0.1 * ((n / 4000) % 2) as f32).collect()
+            })
+            .collect();
+        let audio_seconds = segments.iter().map(Vec::len).sum::<usize>() as f64 / 16000.0;
+
+        let started = std::time::Instant::now();
+        let sequential: Vec<TimestampedResult> = segments
+            .iter()
+            .map(|segment| model.transcribe_samples(segment.clone()).unwrap())
+            .collect();
+        let sequential_time = started.elapsed().as_secs_f64();
+
+        let started = std::time::Instant::now();
+        let batched = model.transcribe_batch(segments.clone()).unwrap();
+        let batched_time = started.elapsed().as_secs_f64();
+
+        assert_eq!(batched.len(), sequential.len());
+        println!(
+            "{:.0}s of audio: sequential {:.1}s ({:.1}x real time), batched {:.1}s ({:.1}x real time)",
+            audio_seconds,
+            sequential_time,
+            audio_seconds / sequential_time,
+            batched_time,
+            audio_seconds / batched_time
+        );
+        for (sequential, batched) in sequential.iter().zip(&batched) {
+            if sequential.text != batched.text {
+                println!("differs: '{}' / '{}'", sequential.text, batched.text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_words_empty_result() {
+        let result = TimestampedResult {
+            text: String::new(),
+            timestamps: vec![],
+            tokens: vec![],
+            confidences: vec![],
+        };
+        assert!(result.words().is_empty());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::parakeet_engine::model::ParakeetModel;
+use crate::parakeet_engine::beam_search::DecodingConfig;
+use crate::parakeet_engine::model::{ParakeetModel, TimestampedResult};
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -82,6 +83,8 @@ pub struct ParakeetEngine {
     cancel_download_flag: Arc<RwLock<Option<String>>>, // Model name being cancelled
     // Active downloads tracking to prevent concurrent downloads
     active_downloads: Arc<RwLock<HashSet<String>>>, // Set of models currently being downloaded
+    // Greedy or beam search decoding, applied to every model this engine loads
+    decoding_config: Arc<RwLock<DecodingConfig>>,
 }
 
 impl ParakeetEngine {
@@ -123,6 +126,7 @@ impl ParakeetEngine {
             cancel_download_flag: Arc::new(RwLock::new(None)),
             // Initialize active downloads tracking
             active_downloads: Arc::new(RwLock::new(HashSet::new())),
+            decoding_config: Arc::new(RwLock::new(DecodingConfig::default())),
         })
     }
 
@@ -137,6 +141,7 @@ impl ParakeetEngine {
         let model_configs = [
             ("parakeet-tdt-0.6b-v3-int8", 670, QuantizationType::Int8, "Ultra Fast (v3)", "Real time on M4 Max, latest version with int8 quantization"),
             ("parakeet-tdt-0.6b-v2-int8", 661, QuantizationType::Int8, "Fast (v2)", "Previous version with int8 quantization, good balance of speed and accuracy"),
+            ("parakeet-tdt-0.6b-v3-fp32", 2554, QuantizationType::FP32, "Precise (v3)", "Full precision, slower but more accurate; used to refine transcripts after recording"),
         ];
 
         for (name, size_mb, quantization, speed, description) in model_configs {
@@ -154,6 +159,7 @@ impl ParakeetEngine {
                     ],
                     QuantizationType::FP32 => vec![
                         "encoder-model.onnx",
+                        "encoder-model.onnx.data",
                         "decoder_joint-model.onnx",
                         "nemo128.onnx",
                         "vocab.txt",
@@ -264,8 +270,9 @@ impl ParakeetEngine {
 
                 // Load model based on quantization type
                 let quantized = model_info.quantization == QuantizationType::Int8;
-                let model = ParakeetModel::new(&model_info.path, quantized)
+                let mut model = ParakeetModel::new(&model_info.path, quantized)
                     .map_err(|e| anyhow!("Failed to load Parakeet model {}: {}", model_name, e))?;
+                model.set_decoding_config(&self.decoding_config.read().await);
 
                 // Update current model and model name
                 *self.current_model.write().await = Some(model);
@@ -317,8 +324,27 @@ impl ParakeetEngine {
         self.current_model.read().await.is_some()
     }
 
+    /// Get how transcripts are decoded
+    pub async fn get_decoding_config(&self) -> DecodingConfig {
+        self.decoding_config.read().await.clone()
+    }
+
+    /// Set how transcripts are decoded, for the loaded model and ones loaded later
+    pub async fn set_decoding_config(&self, config: DecodingConfig) {
+        if let Some(model) = self.current_model.write().await.as_mut() {
+            model.set_decoding_config(&config);
+        }
+        *self.decoding_config.write().await = config;
+    }
+
     /// Transcribe audio samples using the loaded Parakeet model
     pub async fn transcribe_audio(&self, audio_data: Vec<f32>) -> Result<String> {
+        let result = self.transcribe_audio_with_timestamps(audio_data).await?;
+        Ok(result.text)
+    }
+
+    /// Transcribe audio and keep per-token timing (seconds relative to the start of `audio_data`)
+    pub async fn transcribe_audio_with_timestamps(&self, audio_data: Vec<f32>) -> Result<TimestampedResult> {
         let mut model_guard = self.current_model.write().await;
         let model = model_guard
             .as_mut()
@@ -338,7 +364,30 @@ impl ParakeetEngine {
 
         log::debug!("Parakeet transcription result: '{}'", result.text);
 
-        Ok(result.text)
+        Ok(result)
+    }
+
+    /// Transcribe several utterances under one lock on the model, in batched
+    /// runs. Results are in input order.
+    pub async fn transcribe_batch_with_timestamps(
+        &self,
+        utterances: Vec<Vec<f32>>,
+    ) -> Result<Vec<TimestampedResult>> {
+        let mut model_guard = self.current_model.write().await;
+        let model = model_guard
+            .as_mut()
+            .ok_or_else(|| anyhow!("No Parakeet model loaded. Please load a model first."))?;
+
+        let total_samples: usize = utterances.iter().map(Vec::len).sum();
+        log::debug!(
+            "Parakeet transcribing a batch of {} utterances ({:.1}s total)",
+            utterances.len(),
+            total_samples as f64 / 16000.0
+        );
+
+        model
+            .transcribe_batch(utterances)
+            .map_err(|e| anyhow!("Parakeet batch transcription failed: {}", e))
     }
 
     /// Get the models directory path
@@ -461,6 +510,7 @@ impl ParakeetEngine {
             ],
             QuantizationType::FP32 => vec![
                 "encoder-model.onnx",
+                "encoder-model.onnx.data", // External weights of the FP32 encoder
                 "decoder_joint-model.onnx",
                 "nemo128.onnx",
                 "vocab.txt",
@@ -508,7 +558,8 @@ impl ParakeetEngine {
             QuantizationType::FP32 => {
                 // FP32 model sizes (encoder has .onnx + .onnx.data)
                 [
-                    ("encoder-model.onnx", 41_800_000u64 + 2_440_000_000u64), // 41.8 MB + 2.44 GB
+                    ("encoder-model.onnx", 41_800_000u64),                     // 41.8 MB
+                    ("encoder-model.onnx.data", 2_440_000_000u64),             // 2.44 GB
                     ("decoder_joint-model.onnx", 72_500_000u64),               // 72.5 MB
                     ("nemo128.onnx", 140_000u64),                              // 140 KB
                     ("vocab.txt", 93_900u64),                                  // 93.9 KB
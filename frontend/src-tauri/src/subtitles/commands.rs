@@ -0,0 +1,65 @@
+use super::{write_subtitles, CueLimits, SubtitleFormat};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::state::AppState;
+use log::{error as log_error, info as log_info, warn as log_warn};
+use std::path::Path;
+use tauri::{AppHandle, Runtime};
+
+/// Write the meeting's transcript as SRT or WebVTT captions into its recording
+/// folder, next to the audio. Returns the written file's path.
+#[tauri::command]
+pub async fn api_export_subtitles<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    format: SubtitleFormat,
+    max_line_length: Option<usize>,
+    max_duration: Option<f64>,
+) -> Result<String, String> {
+    log_info!(
+        "api_export_subtitles called for meeting_id: {}, format: {:?}",
+        meeting_id,
+        format
+    );
+
+    let defaults = CueLimits::default();
+    let limits = CueLimits {
+        max_line_length: max_line_length.unwrap_or(defaults.max_line_length),
+        max_duration: max_duration.unwrap_or(defaults.max_duration),
+        ..defaults
+    };
+    if limits.max_line_length < 10 || limits.max_duration < limits.min_duration {
+        return Err(format!(
+            "Invalid caption limits: lines need at least 10 characters and cues at least {}s",
+            limits.min_duration
+        ));
+    }
+
+    let pool = state.db_manager.pool();
+    let folder = MeetingsRepository::get_meeting_model(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| "Meeting not found".to_string())?
+        .folder_path
+        .ok_or_else(|| "Recording folder path not available for this meeting".to_string())?;
+    if !Path::new(&folder).is_dir() {
+        log_warn!("Folder path does not exist: {}", folder);
+        return Err(format!("Recording folder not found: {}", folder));
+    }
+
+    let meeting = MeetingsRepository::get_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcript: {}", e))?
+        .ok_or_else(|| "Meeting not found".to_string())?;
+
+    match write_subtitles(Path::new(&folder), &meeting.transcripts, format, &limits) {
+        Ok(path) => {
+            log_info!("Wrote captions for {} to {}", meeting_id, path.display());
+            Ok(path.to_string_lossy().to_string())
+        }
+        Err(e) => {
+            log_error!("Failed to export captions for {}: {}", meeting_id, e);
+            Err(e)
+        }
+    }
+}
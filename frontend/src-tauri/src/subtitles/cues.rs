@@ -0,0 +1,387 @@
+// subtitles/cues.rs
+//
+// Regrouping of transcript segments into caption cues. Transcript segments
+// follow the VAD, so they can be a single word or half a minute of speech;
+// cues are rebuilt word by word so each one fits the on-screen limits.
+
+use crate::api::MeetingTranscript;
+use std::ops::Range;
+
+/// Limits every cue is kept within
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CueLimits {
+    /// Characters per line, including the speaker label on the first line
+    pub max_line_length: usize,
+    pub max_lines: usize,
+    /// Longest a cue stays on screen, in seconds
+    pub max_duration: f64,
+    /// Shortest a cue stays on screen when the next one leaves room, in seconds
+    pub min_duration: f64,
+    /// Speech of one speaker separated by less than this shares a cue, in seconds
+    pub max_gap: f64,
+}
+
+impl Default for CueLimits {
+    /// Common broadcast caption guidelines
+    fn default() -> Self {
+        Self {
+            max_line_length: 42,
+            max_lines: 2,
+            max_duration: 7.0,
+            min_duration: 1.0,
+            max_gap: 1.0,
+        }
+    }
+}
+
+/// One caption, times in seconds from the recording start
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start: f64,
+    pub end: f64,
+    pub speaker: Option<String>,
+    /// Wrapped text, without the speaker label
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct TimedWord {
+    text: String,
+    start: f64,
+    end: f64,
+}
+
+/// Speech of one speaker without a pause longer than `max_gap`
+struct Run<'a> {
+    speaker: Option<&'a str>,
+    words: Vec<TimedWord>,
+}
+
+/// Build caption cues from a meeting's transcript. Segments without recording
+/// times (transcripts saved before timestamps were stored) are skipped.
+///
+/// Consecutive speech of one speaker is merged, then split into as few cues as
+/// the limits allow with the text spread evenly over them.
+pub fn build_cues(transcripts: &[MeetingTranscript], limits: &CueLimits) -> Vec<Cue> {
+    let mut segments: Vec<&MeetingTranscript> = transcripts
+        .iter()
+        .filter(|segment| segment.audio_start_time.is_some())
+        .collect();
+    segments.sort_by(|a, b| {
+        let (a, b) = (a.audio_start_time.unwrap_or(0.0), b.audio_start_time.unwrap_or(0.0));
+        a.total_cmp(&b)
+    });
+
+    let mut runs: Vec<Run> = Vec::new();
+    for segment in segments {
+        let speaker = speaker_label(segment);
+        for word in segment_words(segment) {
+            match runs.last_mut() {
+                Some(run)
+                    if run.speaker == speaker
+                        && word.start - run.words[run.words.len() - 1].end <= limits.max_gap =>
+                {
+                    run.words.push(word)
+                }
+                _ => runs.push(Run {
+                    speaker,
+                    words: vec![word],
+                }),
+            }
+        }
+    }
+
+    let mut cues = Vec::new();
+    for run in &runs {
+        for range in split_run(&run.words, run.speaker, limits) {
+            let words = &run.words[range];
+            cues.push(Cue {
+                start: words[0].start,
+                end: words[words.len() - 1].end,
+                speaker: run.speaker.map(str::to_string),
+                lines: wrap_balanced(words, run.speaker, limits),
+            });
+        }
+    }
+
+    adjust_timing(&mut cues, limits);
+    cues
+}
+
+fn texts(words: &[TimedWord]) -> impl Iterator<Item = &str> + Clone {
+    words.iter().map(|word| word.text.as_str())
+}
+
+fn fits(words: &[TimedWord], speaker: Option<&str>, limits: &CueLimits) -> bool {
+    words[words.len() - 1].end - words[0].start <= limits.max_duration
+        && wrap(texts(words), speaker, limits.max_line_length, limits).len() <= limits.max_lines
+}
+
+/// Split a run into cues. Filling each cue up gives the fewest cues but tends
+/// to leave the last one with a word or two, so the same number of cues is cut
+/// at the word boundaries nearest an even share of the text when that fits too.
+fn split_run(words: &[TimedWord], speaker: Option<&str>, limits: &CueLimits) -> Vec<Range<usize>> {
+    let mut greedy = Vec::new();
+    let mut start = 0;
+    while start < words.len() {
+        let mut end = start + 1;
+        while end < words.len() && fits(&words[start..=end], speaker, limits) {
+            end += 1;
+        }
+        greedy.push(start..end);
+        start = end;
+    }
+
+    let count = greedy.len();
+    if count < 2 {
+        return greedy;
+    }
+
+    // prefix[i]: characters (with the following space) before word i
+    let mut prefix = vec![0usize; words.len() + 1];
+    for (i, word) in words.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + word.text.chars().count() + 1;
+    }
+    let total = prefix[words.len()];
+
+    let mut balanced = Vec::with_capacity(count);
+    let mut start = 0;
+    for cue in 1..count {
+        let target = total * cue / count;
+        // Leave at least one word for each of the remaining cues
+        let end = (start + 1..=words.len() - (count - cue))
+            .min_by_key(|&end| prefix[end].abs_diff(target))
+            .unwrap_or(start + 1);
+        balanced.push(start..end);
+        start = end;
+    }
+    balanced.push(start..words.len());
+
+    if balanced
+        .iter()
+        .all(|range| fits(&words[range.clone()], speaker, limits))
+    {
+        balanced
+    } else {
+        greedy
+    }
+}
+
+/// Diarized name first, then the raw speaker id
+fn speaker_label(segment: &MeetingTranscript) -> Option<&str> {
+    segment
+        .speaker_name
+        .as_deref()
+        .or(segment.speaker.as_deref())
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+}
+
+/// The segment's words with their times. Without word timestamps the segment's
+/// time span is shared out over its words by length.
+fn segment_words(segment: &MeetingTranscript) -> Vec<TimedWord> {
+    let Some(start) = segment.audio_start_time else {
+        return Vec::new();
+    };
+    let end = segment
+        .audio_end_time
+        .or(segment.duration.map(|duration| start + duration))
+        .unwrap_or(start)
+        .max(start);
+
+    if !segment.words.is_empty() {
+        return segment
+            .words
+            .iter()
+            .filter(|word| !word.text.trim().is_empty())
+            .map(|word| TimedWord {
+                text: word.text.trim().to_string(),
+                start: word.start,
+                end: word.end.max(word.start),
+            })
+            .collect();
+    }
+
+    let words: Vec<&str> = segment.text.split_whitespace().collect();
+    let total_chars: usize = words.iter().map(|word| word.chars().count() + 1).sum();
+    let seconds_per_char = if total_chars > 0 {
+        (end - start) / total_chars as f64
+    } else {
+        0.0
+    };
+
+    let mut cursor = start;
+    words
+        .into_iter()
+        .map(|word| {
+            let word_start = cursor;
+            cursor += (word.chars().count() + 1) as f64 * seconds_per_char;
+            TimedWord {
+                text: word.to_string(),
+                start: word_start,
+                end: cursor.min(end),
+            }
+        })
+        .collect()
+}
+
+/// Greedy word wrap at `width` characters; the first line also leaves room for
+/// the `Speaker: ` label within the line length limit. A word longer than a
+/// line gets a line of its own.
+fn wrap<'w>(
+    words: impl IntoIterator<Item = &'w str>,
+    speaker: Option<&str>,
+    width: usize,
+    limits: &CueLimits,
+) -> Vec<String> {
+    let label_length = speaker.map_or(0, |speaker| speaker.chars().count() + 2);
+    let first_width = width.min(limits.max_line_length.saturating_sub(label_length));
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+
+    for word in words {
+        let width = if lines.is_empty() { first_width } else { width };
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Wrap into as few lines as the line length allows, with lines of similar length
+fn wrap_balanced(words: &[TimedWord], speaker: Option<&str>, limits: &CueLimits) -> Vec<String> {
+    let lines = wrap(texts(words), speaker, limits.max_line_length, limits);
+    if lines.len() < 2 {
+        return lines;
+    }
+
+    let text_length = texts(words).map(|word| word.chars().count() + 1).sum::<usize>() - 1;
+    let narrowest = text_length.div_ceil(lines.len());
+    (narrowest..limits.max_line_length)
+        .map(|width| wrap(texts(words), speaker, width, limits))
+        .find(|balanced| balanced.len() <= lines.len())
+        .unwrap_or(lines)
+}
+
+/// Give short cues the minimum display time where the next cue leaves room,
+/// and keep cues from running into the next one
+fn adjust_timing(cues: &mut [Cue], limits: &CueLimits) {
+    for i in 0..cues.len() {
+        let next_start = cues.get(i + 1).map(|next| next.start);
+        let cue = &mut cues[i];
+        cue.end = cue.end.max(cue.start + limits.min_duration);
+        if let Some(next_start) = next_start {
+            // Overlapping speakers (next cue starts before this one) keep their overlap
+            if next_start > cue.start {
+                cue.end = cue.end.min(next_start);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::transcription::WordTimestamp;
+
+    fn segment(text: &str, start: f64, end: f64, speaker: Option<&str>) -> MeetingTranscript {
+        MeetingTranscript {
+            id: format!("t-{}", start),
+            text: text.to_string(),
+            timestamp: String::new(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            words: Vec::new(),
+            speaker: None,
+            speaker_name: speaker.map(str::to_string),
+            source: None,
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn test_wrap_respects_line_length_and_label() {
+        let limits = CueLimits {
+            max_line_length: 12,
+            ..CueLimits::default()
+        };
+        let words = "one two three four five".split(' ');
+        assert_eq!(wrap(words.clone(), None, 12, &limits), vec!["one two", "three four", "five"]);
+        // "Alice: " takes 7 characters of the first line
+        assert_eq!(wrap(words, Some("Alice"), 12, &limits), vec!["one", "two three", "four five"]);
+        assert_eq!(wrap(["extraordinarily"], None, 12, &limits), vec!["extraordinarily"]);
+    }
+
+    #[test]
+    fn test_short_segments_of_one_speaker_are_merged() {
+        let transcripts = vec![
+            segment("Good morning", 0.0, 1.0, Some("Alice")),
+            segment("everyone", 1.2, 1.8, Some("Alice")),
+            segment("Hi", 2.0, 2.3, Some("Bob")),
+        ];
+        let cues = build_cues(&transcripts, &CueLimits::default());
+
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].lines, vec!["Good morning everyone"]);
+        assert_eq!(cues[0].speaker.as_deref(), Some("Alice"));
+        assert_eq!((cues[0].start, cues[0].end), (0.0, 1.8));
+        assert_eq!(cues[1].speaker.as_deref(), Some("Bob"));
+        // Stretched to the minimum duration
+        assert_eq!((cues[1].start, cues[1].end), (2.0, 3.0));
+    }
+
+    #[test]
+    fn test_long_segment_is_split_within_limits() {
+        let text = "this is a rather long segment of speech that goes on and on without \
+                    any pause because the speaker has a lot to say about the quarterly budget";
+        let limits = CueLimits::default();
+        let cues = build_cues(&[segment(text, 10.0, 30.0, None)], &limits);
+
+        assert!(cues.len() > 1);
+        for cue in &cues {
+            assert!(cue.lines.len() <= limits.max_lines);
+            assert!(cue.lines.iter().all(|line| line.len() <= limits.max_line_length));
+            assert!(cue.end - cue.start <= limits.max_duration + 1e-9);
+            // Spread evenly rather than leaving a word or two on a line
+            assert!(cue.lines.iter().all(|line| line.split(' ').count() >= 3));
+        }
+        let rejoined: Vec<String> = cues.iter().map(|cue| cue.lines.join(" ")).collect();
+        assert_eq!(rejoined.join(" "), text.split_whitespace().collect::<Vec<_>>().join(" "));
+        assert_eq!(cues[0].start, 10.0);
+        assert!(cues[cues.len() - 1].end >= 30.0 - 1e-6);
+    }
+
+    #[test]
+    fn test_word_timestamps_and_gaps() {
+        let mut with_words = segment("Hello there. Next part", 0.0, 10.0, None);
+        with_words.words = vec![
+            WordTimestamp { text: " Hello".to_string(), start: 0.0, end: 0.4, confidence: None },
+            WordTimestamp { text: "there.".to_string(), start: 0.5, end: 0.9, confidence: None },
+            WordTimestamp { text: "Next".to_string(), start: 5.0, end: 5.3, confidence: None },
+            WordTimestamp { text: "part".to_string(), start: 5.4, end: 5.8, confidence: None },
+        ];
+        let cues = build_cues(&[with_words], &CueLimits::default());
+
+        // The pause is longer than max_gap, so the words after it start a new cue
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].lines, vec!["Hello there."]);
+        assert_eq!((cues[0].start, cues[0].end), (0.0, 1.0));
+        assert_eq!(cues[1].lines, vec!["Next part"]);
+        assert_eq!((cues[1].start, cues[1].end), (5.0, 6.0));
+    }
+
+    #[test]
+    fn test_segments_without_times_are_skipped() {
+        let mut untimed = segment("Legacy", 0.0, 1.0, None);
+        untimed.audio_start_time = None;
+        assert!(build_cues(&[untimed], &CueLimits::default()).is_empty());
+    }
+}
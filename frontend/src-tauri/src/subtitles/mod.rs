@@ -0,0 +1,45 @@
+// subtitles/mod.rs
+//
+// Caption export (SRT and WebVTT) of timestamped meeting transcripts.
+
+pub mod commands;
+pub mod cues;
+pub mod writer;
+
+pub use commands::*;
+pub use cues::{build_cues, Cue, CueLimits};
+pub use writer::SubtitleFormat;
+
+use crate::api::MeetingTranscript;
+use std::path::{Path, PathBuf};
+
+/// Captions are named after the recording (`audio.srt` next to `audio.mp4`)
+/// so video players pick them up automatically
+const SUBTITLE_FILE_STEM: &str = "audio";
+
+/// Render a transcript as captions. Fails if no segment has recording times.
+pub fn render_subtitles(
+    transcripts: &[MeetingTranscript],
+    format: SubtitleFormat,
+    limits: &CueLimits,
+) -> Result<String, String> {
+    let cues = build_cues(transcripts, limits);
+    if cues.is_empty() {
+        return Err("The transcript has no timestamps to build captions from".to_string());
+    }
+    Ok(writer::render(format, &cues))
+}
+
+/// Write a transcript's captions into a meeting folder and return the file's path
+pub fn write_subtitles(
+    folder: &Path,
+    transcripts: &[MeetingTranscript],
+    format: SubtitleFormat,
+    limits: &CueLimits,
+) -> Result<PathBuf, String> {
+    let content = render_subtitles(transcripts, format, limits)?;
+    let path = folder.join(format!("{}.{}", SUBTITLE_FILE_STEM, format.extension()));
+    std::fs::write(&path, content)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
@@ -0,0 +1,142 @@
+// subtitles/writer.rs
+//
+// SRT and WebVTT serialization of caption cues.
+
+use super::cues::Cue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+}
+
+impl SubtitleFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+        }
+    }
+}
+
+pub fn render(format: SubtitleFormat, cues: &[Cue]) -> String {
+    match format {
+        SubtitleFormat::Srt => to_srt(cues),
+        SubtitleFormat::Vtt => to_vtt(cues),
+    }
+}
+
+/// SRT: numbered cues, `,` before milliseconds, speaker as a `Name: ` prefix
+pub fn to_srt(cues: &[Cue]) -> String {
+    let mut out = String::new();
+    for (index, cue) in cues.iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n",
+            index + 1,
+            format_time(cue.start, ','),
+            format_time(cue.end, ',')
+        ));
+        for (line_index, line) in cue.lines.iter().enumerate() {
+            match &cue.speaker {
+                Some(speaker) if line_index == 0 => out.push_str(&format!("{}: {}\n", speaker, line)),
+                _ => out.push_str(&format!("{}\n", line)),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// WebVTT: `.` before milliseconds, speaker as a `<v Name>` voice span
+pub fn to_vtt(cues: &[Cue]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues {
+        out.push_str(&format!(
+            "{} --> {}\n",
+            format_time(cue.start, '.'),
+            format_time(cue.end, '.')
+        ));
+        for (line_index, line) in cue.lines.iter().enumerate() {
+            match &cue.speaker {
+                Some(speaker) if line_index == 0 => out.push_str(&format!(
+                    "<v {}>{}\n",
+                    escape_vtt(speaker),
+                    escape_vtt(line)
+                )),
+                _ => out.push_str(&format!("{}\n", escape_vtt(line))),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// `HH:MM:SS<separator>mmm`
+fn format_time(seconds: f64, separator: char) -> String {
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        total_ms / 3_600_000,
+        (total_ms / 60_000) % 60,
+        (total_ms / 1000) % 60,
+        separator,
+        total_ms % 1000
+    )
+}
+
+/// Cue text may not contain raw `&`, `<` or `>` in WebVTT
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cues() -> Vec<Cue> {
+        vec![
+            Cue {
+                start: 1.5,
+                end: 4.0,
+                speaker: Some("Alice".to_string()),
+                lines: vec!["Q&A starts".to_string(), "in <five> minutes".to_string()],
+            },
+            Cue {
+                start: 3725.0104,
+                end: 3727.0,
+                speaker: None,
+                lines: vec!["Thanks".to_string()],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(0.0, ','), "00:00:00,000");
+        assert_eq!(format_time(3725.0104, '.'), "01:02:05.010");
+        assert_eq!(format_time(59.9996, ','), "00:01:00,000");
+    }
+
+    #[test]
+    fn test_to_srt() {
+        assert_eq!(
+            to_srt(&cues()),
+            "1\n00:00:01,500 --> 00:00:04,000\nAlice: Q&A starts\nin <five> minutes\n\n\
+             2\n01:02:05,010 --> 01:02:07,000\nThanks\n\n"
+        );
+    }
+
+    #[test]
+    fn test_to_vtt() {
+        assert_eq!(
+            to_vtt(&cues()),
+            "WEBVTT\n\n\
+             00:00:01.500 --> 00:00:04.000\n<v Alice>Q&amp;A starts\nin &lt;five&gt; minutes\n\n\
+             01:02:05.010 --> 01:02:07.000\nThanks\n\n"
+        );
+    }
+}
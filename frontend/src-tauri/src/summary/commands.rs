@@ -1,9 +1,13 @@
+use crate::database::models::SummaryVersion;
 use crate::database::repositories::{
-    meeting::MeetingsRepository, summary::SummaryProcessesRepository,
+    meeting::MeetingsRepository, speaker::SpeakersRepository,
+    summary::SummaryProcessesRepository, summary_version::SummaryVersionsRepository,
     transcript_chunk::TranscriptChunksRepository,
 };
 use crate::state::AppState;
 use crate::summary::service::SummaryService;
+use crate::summary::streaming;
+use crate::summary::versions::SummaryVersionDiff;
 use log::{error as log_error, info as log_info, warn as log_warn};
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Runtime};
@@ -178,19 +182,36 @@ pub async fn api_process_transcript<R: Runtime>(
     _overlap: Option<i32>,
     custom_prompt: Option<String>,
     template_id: Option<String>,
+    structured_output: Option<bool>,
     _auth_token: Option<String>,
 ) -> Result<ProcessTranscriptResponse, String> {
     use uuid::Uuid;
 
     let m_id = meeting_id.unwrap_or_else(|| format!("meeting-{}", Uuid::new_v4()));
+    let structured_output = structured_output.unwrap_or(false);
     log_info!(
-        "api_process_transcript (native) called for meeting_id: {}, model: {}",
+        "api_process_transcript (native) called for meeting_id: {}, model: {}, structured: {}",
         &m_id,
-        &model
+        &model,
+        structured_output
     );
 
     let pool = state.db_manager.pool().clone();
     let final_prompt = custom_prompt.unwrap_or_else(|| "".to_string());
+
+    // Diarized or scored meetings: rebuild the text from the DB so speaker names (including renames)
+    // and low-confidence word marks reach the LLM
+    let text = match SpeakersRepository::build_labeled_transcript(&pool, &m_id).await {
+        Ok(Some(labeled)) => {
+            log_info!("Using labeled transcript for meeting_id: {}", &m_id);
+            labeled
+        }
+        Ok(None) => text,
+        Err(e) => {
+            log_warn!("Failed to build speaker-labeled transcript, using provided text: {}", e);
+            text
+        }
+    };
     let final_template_id = template_id.unwrap_or_else(|| "daily_standup".to_string());
 
     // Create or reset the process entry in the database
@@ -230,6 +251,7 @@ pub async fn api_process_transcript<R: Runtime>(
             model_name,
             final_prompt,
             final_template_id,
+            structured_output,
         )
         .await;
     });
@@ -241,3 +263,108 @@ pub async fn api_process_transcript<R: Runtime>(
         process_id: m_id,
     })
 }
+
+/// Cancels a running summary generation
+///
+/// The background task stops at the next chunk boundary, or immediately while the
+/// final report is streaming, and the process is marked as cancelled.
+#[tauri::command]
+pub async fn api_cancel_summary<R: Runtime>(
+    _app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<bool, String> {
+    log_info!("api_cancel_summary called for meeting_id: {}", meeting_id);
+    let cancelled = streaming::cancel_stream(&streaming::summary_stream_key(&meeting_id));
+    if !cancelled {
+        log_warn!("No running summary generation for meeting_id: {}", meeting_id);
+    }
+    Ok(cancelled)
+}
+
+/// Lists every generated version of a meeting's summary, newest first
+#[tauri::command]
+pub async fn api_list_summary_versions<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<SummaryVersion>, String> {
+    log_info!("api_list_summary_versions called for meeting_id: {}", meeting_id);
+    let pool = state.db_manager.pool();
+
+    SummaryVersionsRepository::get_versions(pool, &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list summary versions for {}: {}", meeting_id, e);
+            format!("Failed to list summary versions: {}", e)
+        })
+}
+
+/// Makes a previous version the meeting's current summary
+///
+/// Returns the restored version; its result is what `api_get_summary` returns from now on.
+#[tauri::command]
+pub async fn api_restore_summary_version<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    version_id: String,
+) -> Result<SummaryVersion, String> {
+    log_info!(
+        "api_restore_summary_version called for meeting_id: {}, version_id: {}",
+        meeting_id,
+        version_id
+    );
+    let pool = state.db_manager.pool();
+
+    match SummaryVersionsRepository::restore_version(pool, &meeting_id, &version_id).await {
+        Ok(Some(version)) => Ok(version),
+        Ok(None) => {
+            log_warn!(
+                "Summary version {} not found for meeting_id: {}",
+                version_id,
+                meeting_id
+            );
+            Err(format!("Summary version not found: {}", version_id))
+        }
+        Err(e) => {
+            log_error!("Failed to restore summary version {}: {}", version_id, e);
+            Err(format!("Failed to restore summary version: {}", e))
+        }
+    }
+}
+
+/// Line diff of the markdown of two summary versions (`from` → `to`)
+#[tauri::command]
+pub async fn api_diff_summary_versions<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    from_version_id: String,
+    to_version_id: String,
+) -> Result<SummaryVersionDiff, String> {
+    log_info!(
+        "api_diff_summary_versions called for meeting_id {}: {} -> {}",
+        meeting_id,
+        from_version_id,
+        to_version_id
+    );
+    let pool = state.db_manager.pool();
+
+    let from = load_summary_version(pool, &meeting_id, &from_version_id).await?;
+    let to = load_summary_version(pool, &meeting_id, &to_version_id).await?;
+
+    Ok(SummaryVersionDiff::new(from, to))
+}
+
+/// A version of the meeting's summary; versions of other meetings are not found
+async fn load_summary_version(
+    pool: &sqlx::SqlitePool,
+    meeting_id: &str,
+    version_id: &str,
+) -> Result<SummaryVersion, String> {
+    SummaryVersionsRepository::get_version(pool, version_id)
+        .await
+        .map_err(|e| format!("Failed to get summary version: {}", e))?
+        .filter(|version| version.meeting_id == meeting_id)
+        .ok_or_else(|| format!("Summary version not found: {}", version_id))
+}
@@ -1,6 +1,10 @@
+use crate::summary::streaming::{
+    self, LineBuffer, StreamCancellation, StreamChunk, StreamOutcome,
+};
+use futures_util::StreamExt;
 use reqwest::{header, Client};
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{info, warn};
 
 // Generic structure for OpenAI-compatible API chat messages
 #[derive(Debug, Serialize)]
@@ -196,6 +200,294 @@ pub async fn generate_summary(
     user_prompt: &str,
     ollama_endpoint: Option<&str>,
 ) -> Result<String, String> {
+    let (api_url, headers) = endpoint_and_headers(provider, api_key, ollama_endpoint)?;
+    let request_body = request_body(provider, model_name, system_prompt, user_prompt);
+
+    info!("🐞 LLM Request to {}: model={}", provider_name(provider), model_name);
+
+    // Send request
+    let response = client
+        .post(api_url)
+        .headers(headers)
+        .json(&request_body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request to LLM: {}", e))?;
+
+    if !response.status().is_success() {
+        let error_body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(format!("LLM API request failed: {}", error_body));
+    }
+
+    // Parse response based on provider
+    if provider == &LLMProvider::Claude {
+        let chat_response = response
+            .json::<ClaudeChatResponse>()
+            .await
+            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+
+        info!("🐞 LLM Response received from Claude");
+
+        let content = chat_response
+            .content
+            .get(0)
+            .ok_or("No content in LLM response")?
+            .text
+            .trim();
+        Ok(content.to_string())
+    } else {
+        let chat_response = response
+            .json::<ChatResponse>()
+            .await
+            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+
+        info!("🐞 LLM Response received from {}", provider_name(provider));
+
+        let content = chat_response
+            .choices
+            .get(0)
+            .ok_or("No content in LLM response")?
+            .message
+            .content
+            .trim();
+        Ok(content.to_string())
+    }
+}
+
+/// Streaming variant of [`generate_summary`]
+///
+/// Sends the same request with `"stream": true` and calls `on_token` with every
+/// text delta as it arrives:
+/// - OpenAI, Groq, OpenRouter: SSE `data:` lines with `choices[0].delta.content`,
+///   terminated by `data: [DONE]`
+/// - Claude: SSE `content_block_delta` events, terminated by `message_stop`
+/// - Ollama: NDJSON from the native `/api/chat` endpoint, terminated by `"done": true`
+///
+/// When `cancellation` fires, the connection is dropped (which stops generation
+/// on the provider side) and the text received so far is returned as
+/// [`StreamOutcome::Cancelled`].
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_summary_stream<F>(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+    ollama_endpoint: Option<&str>,
+    cancellation: &StreamCancellation,
+    mut on_token: F,
+) -> Result<StreamOutcome, String>
+where
+    F: FnMut(&str) + Send,
+{
+    let (mut api_url, headers) = endpoint_and_headers(provider, api_key, ollama_endpoint)?;
+    if provider == &LLMProvider::Ollama {
+        // Ollama's OpenAI-compatible endpoint streams SSE too, but the native API
+        // streams plain NDJSON and reports model errors inline
+        api_url = format!("{}/api/chat", ollama_host(ollama_endpoint));
+    }
+    let mut request_body = request_body(provider, model_name, system_prompt, user_prompt);
+    request_body["stream"] = serde_json::Value::Bool(true);
+
+    let parse_line: fn(&str) -> StreamChunk = match provider {
+        LLMProvider::Claude => streaming::parse_claude_line,
+        LLMProvider::Ollama => streaming::parse_ollama_line,
+        _ => streaming::parse_openai_line,
+    };
+
+    info!(
+        "🐞 LLM streaming request to {}: model={}",
+        provider_name(provider),
+        model_name
+    );
+
+    let response = tokio::select! {
+        response = client.post(api_url).headers(headers).json(&request_body).send() => {
+            response.map_err(|e| format!("Failed to send request to LLM: {}", e))?
+        }
+        _ = cancellation.cancelled() => return Ok(StreamOutcome::Cancelled(String::new())),
+    };
+
+    if !response.status().is_success() {
+        let error_body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(format!("LLM API request failed: {}", error_body));
+    }
+
+    let mut stream = response.bytes_stream();
+    let mut lines = LineBuffer::default();
+    let mut text = String::new();
+
+    loop {
+        let next = tokio::select! {
+            next = stream.next() => next,
+            _ = cancellation.cancelled() => {
+                info!(
+                    "LLM stream from {} cancelled after {} chars",
+                    provider_name(provider),
+                    text.len()
+                );
+                return Ok(StreamOutcome::Cancelled(text.trim().to_string()));
+            }
+        };
+
+        let (batch, end_of_body) = match next {
+            Some(chunk) => {
+                let bytes = chunk.map_err(|e| format!("LLM stream interrupted: {}", e))?;
+                (lines.push(&bytes), false)
+            }
+            None => (lines.finish().into_iter().collect::<Vec<_>>(), true),
+        };
+
+        let mut finished = false;
+        for line in batch {
+            match parse_line(&line) {
+                StreamChunk::Token(delta) => {
+                    text.push_str(&delta);
+                    on_token(&delta);
+                }
+                StreamChunk::Done => {
+                    finished = true;
+                    break;
+                }
+                StreamChunk::Error(message) => {
+                    return Err(format!("LLM API stream error: {}", message));
+                }
+                StreamChunk::Skip => {}
+            }
+        }
+
+        if finished {
+            break;
+        }
+        if end_of_body {
+            warn!(
+                "LLM stream from {} ended without a completion marker",
+                provider_name(provider)
+            );
+            break;
+        }
+    }
+
+    info!(
+        "🐞 LLM streaming response completed from {}",
+        provider_name(provider)
+    );
+
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Err("No content in LLM response".to_string());
+    }
+    Ok(StreamOutcome::Completed(text))
+}
+
+/// Structured-output variant of [`generate_summary`]
+///
+/// Asks the provider to answer with a JSON object matching `schema`, using the
+/// provider's native mechanism:
+/// - OpenAI: `response_format` of type `json_schema` (strict)
+/// - Groq, OpenRouter, Ollama: `response_format` of type `json_object` (the schema
+///   itself travels in the prompt)
+/// - Claude: a single forced tool whose `input_schema` is `schema`; the tool
+///   input is the answer
+///
+/// Returns the raw JSON text. Providers may still deviate from the schema, so
+/// callers validate it before use.
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_summary_json(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+    ollama_endpoint: Option<&str>,
+    schema_name: &str,
+    schema: &serde_json::Value,
+) -> Result<String, String> {
+    let (api_url, headers) = endpoint_and_headers(provider, api_key, ollama_endpoint)?;
+    let mut request_body = request_body(provider, model_name, system_prompt, user_prompt);
+
+    match provider {
+        LLMProvider::OpenAI => {
+            request_body["response_format"] = serde_json::json!({
+                "type": "json_schema",
+                "json_schema": { "name": schema_name, "strict": true, "schema": schema },
+            });
+        }
+        LLMProvider::Claude => {
+            request_body["tools"] = serde_json::json!([{
+                "name": schema_name,
+                "description": "Record the meeting summary",
+                "input_schema": schema,
+            }]);
+            request_body["tool_choice"] = serde_json::json!({ "type": "tool", "name": schema_name });
+        }
+        LLMProvider::Groq | LLMProvider::OpenRouter | LLMProvider::Ollama => {
+            request_body["response_format"] = serde_json::json!({ "type": "json_object" });
+        }
+    }
+
+    info!(
+        "🐞 LLM structured request to {}: model={}",
+        provider_name(provider),
+        model_name
+    );
+
+    let response = client
+        .post(api_url)
+        .headers(headers)
+        .json(&request_body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request to LLM: {}", e))?;
+
+    if !response.status().is_success() {
+        let error_body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(format!("LLM API request failed: {}", error_body));
+    }
+
+    let body = response
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+
+    info!(
+        "🐞 LLM structured response received from {}",
+        provider_name(provider)
+    );
+
+    let content = if provider == &LLMProvider::Claude {
+        body["content"]
+            .as_array()
+            .and_then(|blocks| blocks.iter().find(|b| b["type"] == "tool_use"))
+            .map(|block| block["input"].to_string())
+    } else {
+        body["choices"][0]["message"]["content"]
+            .as_str()
+            .map(|content| content.trim().to_string())
+    };
+
+    content
+        .filter(|content| !content.is_empty())
+        .ok_or_else(|| "No content in LLM response".to_string())
+}
+
+/// API URL (OpenAI-compatible chat completions, or Anthropic messages) and auth headers
+fn endpoint_and_headers(
+    provider: &LLMProvider,
+    api_key: &str,
+    ollama_endpoint: Option<&str>,
+) -> Result<(String, header::HeaderMap), String> {
     let (api_url, mut headers) = match provider {
         LLMProvider::OpenAI => (
             "https://api.openai.com/v1/chat/completions".to_string(),
@@ -209,15 +501,10 @@ pub async fn generate_summary(
             "https://openrouter.ai/api/v1/chat/completions".to_string(),
             header::HeaderMap::new(),
         ),
-        LLMProvider::Ollama => {
-            let host = ollama_endpoint
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| "http://localhost:11434".to_string());
-            (
-                format!("{}/v1/chat/completions", host),
-                header::HeaderMap::new(),
-            )
-        }
+        LLMProvider::Ollama => (
+            format!("{}/v1/chat/completions", ollama_host(ollama_endpoint)),
+            header::HeaderMap::new(),
+        ),
         LLMProvider::Claude => {
             let mut header_map = header::HeaderMap::new();
             header_map.insert(
@@ -252,8 +539,24 @@ pub async fn generate_summary(
             .map_err(|_| "Invalid content type".to_string())?,
     );
 
-    // Build request body based on provider
-    let request_body = if provider != &LLMProvider::Claude {
+    Ok((api_url, headers))
+}
+
+/// Ollama host, defaulting to the local server
+fn ollama_host(ollama_endpoint: Option<&str>) -> String {
+    ollama_endpoint
+        .map(|s| s.trim_end_matches('/').to_string())
+        .unwrap_or_else(|| "http://localhost:11434".to_string())
+}
+
+/// Request body: OpenAI chat format, or Anthropic's with a separate system field
+fn request_body(
+    provider: &LLMProvider,
+    model_name: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+) -> serde_json::Value {
+    if provider != &LLMProvider::Claude {
         serde_json::json!(ChatRequest {
             model: model_name.to_string(),
             messages: vec![
@@ -277,59 +580,6 @@ pub async fn generate_summary(
                 content: user_prompt.to_string(),
             }]
         })
-    };
-
-    info!("🐞 LLM Request to {}: model={}", provider_name(provider), model_name);
-
-    // Send request
-    let response = client
-        .post(api_url)
-        .headers(headers)
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to send request to LLM: {}", e))?;
-
-    if !response.status().is_success() {
-        let error_body = response
-            .text()
-            .await
-            .unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(format!("LLM API request failed: {}", error_body));
-    }
-
-    // Parse response based on provider
-    if provider == &LLMProvider::Claude {
-        let chat_response = response
-            .json::<ClaudeChatResponse>()
-            .await
-            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
-
-        info!("🐞 LLM Response received from Claude");
-
-        let content = chat_response
-            .content
-            .get(0)
-            .ok_or("No content in LLM response")?
-            .text
-            .trim();
-        Ok(content.to_string())
-    } else {
-        let chat_response = response
-            .json::<ChatResponse>()
-            .await
-            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
-
-        info!("🐞 LLM Response received from {}", provider_name(provider));
-
-        let content = chat_response
-            .choices
-            .get(0)
-            .ok_or("No content in LLM response")?
-            .message
-            .content
-            .trim();
-        Ok(content.to_string())
     }
 }
 
@@ -4,7 +4,10 @@
 /// - LLM client for communicating with various AI providers (OpenAI, Claude, Groq, Ollama, OpenRouter)
 /// - Processor for chunking transcripts and generating summaries
 /// - Service layer for orchestrating summary generation
+/// - Streaming parsers and cancellation for token-by-token LLM output
 /// - Templates for structured meeting summary generation
+/// - Structured (JSON) summary output validated against the template
+/// - Version history and diffs of generated summaries
 /// - Tauri commands for frontend integration
 
 pub mod commands;
@@ -12,13 +15,19 @@ pub mod llm_client;
 pub mod processor;
 pub mod prompts;
 pub mod service;
+pub mod streaming;
+pub mod structured;
 pub mod template_commands;
 pub mod templates;
+pub mod versions;
 
 // Re-export Tauri commands (with their generated __cmd__ variants)
 pub use commands::{
-    __cmd__api_get_summary, __cmd__api_process_transcript, __cmd__api_save_meeting_summary,
-    api_get_summary, api_process_transcript, api_save_meeting_summary,
+    __cmd__api_cancel_summary, __cmd__api_diff_summary_versions, __cmd__api_get_summary,
+    __cmd__api_list_summary_versions, __cmd__api_process_transcript,
+    __cmd__api_restore_summary_version, __cmd__api_save_meeting_summary, api_cancel_summary,
+    api_diff_summary_versions, api_get_summary, api_list_summary_versions,
+    api_process_transcript, api_restore_summary_version, api_save_meeting_summary,
 };
 
 // Re-export template commands
@@ -31,6 +40,6 @@ pub use template_commands::{
 pub use llm_client::LLMProvider;
 pub use processor::{
     chunk_text, clean_llm_markdown_output, extract_meeting_name_from_markdown,
-    generate_meeting_summary, rough_token_count,
+    generate_meeting_summary, generate_structured_meeting_summary, rough_token_count,
 };
 pub use service::SummaryService;
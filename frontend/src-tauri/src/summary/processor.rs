@@ -1,9 +1,18 @@
-use crate::summary::llm_client::{generate_summary, LLMProvider};
+use crate::audio::transcription::has_low_confidence_marks;
+use crate::summary::llm_client::{
+    generate_summary, generate_summary_json, generate_summary_stream, LLMProvider,
+};
 use crate::summary::prompts;
+use crate::summary::streaming::{StreamCancellation, StreamOutcome};
+use crate::summary::structured;
 use crate::summary::templates;
 use regex::Regex;
 use reqwest::Client;
-use tracing::{error, info};
+use serde_json::Value;
+use tracing::{error, info, warn};
+
+/// Error returned by `generate_meeting_summary` when the user cancels it
+pub const SUMMARY_CANCELLED: &str = "Summary generation cancelled";
 
 /// Rough token count estimation (4 characters ≈ 1 token)
 pub fn rough_token_count(s: &str) -> usize {
@@ -263,10 +272,13 @@ pub fn extract_meeting_name_from_markdown(markdown: &str) -> Option<String> {
 /// * `token_threshold` - Token limit for single-pass processing (default 4000)
 /// * `ollama_endpoint` - Optional custom Ollama endpoint
 /// * `language` - Language for prompts: 'pt' (Portuguese) or 'en' (English) - Added 13/11/2025 by Luiz
+/// * `cancellation` - Checked between chunk calls; aborts the streamed final call
+/// * `on_token` - Receives the final report's text as it streams in
 ///
 /// # Returns
-/// Tuple of (final_summary_markdown, number_of_chunks_processed)
-pub async fn generate_meeting_summary(
+/// Tuple of (final_summary_markdown, number_of_chunks_processed), or `SUMMARY_CANCELLED`
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_meeting_summary<F>(
     client: &Client,
     provider: &LLMProvider,
     model_name: &str,
@@ -277,12 +289,205 @@ pub async fn generate_meeting_summary(
     token_threshold: usize,
     ollama_endpoint: Option<&str>,
     language: &str,
-) -> Result<(String, i64), String> {
+    cancellation: &StreamCancellation,
+    on_token: F,
+) -> Result<(String, i64), String>
+where
+    F: FnMut(&str) + Send,
+{
     info!(
         "Starting summary generation with provider: {:?}, model: {}",
         provider, model_name
     );
 
+    let (content_to_summarize, successful_chunk_count) = condense_transcript(
+        client,
+        provider,
+        model_name,
+        api_key,
+        text,
+        token_threshold,
+        ollama_endpoint,
+        language,
+        cancellation,
+    )
+    .await?;
+
+    info!("Generating final markdown report with template: {}", template_id);
+
+    // Load the template using the provided template_id
+    let template = templates::get_template(template_id)
+        .map_err(|e| format!("Failed to load template '{}': {}", template_id, e))?;
+
+    // Generate markdown structure and section instructions using template methods
+    let clean_template_markdown = template.to_markdown_structure();
+    let section_instructions = template.to_section_instructions();
+
+    // Get final prompt template in the appropriate language and format it
+    // Date: 13/11/2025 - Author: Luiz
+    let template_str = prompts::get_final_system_prompt_template(language);
+    // Since format! requires a string literal, we use string replace for dynamic templates
+    let final_system_prompt = template_str
+        .replacen("{}", &section_instructions, 1)
+        .replacen("{}", &clean_template_markdown, 1);
+
+    let final_user_prompt = build_final_user_prompt(&content_to_summarize, custom_prompt, language);
+
+    if cancellation.is_cancelled() {
+        return Err(SUMMARY_CANCELLED.to_string());
+    }
+
+    // The final report is streamed so the frontend can show it as it is written
+    let raw_markdown = match generate_summary_stream(
+        client,
+        provider,
+        model_name,
+        api_key,
+        &final_system_prompt,
+        &final_user_prompt,
+        ollama_endpoint,
+        cancellation,
+        on_token,
+    )
+    .await?
+    {
+        StreamOutcome::Completed(text) => text,
+        StreamOutcome::Cancelled(_) => return Err(SUMMARY_CANCELLED.to_string()),
+    };
+
+    // Clean the output
+    let final_markdown = clean_llm_markdown_output(&raw_markdown);
+
+    info!("Summary generation completed successfully");
+    Ok((final_markdown, successful_chunk_count))
+}
+
+/// Structured variant of [`generate_meeting_summary`]
+///
+/// Condenses the transcript the same way, then asks for the report as JSON keyed
+/// by the template's section titles (see `summary::structured`). Responses that
+/// violate the template's schema are retried with the validation errors, up to
+/// `MAX_STRUCTURED_ATTEMPTS` times. The markdown is rendered from the validated
+/// JSON, so it always follows the template's layout.
+///
+/// # Returns
+/// Tuple of (rendered_markdown, structured_json, number_of_chunks_processed),
+/// or `SUMMARY_CANCELLED`
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_structured_meeting_summary(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    text: &str,
+    custom_prompt: &str,
+    template_id: &str,
+    token_threshold: usize,
+    ollama_endpoint: Option<&str>,
+    language: &str,
+    cancellation: &StreamCancellation,
+) -> Result<(String, Value, i64), String> {
+    info!(
+        "Starting structured summary generation with provider: {:?}, model: {}",
+        provider, model_name
+    );
+
+    let (content_to_summarize, successful_chunk_count) = condense_transcript(
+        client,
+        provider,
+        model_name,
+        api_key,
+        text,
+        token_threshold,
+        ollama_endpoint,
+        language,
+        cancellation,
+    )
+    .await?;
+
+    info!("Generating structured report with template: {}", template_id);
+
+    let template = templates::get_template(template_id)
+        .map_err(|e| format!("Failed to load template '{}': {}", template_id, e))?;
+
+    let schema = structured::template_json_schema(&template);
+    let schema_text = serde_json::to_string_pretty(&schema)
+        .map_err(|e| format!("Failed to serialize template schema: {}", e))?;
+    let system_prompt = prompts::get_structured_system_prompt_template(language)
+        .replacen("{}", &template.to_section_instructions(), 1)
+        .replacen("{}", &schema_text, 1);
+
+    let user_prompt = build_final_user_prompt(&content_to_summarize, custom_prompt, language);
+    let mut attempt_prompt = user_prompt.clone();
+    let mut attempt = 1;
+
+    loop {
+        if cancellation.is_cancelled() {
+            return Err(SUMMARY_CANCELLED.to_string());
+        }
+
+        let response = tokio::select! {
+            response = generate_summary_json(
+                client,
+                provider,
+                model_name,
+                api_key,
+                &system_prompt,
+                &attempt_prompt,
+                ollama_endpoint,
+                structured::STRUCTURED_SCHEMA_NAME,
+                &schema,
+            ) => response?,
+            _ = cancellation.cancelled() => return Err(SUMMARY_CANCELLED.to_string()),
+        };
+
+        match structured::parse_structured_summary(&response, &template) {
+            Ok(structured_summary) => {
+                let markdown =
+                    structured::render_structured_markdown(&structured_summary, &template, language);
+                info!(
+                    "Structured summary generation completed successfully (attempt {})",
+                    attempt
+                );
+                return Ok((markdown, structured_summary, successful_chunk_count));
+            }
+            Err(errors) => {
+                warn!(
+                    "Structured summary attempt {}/{} violated the template schema: {}",
+                    attempt,
+                    structured::MAX_STRUCTURED_ATTEMPTS,
+                    errors.join("; ")
+                );
+                if attempt == structured::MAX_STRUCTURED_ATTEMPTS {
+                    return Err(format!(
+                        "LLM response did not match the template schema after {} attempts: {}",
+                        attempt,
+                        errors.join("; ")
+                    ));
+                }
+                attempt_prompt = structured::build_retry_prompt(&user_prompt, &response, &errors);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Reduce the transcript to something that fits the final call: the transcript
+/// itself (single-pass), or the combined chunk summaries (multi-level).
+///
+/// Returns the content and the number of chunks processed.
+#[allow(clippy::too_many_arguments)]
+async fn condense_transcript(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    text: &str,
+    token_threshold: usize,
+    ollama_endpoint: Option<&str>,
+    language: &str,
+    cancellation: &StreamCancellation,
+) -> Result<(String, i64), String> {
     let total_tokens = rough_token_count(text);
     info!("Transcript length: {} tokens", total_tokens);
 
@@ -332,8 +537,15 @@ pub async fn generate_meeting_summary(
         let user_prompt_template_chunk = prompts::get_chunk_user_prompt_template(language);
 
         for (i, chunk) in chunks.iter().enumerate() {
+            if cancellation.is_cancelled() {
+                return Err(SUMMARY_CANCELLED.to_string());
+            }
             info!("⏲️ Processing chunk {}/{}", i + 1, num_chunks);
-            let user_prompt_chunk = user_prompt_template_chunk.replace("{}", chunk.as_str());
+            let mut user_prompt_chunk = user_prompt_template_chunk.replace("{}", chunk.as_str());
+            if has_low_confidence_marks(chunk) {
+                user_prompt_chunk.push_str("\n\n");
+                user_prompt_chunk.push_str(prompts::get_low_confidence_note(language));
+            }
 
             match generate_summary(
                 client,
@@ -370,6 +582,10 @@ pub async fn generate_meeting_summary(
         );
 
         // Combine chunk summaries if multiple chunks
+        if cancellation.is_cancelled() {
+            return Err(SUMMARY_CANCELLED.to_string());
+        }
+
         content_to_summarize = if chunk_summaries.len() > 1 {
             info!(
                 "Combining {} chunk summaries into cohesive summary",
@@ -398,24 +614,16 @@ pub async fn generate_meeting_summary(
         };
     }
 
-    info!("Generating final markdown report with template: {}", template_id);
-
-    // Load the template using the provided template_id
-    let template = templates::get_template(template_id)
-        .map_err(|e| format!("Failed to load template '{}': {}", template_id, e))?;
-
-    // Generate markdown structure and section instructions using template methods
-    let clean_template_markdown = template.to_markdown_structure();
-    let section_instructions = template.to_section_instructions();
-
-    // Get final prompt template in the appropriate language and format it
-    // Date: 13/11/2025 - Author: Luiz
-    let template_str = prompts::get_final_system_prompt_template(language);
-    // Since format! requires a string literal, we use string replace for dynamic templates
-    let final_system_prompt = template_str
-        .replacen("{}", &section_instructions, 1)
-        .replacen("{}", &clean_template_markdown, 1);
+    Ok((content_to_summarize, successful_chunk_count))
+}
 
+/// User prompt of the final call: the condensed transcript, a note on low-confidence
+/// words if it has any, plus any user-provided context
+fn build_final_user_prompt(
+    content_to_summarize: &str,
+    custom_prompt: &str,
+    language: &str,
+) -> String {
     let mut final_user_prompt = format!(
         r#"
 <transcript_chunks>
@@ -425,26 +633,16 @@ pub async fn generate_meeting_summary(
         content_to_summarize
     );
 
+    if has_low_confidence_marks(content_to_summarize) {
+        final_user_prompt.push('\n');
+        final_user_prompt.push_str(prompts::get_low_confidence_note(language));
+    }
+
     if !custom_prompt.is_empty() {
         final_user_prompt.push_str("\n\nUser Provided Context:\n\n<user_context>\n");
         final_user_prompt.push_str(custom_prompt);
         final_user_prompt.push_str("\n</user_context>");
     }
 
-    let raw_markdown = generate_summary(
-        client,
-        provider,
-        model_name,
-        api_key,
-        &final_system_prompt,
-        &final_user_prompt,
-        ollama_endpoint,
-    )
-    .await?;
-
-    // Clean the output
-    let final_markdown = clean_llm_markdown_output(&raw_markdown);
-
-    info!("Summary generation completed successfully");
-    Ok((final_markdown, successful_chunk_count))
+    final_user_prompt
 }
@@ -1,12 +1,20 @@
+use crate::action_items::service::{ActionItemService, ExtractionModel};
 use crate::database::repositories::{
-    meeting::MeetingsRepository, setting::SettingsRepository, summary::SummaryProcessesRepository,
+    meeting::MeetingsRepository,
+    setting::SettingsRepository,
+    summary::SummaryProcessesRepository,
+    summary_version::{NewSummaryVersion, SummaryVersionsRepository},
 };
 use crate::summary::llm_client::LLMProvider;
-use crate::summary::processor::{extract_meeting_name_from_markdown, generate_meeting_summary};
+use crate::summary::processor::{
+    extract_meeting_name_from_markdown, generate_meeting_summary,
+    generate_structured_meeting_summary,
+};
+use crate::summary::streaming::{self, StreamEvent, SUMMARY_STREAM_EVENT};
 use crate::ollama::metadata::ModelMetadataCache;
 use sqlx::SqlitePool;
 use std::time::{Duration, Instant};
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter};
 use tracing::{error, info, warn};
 use once_cell::sync::Lazy;
 
@@ -15,6 +23,14 @@ static METADATA_CACHE: Lazy<ModelMetadataCache> = Lazy::new(|| {
     ModelMetadataCache::new(Duration::from_secs(300))
 });
 
+/// Provider settings saved for a summary model
+pub struct ModelSettings {
+    pub provider: LLMProvider,
+    pub api_key: String,
+    pub ollama_endpoint: Option<String>,
+    pub language: String,
+}
+
 /// Summary service - handles all summary generation logic
 pub struct SummaryService;
 
@@ -51,7 +67,9 @@ impl SummaryService {
     ///
     /// **4. SUMMARY GENERATION (lines 119-131)**
     /// - Calls generate_meeting_summary() with all parameters
-    /// - Returns (final_markdown, chunk_count)
+    /// - Or generate_structured_meeting_summary() when structured output is requested,
+    ///   which validates the LLM's JSON against the template and renders the markdown
+    /// - Returns (final_markdown, chunk_count), plus the JSON in structured mode
     ///
     /// **5. RESULT POST-PROCESSING (lines 136-183)**
     /// - **Meeting Name Extraction**:
@@ -61,6 +79,7 @@ impl SummaryService {
     /// - **JSON Formatting**:
     ///   - Creates object `{"markdown": "...", "summary_json": null}`
     ///   - summary_json is filled later when user edits
+    ///   - In structured mode, the validated JSON is stored under `structured`
     ///
     /// **6. PERSISTENCE (lines 191-209)**
     /// - Updates summary_processes table:
@@ -70,6 +89,10 @@ impl SummaryService {
     ///   - processing_time: duration in seconds
     /// - On error, calls update_process_failed()
     ///
+    /// **7. ACTION ITEM EXTRACTION**
+    /// - Runs ActionItemService::extract_for_meeting() with the same model
+    /// - Failures are logged only; the summary is already saved
+    ///
     /// # Error Handling:
     ///
     /// - Invalid provider → Immediate failure
@@ -98,8 +121,10 @@ impl SummaryService {
     /// * `model_name` - Specific model (e.g., "gpt-4", "llama3.2:latest")
     /// * `custom_prompt` - Optional user-provided context
     /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
+    /// * `structured_output` - Ask for JSON keyed by the template's sections instead of markdown
+    #[allow(clippy::too_many_arguments)]
     pub async fn process_transcript_background<R: tauri::Runtime>(
-        _app: AppHandle<R>,
+        app: AppHandle<R>,
         pool: SqlitePool,
         meeting_id: String,
         text: String,
@@ -107,6 +132,7 @@ impl SummaryService {
         model_name: String,
         custom_prompt: String,
         template_id: String,
+        structured_output: bool,
     ) {
         let start_time = Instant::now();
         info!(
@@ -114,36 +140,188 @@ impl SummaryService {
             meeting_id
         );
 
-        // Parse provider
-        let provider = match LLMProvider::from_str(&model_provider) {
-            Ok(p) => p,
+        let settings = match Self::resolve_model_settings(&pool, &model_provider).await {
+            Ok(settings) => settings,
             Err(e) => {
                 Self::update_process_failed(&pool, &meeting_id, &e).await;
                 return;
             }
         };
+        let ModelSettings {
+            provider,
+            api_key,
+            ollama_endpoint,
+            language,
+        } = settings;
 
-        // Validate and setup api_key, Flexible for Ollama
-        let api_key = match SettingsRepository::get_api_key(&pool, &model_provider).await {
-            Ok(Some(key)) if !key.is_empty() => key,
-            Ok(None) | Ok(Some(_)) => {
-                if provider != LLMProvider::Ollama {
-                    let err_msg = format!("Api key not found for {}", &model_provider);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
+        // Dynamically fetch context size for Ollama models
+        let token_threshold =
+            Self::token_threshold(&provider, &model_name, ollama_endpoint.as_deref()).await;
+
+        // Register before the (possibly long) generation so it can be cancelled at any point
+        let registration = streaming::register_stream(streaming::summary_stream_key(&meeting_id));
+
+        // Generate summary
+        let client = reqwest::Client::new();
+        let result = if structured_output {
+            // JSON mode has nothing meaningful to stream; the rendered markdown
+            // is sent with the completed event
+            generate_structured_meeting_summary(
+                &client,
+                &provider,
+                &model_name,
+                &api_key,
+                &text,
+                &custom_prompt,
+                &template_id,
+                token_threshold,
+                ollama_endpoint.as_deref(),
+                &language,
+                registration.cancellation(),
+            )
+            .await
+            .map(|(markdown, structured, num_chunks)| (markdown, Some(structured), num_chunks))
+        } else {
+            generate_meeting_summary(
+                &client,
+                &provider,
+                &model_name,
+                &api_key,
+                &text,
+                &custom_prompt,
+                &template_id,
+                token_threshold,
+                ollama_endpoint.as_deref(),
+                &language,  // Pass language for dynamic prompts - Date: 13/11/2025 - Author: Luiz
+                registration.cancellation(),
+                |delta| {
+                    let _ = app.emit(SUMMARY_STREAM_EVENT, StreamEvent::token(&meeting_id, delta));
+                },
+            )
+            .await
+            .map(|(markdown, num_chunks)| (markdown, None, num_chunks))
+        };
+
+        let duration = start_time.elapsed().as_secs_f64();
+
+        match result {
+            Ok((mut final_markdown, structured, num_chunks)) => {
+                if num_chunks == 0 && final_markdown.is_empty() {
+                    Self::update_process_failed(
+                        &pool,
+                        &meeting_id,
+                        "Summary generation failed: No content was processed.",
+                    )
+                    .await;
                     return;
                 }
-                String::new()
+
+                info!(
+                    "✓ Successfully processed {} chunks for meeting_id: {}. Duration: {:.2}s",
+                    num_chunks, meeting_id, duration
+                );
+                info!("final markdown is {}", &final_markdown);
+
+                final_markdown =
+                    Self::apply_summary_title(&pool, &meeting_id, final_markdown).await;
+
+                // Create result JSON with markdown only (summary_json will be added on first edit)
+                let mut result_json = serde_json::json!({
+                    "markdown": final_markdown,
+                });
+                if let Some(structured) = structured {
+                    result_json["structured"] = structured;
+                }
+
+                let _ = app.emit(
+                    SUMMARY_STREAM_EVENT,
+                    StreamEvent::completed(&meeting_id, &final_markdown),
+                );
+
+                let new_version = NewSummaryVersion {
+                    meeting_id: &meeting_id,
+                    template_id: &template_id,
+                    provider: &model_provider,
+                    model: &model_name,
+                    custom_prompt: &custom_prompt,
+                    language: &language,
+                    processing_time: duration,
+                    chunk_count: num_chunks,
+                    result: &result_json,
+                };
+                Self::save_completed_run(&pool, &new_version).await;
+
+                // Action items get their own pass, matched against the open items of
+                // earlier meetings; a failure here leaves the summary untouched
+                let extraction_model = ExtractionModel {
+                    provider: &provider,
+                    model_name: &model_name,
+                    api_key: &api_key,
+                    ollama_endpoint: ollama_endpoint.as_deref(),
+                    token_threshold,
+                };
+                if let Err(e) = ActionItemService::extract_for_meeting(
+                    &app,
+                    &pool,
+                    &client,
+                    &extraction_model,
+                    &meeting_id,
+                    Some(&final_markdown),
+                    &language,
+                )
+                .await
+                {
+                    warn!("⚠️ Action item extraction failed for {}: {}", meeting_id, e);
+                }
+            }
+            Err(_) if registration.cancellation().is_cancelled() => {
+                info!("⏹️ Summary generation cancelled for meeting_id: {}", meeting_id);
+                // Replaced by a newer summary of the same meeting, which owns the
+                // process status and the stream from here on
+                if !registration.is_current() {
+                    return;
+                }
+                let _ = app.emit(SUMMARY_STREAM_EVENT, StreamEvent::cancelled(&meeting_id, ""));
+                if let Err(e) =
+                    SummaryProcessesRepository::update_process_cancelled(&pool, &meeting_id).await
+                {
+                    error!(
+                        "⚠️ Failed to update DB status to cancelled for {}: {}",
+                        meeting_id, e
+                    );
+                }
             }
             Err(e) => {
-                let err_msg = format!("Failed to retrieve api key for {} : {}", &model_provider, e);
-                Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                return;
+                let _ = app.emit(SUMMARY_STREAM_EVENT, StreamEvent::failed(&meeting_id, &e));
+                Self::update_process_failed(&pool, &meeting_id, &e).await;
+            }
+        }
+    }
+
+    /// Read the API key, Ollama endpoint and prompt language saved for a provider.
+    /// Fails on an unknown provider or a missing key for a cloud provider.
+    pub async fn resolve_model_settings(
+        pool: &SqlitePool,
+        model_provider: &str,
+    ) -> Result<ModelSettings, String> {
+        let provider = LLMProvider::from_str(model_provider)?;
+
+        // Validate and setup api_key, Flexible for Ollama
+        let api_key = match SettingsRepository::get_api_key(pool, model_provider).await {
+            Ok(Some(key)) if !key.is_empty() => key,
+            Ok(_) if provider == LLMProvider::Ollama => String::new(),
+            Ok(_) => return Err(format!("Api key not found for {}", model_provider)),
+            Err(e) => {
+                return Err(format!(
+                    "Failed to retrieve api key for {} : {}",
+                    model_provider, e
+                ))
             }
         };
 
         // Get Ollama endpoint if provider is Ollama
         let ollama_endpoint = if provider == LLMProvider::Ollama {
-            match SettingsRepository::get_model_config(&pool).await {
+            match SettingsRepository::get_model_config(pool).await {
                 Ok(Some(config)) => config.ollama_endpoint,
                 Ok(None) => None,
                 Err(e) => {
@@ -157,7 +335,7 @@ impl SummaryService {
 
         // Fetch user language preference for prompts
         // Date: 13/11/2025 - Author: Luiz
-        let language = match SettingsRepository::get_language(&pool).await {
+        let language = match SettingsRepository::get_language(pool).await {
             Ok(lang) => lang,
             Err(e) => {
                 warn!("Failed to fetch language setting: {}, defaulting to 'pt'", e);
@@ -165,9 +343,95 @@ impl SummaryService {
             }
         };
 
-        // Dynamically fetch context size for Ollama models
-        let token_threshold = if provider == LLMProvider::Ollama {
-            match METADATA_CACHE.get_or_fetch(&model_name, ollama_endpoint.as_deref()).await {
+        Ok(ModelSettings {
+            provider,
+            api_key,
+            ollama_endpoint,
+            language,
+        })
+    }
+
+    /// Rename the meeting after the summary's `# Title` line, if it has one, and
+    /// return the markdown without that line (avoids duplication in UI)
+    pub async fn apply_summary_title(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        mut final_markdown: String,
+    ) -> String {
+        if let Some(name) = extract_meeting_name_from_markdown(&final_markdown) {
+            if !name.is_empty() {
+                info!(
+                    "📝 Updating meeting name to '{}' for meeting_id: {}",
+                    name, meeting_id
+                );
+                if let Err(e) =
+                    MeetingsRepository::update_meeting_title(pool, meeting_id, &name).await
+                {
+                    error!("⚠️ Failed to update meeting name for {}: {}", meeting_id, e);
+                }
+
+                // Strip the title line from markdown
+                info!("✂️ Stripping title from final_markdown");
+                if let Some(hash_pos) = final_markdown.find('#') {
+                    // Find end of first line after '#'
+                    let body_start = if let Some(line_end) = final_markdown[hash_pos..].find('\n') {
+                        hash_pos + line_end
+                    } else {
+                        final_markdown.len() // No newline, whole string is title
+                    };
+
+                    final_markdown = final_markdown[body_start..].trim_start().to_string();
+                } else {
+                    // No '#' found, clear the string
+                    final_markdown.clear();
+                }
+            }
+        }
+        final_markdown
+    }
+
+    /// Keep a finished run in the version history and make it the meeting's
+    /// current summary. Failures are logged; the summary was already generated.
+    pub async fn save_completed_run(pool: &SqlitePool, new_version: &NewSummaryVersion<'_>) {
+        let meeting_id = new_version.meeting_id;
+
+        // Keep this run in the version history before it becomes the current summary
+        if let Err(e) = SummaryVersionsRepository::create_version(pool, new_version).await {
+            error!("⚠️ Failed to save summary version for {}: {}", meeting_id, e);
+        }
+
+        // Update database with completed status
+        if let Err(e) = SummaryProcessesRepository::update_process_completed(
+            pool,
+            meeting_id,
+            new_version.result.clone(),
+            new_version.chunk_count,
+            new_version.processing_time,
+        )
+        .await
+        {
+            error!(
+                "⚠️ Failed to save completed process for {}: {}",
+                meeting_id, e
+            );
+        } else {
+            info!(
+                "💾 Summary saved successfully for meeting_id: {}",
+                meeting_id
+            );
+        }
+    }
+
+    /// Token limit for single-pass processing with this model: the model's
+    /// context size (minus prompt overhead) for Ollama, effectively unlimited
+    /// for cloud providers
+    pub async fn token_threshold(
+        provider: &LLMProvider,
+        model_name: &str,
+        ollama_endpoint: Option<&str>,
+    ) -> usize {
+        if provider == &LLMProvider::Ollama {
+            match METADATA_CACHE.get_or_fetch(model_name, ollama_endpoint).await {
                 Ok(metadata) => {
                     // Reserve 300 tokens for prompt overhead
                     let optimal = metadata.context_size.saturating_sub(300);
@@ -188,105 +452,6 @@ impl SummaryService {
         } else {
             // Cloud providers (OpenAI, Claude, Groq) handle large contexts automatically
             100000  // Effectively unlimited for single-pass processing
-        };
-
-        // Generate summary
-        let client = reqwest::Client::new();
-        let result = generate_meeting_summary(
-            &client,
-            &provider,
-            &model_name,
-            &api_key,
-            &text,
-            &custom_prompt,
-            &template_id,
-            token_threshold,
-            ollama_endpoint.as_deref(),
-            &language,  // Pass language for dynamic prompts - Date: 13/11/2025 - Author: Luiz
-        )
-        .await;
-
-        let duration = start_time.elapsed().as_secs_f64();
-
-        match result {
-            Ok((mut final_markdown, num_chunks)) => {
-                if num_chunks == 0 && final_markdown.is_empty() {
-                    Self::update_process_failed(
-                        &pool,
-                        &meeting_id,
-                        "Summary generation failed: No content was processed.",
-                    )
-                    .await;
-                    return;
-                }
-
-                info!(
-                    "✓ Successfully processed {} chunks for meeting_id: {}. Duration: {:.2}s",
-                    num_chunks, meeting_id, duration
-                );
-                info!("final markdown is {}", &final_markdown);
-
-                // Extract and update meeting name if present
-                if let Some(name) = extract_meeting_name_from_markdown(&final_markdown) {
-                    if !name.is_empty() {
-                        info!(
-                            "📝 Updating meeting name to '{}' for meeting_id: {}",
-                            name, meeting_id
-                        );
-                        if let Err(e) =
-                            MeetingsRepository::update_meeting_title(&pool, &meeting_id, &name).await
-                        {
-                            error!("⚠️ Failed to update meeting name for {}: {}", meeting_id, e);
-                        }
-
-                        // Strip the title line from markdown
-                        info!("✂️ Stripping title from final_markdown");
-                        if let Some(hash_pos) = final_markdown.find('#') {
-                            // Find end of first line after '#'
-                            let body_start =
-                                if let Some(line_end) = final_markdown[hash_pos..].find('\n') {
-                                    hash_pos + line_end
-                                } else {
-                                    final_markdown.len() // No newline, whole string is title
-                                };
-
-                            final_markdown = final_markdown[body_start..].trim_start().to_string();
-                        } else {
-                            // No '#' found, clear the string
-                            final_markdown.clear();
-                        }
-                    }
-                }
-
-                // Create result JSON with markdown only (summary_json will be added on first edit)
-                let result_json = serde_json::json!({
-                    "markdown": final_markdown,
-                });
-
-                // Update database with completed status
-                if let Err(e) = SummaryProcessesRepository::update_process_completed(
-                    &pool,
-                    &meeting_id,
-                    result_json,
-                    num_chunks,
-                    duration,
-                )
-                .await
-                {
-                    error!(
-                        "⚠️ Failed to save completed process for {}: {}",
-                        meeting_id, e
-                    );
-                } else {
-                    info!(
-                        "💾 Summary saved successfully for meeting_id: {}",
-                        meeting_id
-                    );
-                }
-            }
-            Err(e) => {
-                Self::update_process_failed(&pool, &meeting_id, &e).await;
-            }
         }
     }
 
@@ -296,7 +461,7 @@ impl SummaryService {
     /// * `pool` - SQLx connection pool
     /// * `meeting_id` - Meeting identifier
     /// * `error_msg` - Error message to store
-    async fn update_process_failed(pool: &SqlitePool, meeting_id: &str, error_msg: &str) {
+    pub(crate) async fn update_process_failed(pool: &SqlitePool, meeting_id: &str, error_msg: &str) {
         error!(
             "❌ Processing failed for meeting_id {}: {}",
             meeting_id, error_msg
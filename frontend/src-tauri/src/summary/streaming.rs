@@ -0,0 +1,424 @@
+// summary/streaming.rs
+//
+// Streaming support for LLM calls: parsers for the provider wire formats
+// (SSE for OpenAI-compatible APIs and Anthropic, NDJSON for Ollama), the
+// per-meeting cancellation registry and the Tauri event payloads emitted
+// while tokens arrive.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+/// Event emitted while a meeting summary streams in
+pub const SUMMARY_STREAM_EVENT: &str = "summary-stream";
+
+/// Event emitted while a meeting chat answer streams in
+pub const CHAT_STREAM_EVENT: &str = "chat-stream";
+
+/// Result of a streamed completion
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamOutcome {
+    /// The provider finished; full (trimmed) text
+    Completed(String),
+    /// Cancelled by the user; text received so far
+    Cancelled(String),
+}
+
+/// One parsed line of a provider stream
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamChunk {
+    Token(String),
+    Done,
+    Error(String),
+    /// Keep-alives, comments, role deltas and other events without text
+    Skip,
+}
+
+/// Cancellation flag shared between a running stream and the cancel command
+#[derive(Debug, Default)]
+pub struct StreamCancellation {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl StreamCancellation {
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once `cancel` has been called
+    pub async fn cancelled(&self) {
+        loop {
+            // Register before checking the flag so a concurrent cancel() is not missed
+            let notified = self.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+static ACTIVE_STREAMS: Lazy<Mutex<HashMap<String, Arc<StreamCancellation>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Registry entry of a running stream; unregisters itself when dropped
+pub struct StreamRegistration {
+    key: String,
+    cancellation: Arc<StreamCancellation>,
+}
+
+impl StreamRegistration {
+    pub fn cancellation(&self) -> &StreamCancellation {
+        &self.cancellation
+    }
+
+    /// Owned handle to the flag, for work moved to another thread
+    pub fn shared_cancellation(&self) -> Arc<StreamCancellation> {
+        self.cancellation.clone()
+    }
+
+    /// Whether this is still the stream registered under its key, rather than
+    /// one a newer stream for the same key has replaced
+    pub fn is_current(&self) -> bool {
+        ACTIVE_STREAMS.lock().is_ok_and(|streams| self.is_current_in(&streams))
+    }
+
+    fn is_current_in(&self, streams: &HashMap<String, Arc<StreamCancellation>>) -> bool {
+        streams
+            .get(&self.key)
+            .is_some_and(|c| Arc::ptr_eq(c, &self.cancellation))
+    }
+}
+
+impl Drop for StreamRegistration {
+    fn drop(&mut self) {
+        if let Ok(mut streams) = ACTIVE_STREAMS.lock() {
+            // A newer stream for the same key may have replaced this one
+            if self.is_current_in(&streams) {
+                streams.remove(&self.key);
+            }
+        }
+    }
+}
+
+pub fn summary_stream_key(meeting_id: &str) -> String {
+    format!("summary:{}", meeting_id)
+}
+
+pub fn chat_stream_key(meeting_id: &str) -> String {
+    format!("chat:{}", meeting_id)
+}
+
+/// Library-wide answers are keyed by the request id the frontend sends with the
+/// question, which also stands in for the meeting id in their `chat-stream` events
+pub fn library_stream_key(request_id: &str) -> String {
+    format!("library:{}", request_id)
+}
+
+/// Register a new stream under `key`, cancelling any stream already running there
+pub fn register_stream(key: String) -> StreamRegistration {
+    let cancellation = Arc::new(StreamCancellation::default());
+    if let Ok(mut streams) = ACTIVE_STREAMS.lock() {
+        if let Some(previous) = streams.insert(key.clone(), cancellation.clone()) {
+            previous.cancel();
+        }
+    }
+    StreamRegistration { key, cancellation }
+}
+
+/// Cancel the stream registered under `key`. Returns false if none is running.
+pub fn cancel_stream(key: &str) -> bool {
+    match ACTIVE_STREAMS.lock() {
+        Ok(streams) => match streams.get(key) {
+            Some(cancellation) => {
+                cancellation.cancel();
+                true
+            }
+            None => false,
+        },
+        Err(_) => false,
+    }
+}
+
+/// Payload of `summary-stream` and `chat-stream` events
+#[derive(Debug, Clone, Serialize)]
+pub struct StreamEvent {
+    pub meeting_id: String,
+    /// "token", "completed", "cancelled" or "failed"
+    pub status: &'static str,
+    /// Newly received text (token events)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta: Option<String>,
+    /// Full text so far (completed/cancelled events)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl StreamEvent {
+    fn new(meeting_id: &str, status: &'static str) -> Self {
+        Self {
+            meeting_id: meeting_id.to_string(),
+            status,
+            delta: None,
+            text: None,
+            error: None,
+        }
+    }
+
+    pub fn token(meeting_id: &str, delta: &str) -> Self {
+        Self {
+            delta: Some(delta.to_string()),
+            ..Self::new(meeting_id, "token")
+        }
+    }
+
+    pub fn completed(meeting_id: &str, text: &str) -> Self {
+        Self {
+            text: Some(text.to_string()),
+            ..Self::new(meeting_id, "completed")
+        }
+    }
+
+    pub fn cancelled(meeting_id: &str, text: &str) -> Self {
+        Self {
+            text: Some(text.to_string()),
+            ..Self::new(meeting_id, "cancelled")
+        }
+    }
+
+    pub fn failed(meeting_id: &str, error: &str) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::new(meeting_id, "failed")
+        }
+    }
+}
+
+/// Splits a byte stream into lines. Network chunks can end mid-line or even
+/// mid-character, so bytes are buffered until a newline arrives.
+#[derive(Debug, Default)]
+pub struct LineBuffer {
+    pending: Vec<u8>,
+}
+
+impl LineBuffer {
+    /// Append bytes and return every complete line (without the line ending)
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.pending.extend_from_slice(bytes);
+        let mut lines = Vec::new();
+        while let Some(pos) = self.pending.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            lines.push(line.trim_end_matches(['\n', '\r']).to_string());
+        }
+        lines
+    }
+
+    /// The trailing line of a stream that did not end with a newline
+    pub fn finish(&mut self) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        let line = String::from_utf8_lossy(&self.pending).trim_end().to_string();
+        self.pending.clear();
+        Some(line)
+    }
+}
+
+/// Payload of an SSE `data:` line, if the line is one
+fn sse_data(line: &str) -> Option<&str> {
+    line.strip_prefix("data:").map(str::trim)
+}
+
+/// Parse one SSE line of an OpenAI-compatible chat completions stream
+pub fn parse_openai_line(line: &str) -> StreamChunk {
+    let Some(data) = sse_data(line) else {
+        return StreamChunk::Skip;
+    };
+    if data == "[DONE]" {
+        return StreamChunk::Done;
+    }
+    let Ok(value) = serde_json::from_str::<Value>(data) else {
+        return StreamChunk::Skip;
+    };
+    if let Some(message) = error_message(&value) {
+        return StreamChunk::Error(message);
+    }
+    match value["choices"][0]["delta"]["content"].as_str() {
+        Some(text) if !text.is_empty() => StreamChunk::Token(text.to_string()),
+        _ => StreamChunk::Skip,
+    }
+}
+
+/// Parse one SSE line of an Anthropic Messages stream
+pub fn parse_claude_line(line: &str) -> StreamChunk {
+    let Some(data) = sse_data(line) else {
+        return StreamChunk::Skip;
+    };
+    let Ok(value) = serde_json::from_str::<Value>(data) else {
+        return StreamChunk::Skip;
+    };
+    match value["type"].as_str() {
+        Some("content_block_delta") => match value["delta"]["text"].as_str() {
+            Some(text) if !text.is_empty() => StreamChunk::Token(text.to_string()),
+            _ => StreamChunk::Skip,
+        },
+        Some("message_stop") => StreamChunk::Done,
+        Some("error") => StreamChunk::Error(
+            error_message(&value).unwrap_or_else(|| "Unknown streaming error".to_string()),
+        ),
+        _ => StreamChunk::Skip,
+    }
+}
+
+/// Parse one NDJSON line of an Ollama /api/chat stream
+pub fn parse_ollama_line(line: &str) -> StreamChunk {
+    let line = line.trim();
+    if line.is_empty() {
+        return StreamChunk::Skip;
+    }
+    let Ok(value) = serde_json::from_str::<Value>(line) else {
+        return StreamChunk::Skip;
+    };
+    if let Some(message) = error_message(&value) {
+        return StreamChunk::Error(message);
+    }
+    if value["done"].as_bool() == Some(true) {
+        // The final object may still carry the last piece of text
+        return match value["message"]["content"].as_str() {
+            Some(text) if !text.is_empty() => StreamChunk::Token(text.to_string()),
+            _ => StreamChunk::Done,
+        };
+    }
+    match value["message"]["content"].as_str() {
+        Some(text) if !text.is_empty() => StreamChunk::Token(text.to_string()),
+        _ => StreamChunk::Skip,
+    }
+}
+
+/// `{"error": "..."}` or `{"error": {"message": "..."}}`
+fn error_message(value: &Value) -> Option<String> {
+    match &value["error"] {
+        Value::String(message) => Some(message.clone()),
+        Value::Object(error) => Some(
+            error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or("Unknown streaming error")
+                .to_string(),
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_buffer_handles_split_lines_and_utf8() {
+        let mut buffer = LineBuffer::default();
+        let text = "data: decisão\r\ndata: b\n";
+        let bytes = text.as_bytes();
+        // Split inside the two-byte "ã"
+        let split = text.find('ã').unwrap() + 1;
+
+        assert!(buffer.push(&bytes[..split]).is_empty());
+        assert_eq!(buffer.push(&bytes[split..]), vec!["data: decisão", "data: b"]);
+        assert_eq!(buffer.finish(), None);
+
+        buffer.push(b"{\"done\":true}");
+        assert_eq!(buffer.finish(), Some("{\"done\":true}".to_string()));
+    }
+
+    #[test]
+    fn test_parse_openai_line() {
+        assert_eq!(
+            parse_openai_line(r#"data: {"choices":[{"delta":{"content":"Hi"},"finish_reason":null}]}"#),
+            StreamChunk::Token("Hi".to_string())
+        );
+        assert_eq!(
+            parse_openai_line(r#"data: {"choices":[{"delta":{"role":"assistant"}}]}"#),
+            StreamChunk::Skip
+        );
+        assert_eq!(parse_openai_line(": OPENROUTER PROCESSING"), StreamChunk::Skip);
+        assert_eq!(parse_openai_line(""), StreamChunk::Skip);
+        assert_eq!(parse_openai_line("data: [DONE]"), StreamChunk::Done);
+        assert_eq!(
+            parse_openai_line(r#"data: {"error":{"message":"rate limited"}}"#),
+            StreamChunk::Error("rate limited".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_claude_line() {
+        assert_eq!(parse_claude_line("event: content_block_delta"), StreamChunk::Skip);
+        assert_eq!(
+            parse_claude_line(
+                r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hello"}}"#
+            ),
+            StreamChunk::Token("Hello".to_string())
+        );
+        assert_eq!(parse_claude_line(r#"data: {"type":"ping"}"#), StreamChunk::Skip);
+        assert_eq!(parse_claude_line(r#"data: {"type":"message_stop"}"#), StreamChunk::Done);
+        assert_eq!(
+            parse_claude_line(r#"data: {"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#),
+            StreamChunk::Error("Overloaded".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_ollama_line() {
+        assert_eq!(
+            parse_ollama_line(r#"{"model":"llama3","message":{"role":"assistant","content":"Ok"},"done":false}"#),
+            StreamChunk::Token("Ok".to_string())
+        );
+        assert_eq!(
+            parse_ollama_line(r#"{"model":"llama3","message":{"role":"assistant","content":""},"done":true}"#),
+            StreamChunk::Done
+        );
+        assert_eq!(
+            parse_ollama_line(r#"{"error":"model 'x' not found"}"#),
+            StreamChunk::Error("model 'x' not found".to_string())
+        );
+    }
+
+    #[test]
+    fn test_register_and_cancel_stream() {
+        let key = summary_stream_key("meeting-test");
+        let registration = register_stream(key.clone());
+        assert!(!registration.cancellation().is_cancelled());
+        assert!(cancel_stream(&key));
+        assert!(registration.cancellation().is_cancelled());
+
+        drop(registration);
+        assert!(!cancel_stream(&key));
+    }
+
+    #[test]
+    fn test_new_registration_cancels_previous() {
+        let key = chat_stream_key("meeting-replaced");
+        let first = register_stream(key.clone());
+        let second = register_stream(key.clone());
+        assert!(first.cancellation().is_cancelled());
+        assert!(!second.cancellation().is_cancelled());
+        assert!(!first.is_current());
+        assert!(second.is_current());
+
+        // Dropping the stale registration must not unregister the new one
+        drop(first);
+        assert!(cancel_stream(&key));
+    }
+}
@@ -0,0 +1,336 @@
+// summary/structured.rs
+//
+// Structured summary output: instead of free markdown, the LLM returns JSON
+// keyed by the template's section titles. The JSON is validated against the
+// template (every section present, with the type its `format` declares) and
+// rendered to markdown deterministically, so the same JSON always produces
+// the same report.
+
+use crate::summary::templates::{Template, TemplateSection};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+/// Attempts before giving up on a response that keeps violating the schema
+pub const MAX_STRUCTURED_ATTEMPTS: usize = 3;
+
+/// Name of the JSON schema / Anthropic tool that carries the summary
+pub const STRUCTURED_SCHEMA_NAME: &str = "meeting_summary";
+
+/// Thinking blocks some models put before their answer
+static THINKING_RE: Lazy<Result<Regex, regex::Error>> =
+    Lazy::new(|| Regex::new(r"(?s)<think(?:ing)?>.*?</think(?:ing)?>"));
+
+/// JSON schema of a template's structured summary:
+/// `{"meeting_title": "...", "sections": {"<section title>": <string | [string]>}}`
+pub fn template_json_schema(template: &Template) -> Value {
+    let mut properties = Map::new();
+    for section in &template.sections {
+        let schema = match section.format.as_str() {
+            "list" => json!({
+                "type": "array",
+                "items": { "type": "string" },
+                "description": section_description(section),
+            }),
+            _ => json!({
+                "type": "string",
+                "description": section_description(section),
+            }),
+        };
+        properties.insert(section.title.clone(), schema);
+    }
+    let required: Vec<&str> = template.sections.iter().map(|s| s.title.as_str()).collect();
+
+    json!({
+        "type": "object",
+        "properties": {
+            "meeting_title": {
+                "type": "string",
+                "description": "Concise, descriptive title for the meeting",
+            },
+            "sections": {
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            },
+        },
+        "required": ["meeting_title", "sections"],
+        "additionalProperties": false,
+    })
+}
+
+fn section_description(section: &TemplateSection) -> String {
+    match section.item_format.as_ref().or(section.example_item_format.as_ref()) {
+        Some(item_format) => format!(
+            "{} Each item is one row following: {}",
+            section.instruction, item_format
+        ),
+        None => section.instruction.clone(),
+    }
+}
+
+/// Parse the LLM output and validate it against the template.
+///
+/// Section keys are matched case-insensitively and normalized to the template's
+/// titles. On success returns `{"meeting_title", "sections"}` with sections in
+/// template order; otherwise every violation found, for the retry prompt.
+pub fn parse_structured_summary(raw: &str, template: &Template) -> Result<Value, Vec<String>> {
+    let value = extract_json(raw).ok_or_else(|| vec!["Response is not a JSON object".to_string()])?;
+
+    let mut errors = Vec::new();
+
+    let meeting_title = match value.get("meeting_title") {
+        Some(Value::String(title)) if !title.trim().is_empty() => title.trim().to_string(),
+        Some(Value::String(_)) => {
+            errors.push("\"meeting_title\" must not be empty".to_string());
+            String::new()
+        }
+        Some(_) => {
+            errors.push("\"meeting_title\" must be a string".to_string());
+            String::new()
+        }
+        None => {
+            errors.push("Missing \"meeting_title\"".to_string());
+            String::new()
+        }
+    };
+
+    let Some(sections) = value.get("sections").and_then(Value::as_object) else {
+        errors.push("Missing \"sections\" object".to_string());
+        return Err(errors);
+    };
+
+    let mut normalized = Map::new();
+    for section in &template.sections {
+        let found = sections
+            .iter()
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(section.title.trim()));
+
+        let Some((_, content)) = found else {
+            errors.push(format!("Missing section \"{}\"", section.title));
+            continue;
+        };
+
+        match (section.format.as_str(), content) {
+            ("list", Value::Array(items)) => {
+                let mut list = Vec::new();
+                for (i, item) in items.iter().enumerate() {
+                    match item {
+                        Value::String(text) if !text.trim().is_empty() => {
+                            list.push(Value::String(text.trim().to_string()))
+                        }
+                        Value::String(_) => {}
+                        _ => errors.push(format!(
+                            "Item {} of section \"{}\" must be a string",
+                            i, section.title
+                        )),
+                    }
+                }
+                normalized.insert(section.title.clone(), Value::Array(list));
+            }
+            ("list", _) => errors.push(format!(
+                "Section \"{}\" must be an array of strings",
+                section.title
+            )),
+            (_, Value::String(text)) => {
+                normalized.insert(section.title.clone(), Value::String(text.trim().to_string()));
+            }
+            (_, _) => errors.push(format!("Section \"{}\" must be a string", section.title)),
+        }
+    }
+
+    for key in sections.keys() {
+        let known = template
+            .sections
+            .iter()
+            .any(|s| key.trim().eq_ignore_ascii_case(s.title.trim()));
+        if !known {
+            errors.push(format!("Unexpected section \"{}\"", key));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(json!({ "meeting_title": meeting_title, "sections": normalized }))
+    } else {
+        Err(errors)
+    }
+}
+
+/// The JSON object in an LLM response, ignoring thinking blocks, code fences
+/// and any prose around it
+fn extract_json(raw: &str) -> Option<Value> {
+    let text = match &*THINKING_RE {
+        Ok(regex) => regex.replace_all(raw, ""),
+        Err(_) => raw.into(), // Fallback if regex failed to compile
+    };
+    let start = text.find('{')?;
+    let end = text.rfind('}')?;
+    if end < start {
+        return None;
+    }
+    serde_json::from_str::<Value>(&text[start..=end])
+        .ok()
+        .filter(Value::is_object)
+}
+
+/// Render a validated structured summary to markdown, in template order.
+///
+/// Uses the same layout as the markdown mode (`# Title`, then `**Section**`
+/// headings). List items that are table rows are emitted under the section's
+/// table header; other items become bullets.
+pub fn render_structured_markdown(structured: &Value, template: &Template, language: &str) -> String {
+    let empty_section = match language {
+        "pt" => "Nada observado nesta seção.",
+        _ => "None noted in this section.",
+    };
+
+    let mut markdown = String::new();
+    if let Some(title) = structured["meeting_title"].as_str() {
+        markdown.push_str(&format!("# {}\n\n", title));
+    }
+
+    for section in &template.sections {
+        markdown.push_str(&format!("**{}**\n\n", section.title));
+
+        match &structured["sections"][&section.title] {
+            Value::Array(items) if !items.is_empty() => {
+                let items: Vec<&str> = items.iter().filter_map(Value::as_str).collect();
+                let table_header = section
+                    .item_format
+                    .as_ref()
+                    .or(section.example_item_format.as_ref())
+                    .filter(|f| f.trim_start().starts_with('|'));
+
+                if let (Some(header), true) = (table_header, items.iter().all(|i| i.starts_with('|'))) {
+                    markdown.push_str(header.trim());
+                    markdown.push('\n');
+                    for item in items {
+                        markdown.push_str(item);
+                        markdown.push('\n');
+                    }
+                } else {
+                    for item in items {
+                        markdown.push_str(&format!("- {}\n", item));
+                    }
+                }
+            }
+            Value::String(text) if !text.is_empty() => {
+                markdown.push_str(text);
+                markdown.push('\n');
+            }
+            _ => {
+                markdown.push_str(empty_section);
+                markdown.push('\n');
+            }
+        }
+        markdown.push('\n');
+    }
+
+    markdown.trim_end().to_string()
+}
+
+/// Follow-up prompt after a response that failed validation
+pub fn build_retry_prompt(user_prompt: &str, previous_response: &str, errors: &[String]) -> String {
+    let errors = errors
+        .iter()
+        .map(|e| format!("- {}", e))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "{}\n\n<previous_response>\n{}\n</previous_response>\n\nYour previous response did not match the required JSON schema:\n{}\n\nReturn only the corrected JSON object.",
+        user_prompt, previous_response, errors
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(title: &str, format: &str, item_format: Option<&str>) -> TemplateSection {
+        TemplateSection {
+            title: title.to_string(),
+            instruction: format!("Fill {}", title),
+            format: format.to_string(),
+            item_format: item_format.map(str::to_string),
+            example_item_format: None,
+        }
+    }
+
+    fn template() -> Template {
+        Template {
+            name: "Test".to_string(),
+            description: "Test".to_string(),
+            sections: vec![
+                section("Summary", "paragraph", None),
+                section("Key Points", "list", None),
+                section(
+                    "Action Items",
+                    "list",
+                    Some("| **Owner** | **Task** |\n| --- | --- |"),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_schema_lists_every_section() {
+        let schema = template_json_schema(&template());
+        let sections = &schema["properties"]["sections"];
+        assert_eq!(sections["required"], json!(["Summary", "Key Points", "Action Items"]));
+        assert_eq!(sections["properties"]["Summary"]["type"], "string");
+        assert_eq!(sections["properties"]["Key Points"]["type"], "array");
+    }
+
+    #[test]
+    fn test_parse_valid_response_in_code_fence() {
+        let raw = r#"<think>plan</think>
+```json
+{"meeting_title": "Billing sync", "sections": {
+  "summary": "We agreed on Friday.",
+  "Key Points": ["Ship billing", ""],
+  "Action Items": ["| Ana | Deploy |"]
+}}
+```"#;
+        let parsed = parse_structured_summary(raw, &template()).unwrap();
+        assert_eq!(parsed["meeting_title"], "Billing sync");
+        // Keys are normalized to the template's titles and empty items dropped
+        assert_eq!(parsed["sections"]["Summary"], "We agreed on Friday.");
+        assert_eq!(parsed["sections"]["Key Points"], json!(["Ship billing"]));
+    }
+
+    #[test]
+    fn test_parse_reports_every_violation() {
+        let raw = r#"{"sections": {"Summary": ["not", "a", "paragraph"], "Key Points": "one", "Extra": ""}}"#;
+        let errors = parse_structured_summary(raw, &template()).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                "Missing \"meeting_title\"",
+                "Section \"Summary\" must be a string",
+                "Section \"Key Points\" must be an array of strings",
+                "Missing section \"Action Items\"",
+                "Unexpected section \"Extra\"",
+            ]
+        );
+        assert!(parse_structured_summary("Sorry, I can't help", &template()).is_err());
+    }
+
+    #[test]
+    fn test_render_is_deterministic() {
+        let structured = json!({
+            "meeting_title": "Billing sync",
+            "sections": {
+                "Action Items": ["| Ana | Deploy |"],
+                "Summary": "We agreed on Friday.",
+                "Key Points": [],
+            }
+        });
+        let markdown = render_structured_markdown(&structured, &template(), "en");
+        assert_eq!(
+            markdown,
+            "# Billing sync\n\n**Summary**\n\nWe agreed on Friday.\n\n**Key Points**\n\nNone noted in this section.\n\n**Action Items**\n\n| **Owner** | **Task** |\n| --- | --- |\n| Ana | Deploy |"
+        );
+        assert_eq!(markdown, render_structured_markdown(&structured, &template(), "en"));
+    }
+}
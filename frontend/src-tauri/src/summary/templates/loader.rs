@@ -117,6 +117,40 @@ pub fn get_template(template_id: &str) -> Result<Template, String> {
     validate_and_parse_template(&json_content)
 }
 
+/// Save a template to the user's custom templates directory, for templates
+/// that arrive with meetings from another library
+///
+/// # Arguments
+/// * `template_id` - Template identifier, made of letters, digits, `-` and `_`
+/// * `template` - Template to save; it is validated first
+///
+/// # Returns
+/// Path of the saved template file
+pub fn save_custom_template(template_id: &str, template: &Template) -> Result<PathBuf, String> {
+    let valid_id = !template_id.is_empty()
+        && template_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid_id {
+        return Err(format!("Invalid template identifier '{}'", template_id));
+    }
+    template.validate()?;
+
+    let custom_dir = get_custom_templates_dir()
+        .ok_or_else(|| "Custom templates directory not available".to_string())?;
+    std::fs::create_dir_all(&custom_dir)
+        .map_err(|e| format!("Failed to create custom templates directory: {}", e))?;
+
+    let template_path = custom_dir.join(format!("{}.json", template_id));
+    let json_content = serde_json::to_string_pretty(template)
+        .map_err(|e| format!("Failed to serialize template: {}", e))?;
+    std::fs::write(&template_path, json_content)
+        .map_err(|e| format!("Failed to save template: {}", e))?;
+
+    info!("Saved custom template '{}' to {:?}", template_id, template_path);
+    Ok(template_path)
+}
+
 /// Validate and parse template JSON
 ///
 /// # Arguments
@@ -43,8 +43,8 @@ mod types;
 
 // Re-export public API
 pub use loader::{
-    get_template, list_template_ids, list_templates, set_bundled_templates_dir,
-    validate_and_parse_template,
+    get_template, list_template_ids, list_templates, save_custom_template,
+    set_bundled_templates_dir, validate_and_parse_template,
 };
 pub use types::{Template, TemplateSection};
 
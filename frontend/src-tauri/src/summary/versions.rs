@@ -0,0 +1,174 @@
+// summary/versions.rs
+//
+// Comparing summary versions: extracts the markdown of a stored result and
+// produces a line diff, so outputs of different models or templates for the
+// same meeting can be compared side by side.
+
+use crate::database::models::SummaryVersion;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Above this many cells the LCS table gets too large; fall back to a plain replace
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffKind {
+    Equal,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+/// Line diff between two versions of a meeting's summary
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryVersionDiff {
+    pub from: SummaryVersion,
+    pub to: SummaryVersion,
+    pub lines: Vec<DiffLine>,
+    pub added: usize,
+    pub removed: usize,
+}
+
+impl SummaryVersionDiff {
+    pub fn new(from: SummaryVersion, to: SummaryVersion) -> Self {
+        let lines = diff_lines(&summary_markdown(&from.result), &summary_markdown(&to.result));
+        let added = lines.iter().filter(|l| l.kind == DiffKind::Added).count();
+        let removed = lines.iter().filter(|l| l.kind == DiffKind::Removed).count();
+        Self {
+            from,
+            to,
+            lines,
+            added,
+            removed,
+        }
+    }
+}
+
+/// Markdown of a stored summary result. Results are `{"markdown": ...}`; older
+/// section-based results are compared as pretty-printed JSON.
+pub fn summary_markdown(result: &str) -> String {
+    match serde_json::from_str::<Value>(result) {
+        Ok(value) => match value.get("markdown").and_then(Value::as_str) {
+            Some(markdown) => markdown.to_string(),
+            None => serde_json::to_string_pretty(&value).unwrap_or_else(|_| result.to_string()),
+        },
+        Err(_) => result.to_string(),
+    }
+}
+
+/// Longest-common-subsequence line diff of `old` and `new`
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Common prefix and suffix don't need the LCS table
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let line = |kind, text: &str| DiffLine {
+        kind,
+        text: text.to_string(),
+    };
+
+    let mut lines: Vec<DiffLine> = old[..prefix].iter().map(|t| line(DiffKind::Equal, t)).collect();
+
+    if (old_mid.len() + 1) * (new_mid.len() + 1) > MAX_DIFF_CELLS {
+        lines.extend(old_mid.iter().map(|t| line(DiffKind::Removed, t)));
+        lines.extend(new_mid.iter().map(|t| line(DiffKind::Added, t)));
+    } else {
+        // lcs[i][j] = LCS length of old_mid[i..] and new_mid[j..]
+        let (n, m) = (old_mid.len(), new_mid.len());
+        let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i][j] = if old_mid[i] == new_mid[j] {
+                    lcs[i + 1][j + 1] + 1
+                } else {
+                    lcs[i + 1][j].max(lcs[i][j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            if i < n && j < m && old_mid[i] == new_mid[j] {
+                lines.push(line(DiffKind::Equal, old_mid[i]));
+                i += 1;
+                j += 1;
+            } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+                lines.push(line(DiffKind::Removed, old_mid[i]));
+                i += 1;
+            } else {
+                lines.push(line(DiffKind::Added, new_mid[j]));
+                j += 1;
+            }
+        }
+    }
+
+    lines.extend(old[old.len() - suffix..].iter().map(|t| line(DiffKind::Equal, t)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(lines: &[DiffLine]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| {
+                let sign = match l.kind {
+                    DiffKind::Equal => ' ',
+                    DiffKind::Added => '+',
+                    DiffKind::Removed => '-',
+                };
+                format!("{}{}", sign, l.text)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let old = "# Summary\n- Ship on Friday\n- Owner: Ana\n## Risks\nNone";
+        let new = "# Summary\n- Ship on Monday\n- Owner: Ana\n- QA signs off\n## Risks\nNone";
+        assert_eq!(
+            render(&diff_lines(old, new)),
+            vec![
+                " # Summary",
+                "-- Ship on Friday",
+                "+- Ship on Monday",
+                " - Owner: Ana",
+                "+- QA signs off",
+                " ## Risks",
+                " None",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_identical_and_empty() {
+        assert!(diff_lines("a\nb", "a\nb").iter().all(|l| l.kind == DiffKind::Equal));
+        assert_eq!(render(&diff_lines("", "a")), vec!["+a"]);
+        assert_eq!(render(&diff_lines("a", "")), vec!["-a"]);
+    }
+
+    #[test]
+    fn test_summary_markdown() {
+        assert_eq!(summary_markdown(r##"{"markdown":"# Notes\n- a"}"##), "# Notes\n- a");
+        assert!(summary_markdown(r#"{"Agenda":{"title":"Agenda","blocks":[]}}"#).contains("\"Agenda\""));
+        assert_eq!(summary_markdown("plain text"), "plain text");
+    }
+}
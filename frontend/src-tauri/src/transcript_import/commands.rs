@@ -0,0 +1,48 @@
+use super::{import_transcript_file, SUPPORTED_EXTENSIONS};
+use crate::state::AppState;
+use log::{error as log_error, info as log_info};
+use std::path::Path;
+use tauri::{AppHandle, Runtime};
+
+/// Open a dialog to pick a transcript file (WebVTT, SRT, Zoom text or Otter JSON)
+#[tauri::command]
+pub async fn select_transcript_file<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Option<String>, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let file_path = app
+        .dialog()
+        .file()
+        .add_filter("Transcripts", &SUPPORTED_EXTENSIONS)
+        .blocking_pick_file();
+
+    Ok(file_path.map(|path| path.to_string()))
+}
+
+/// Import an exported transcript as a new meeting. Returns the meeting's id.
+#[tauri::command]
+pub async fn api_import_transcript_file<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    file_path: String,
+    title: Option<String>,
+) -> Result<String, String> {
+    log_info!("api_import_transcript_file called for: {}", file_path);
+
+    let pool = state.db_manager.pool();
+    match import_transcript_file(pool, Path::new(&file_path), title).await {
+        Ok(meeting_id) => {
+            log_info!(
+                "✅ Imported transcript {} as meeting {}",
+                file_path,
+                meeting_id
+            );
+            Ok(meeting_id)
+        }
+        Err(e) => {
+            log_error!("❌ Import of transcript {} failed: {}", file_path, e);
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,242 @@
+// transcript_import/mod.rs
+//
+// Import of transcripts exported by other tools (WebVTT, SRT, Teams, Zoom and
+// Otter) as meetings. Imported meetings have no recording; their segments are
+// stored like recorded ones, so summaries, chat and search work the same way.
+
+pub mod commands;
+pub mod parsers;
+
+pub use commands::*;
+
+use crate::api::TranscriptSegment;
+use crate::database::repositories::speaker::SpeakersRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::diarization::{default_speaker_name, speaker_id};
+use crate::utils::format_timestamp;
+use log::{info, warn};
+use parsers::ParsedSegment;
+use sqlx::SqlitePool;
+use std::path::Path;
+
+/// File extensions the transcript import accepts
+pub const SUPPORTED_EXTENSIONS: [&str; 4] = ["vtt", "srt", "txt", "json"];
+
+/// Consecutive cues of one speaker at most this far apart are joined
+const MERGE_MAX_GAP_SECONDS: f64 = 1.0;
+
+/// Longest segment produced by joining cues
+const MERGE_MAX_DURATION_SECONDS: f64 = 30.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptFileFormat {
+    /// WebVTT, including Teams and Zoom `.vtt` transcripts
+    WebVtt,
+    Srt,
+    /// Zoom's plain text transcript
+    ZoomText,
+    /// Otter's JSON export
+    OtterJson,
+}
+
+impl TranscriptFileFormat {
+    /// Format from the file's content, falling back to its extension
+    pub fn detect(path: &Path, content: &str) -> Result<Self, String> {
+        if content
+            .trim_start_matches('\u{feff}')
+            .trim_start()
+            .starts_with("WEBVTT")
+        {
+            return Ok(Self::WebVtt);
+        }
+
+        let extension = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        match extension.as_str() {
+            "vtt" => Ok(Self::WebVtt),
+            "srt" => Ok(Self::Srt),
+            "txt" => Ok(Self::ZoomText),
+            "json" => Ok(Self::OtterJson),
+            _ => Err(format!(
+                "Unsupported transcript file: expected one of {}",
+                SUPPORTED_EXTENSIONS.join(", ")
+            )),
+        }
+    }
+
+    pub fn parse(self, content: &str) -> Result<Vec<ParsedSegment>, String> {
+        match self {
+            Self::WebVtt => parsers::parse_vtt(content),
+            Self::Srt => parsers::parse_srt(content),
+            Self::ZoomText => parsers::parse_zoom_text(content),
+            Self::OtterJson => parsers::parse_otter_json(content),
+        }
+    }
+}
+
+/// A parsed transcript ready to be saved
+#[derive(Debug, Clone)]
+pub struct ImportedTranscript {
+    pub segments: Vec<TranscriptSegment>,
+    /// Display name of each speaker id used in `segments`
+    pub speaker_names: Vec<(String, String)>,
+}
+
+/// Parse a transcript file's content into meeting segments
+pub fn parse_transcript(path: &Path, content: &str) -> Result<ImportedTranscript, String> {
+    let format = TranscriptFileFormat::detect(path, content)?;
+    let parsed = format.parse(content)?;
+    let parsed =
+        parsers::merge_consecutive(parsed, MERGE_MAX_GAP_SECONDS, MERGE_MAX_DURATION_SECONDS);
+    if parsed.is_empty() {
+        return Err("The transcript file contains no text".to_string());
+    }
+    Ok(to_segments(parsed))
+}
+
+/// Speakers become `speaker_N` ids in order of first appearance, like
+/// diarized speakers, with their exported names as display names
+fn to_segments(parsed: Vec<ParsedSegment>) -> ImportedTranscript {
+    let mut speaker_names: Vec<(String, String)> = Vec::new();
+
+    let segments = parsed
+        .into_iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            let speaker = segment.speaker.map(|name| {
+                match speaker_names.iter().find(|(_, existing)| *existing == name) {
+                    Some((id, _)) => id.clone(),
+                    None => {
+                        let id = speaker_id(speaker_names.len());
+                        speaker_names.push((id.clone(), name));
+                        id
+                    }
+                }
+            });
+
+            TranscriptSegment {
+                id: format!("import-{}", index),
+                text: segment.text,
+                timestamp: format_timestamp(segment.start),
+                audio_start_time: Some(segment.start),
+                audio_end_time: Some(segment.end),
+                duration: Some(segment.end - segment.start),
+                words: Vec::new(),
+                speaker,
+                source: None,
+                confidence: None,
+            }
+        })
+        .collect();
+
+    ImportedTranscript {
+        segments,
+        speaker_names,
+    }
+}
+
+/// Import a transcript file as a new meeting titled after the file unless a
+/// title is given. Returns the new meeting's id.
+pub async fn import_transcript_file(
+    pool: &SqlitePool,
+    path: &Path,
+    title: Option<String>,
+) -> Result<String, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let imported = parse_transcript(path, &content)?;
+
+    let title = title
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .or_else(|| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| "Imported transcript".to_string());
+
+    let meeting_id = TranscriptsRepository::save_transcript(pool, &title, &imported.segments, None)
+        .await
+        .map_err(|e| format!("Failed to save transcript: {}", e))?;
+
+    for (speaker, name) in &imported.speaker_names {
+        if *name == default_speaker_name(speaker) {
+            continue;
+        }
+        // The transcript is saved either way; the speaker keeps its default label
+        if let Err(e) = SpeakersRepository::rename_speaker(pool, &meeting_id, speaker, name).await {
+            warn!(
+                "Failed to name {} '{}' in meeting {}: {}",
+                speaker, name, meeting_id, e
+            );
+        }
+    }
+
+    info!(
+        "Imported {} segments and {} speakers from {} as meeting {}",
+        imported.segments.len(),
+        imported.speaker_names.len(),
+        path.display(),
+        meeting_id
+    );
+    Ok(meeting_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_format() {
+        let detect =
+            |name: &str, content: &str| TranscriptFileFormat::detect(Path::new(name), content);
+        assert_eq!(detect("call.vtt", ""), Ok(TranscriptFileFormat::WebVtt));
+        assert_eq!(
+            detect("call.txt", "\u{feff}WEBVTT\n"),
+            Ok(TranscriptFileFormat::WebVtt)
+        );
+        assert_eq!(detect("call.SRT", ""), Ok(TranscriptFileFormat::Srt));
+        assert_eq!(
+            detect("call.txt", "[Ann] 10:00:00"),
+            Ok(TranscriptFileFormat::ZoomText)
+        );
+        assert_eq!(
+            detect("call.json", "{}"),
+            Ok(TranscriptFileFormat::OtterJson)
+        );
+        assert!(detect("call.docx", "").is_err());
+    }
+
+    #[test]
+    fn test_parse_transcript_assigns_speakers() {
+        let vtt = "WEBVTT\n\n00:00:01.000 --> 00:00:02.000\n<v Alice>Hello</v>\n\n\
+                   00:00:02.200 --> 00:00:03.000\n<v Alice>everyone</v>\n\n\
+                   00:00:03.500 --> 00:00:05.000\n<v Bob>Hi Alice</v>\n\n\
+                   00:00:06.000 --> 00:00:08.000\n<v Alice>Let's begin</v>\n";
+        let imported = parse_transcript(Path::new("sync.vtt"), vtt).unwrap();
+
+        let speakers: Vec<_> = imported
+            .segments
+            .iter()
+            .map(|s| s.speaker.as_deref())
+            .collect();
+        assert_eq!(
+            speakers,
+            vec![Some("speaker_1"), Some("speaker_2"), Some("speaker_1")]
+        );
+        assert_eq!(imported.segments[0].text, "Hello everyone");
+        assert_eq!(imported.segments[0].audio_end_time, Some(3.0));
+        assert_eq!(imported.segments[2].timestamp, "00:00:06");
+        assert_eq!(
+            imported.speaker_names,
+            vec![
+                ("speaker_1".to_string(), "Alice".to_string()),
+                ("speaker_2".to_string(), "Bob".to_string()),
+            ]
+        );
+
+        assert!(parse_transcript(Path::new("empty.vtt"), "WEBVTT\n").is_err());
+    }
+}
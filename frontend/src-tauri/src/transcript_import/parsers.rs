@@ -0,0 +1,572 @@
+// transcript_import/parsers.rs
+//
+// Parsers for transcripts exported by meeting platforms. Each returns segments
+// with times in seconds from the start of the meeting and the speaker's name
+// when the export has one.
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Speaking rate used to estimate how long an entry lasts when the export only
+/// gives its start time
+const WORDS_PER_SECOND: f64 = 2.5;
+
+/// Longest a speaker prefix (`Name: text`) can be
+const MAX_SPEAKER_PREFIX_CHARS: usize = 40;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedSegment {
+    pub start: f64,
+    pub end: f64,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+/// An entry of a format that may only give start times
+struct Entry {
+    start: f64,
+    end: Option<f64>,
+    speaker: Option<String>,
+    text: String,
+}
+
+/// WebVTT, including Teams transcripts (`<v Speaker>` voice spans) and Zoom
+/// recording transcripts (`Speaker: ` prefixes)
+pub fn parse_vtt(content: &str) -> Result<Vec<ParsedSegment>, String> {
+    let content = normalize_newlines(content);
+    if !content.trim_start().starts_with("WEBVTT") {
+        return Err("Not a WebVTT file (missing WEBVTT header)".to_string());
+    }
+
+    let segments = parse_cue_blocks(&content, |payload| {
+        let speaker = vtt_voice(payload);
+        (speaker, clean_text(payload))
+    });
+    Ok(with_prefix_speakers(segments))
+}
+
+/// SubRip subtitles; speakers only when lines carry `Speaker: ` prefixes
+pub fn parse_srt(content: &str) -> Result<Vec<ParsedSegment>, String> {
+    let content = normalize_newlines(content);
+    let segments = parse_cue_blocks(&content, |payload| (None, clean_text(payload)));
+    if segments.is_empty() {
+        return Err("No subtitles found in the SRT file".to_string());
+    }
+    Ok(with_prefix_speakers(segments))
+}
+
+/// Zoom transcript text. Entries are either a `[Name] HH:MM:SS` line followed
+/// by the text, or single `HH:MM:SS Name: text` lines. Times are made relative
+/// to the first entry, since Zoom writes the time of day.
+pub fn parse_zoom_text(content: &str) -> Result<Vec<ParsedSegment>, String> {
+    let content = normalize_newlines(content);
+    let mut entries: Vec<Entry> = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() {
+            continue;
+        }
+
+        // [Name] 10:01:15
+        if let Some((speaker, time)) = line
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once(']'))
+            .and_then(|(speaker, rest)| Some((speaker, parse_timestamp(rest.trim())?)))
+        {
+            entries.push(Entry {
+                start: time,
+                end: None,
+                speaker: Some(speaker.trim().to_string()).filter(|s| !s.is_empty()),
+                text: String::new(),
+            });
+            continue;
+        }
+
+        // 10:01:15 Name: text
+        if let Some((time, rest)) = line
+            .split_once(char::is_whitespace)
+            .and_then(|(time, rest)| Some((parse_timestamp(time)?, rest.trim())))
+        {
+            let (speaker, text) = match split_speaker_prefix(rest) {
+                Some((speaker, text)) => (Some(speaker.to_string()), text.to_string()),
+                None => (None, rest.to_string()),
+            };
+            entries.push(Entry {
+                start: time,
+                end: None,
+                speaker,
+                text,
+            });
+            continue;
+        }
+
+        // Text of the current entry
+        match entries.last_mut() {
+            Some(entry) => {
+                if !entry.text.is_empty() {
+                    entry.text.push(' ');
+                }
+                entry.text.push_str(line);
+            }
+            None => return Err("Not a Zoom transcript (no timestamped entries)".to_string()),
+        }
+    }
+
+    if entries.is_empty() {
+        return Err("Not a Zoom transcript (no timestamped entries)".to_string());
+    }
+
+    // Time of day: relative to the first entry, across midnight if needed
+    let first = entries[0].start;
+    let mut previous = first;
+    let mut day_offset = 0.0;
+    for entry in &mut entries {
+        if entry.start + day_offset < previous {
+            day_offset += 24.0 * 3600.0;
+        }
+        entry.start += day_offset;
+        previous = entry.start;
+        entry.start -= first;
+    }
+
+    Ok(finish_entries(entries))
+}
+
+/// Otter JSON export: `transcripts` (optionally under `speech`) with
+/// `start_offset`/`end_offset` in milliseconds or `start`/`end` in seconds,
+/// and the speaker by name or by id into `speakers`
+pub fn parse_otter_json(content: &str) -> Result<Vec<ParsedSegment>, String> {
+    let root: Value =
+        serde_json::from_str(content).map_err(|e| format!("Invalid JSON transcript: {}", e))?;
+    let speech = root.get("speech").unwrap_or(&root);
+
+    let items = speech
+        .get("transcripts")
+        .or_else(|| speech.get("segments"))
+        .or(Some(speech))
+        .and_then(Value::as_array)
+        .ok_or_else(|| "Not an Otter transcript (no transcripts list)".to_string())?;
+
+    let speakers: HashMap<String, String> = speech
+        .get("speakers")
+        .and_then(Value::as_array)
+        .map(|speakers| {
+            speakers
+                .iter()
+                .filter_map(|speaker| {
+                    let id = json_id(speaker.get("id")?)?;
+                    let name = speaker
+                        .get("speaker_name")
+                        .or_else(|| speaker.get("name"))
+                        .and_then(Value::as_str)?;
+                    Some((id, name.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut entries = Vec::new();
+    for item in items {
+        let Some(text) = item
+            .get("transcript")
+            .or_else(|| item.get("text"))
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+        else {
+            continue;
+        };
+        let Some(start) = json_seconds(item, "start_offset", "start") else {
+            continue;
+        };
+
+        let speaker = item
+            .get("speaker_name")
+            .or_else(|| item.get("speaker"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .or_else(|| {
+                let id = json_id(item.get("speaker_id")?)?;
+                speakers.get(&id).cloned()
+            })
+            .filter(|name| !name.trim().is_empty());
+
+        entries.push(Entry {
+            start,
+            end: json_seconds(item, "end_offset", "end"),
+            speaker,
+            text: text.to_string(),
+        });
+    }
+
+    if entries.is_empty() {
+        return Err("The Otter transcript has no timed entries".to_string());
+    }
+    entries.sort_by(|a, b| a.start.total_cmp(&b.start));
+    Ok(finish_entries(entries))
+}
+
+/// Merge consecutive segments of one speaker separated by at most `max_gap`
+/// seconds, up to `max_duration` per segment. Platforms split an utterance
+/// into short caption-sized cues; recorded meetings have longer segments.
+pub fn merge_consecutive(
+    segments: Vec<ParsedSegment>,
+    max_gap: f64,
+    max_duration: f64,
+) -> Vec<ParsedSegment> {
+    let mut merged: Vec<ParsedSegment> = Vec::with_capacity(segments.len());
+    for segment in segments {
+        match merged.last_mut() {
+            Some(last)
+                if last.speaker == segment.speaker
+                    && segment.start - last.end <= max_gap
+                    && segment.end - last.start <= max_duration =>
+            {
+                last.text.push(' ');
+                last.text.push_str(&segment.text);
+                last.end = last.end.max(segment.end);
+            }
+            _ => merged.push(segment),
+        }
+    }
+    merged
+}
+
+/// `HH:MM:SS.mmm`, `MM:SS.mmm` or `HH:MM:SS` (`,` also accepted before the
+/// milliseconds, as in SRT). Returns seconds.
+pub fn parse_timestamp(value: &str) -> Option<f64> {
+    let value = value.trim().replace(',', ".");
+    let parts: Vec<&str> = value.split(':').collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return None;
+    }
+
+    let seconds: f64 = parts[parts.len() - 1].parse().ok()?;
+    let minutes: u64 = parts[parts.len() - 2].parse().ok()?;
+    let hours: u64 = if parts.len() == 3 {
+        parts[0].parse().ok()?
+    } else {
+        0
+    };
+    if !(0.0..60.0).contains(&seconds) || minutes >= 60 {
+        return None;
+    }
+    Some((hours * 3600 + minutes * 60) as f64 + seconds)
+}
+
+fn normalize_newlines(content: &str) -> String {
+    content
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+}
+
+/// Blocks separated by blank lines with a `start --> end` timing line; anything
+/// else (headers, NOTE/STYLE blocks) is skipped. `payload` turns the lines after
+/// the timing line into the speaker and text.
+fn parse_cue_blocks<F>(content: &str, payload: F) -> Vec<ParsedSegment>
+where
+    F: Fn(&str) -> (Option<String>, String),
+{
+    let mut segments = Vec::new();
+    for block in content.split("\n\n") {
+        let lines: Vec<&str> = block.lines().collect();
+        let Some(timing_index) = lines.iter().position(|line| line.contains("-->")) else {
+            continue;
+        };
+        let Some((start, end)) = lines[timing_index].split_once("-->") else {
+            continue;
+        };
+        // Cue settings may follow the end time
+        let end = end.split_whitespace().next().unwrap_or_default();
+        let (Some(start), Some(end)) = (parse_timestamp(start), parse_timestamp(end)) else {
+            continue;
+        };
+
+        let (speaker, text) = payload(&lines[timing_index + 1..].join("\n"));
+        if text.is_empty() {
+            continue;
+        }
+        segments.push(ParsedSegment {
+            start,
+            end: end.max(start),
+            speaker,
+            text,
+        });
+    }
+    segments.sort_by(|a, b| a.start.total_cmp(&b.start));
+    segments
+}
+
+/// Speaker of the first `<v Name>` (or `<v.class Name>`) voice span
+fn vtt_voice(payload: &str) -> Option<String> {
+    let start = payload.find("<v")?;
+    let tag = &payload[start + 2..];
+    let tag = &tag[..tag.find('>')?];
+    // Classes are attached to the tag name: <v.loud Name>
+    let (_, name) = tag.split_once(' ')?;
+    let name = decode_entities(name.trim());
+    Some(name).filter(|name| !name.is_empty())
+}
+
+/// Drop markup (`<...>` tags, `{\...}` SSA overrides), decode entities and
+/// join the lines
+fn clean_text(payload: &str) -> String {
+    let mut text = String::with_capacity(payload.len());
+    let mut in_tag = false;
+    let mut in_override = false;
+    for c in payload.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            '{' if !in_tag => in_override = true,
+            '}' if in_override => in_override = false,
+            _ if in_tag || in_override => {}
+            _ => text.push(c),
+        }
+    }
+    decode_entities(&text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&lrm;", "")
+        .replace("&rlm;", "")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// `Name: text` → (`Name`, `text`), for short name-like prefixes only
+fn split_speaker_prefix(text: &str) -> Option<(&str, &str)> {
+    let (speaker, rest) = text.split_once(": ")?;
+    let speaker = speaker.trim();
+    let rest = rest.trim();
+    let name_like = !speaker.is_empty()
+        && speaker.chars().count() <= MAX_SPEAKER_PREFIX_CHARS
+        && speaker.split_whitespace().count() <= 4
+        && !speaker.chars().any(|c| ".!?,;:\"".contains(c))
+        && !speaker.chars().all(|c| c.is_ascii_digit());
+    (name_like && !rest.is_empty()).then_some((speaker, rest))
+}
+
+/// Take speakers from `Name: ` prefixes when most segments have one; a prefix
+/// on the odd line is more likely part of what was said
+fn with_prefix_speakers(mut segments: Vec<ParsedSegment>) -> Vec<ParsedSegment> {
+    let unlabeled = segments.iter().filter(|s| s.speaker.is_none()).count();
+    let prefixed = segments
+        .iter()
+        .filter(|s| s.speaker.is_none() && split_speaker_prefix(&s.text).is_some())
+        .count();
+    if prefixed == 0 || prefixed * 2 < unlabeled {
+        return segments;
+    }
+
+    for segment in segments.iter_mut().filter(|s| s.speaker.is_none()) {
+        if let Some((speaker, text)) = split_speaker_prefix(&segment.text) {
+            let (speaker, text) = (speaker.to_string(), text.to_string());
+            segment.speaker = Some(speaker);
+            segment.text = text;
+        }
+    }
+    segments
+}
+
+/// Fill in missing end times: the entry's estimated speaking time, cut off
+/// where the next entry starts
+fn finish_entries(entries: Vec<Entry>) -> Vec<ParsedSegment> {
+    let next_starts: Vec<Option<f64>> = entries
+        .iter()
+        .skip(1)
+        .map(|entry| Some(entry.start))
+        .chain([None])
+        .collect();
+
+    entries
+        .into_iter()
+        .zip(next_starts)
+        .filter(|(entry, _)| !entry.text.trim().is_empty())
+        .map(|(entry, next_start)| {
+            let estimated = entry.start
+                + (entry.text.split_whitespace().count() as f64 / WORDS_PER_SECOND).max(1.0);
+            let end = match (entry.end, next_start) {
+                (Some(end), _) => end.max(entry.start),
+                (None, Some(next)) if next > entry.start => estimated.min(next),
+                (None, _) => estimated,
+            };
+            ParsedSegment {
+                start: entry.start,
+                end,
+                speaker: entry.speaker,
+                text: entry.text.trim().to_string(),
+            }
+        })
+        .collect()
+}
+
+fn json_id(value: &Value) -> Option<String> {
+    match value {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+fn json_seconds(item: &Value, millis_key: &str, seconds_key: &str) -> Option<f64> {
+    item.get(millis_key)
+        .and_then(Value::as_f64)
+        .map(|millis| millis / 1000.0)
+        .or_else(|| item.get(seconds_key).and_then(Value::as_f64))
+        .filter(|seconds| *seconds >= 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: f64, end: f64, speaker: Option<&str>, text: &str) -> ParsedSegment {
+        ParsedSegment {
+            start,
+            end,
+            speaker: speaker.map(str::to_string),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("00:01:02.500"), Some(62.5));
+        assert_eq!(parse_timestamp("01:00:00,250"), Some(3600.25));
+        assert_eq!(parse_timestamp("02:03.000"), Some(123.0));
+        assert_eq!(parse_timestamp("10:01:15"), Some(36075.0));
+        assert_eq!(parse_timestamp("1:75:00"), None);
+        assert_eq!(parse_timestamp("hello"), None);
+    }
+
+    #[test]
+    fn test_parse_teams_vtt() {
+        let vtt = "WEBVTT\r\n\r\n\
+            NOTE exported by Teams\r\n\r\n\
+            3a1f/12-0\r\n\
+            00:00:01.200 --> 00:00:04.000\r\n\
+            <v Alice Smith>Hi all, Q&amp;A is at the end.</v>\r\n\r\n\
+            3a1f/13-0\r\n\
+            00:00:04.500 --> 00:00:06.000 align:start\r\n\
+            <v.loud Bob>Sounds <i>good</i>.</v>\r\n";
+        let segments = parse_vtt(vtt).unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                segment(1.2, 4.0, Some("Alice Smith"), "Hi all, Q&A is at the end."),
+                segment(4.5, 6.0, Some("Bob"), "Sounds good."),
+            ]
+        );
+        assert!(parse_vtt("1\n00:00:01,000 --> 00:00:02,000\nHi\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_vtt_with_speaker_prefixes() {
+        let vtt = "WEBVTT\n\n1\n00:00:01.000 --> 00:00:02.000\nJohn Doe: Hello there\n\n\
+                   2\n00:00:02.500 --> 00:00:04.000\nJane: Hi John\n";
+        let segments = parse_vtt(vtt).unwrap();
+        assert_eq!(segments[0].speaker.as_deref(), Some("John Doe"));
+        assert_eq!(segments[0].text, "Hello there");
+        assert_eq!(segments[1].speaker.as_deref(), Some("Jane"));
+    }
+
+    #[test]
+    fn test_parse_srt() {
+        let srt = "1\n00:00:01,000 --> 00:00:03,500\n{\\an8}First <i>line</i>\nsecond line\n\n\
+                   2\n00:00:04,000 --> 00:00:05,000\nNote: this stays text\n\n\
+                   3\n00:00:06,000 --> 00:00:07,000\nAnd the end\n";
+        let segments = parse_srt(srt).unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                segment(1.0, 3.5, None, "First line second line"),
+                // One prefixed line among three is not a speaker label
+                segment(4.0, 5.0, None, "Note: this stays text"),
+                segment(6.0, 7.0, None, "And the end"),
+            ]
+        );
+        assert!(parse_srt("just some text").is_err());
+    }
+
+    #[test]
+    fn test_parse_zoom_text() {
+        let zoom = "[Alice] 23:59:50\nGood evening everyone.\nLet's start.\n\n\
+                    [Bob] 23:59:58\nHi\n\n\
+                    [Alice] 00:00:30\nOne more thing from me before we close\n";
+        let segments = parse_zoom_text(zoom).unwrap();
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].speaker.as_deref(), Some("Alice"));
+        assert_eq!(segments[0].text, "Good evening everyone. Let's start.");
+        assert_eq!((segments[0].start, segments[0].end), (0.0, 2.0));
+        // Estimated duration is cut off by the next entry
+        assert_eq!((segments[1].start, segments[1].end), (8.0, 9.0));
+        // Past midnight
+        assert_eq!(segments[2].start, 40.0);
+
+        let single_lines = "00:00:05 Carol: Quick update\n00:00:09 Dan: Thanks Carol\n";
+        let segments = parse_zoom_text(single_lines).unwrap();
+        assert_eq!(segments[0].speaker.as_deref(), Some("Carol"));
+        assert_eq!(segments[1].start, 4.0);
+        assert!(parse_zoom_text("no timestamps here").is_err());
+    }
+
+    #[test]
+    fn test_parse_otter_json() {
+        let otter = r#"{
+            "speech": {
+                "speakers": [{"id": 7, "speaker_name": "Priya"}],
+                "transcripts": [
+                    {"start_offset": 2000, "end_offset": 5500, "speaker_id": 7, "transcript": "Budget first."},
+                    {"start_offset": 6000, "speaker_name": "Tom", "transcript": "Agreed"},
+                    {"start_offset": 9000, "transcript": "  "}
+                ]
+            }
+        }"#;
+        let segments = parse_otter_json(otter).unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                segment(2.0, 5.5, Some("Priya"), "Budget first."),
+                segment(6.0, 7.0, Some("Tom"), "Agreed"),
+            ]
+        );
+
+        let plain = r#"[{"start": 1.5, "end": 2.0, "speaker": "Ann", "text": "Hi"}]"#;
+        assert_eq!(
+            parse_otter_json(plain).unwrap(),
+            vec![segment(1.5, 2.0, Some("Ann"), "Hi")]
+        );
+        assert!(parse_otter_json(r#"{"foo": 1}"#).is_err());
+    }
+
+    #[test]
+    fn test_merge_consecutive() {
+        let merged = merge_consecutive(
+            vec![
+                segment(0.0, 2.0, Some("A"), "one"),
+                segment(2.5, 4.0, Some("A"), "two"),
+                segment(4.2, 5.0, Some("B"), "three"),
+                segment(8.0, 9.0, Some("B"), "four"),
+            ],
+            1.0,
+            30.0,
+        );
+        assert_eq!(
+            merged,
+            vec![
+                segment(0.0, 4.0, Some("A"), "one two"),
+                segment(4.2, 5.0, Some("B"), "three"),
+                segment(8.0, 9.0, Some("B"), "four"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,377 @@
+"use client";
+
+import { useState, useEffect, useRef } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { useRouter } from 'next/navigation';
+import { useTranslation } from 'react-i18next';
+import { MessageSquare, Plus, Trash2 } from 'lucide-react';
+import { useSidebar } from '@/components/Sidebar/SidebarProvider';
+import { useModelConfiguration } from '@/hooks/meeting-details/useModelConfiguration';
+
+interface ConversationFilters {
+  date_from?: string;
+  date_to?: string;
+  title_query?: string;
+}
+
+interface ChatConversation {
+  id: string;
+  title: string;
+  filters?: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+interface ConversationMessage {
+  id: string;
+  conversation_id: string;
+  role: 'user' | 'assistant';
+  content: string;
+  created_at: string;
+  metadata?: string | null;
+}
+
+interface ChatCitation {
+  index: number;
+  meeting_id: string;
+  meeting_title?: string;
+  audio_start_time?: number | null;
+  excerpt: string;
+}
+
+interface ChatStreamEvent {
+  meeting_id: string;
+  status: 'token' | 'completed' | 'cancelled' | 'failed';
+  delta?: string;
+}
+
+interface AskLibraryQuestionResponse {
+  request_id: string;
+  conversation: ChatConversation;
+  answer: string;
+  user_message: ConversationMessage;
+  assistant_message: ConversationMessage;
+  citations: ChatCitation[];
+}
+
+const getCitations = (message: ConversationMessage): ChatCitation[] => {
+  if (!message.metadata) return [];
+  try {
+    const parsed = JSON.parse(message.metadata);
+    return Array.isArray(parsed?.citations) ? parsed.citations : [];
+  } catch {
+    return [];
+  }
+};
+
+const parseFilters = (conversation: ChatConversation): ConversationFilters => {
+  if (!conversation.filters) return {};
+  try {
+    return JSON.parse(conversation.filters);
+  } catch {
+    return {};
+  }
+};
+
+const formatAudioTime = (seconds: number) => {
+  const total = Math.floor(seconds);
+  const h = Math.floor(total / 3600);
+  const m = Math.floor((total % 3600) / 60);
+  const s = total % 60;
+  const mmss = `${m.toString().padStart(2, '0')}:${s.toString().padStart(2, '0')}`;
+  return h > 0 ? `${h}:${mmss}` : mmss;
+};
+
+export default function LibraryChatPage() {
+  const { t } = useTranslation();
+  const router = useRouter();
+  const { serverAddress } = useSidebar();
+  const { modelConfig } = useModelConfiguration({ serverAddress });
+
+  const [conversations, setConversations] = useState<ChatConversation[]>([]);
+  const [activeId, setActiveId] = useState<string | null>(null);
+  const [messages, setMessages] = useState<ConversationMessage[]>([]);
+  const [filters, setFilters] = useState<ConversationFilters>({});
+  const [question, setQuestion] = useState('');
+  const [isLoading, setIsLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [streamingAnswer, setStreamingAnswer] = useState('');
+  const messagesEndRef = useRef<HTMLDivElement>(null);
+  // Library answers stream as chat-stream events under their request id instead of a meeting id
+  const requestIdRef = useRef<string | null>(null);
+
+  useEffect(() => {
+    loadConversations();
+  }, []);
+
+  // Show the answer as it streams in; the saved message replaces it when the request returns
+  useEffect(() => {
+    const unlisten = listen<ChatStreamEvent>('chat-stream', (event) => {
+      if (event.payload.meeting_id === requestIdRef.current && event.payload.status === 'token') {
+        setStreamingAnswer(prev => prev + (event.payload.delta || ''));
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, []);
+
+  useEffect(() => {
+    messagesEndRef.current?.scrollIntoView({ behavior: 'smooth' });
+  }, [messages, streamingAnswer]);
+
+  const loadConversations = async () => {
+    try {
+      setConversations(await invoke<ChatConversation[]>('api_get_conversations'));
+    } catch (err) {
+      console.error('Failed to load conversations:', err);
+    }
+  };
+
+  const openConversation = async (conversation: ChatConversation) => {
+    setActiveId(conversation.id);
+    setFilters(parseFilters(conversation));
+    setError(null);
+    try {
+      setMessages(
+        await invoke<ConversationMessage[]>('api_get_conversation_messages', {
+          conversationId: conversation.id,
+        })
+      );
+    } catch (err) {
+      console.error('Failed to load conversation messages:', err);
+      setError(t('chat.errorLoadingMessages', 'Failed to load chat messages'));
+    }
+  };
+
+  const startNewConversation = () => {
+    setActiveId(null);
+    setMessages([]);
+    setFilters({});
+    setError(null);
+  };
+
+  const deleteConversation = async (conversationId: string) => {
+    try {
+      await invoke('api_delete_conversation', { conversationId });
+      if (conversationId === activeId) startNewConversation();
+      await loadConversations();
+    } catch (err) {
+      console.error('Failed to delete conversation:', err);
+    }
+  };
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!question.trim() || isLoading) return;
+
+    if (!modelConfig.provider || !modelConfig.model) {
+      setError(t('chat.errorNoModel', 'Please configure a model in settings first'));
+      return;
+    }
+
+    setIsLoading(true);
+    setError(null);
+    setStreamingAnswer('');
+    const requestId = crypto.randomUUID();
+    requestIdRef.current = requestId;
+
+    try {
+      const response = await invoke<AskLibraryQuestionResponse>('api_ask_library_question', {
+        requestId,
+        conversationId: activeId,
+        question: question.trim(),
+        filters: {
+          date_from: filters.date_from || undefined,
+          date_to: filters.date_to || undefined,
+          title_query: filters.title_query?.trim() || undefined,
+        },
+        modelProvider: modelConfig.provider,
+        modelName: modelConfig.model,
+      });
+
+      setActiveId(response.conversation.id);
+      setMessages(prev => [...prev, response.user_message, response.assistant_message]);
+      setQuestion('');
+      loadConversations();
+    } catch (err: any) {
+      // Stopped before any text arrived: nothing was saved, nothing to report
+      if (!err?.toString().includes('Answer cancelled')) {
+        console.error('Failed to ask library question:', err);
+        setError(err?.toString() || t('chat.errorAskingQuestion', 'Failed to get answer'));
+      }
+    } finally {
+      requestIdRef.current = null;
+      setIsLoading(false);
+      setStreamingAnswer('');
+    }
+  };
+
+  const handleStop = async () => {
+    const requestId = requestIdRef.current;
+    if (!requestId) return;
+    try {
+      await invoke('api_cancel_library_answer', { requestId });
+    } catch (err) {
+      console.error('Failed to stop answer:', err);
+    }
+  };
+
+  return (
+    <div className="flex h-screen bg-gray-50">
+      {/* Conversation list */}
+      <div className="w-64 border-r border-gray-200 bg-white flex flex-col">
+        <button
+          onClick={startNewConversation}
+          className="m-3 flex items-center justify-center px-3 py-2 text-sm font-medium text-white bg-blue-600 hover:bg-blue-700 rounded-lg"
+        >
+          <Plus className="w-4 h-4 mr-1" />
+          {t('chat.newConversation', 'New conversation')}
+        </button>
+        <div className="flex-1 overflow-y-auto">
+          {conversations.map(conversation => (
+            <div
+              key={conversation.id}
+              onClick={() => openConversation(conversation)}
+              className={`group flex items-center px-3 py-2 mx-2 rounded-lg cursor-pointer text-sm ${
+                conversation.id === activeId ? 'bg-gray-100' : 'hover:bg-gray-50'
+              }`}
+            >
+              <MessageSquare className="w-4 h-4 mr-2 text-gray-400 flex-shrink-0" />
+              <span className="flex-1 truncate">{conversation.title}</span>
+              <button
+                onClick={e => {
+                  e.stopPropagation();
+                  deleteConversation(conversation.id);
+                }}
+                className="opacity-0 group-hover:opacity-100 text-gray-400 hover:text-red-500"
+              >
+                <Trash2 className="w-4 h-4" />
+              </button>
+            </div>
+          ))}
+        </div>
+      </div>
+
+      {/* Chat */}
+      <div className="flex-1 flex flex-col">
+        {/* Scope filters */}
+        <div className="flex flex-wrap items-center gap-2 p-3 border-b border-gray-200 bg-white text-sm">
+          <span className="text-gray-500">{t('chat.searchScope', 'Search meetings')}</span>
+          <input
+            type="date"
+            value={filters.date_from || ''}
+            onChange={e => setFilters(f => ({ ...f, date_from: e.target.value }))}
+            className="px-2 py-1 border border-gray-300 rounded"
+          />
+          <span className="text-gray-400">–</span>
+          <input
+            type="date"
+            value={filters.date_to || ''}
+            onChange={e => setFilters(f => ({ ...f, date_to: e.target.value }))}
+            className="px-2 py-1 border border-gray-300 rounded"
+          />
+          <input
+            type="text"
+            value={filters.title_query || ''}
+            onChange={e => setFilters(f => ({ ...f, title_query: e.target.value }))}
+            placeholder={t('chat.titleFilter', 'Title contains...')}
+            className="flex-1 min-w-[140px] px-2 py-1 border border-gray-300 rounded"
+          />
+        </div>
+
+        <div className="flex-1 overflow-y-auto p-4 space-y-4">
+          {messages.length === 0 && (
+            <p className="text-center text-sm text-gray-500 py-8">
+              {t('chat.libraryEmpty', 'Ask a question about all of your meetings, e.g. "What did we decide about pricing last month?"')}
+            </p>
+          )}
+          {messages.map(message => (
+            <div
+              key={message.id}
+              className={`flex ${message.role === 'user' ? 'justify-end' : 'justify-start'}`}
+            >
+              <div
+                className={`max-w-[80%] rounded-lg px-4 py-2 ${
+                  message.role === 'user'
+                    ? 'bg-blue-600 text-white'
+                    : 'bg-white text-gray-900 border border-gray-200'
+                }`}
+              >
+                <div className="text-base whitespace-pre-wrap break-words">{message.content}</div>
+                {message.role === 'assistant' && getCitations(message).length > 0 && (
+                  <div className="flex flex-wrap gap-1 mt-2">
+                    {getCitations(message).map(citation => (
+                      <button
+                        key={citation.index}
+                        type="button"
+                        title={citation.excerpt}
+                        onClick={() => router.push(`/meeting-details?id=${citation.meeting_id}`)}
+                        className="text-xs px-2 py-0.5 rounded bg-blue-50 text-blue-700 border border-blue-200 hover:bg-blue-100"
+                      >
+                        [{citation.index}] {citation.meeting_title || citation.meeting_id}
+                        {citation.audio_start_time != null && ` · ${formatAudioTime(citation.audio_start_time)}`}
+                      </button>
+                    ))}
+                  </div>
+                )}
+              </div>
+            </div>
+          ))}
+          {isLoading && (
+            streamingAnswer ? (
+              <div className="flex justify-start">
+                <div className="max-w-[80%] rounded-lg px-4 py-2 bg-white text-gray-900 border border-gray-200">
+                  <div className="text-base whitespace-pre-wrap break-words">{streamingAnswer}</div>
+                </div>
+              </div>
+            ) : (
+              <p className="text-sm text-gray-500">{t('chat.thinking', 'Thinking...')}</p>
+            )
+          )}
+          <div ref={messagesEndRef} />
+        </div>
+
+        {error && (
+          <div className="px-4 py-2 bg-red-50 border-t border-red-200">
+            <p className="text-sm text-red-600">{error}</p>
+          </div>
+        )}
+
+        <form onSubmit={handleSubmit} className="flex items-end space-x-2 border-t border-gray-200 bg-white p-4">
+          <textarea
+            value={question}
+            onChange={e => setQuestion(e.target.value)}
+            onKeyDown={e => {
+              if (e.key === 'Enter' && !e.shiftKey) {
+                e.preventDefault();
+                handleSubmit(e as any);
+              }
+            }}
+            placeholder={t('chat.libraryPlaceholder', 'Ask across all meetings...')}
+            className="flex-1 px-3 py-2 border border-gray-300 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500 resize-none"
+            rows={1}
+            disabled={isLoading}
+          />
+          {isLoading && (
+            <button
+              type="button"
+              onClick={handleStop}
+              className="px-4 py-2 text-gray-700 bg-gray-100 rounded-lg hover:bg-gray-200"
+            >
+              {t('chat.stop', 'Stop')}
+            </button>
+          )}
+          <button
+            type="submit"
+            disabled={!question.trim() || isLoading}
+            className="px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:bg-gray-300 disabled:cursor-not-allowed"
+          >
+            {t('chat.send', 'Send')}
+          </button>
+        </form>
+      </div>
+    </div>
+  );
+}
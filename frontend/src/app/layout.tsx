@@ -16,6 +16,7 @@ import { TooltipProvider } from '@/components/ui/tooltip'
 import { RecordingStateProvider } from '@/contexts/RecordingStateContext'
 import { OllamaDownloadProvider } from '@/contexts/OllamaDownloadContext'
 import { I18nProvider } from '@/contexts/I18nProvider'
+import { RecordingRecovery } from '@/components/RecordingRecovery'
 
 const sourceSans3 = Source_Sans_3({
   subsets: ['latin'],
@@ -83,6 +84,7 @@ export default function RootLayout({
             <RecordingStateProvider>
               <OllamaDownloadProvider>
                 <SidebarProvider>
+                  <RecordingRecovery />
                   <TooltipProvider>
                     {/* <div className="titlebar h-8 w-full fixed top-0 left-0 bg-transparent" /> */}
                     <div className="flex">
@@ -38,6 +38,7 @@ export default function PageContent({
   const [customPrompt, setCustomPrompt] = useState<string>('');
   const [isRecording] = useState(false);
   const [summaryResponse] = useState<SummaryResponse | null>(null);
+  const folderMissing = meeting.status === 'folder_missing';
 
   // Sidebar context
   const { serverAddress } = useSidebar();
@@ -53,6 +54,7 @@ export default function PageContent({
     modelConfig: modelConfig.modelConfig,
     isModelConfigLoading: modelConfig.isLoading,
     selectedTemplate: templates.selectedTemplate,
+    structuredOutput: templates.structuredOutput,
     onMeetingUpdated,
     updateMeetingTitle: meetingData.updateMeetingTitle,
     setAiSummary: meetingData.setAiSummary,
@@ -108,7 +110,12 @@ export default function PageContent({
           onPromptChange={setCustomPrompt}
           onCopyTranscript={copyOperations.handleCopyTranscript}
           onOpenMeetingFolder={meetingOperations.handleOpenMeetingFolder}
+          onExportSubtitles={meetingOperations.handleExportSubtitles}
+          onExportArchive={meetingOperations.handleExportArchive}
+          meetingId={meeting.id}
+          onTranscriptChanged={async () => { await onMeetingUpdated?.(); }}
           isRecording={isRecording}
+          folderMissing={folderMissing}
         />
 
           <SummaryPanel
@@ -124,6 +131,7 @@ export default function PageContent({
           onSaveAll={meetingData.saveAllChanges}
           onCopySummary={copyOperations.handleCopySummary}
           onOpenFolder={meetingOperations.handleOpenMeetingFolder}
+          folderMissing={folderMissing}
           aiSummary={meetingData.aiSummary}
           summaryStatus={summaryGeneration.summaryStatus}
           transcripts={meetingData.transcripts}
@@ -138,10 +146,15 @@ export default function PageContent({
           onDirtyChange={meetingData.setIsSummaryDirty}
           summaryError={summaryGeneration.summaryError}
           onRegenerateSummary={summaryGeneration.handleRegenerateSummary}
+          streamingSummary={summaryGeneration.streamingSummary}
+          onCancelSummary={summaryGeneration.handleCancelSummary}
+          onSummaryRestored={meetingData.setAiSummary}
           getSummaryStatusMessage={summaryGeneration.getSummaryStatusMessage}
           availableTemplates={templates.availableTemplates}
           selectedTemplate={templates.selectedTemplate}
           onTemplateSelect={templates.handleTemplateSelection}
+          structuredOutput={templates.structuredOutput}
+          onStructuredOutputToggle={templates.handleStructuredOutputToggle}
           isModelConfigLoading={modelConfig.isLoading}
         />
 
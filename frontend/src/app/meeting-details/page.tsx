@@ -6,6 +6,8 @@ import PageContent from "./page-content";
 import { useRouter, useSearchParams } from "next/navigation";
 import Analytics from "@/lib/analytics";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { toast } from "sonner";
 import { LoaderIcon } from "lucide-react";
 
 interface MeetingDetailsResponse {
@@ -103,6 +105,25 @@ function MeetingDetailsContent() {
     }
   }, [meetingId, setCurrentMeeting]);
 
+  // Show the improved transcript once the refinement pass after recording replaces the live one
+  useEffect(() => {
+    if (!meetingId) return;
+    const unlisten = listen<{ meeting_id: string; status: string; changed_segments?: number }>(
+      'transcript-refinement',
+      (event) => {
+        const { meeting_id, status, changed_segments } = event.payload;
+        if (meeting_id !== meetingId || status !== 'completed' || !changed_segments) return;
+        fetchMeetingDetails();
+        toast.success('Transcript improved', {
+          description: `${changed_segments} segments were corrected. The live transcript is kept as a version.`
+        });
+      }
+    );
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [meetingId, fetchMeetingDetails]);
+
   // Reset states when meetingId changes
   useEffect(() => {
     setMeetingDetails(null);
@@ -383,16 +383,34 @@ export default function Home() {
 
       if (allNewTranscripts.length > 0) {
         setTranscripts(prev => {
-          // Create a set of existing sequence_ids for deduplication
-          const existingSequenceIds = new Set(prev.map(t => t.sequence_id).filter(id => id !== undefined));
+          // A partial result is replaced by the next result with the same sequence_id
+          // (an empty final result withdraws it). The replacement keeps its id.
+          const incomingSequenceIds = new Set(allNewTranscripts.map(t => t.sequence_id));
+          const replacedPartials = new Map<number, Transcript>();
+          const kept = prev.filter(t => {
+            if (t.is_partial && t.sequence_id !== undefined && incomingSequenceIds.has(t.sequence_id)) {
+              replacedPartials.set(t.sequence_id, t);
+              return false;
+            }
+            return true;
+          });
 
-          // Filter out any new transcripts that already exist
-          const uniqueNewTranscripts = allNewTranscripts.filter(transcript =>
-            transcript.sequence_id !== undefined && !existingSequenceIds.has(transcript.sequence_id)
-          );
+          // Create a set of existing sequence_ids for deduplication
+          const existingSequenceIds = new Set(kept.map(t => t.sequence_id).filter(id => id !== undefined));
+
+          // Filter out any new transcripts that already exist, and withdrawals
+          const uniqueNewTranscripts = allNewTranscripts
+            .filter(transcript =>
+              transcript.sequence_id !== undefined && !existingSequenceIds.has(transcript.sequence_id)
+            )
+            .filter(transcript => transcript.is_partial || transcript.text.trim() !== '')
+            .map(transcript => {
+              const replaced = replacedPartials.get(transcript.sequence_id!);
+              return replaced ? { ...transcript, id: replaced.id } : transcript;
+            });
 
           // Only combine if we have unique new transcripts
-          if (uniqueNewTranscripts.length === 0) {
+          if (uniqueNewTranscripts.length === 0 && replacedPartials.size === 0) {
             console.log('No unique transcripts to add - all were duplicates');
             return prev; // No new unique transcripts to add
           }
@@ -400,7 +418,7 @@ export default function Home() {
           console.log(`Adding ${uniqueNewTranscripts.length} unique transcripts out of ${allNewTranscripts.length} received`);
 
           // Merge with existing transcripts, maintaining chronological order
-          const combined = [...prev, ...uniqueNewTranscripts];
+          const combined = [...kept, ...uniqueNewTranscripts];
 
           // Sort by chunk_start_time first, then by sequence_id
           return combined.sort((a, b) => {
@@ -435,8 +453,8 @@ export default function Home() {
             buffer_size_before: transcriptBuffer.size
           });
 
-          // Check for duplicate sequence_id before processing
-          if (transcriptBuffer.has(event.payload.sequence_id)) {
+          // Check for duplicate sequence_id before processing (a buffered partial result gets replaced)
+          if (transcriptBuffer.get(event.payload.sequence_id)?.is_partial === false) {
             console.log('🚫 MAIN LISTENER: Duplicate sequence_id, skipping buffer:', event.payload.sequence_id);
             return;
           }
@@ -454,6 +472,9 @@ export default function Home() {
             audio_start_time: event.payload.audio_start_time,
             audio_end_time: event.payload.audio_end_time,
             duration: event.payload.duration,
+            words: event.payload.words,
+            speaker: event.payload.speaker ?? undefined,
+            source: event.payload.source,
           };
 
           // Add to buffer
@@ -516,6 +537,9 @@ export default function Home() {
             audio_start_time: segment.audio_start_time,
             audio_end_time: segment.audio_end_time,
             duration: segment.duration,
+            words: segment.words,
+            speaker: segment.speaker ?? undefined,
+            source: segment.source ?? undefined,
           }));
 
           setTranscripts(formattedTranscripts);
@@ -581,10 +605,11 @@ export default function Home() {
           message: string;
           folder_path?: string;
           meeting_name?: string;
+          meeting_id?: string | null;
         }>('recording-stopped', async (event) => {
           console.log('Recording stopped event received:', event.payload);
 
-          const { folder_path, meeting_name } = event.payload;
+          const { folder_path, meeting_name, meeting_id } = event.payload;
 
           // Store folder_path and meeting_name for later use in handleRecordingStop2
           if (folder_path) {
@@ -595,6 +620,14 @@ export default function Home() {
             sessionStorage.setItem('last_recording_meeting_name', meeting_name);
             console.log('✅ Stored meeting_name for frontend save:', meeting_name);
           }
+          // Set when the transcript was saved to the database during the recording;
+          // always written so an id from an earlier recording is never reused
+          if (meeting_id) {
+            sessionStorage.setItem('last_recording_meeting_id', meeting_id);
+            console.log('✅ Stored live meeting_id:', meeting_id);
+          } else {
+            sessionStorage.removeItem('last_recording_meeting_id');
+          }
 
         });
         console.log('Recording stopped listener setup complete');
@@ -954,11 +987,13 @@ export default function Home() {
         setIsSavingTranscript(true);
 
         // Get fresh transcript state (ALL transcripts including late ones)
-        const freshTranscripts = [...transcriptsRef.current];
+        // Partial results never got a final one if the recording stopped mid-sentence
+        const freshTranscripts = transcriptsRef.current.filter(t => !t.is_partial);
 
         // Get folder_path and meeting_name from recording-stopped event
         const folderPath = sessionStorage.getItem('last_recording_folder_path');
         const savedMeetingName = sessionStorage.getItem('last_recording_meeting_name');
+        const liveMeetingId = sessionStorage.getItem('last_recording_meeting_id');
 
         console.log('💾 Saving COMPLETE transcripts to database...', {
           transcript_count: freshTranscripts.length,
@@ -969,15 +1004,21 @@ export default function Home() {
         });
 
         try {
-          const responseData = await invoke('api_save_transcript', {
-            meetingTitle: meetingTitle || savedMeetingName,
-            transcripts: freshTranscripts, 
-            folderPath: folderPath, 
-          }) as any;
+          // With a live meeting the backend saved the transcript as it arrived; only
+          // a title edited during the recording is left to save
+          const meetingId: string | undefined = liveMeetingId
+            ? liveMeetingId
+            : ((await invoke('api_save_transcript', {
+                meetingTitle: meetingTitle || savedMeetingName,
+                transcripts: freshTranscripts,
+                folderPath: folderPath,
+              })) as any).meeting_id;
+          if (liveMeetingId && meetingTitle && meetingTitle !== savedMeetingName) {
+            await invoke('api_save_meeting_title', { meetingId: liveMeetingId, title: meetingTitle });
+          }
 
-          const meetingId = responseData.meeting_id;
           if (!meetingId) {
-            console.error('No meeting_id in response:', responseData);
+            console.error('No meeting_id for the saved recording');
             throw new Error('No meeting ID received from save operation');
           }
 
@@ -1085,6 +1126,8 @@ export default function Home() {
           });
           throw saveError;
         } finally {
+          // Even when the save failed, the next recording must not update this meeting
+          sessionStorage.removeItem('last_recording_meeting_id');
           setIsSavingTranscript(false);
         }
       }
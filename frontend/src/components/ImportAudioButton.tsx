@@ -0,0 +1,135 @@
+"use client";
+
+import React, { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { useRouter } from 'next/navigation';
+import { toast } from 'sonner';
+import { FileAudio, X } from 'lucide-react';
+import { useSidebar } from './Sidebar/SidebarProvider';
+import Analytics from '@/lib/analytics';
+
+interface ImportProgress {
+  file_path: string;
+  status: 'decoding' | 'detecting_speech' | 'transcribing' | 'saving' | 'completed' | 'cancelled' | 'failed';
+  progress: number;
+  meeting_id?: string;
+  error?: string;
+}
+
+const STATUS_LABELS: Record<string, string> = {
+  decoding: 'Decoding audio',
+  detecting_speech: 'Detecting speech',
+  transcribing: 'Transcribing',
+  saving: 'Saving meeting',
+};
+
+interface ImportAudioButtonProps {
+  isCollapsed: boolean;
+}
+
+export function ImportAudioButton({ isCollapsed }: ImportAudioButtonProps) {
+  const router = useRouter();
+  const { refetchMeetings, setCurrentMeeting } = useSidebar();
+  const [filePath, setFilePath] = useState<string | null>(null);
+  const [progress, setProgress] = useState<ImportProgress | null>(null);
+
+  useEffect(() => {
+    if (!filePath) return;
+    const unlisten = listen<ImportProgress>('import-progress', (event) => {
+      if (event.payload.file_path === filePath) {
+        setProgress(event.payload);
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [filePath]);
+
+  const handleImport = async () => {
+    try {
+      const selected = await invoke<string | null>('select_import_file');
+      if (!selected) return;
+
+      Analytics.trackButtonClick('import_audio_file', 'sidebar');
+      setFilePath(selected);
+      setProgress(null);
+
+      const title = selected.split(/[\\/]/).pop()?.replace(/\.[^.]+$/, '') || 'Imported meeting';
+      const meetingId = await invoke<string | null>('api_import_audio_file', {
+        filePath: selected,
+        title,
+      });
+
+      if (meetingId) {
+        toast.success('Recording imported', { description: title });
+        await refetchMeetings();
+        setCurrentMeeting({ id: meetingId, title });
+        router.push(`/meeting-details?id=${meetingId}`);
+      } else {
+        toast.info('Import cancelled');
+      }
+    } catch (error) {
+      console.error('Failed to import recording:', error);
+      toast.error('Failed to import recording', { description: String(error) });
+    } finally {
+      setFilePath(null);
+      setProgress(null);
+    }
+  };
+
+  const handleCancel = async () => {
+    if (!filePath) return;
+    try {
+      await invoke<boolean>('api_cancel_import', { filePath });
+    } catch (error) {
+      console.error('Failed to cancel import:', error);
+    }
+  };
+
+  const isImporting = filePath !== null;
+  const percent = Math.round(progress?.progress ?? 0);
+  const label = (progress && STATUS_LABELS[progress.status]) || 'Preparing';
+
+  if (isCollapsed) {
+    return (
+      <button
+        onClick={isImporting ? handleCancel : handleImport}
+        className="p-2 rounded-lg transition-colors duration-150 hover:bg-gray-100"
+        title={isImporting ? `${label}... ${percent}% (click to cancel)` : 'Import recording'}
+      >
+        <FileAudio className={`w-5 h-5 text-gray-600 ${isImporting ? 'animate-pulse' : ''}`} />
+      </button>
+    );
+  }
+
+  if (isImporting) {
+    return (
+      <div className="w-full px-3 py-1.5 mt-1 rounded-lg bg-gray-100 text-sm text-gray-700">
+        <div className="flex items-center justify-between">
+          <span className="truncate">{label}... {percent}%</span>
+          <button
+            onClick={handleCancel}
+            className="ml-2 text-gray-500 hover:text-red-600"
+            title="Cancel import"
+          >
+            <X className="w-4 h-4" />
+          </button>
+        </div>
+        <div className="mt-1 h-1 w-full rounded bg-gray-200">
+          <div className="h-1 rounded bg-blue-500 transition-all" style={{ width: `${percent}%` }} />
+        </div>
+      </div>
+    );
+  }
+
+  return (
+    <button
+      onClick={handleImport}
+      className="w-full flex items-center justify-center px-3 py-1.5 mt-1 text-sm font-medium text-gray-700 bg-gray-200 hover:bg-gray-300 rounded-lg transition-colors shadow-sm"
+    >
+      <FileAudio className="w-4 h-4 mr-2" />
+      <span>Import recording</span>
+    </button>
+  );
+}
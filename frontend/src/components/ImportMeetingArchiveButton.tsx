@@ -0,0 +1,78 @@
+"use client";
+
+import React, { useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { useRouter } from 'next/navigation';
+import { toast } from 'sonner';
+import { Package } from 'lucide-react';
+import { useSidebar } from './Sidebar/SidebarProvider';
+import Analytics from '@/lib/analytics';
+
+interface ImportedMeeting {
+  meeting_id: string;
+  title: string;
+  folder_path: string;
+  installed_templates: string[];
+}
+
+interface ImportMeetingArchiveButtonProps {
+  isCollapsed: boolean;
+}
+
+export function ImportMeetingArchiveButton({ isCollapsed }: ImportMeetingArchiveButtonProps) {
+  const router = useRouter();
+  const { refetchMeetings, setCurrentMeeting } = useSidebar();
+  const [isImporting, setIsImporting] = useState(false);
+
+  const handleImport = async () => {
+    try {
+      const selected = await invoke<string | null>('select_meeting_archive_file');
+      if (!selected) return;
+
+      Analytics.trackButtonClick('import_meeting_archive', 'sidebar');
+      setIsImporting(true);
+
+      const imported = await invoke<ImportedMeeting>('api_import_meeting_archive', {
+        filePath: selected,
+      });
+
+      toast.success('Meeting imported', {
+        description: imported.installed_templates.length > 0
+          ? `${imported.title}. Added templates: ${imported.installed_templates.join(', ')}`
+          : imported.title,
+      });
+      await refetchMeetings();
+      setCurrentMeeting({ id: imported.meeting_id, title: imported.title });
+      router.push(`/meeting-details?id=${imported.meeting_id}`);
+    } catch (error) {
+      console.error('Failed to import meeting archive:', error);
+      toast.error('Failed to import meeting', { description: String(error) });
+    } finally {
+      setIsImporting(false);
+    }
+  };
+
+  if (isCollapsed) {
+    return (
+      <button
+        onClick={handleImport}
+        disabled={isImporting}
+        className="p-2 rounded-lg transition-colors duration-150 hover:bg-gray-100"
+        title={isImporting ? 'Importing meeting...' : 'Import meeting archive'}
+      >
+        <Package className={`w-5 h-5 text-gray-600 ${isImporting ? 'animate-pulse' : ''}`} />
+      </button>
+    );
+  }
+
+  return (
+    <button
+      onClick={handleImport}
+      disabled={isImporting}
+      className="w-full flex items-center justify-center px-3 py-1.5 mt-1 text-sm font-medium text-gray-700 bg-gray-200 hover:bg-gray-300 rounded-lg transition-colors shadow-sm disabled:opacity-60"
+    >
+      <Package className="w-4 h-4 mr-2" />
+      <span>{isImporting ? 'Importing...' : 'Import meeting archive'}</span>
+    </button>
+  );
+}
@@ -0,0 +1,69 @@
+"use client";
+
+import React, { useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { useRouter } from 'next/navigation';
+import { toast } from 'sonner';
+import { FileText } from 'lucide-react';
+import { useSidebar } from './Sidebar/SidebarProvider';
+import Analytics from '@/lib/analytics';
+
+interface ImportTranscriptButtonProps {
+  isCollapsed: boolean;
+}
+
+export function ImportTranscriptButton({ isCollapsed }: ImportTranscriptButtonProps) {
+  const router = useRouter();
+  const { refetchMeetings, setCurrentMeeting } = useSidebar();
+  const [isImporting, setIsImporting] = useState(false);
+
+  const handleImport = async () => {
+    try {
+      const selected = await invoke<string | null>('select_transcript_file');
+      if (!selected) return;
+
+      Analytics.trackButtonClick('import_transcript_file', 'sidebar');
+      setIsImporting(true);
+
+      const title = selected.split(/[\\/]/).pop()?.replace(/\.[^.]+$/, '') || 'Imported transcript';
+      const meetingId = await invoke<string>('api_import_transcript_file', {
+        filePath: selected,
+        title,
+      });
+
+      toast.success('Transcript imported', { description: title });
+      await refetchMeetings();
+      setCurrentMeeting({ id: meetingId, title });
+      router.push(`/meeting-details?id=${meetingId}`);
+    } catch (error) {
+      console.error('Failed to import transcript:', error);
+      toast.error('Failed to import transcript', { description: String(error) });
+    } finally {
+      setIsImporting(false);
+    }
+  };
+
+  if (isCollapsed) {
+    return (
+      <button
+        onClick={handleImport}
+        disabled={isImporting}
+        className="p-2 rounded-lg transition-colors duration-150 hover:bg-gray-100"
+        title={isImporting ? 'Importing transcript...' : 'Import transcript'}
+      >
+        <FileText className={`w-5 h-5 text-gray-600 ${isImporting ? 'animate-pulse' : ''}`} />
+      </button>
+    );
+  }
+
+  return (
+    <button
+      onClick={handleImport}
+      disabled={isImporting}
+      className="w-full flex items-center justify-center px-3 py-1.5 mt-1 text-sm font-medium text-gray-700 bg-gray-200 hover:bg-gray-300 rounded-lg transition-colors shadow-sm disabled:opacity-60"
+    >
+      <FileText className="w-4 h-4 mr-2" />
+      <span>{isImporting ? 'Importing...' : 'Import transcript'}</span>
+    </button>
+  );
+}
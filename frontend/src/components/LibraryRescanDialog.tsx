@@ -0,0 +1,158 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { toast } from 'sonner';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription } from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { useSidebar } from './Sidebar/SidebarProvider';
+import Analytics from '@/lib/analytics';
+
+interface LibraryScanReport {
+  matched: number;
+  orphans: { folder_path: string; title: string; created_at: string; transcript_segments: number }[];
+  moved: { meeting_id: string; title: string; previous_folder_path: string | null; folder_path: string }[];
+  missing: { meeting_id: string; title: string; folder_path: string }[];
+  reappeared: { meeting_id: string; title: string; folder_path: string }[];
+  skipped: { folder_path: string; reason: string }[];
+}
+
+interface LibraryScanResult {
+  imported: number;
+  relinked: number;
+  flagged_missing: number;
+  unflagged: number;
+  errors: string[];
+}
+
+interface LibraryRescanDialogProps {
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+}
+
+function folderName(path: string) {
+  return path.split(/[\\/]/).filter(Boolean).pop() || path;
+}
+
+function Section({ title, items }: { title: string; items: { key: string; label: string; detail: string }[] }) {
+  if (items.length === 0) return null;
+  return (
+    <div>
+      <p className="text-sm font-medium mb-1">{title} ({items.length})</p>
+      <div className="space-y-1">
+        {items.map(item => (
+          <div key={item.key} className="flex items-center gap-3 px-2 py-1 rounded hover:bg-gray-50 text-sm">
+            <span className="flex-1 truncate text-gray-700">{item.label}</span>
+            <span className="text-gray-500 text-xs truncate max-w-[50%]" title={item.detail}>{item.detail}</span>
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+}
+
+/**
+ * Compares the meeting folders on disk with the library and, once reviewed,
+ * imports meetings missing from it and flags meetings whose folder is gone.
+ */
+export function LibraryRescanDialog({ open, onOpenChange }: LibraryRescanDialogProps) {
+  const { refetchMeetings } = useSidebar();
+  const [report, setReport] = useState<LibraryScanReport | null>(null);
+  const [isBusy, setIsBusy] = useState(false);
+
+  useEffect(() => {
+    if (!open) return;
+    setReport(null);
+    setIsBusy(true);
+    invoke<LibraryScanReport>('api_scan_library')
+      .then(setReport)
+      .catch(error => {
+        console.error('Failed to scan library:', error);
+        toast.error('Failed to scan library', { description: String(error) });
+      })
+      .finally(() => setIsBusy(false));
+  }, [open]);
+
+  const changeCount = report
+    ? report.orphans.length + report.moved.length + report.missing.length + report.reappeared.length
+    : 0;
+
+  const handleApply = async () => {
+    if (!report) return;
+    setIsBusy(true);
+    try {
+      Analytics.trackButtonClick('apply_library_rescan', 'settings');
+      const result = await invoke<LibraryScanResult>('api_apply_library_scan', { report });
+      const summary = `${result.imported} imported, ${result.relinked} relinked, ${result.flagged_missing + result.unflagged} flags updated`;
+      if (result.errors.length > 0) {
+        toast.warning('Library rescanned with errors', { description: `${summary}. ${result.errors.join(' ')}` });
+      } else {
+        toast.success('Library rescanned', { description: summary });
+      }
+      await refetchMeetings();
+      onOpenChange(false);
+    } catch (error) {
+      console.error('Failed to apply library rescan:', error);
+      toast.error('Failed to apply library rescan', { description: String(error) });
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-[640px] max-h-[80vh] flex flex-col">
+        <DialogHeader>
+          <DialogTitle>Rescan library</DialogTitle>
+          <DialogDescription>
+            Compares the meeting folders in your recordings folder with the library. Nothing changes until you apply.
+          </DialogDescription>
+        </DialogHeader>
+
+        {!report ? (
+          <p className="text-sm text-gray-500">{isBusy ? 'Scanning meeting folders...' : 'No scan results.'}</p>
+        ) : (
+          <div className="overflow-y-auto space-y-4">
+            <p className="text-sm text-gray-600">
+              {report.matched} folders match their meeting.
+              {changeCount === 0 && ' The library is up to date.'}
+            </p>
+            <Section
+              title="Import as new meetings"
+              items={report.orphans.map(o => ({
+                key: o.folder_path,
+                label: o.title,
+                detail: `${o.transcript_segments} segments · ${new Date(o.created_at).toLocaleString()}`,
+              }))}
+            />
+            <Section
+              title="Folder moved"
+              items={report.moved.map(m => ({ key: m.meeting_id, label: m.title, detail: m.folder_path }))}
+            />
+            <Section
+              title="Flag as missing its folder"
+              items={report.missing.map(m => ({ key: m.meeting_id, label: m.title, detail: m.folder_path }))}
+            />
+            <Section
+              title="Folder found again"
+              items={report.reappeared.map(m => ({ key: m.meeting_id, label: m.title, detail: m.folder_path }))}
+            />
+            <Section
+              title="Skipped"
+              items={report.skipped.map(s => ({ key: s.folder_path, label: folderName(s.folder_path), detail: s.reason }))}
+            />
+          </div>
+        )}
+
+        <div className="flex items-center justify-end gap-2 border-t border-gray-200 pt-3">
+          <Button variant="outline" size="sm" disabled={isBusy} onClick={() => onOpenChange(false)}>
+            Cancel
+          </Button>
+          <Button size="sm" disabled={isBusy || changeCount === 0} onClick={handleApply}>
+            Apply {changeCount > 0 ? `${changeCount} changes` : ''}
+          </Button>
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,220 @@
+"use client";
+
+import { useCallback, useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import { Trash2, RefreshCw } from 'lucide-react';
+import Analytics from '@/lib/analytics';
+
+export interface ActionItem {
+  id: string;
+  meeting_id: string;
+  meeting_title?: string | null;
+  text: string;
+  owner?: string | null;
+  due_date?: string | null;
+  source_timestamp?: number | null;
+  status: 'open' | 'done' | 'cancelled';
+  last_seen_meeting_id?: string | null;
+  created_at: string;
+  updated_at: string;
+  completed_at?: string | null;
+}
+
+interface ActionItemsPanelProps {
+  meetingId: string;
+  modelProvider: string;
+  modelName: string;
+}
+
+const formatTimestamp = (seconds: number) => {
+  const total = Math.floor(seconds);
+  const h = Math.floor(total / 3600);
+  const m = Math.floor((total % 3600) / 60);
+  const s = total % 60;
+  const pad = (n: number) => n.toString().padStart(2, '0');
+  return h > 0 ? `${h}:${pad(m)}:${pad(s)}` : `${pad(m)}:${pad(s)}`;
+};
+
+export function ActionItemsPanel({ meetingId, modelProvider, modelName }: ActionItemsPanelProps) {
+  const [items, setItems] = useState<ActionItem[]>([]);
+  const [showAllOpen, setShowAllOpen] = useState(false);
+  const [newItemText, setNewItemText] = useState('');
+  const [isExtracting, setIsExtracting] = useState(false);
+
+  const loadItems = useCallback(async () => {
+    try {
+      setItems(showAllOpen
+        ? await invoke<ActionItem[]>('api_get_open_action_items', { owner: null })
+        : await invoke<ActionItem[]>('api_get_action_items', { meetingId }));
+    } catch (error) {
+      console.error('Failed to load action items:', error);
+      toast.error('Failed to load action items');
+    }
+  }, [meetingId, showAllOpen]);
+
+  useEffect(() => {
+    loadItems();
+  }, [loadItems]);
+
+  // Extraction runs in the background after every summary
+  useEffect(() => {
+    const unlisten = listen<{ meeting_id: string }>('action-items-updated', () => {
+      loadItems();
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [loadItems]);
+
+  const handleToggle = async (item: ActionItem) => {
+    try {
+      Analytics.trackButtonClick('toggle_action_item', 'meeting_details');
+      await invoke<ActionItem>('api_update_action_item', {
+        itemId: item.id,
+        update: { status: item.status === 'open' ? 'done' : 'open' },
+      });
+      await loadItems();
+    } catch (error) {
+      console.error('Failed to update action item:', error);
+      toast.error('Failed to update action item');
+    }
+  };
+
+  const handleDelete = async (item: ActionItem) => {
+    try {
+      await invoke<boolean>('api_delete_action_item', { itemId: item.id });
+      setItems(prev => prev.filter(i => i.id !== item.id));
+    } catch (error) {
+      console.error('Failed to delete action item:', error);
+      toast.error('Failed to delete action item');
+    }
+  };
+
+  const handleAdd = async () => {
+    const text = newItemText.trim();
+    if (!text) return;
+    try {
+      Analytics.trackButtonClick('add_action_item', 'meeting_details');
+      await invoke<ActionItem>('api_create_action_item', { meetingId, text });
+      setNewItemText('');
+      await loadItems();
+    } catch (error) {
+      console.error('Failed to create action item:', error);
+      toast.error('Failed to add action item');
+    }
+  };
+
+  const handleExtract = async () => {
+    if (!modelProvider || !modelName) {
+      toast.error('Select a model in Model Settings first');
+      return;
+    }
+    setIsExtracting(true);
+    try {
+      Analytics.trackButtonClick('extract_action_items', 'meeting_details');
+      await invoke<ActionItem[]>('api_extract_action_items', {
+        meetingId,
+        modelProvider,
+        modelName,
+      });
+      await loadItems();
+    } catch (error) {
+      console.error('Failed to extract action items:', error);
+      toast.error('Failed to extract action items', { description: String(error) });
+    } finally {
+      setIsExtracting(false);
+    }
+  };
+
+  return (
+    <div className="h-full flex flex-col">
+      <div className="flex items-center gap-2 px-6 pt-4 pb-2">
+        <div className="flex rounded-md border border-gray-200 text-sm overflow-hidden">
+          <button
+            className={`px-3 py-1 ${!showAllOpen ? 'bg-gray-100 font-medium' : 'text-gray-600'}`}
+            onClick={() => setShowAllOpen(false)}
+          >
+            This meeting
+          </button>
+          <button
+            className={`px-3 py-1 ${showAllOpen ? 'bg-gray-100 font-medium' : 'text-gray-600'}`}
+            onClick={() => setShowAllOpen(true)}
+          >
+            All open
+          </button>
+        </div>
+        <div className="flex-1" />
+        {!showAllOpen && (
+          <Button variant="outline" size="sm" disabled={isExtracting} onClick={handleExtract}>
+            <RefreshCw className={isExtracting ? 'animate-spin' : ''} />
+            Extract again
+          </Button>
+        )}
+      </div>
+
+      <div className="flex-1 overflow-y-auto px-6 pb-4">
+        {items.length === 0 ? (
+          <p className="text-sm text-gray-500 py-4">
+            {showAllOpen ? 'No open action items.' : 'No action items for this meeting yet.'}
+          </p>
+        ) : (
+          <ul className="divide-y divide-gray-100">
+            {items.map(item => (
+              <li key={item.id} className="flex items-start gap-3 py-2 group">
+                <input
+                  type="checkbox"
+                  className="mt-1"
+                  checked={item.status === 'done'}
+                  disabled={item.status === 'cancelled'}
+                  onChange={() => handleToggle(item)}
+                />
+                <div className="flex-1 min-w-0">
+                  <p className={`text-sm ${item.status !== 'open' ? 'line-through text-gray-400' : 'text-gray-900'}`}>
+                    {item.text}
+                  </p>
+                  <p className="text-xs text-gray-500 space-x-2">
+                    {item.owner && <span>👤 {item.owner}</span>}
+                    {item.due_date && <span>📅 {item.due_date}</span>}
+                    {item.source_timestamp != null && item.meeting_id === meetingId && (
+                      <span>⏱ {formatTimestamp(item.source_timestamp)}</span>
+                    )}
+                    {item.meeting_id !== meetingId && item.meeting_title && (
+                      <span>from “{item.meeting_title}”</span>
+                    )}
+                  </p>
+                </div>
+                <button
+                  className="opacity-0 group-hover:opacity-100 text-gray-400 hover:text-red-600"
+                  title="Delete"
+                  onClick={() => handleDelete(item)}
+                >
+                  <Trash2 className="h-4 w-4" />
+                </button>
+              </li>
+            ))}
+          </ul>
+        )}
+      </div>
+
+      {!showAllOpen && (
+        <div className="flex gap-2 px-6 py-3 border-t border-gray-200">
+          <input
+            className="flex-1 text-sm border border-gray-200 rounded-md px-3 py-1.5"
+            placeholder="Add an action item..."
+            value={newItemText}
+            onChange={e => setNewItemText(e.target.value)}
+            onKeyDown={e => {
+              if (e.key === 'Enter') handleAdd();
+            }}
+          />
+          <Button size="sm" disabled={!newItemText.trim()} onClick={handleAdd}>
+            Add
+          </Button>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -2,6 +2,7 @@
 
 import { useState, useEffect, useRef } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { useTranslation } from 'react-i18next';
 
 interface ChatMessage {
@@ -13,18 +14,60 @@ interface ChatMessage {
   metadata?: string | null;
 }
 
+interface ChatCitation {
+  index: number;
+  meeting_id: string;
+  chunk_index: number;
+  audio_start_time?: number | null;
+  audio_end_time?: number | null;
+  transcript_ids: string[];
+  excerpt: string;
+  score: number;
+}
+
+interface ChatStreamEvent {
+  meeting_id: string;
+  status: 'token' | 'completed' | 'cancelled' | 'failed';
+  delta?: string;
+  text?: string;
+  error?: string;
+}
+
 interface MeetingChatProps {
   meetingId: string;
   modelProvider: string;
   modelName: string;
+  /** Called when a citation is clicked, e.g. to jump to its audio_start_time */
+  onCitationClick?: (citation: ChatCitation) => void;
 }
 
-export function MeetingChat({ meetingId, modelProvider, modelName }: MeetingChatProps) {
+// Citations are stored in the assistant message metadata as {"citations": [...]}
+const getCitations = (message: ChatMessage): ChatCitation[] => {
+  if (!message.metadata) return [];
+  try {
+    const parsed = JSON.parse(message.metadata);
+    return Array.isArray(parsed?.citations) ? parsed.citations : [];
+  } catch {
+    return [];
+  }
+};
+
+const formatAudioTime = (seconds: number) => {
+  const total = Math.floor(seconds);
+  const h = Math.floor(total / 3600);
+  const m = Math.floor((total % 3600) / 60);
+  const s = total % 60;
+  const mmss = `${m.toString().padStart(2, '0')}:${s.toString().padStart(2, '0')}`;
+  return h > 0 ? `${h}:${mmss}` : mmss;
+};
+
+export function MeetingChat({ meetingId, modelProvider, modelName, onCitationClick }: MeetingChatProps) {
   const { t } = useTranslation();
   const [messages, setMessages] = useState<ChatMessage[]>([]);
   const [question, setQuestion] = useState('');
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [streamingAnswer, setStreamingAnswer] = useState('');
   const messagesEndRef = useRef<HTMLDivElement>(null);
   const textareaRef = useRef<HTMLTextAreaElement>(null);
 
@@ -33,10 +76,22 @@ export function MeetingChat({ meetingId, modelProvider, modelName }: MeetingChat
     loadMessages();
   }, [meetingId]);
 
+  // Show the answer as it streams in; the saved message replaces it when the request returns
+  useEffect(() => {
+    const unlisten = listen<ChatStreamEvent>('chat-stream', (event) => {
+      if (event.payload.meeting_id === meetingId && event.payload.status === 'token') {
+        setStreamingAnswer(prev => prev + (event.payload.delta || ''));
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [meetingId]);
+
   // Scroll to bottom when messages change
   useEffect(() => {
     scrollToBottom();
-  }, [messages]);
+  }, [messages, streamingAnswer]);
 
   const scrollToBottom = () => {
     messagesEndRef.current?.scrollIntoView({ behavior: 'smooth' });
@@ -69,6 +124,7 @@ export function MeetingChat({ meetingId, modelProvider, modelName }: MeetingChat
 
     setIsLoading(true);
     setError(null);
+    setStreamingAnswer('');
 
     try {
       const response = await invoke<{
@@ -91,10 +147,22 @@ export function MeetingChat({ meetingId, modelProvider, modelName }: MeetingChat
         textareaRef.current.style.height = 'auto';
       }
     } catch (err: any) {
-      console.error('Failed to ask question:', err);
-      setError(err?.toString() || t('chat.errorAskingQuestion', 'Failed to get answer'));
+      // Stopped before any text arrived: nothing was saved, nothing to report
+      if (!err?.toString().includes('Answer cancelled')) {
+        console.error('Failed to ask question:', err);
+        setError(err?.toString() || t('chat.errorAskingQuestion', 'Failed to get answer'));
+      }
     } finally {
       setIsLoading(false);
+      setStreamingAnswer('');
+    }
+  };
+
+  const handleStop = async () => {
+    try {
+      await invoke('api_cancel_chat_answer', { meetingId });
+    } catch (err) {
+      console.error('Failed to stop answer:', err);
     }
   };
 
@@ -162,6 +230,22 @@ export function MeetingChat({ meetingId, modelProvider, modelName }: MeetingChat
                 <div className="text-base whitespace-pre-wrap break-words">
                   {message.content}
                 </div>
+                {message.role === 'assistant' && getCitations(message).length > 0 && (
+                  <div className="flex flex-wrap gap-1 mt-2">
+                    {getCitations(message).map((citation) => (
+                      <button
+                        key={citation.index}
+                        type="button"
+                        onClick={() => onCitationClick?.(citation)}
+                        title={citation.excerpt}
+                        className="text-xs px-2 py-0.5 rounded bg-blue-50 text-blue-700 border border-blue-200 hover:bg-blue-100"
+                      >
+                        [{citation.index}]
+                        {citation.audio_start_time != null && ` ${formatAudioTime(citation.audio_start_time)}`}
+                      </button>
+                    ))}
+                  </div>
+                )}
                 <div
                   className={`text-xs mt-1 ${
                     message.role === 'user' ? 'text-blue-100' : 'text-gray-500'
@@ -175,17 +259,21 @@ export function MeetingChat({ meetingId, modelProvider, modelName }: MeetingChat
         )}
         {isLoading && (
           <div className="flex justify-start">
-            <div className="bg-white text-gray-900 border border-gray-200 rounded-lg px-4 py-2">
-              <div className="flex items-center space-x-2">
-                <div className="animate-pulse flex space-x-1">
-                  <div className="h-2 w-2 bg-gray-400 rounded-full"></div>
-                  <div className="h-2 w-2 bg-gray-400 rounded-full"></div>
-                  <div className="h-2 w-2 bg-gray-400 rounded-full"></div>
+            <div className="max-w-[80%] bg-white text-gray-900 border border-gray-200 rounded-lg px-4 py-2">
+              {streamingAnswer ? (
+                <div className="text-base whitespace-pre-wrap break-words">{streamingAnswer}</div>
+              ) : (
+                <div className="flex items-center space-x-2">
+                  <div className="animate-pulse flex space-x-1">
+                    <div className="h-2 w-2 bg-gray-400 rounded-full"></div>
+                    <div className="h-2 w-2 bg-gray-400 rounded-full"></div>
+                    <div className="h-2 w-2 bg-gray-400 rounded-full"></div>
+                  </div>
+                  <span className="text-sm text-gray-500">
+                    {t('chat.thinking', 'Thinking...')}
+                  </span>
                 </div>
-                <span className="text-sm text-gray-500">
-                  {t('chat.thinking', 'Thinking...')}
-                </span>
-              </div>
+              )}
             </div>
           </div>
         )}
@@ -218,6 +306,16 @@ export function MeetingChat({ meetingId, modelProvider, modelName }: MeetingChat
               {t('chat.hint', 'Press Enter to send, Shift+Enter for new line')}
             </p>
           </div>
+          {isLoading && (
+            <button
+              type="button"
+              onClick={handleStop}
+              className="px-4 py-2 text-gray-700 bg-gray-100 rounded-lg hover:bg-gray-200 transition-colors"
+              style={{ minHeight: '40px' }}
+            >
+              {t('chat.stop', 'Stop')}
+            </button>
+          )}
           <button
             type="submit"
             disabled={!question.trim() || isLoading}
@@ -0,0 +1,257 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { toast } from 'sonner';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription } from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { ParakeetAPI, ParakeetModelInfo, MODEL_DISPLAY_CONFIG } from '@/lib/parakeet';
+import Analytics from '@/lib/analytics';
+
+export interface TranscriptVersion {
+  id: string;
+  meeting_id: string;
+  version: number;
+  label: string;
+  model?: string | null;
+  language?: string | null;
+  vad_settings?: string | null;
+  segment_count: number;
+  segments: string;
+  created_at: string;
+}
+
+interface RetranscribeProgress {
+  meeting_id: string;
+  status: string;
+  progress: number;
+  error?: string;
+}
+
+type RetranscribeMode = 'replace' | 'alternate';
+
+interface RetranscribeDialogProps {
+  meetingId: string;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  /** Called once the meeting's current transcript has changed */
+  onTranscriptChanged: () => Promise<void>;
+}
+
+const STAGE_LABELS: Record<string, string> = {
+  decoding: 'Decoding audio',
+  detecting_speech: 'Detecting speech',
+  transcribing: 'Transcribing',
+  saving: 'Saving',
+};
+
+export function RetranscribeDialog({ meetingId, open, onOpenChange, onTranscriptChanged }: RetranscribeDialogProps) {
+  const [models, setModels] = useState<ParakeetModelInfo[]>([]);
+  const [model, setModel] = useState('');
+  const [redemptionMs, setRedemptionMs] = useState(400);
+  const [maxSegmentSeconds, setMaxSegmentSeconds] = useState(20);
+  const [mode, setMode] = useState<RetranscribeMode>('replace');
+  const [versions, setVersions] = useState<TranscriptVersion[]>([]);
+  const [progress, setProgress] = useState<RetranscribeProgress | null>(null);
+  const [isBusy, setIsBusy] = useState(false);
+
+  const loadVersions = () =>
+    invoke<TranscriptVersion[]>('api_list_transcript_versions', { meetingId })
+      .then(setVersions)
+      .catch(error => console.error('Failed to load transcript versions:', error));
+
+  useEffect(() => {
+    if (!open) return;
+    setProgress(null);
+    loadVersions();
+    ParakeetAPI.getAvailableModels()
+      .then(all => setModels(all.filter(m => m.status === 'Available')))
+      .catch(error => console.error('Failed to load transcription models:', error));
+  }, [open, meetingId]);
+
+  useEffect(() => {
+    const unlisten = listen<RetranscribeProgress>('retranscribe-progress', event => {
+      if (event.payload.meeting_id === meetingId) {
+        setProgress(event.payload);
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [meetingId]);
+
+  const handleRetranscribe = async () => {
+    setIsBusy(true);
+    try {
+      Analytics.trackButtonClick('retranscribe_meeting', 'meeting_details');
+      const result = await invoke<{ segment_count: number } | null>('api_retranscribe_meeting', {
+        meetingId,
+        options: {
+          model: model || null,
+          vad: { redemption_time_ms: redemptionMs, max_segment_ms: maxSegmentSeconds * 1000 },
+          mode,
+        },
+      });
+      if (!result) {
+        toast.info('Re-transcription cancelled');
+        return;
+      }
+      toast.success(
+        mode === 'replace' ? 'Transcript replaced' : 'Alternate transcript saved',
+        { description: `${result.segment_count} segments` }
+      );
+      await loadVersions();
+      if (mode === 'replace') {
+        await onTranscriptChanged();
+      }
+    } catch (error) {
+      console.error('Failed to re-transcribe meeting:', error);
+      toast.error('Failed to re-transcribe', { description: String(error) });
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const handleCancel = () => {
+    invoke('api_cancel_retranscribe', { meetingId }).catch(error =>
+      console.error('Failed to cancel re-transcription:', error)
+    );
+  };
+
+  const handleRestore = async (version: TranscriptVersion) => {
+    setIsBusy(true);
+    try {
+      Analytics.trackButtonClick('restore_transcript_version', 'meeting_details');
+      await invoke('api_restore_transcript_version', { meetingId, versionId: version.id });
+      toast.success(`Restored version ${version.version}`);
+      await loadVersions();
+      await onTranscriptChanged();
+    } catch (error) {
+      console.error('Failed to restore transcript version:', error);
+      toast.error('Failed to restore version');
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const handleDelete = async (version: TranscriptVersion) => {
+    try {
+      await invoke('api_delete_transcript_version', { meetingId, versionId: version.id });
+      await loadVersions();
+    } catch (error) {
+      console.error('Failed to delete transcript version:', error);
+      toast.error('Failed to delete version');
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-[640px] max-h-[80vh] flex flex-col">
+        <DialogHeader>
+          <DialogTitle>Re-transcribe</DialogTitle>
+          <DialogDescription>
+            Transcribe the meeting&apos;s recording again, for example with a newer or more precise model.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="grid grid-cols-2 gap-3 text-sm">
+          <label className="flex flex-col gap-1">
+            <span className="text-gray-600">Model</span>
+            <select
+              className="border border-gray-200 rounded px-2 py-1"
+              value={model}
+              onChange={e => setModel(e.target.value)}
+              disabled={isBusy}
+            >
+              <option value="">Current model</option>
+              {models.map(m => (
+                <option key={m.name} value={m.name}>
+                  {MODEL_DISPLAY_CONFIG[m.name]?.friendlyName || m.name} ({m.quantization})
+                </option>
+              ))}
+            </select>
+          </label>
+          <label className="flex flex-col gap-1">
+            <span className="text-gray-600">Pause that ends a segment (ms)</span>
+            <input
+              type="number"
+              min={100}
+              max={10000}
+              step={100}
+              className="border border-gray-200 rounded px-2 py-1"
+              value={redemptionMs}
+              onChange={e => setRedemptionMs(Number(e.target.value))}
+              disabled={isBusy}
+            />
+          </label>
+          <label className="flex flex-col gap-1">
+            <span className="text-gray-600">Longest segment (s)</span>
+            <input
+              type="number"
+              min={5}
+              max={120}
+              step={5}
+              className="border border-gray-200 rounded px-2 py-1"
+              value={maxSegmentSeconds}
+              onChange={e => setMaxSegmentSeconds(Number(e.target.value))}
+              disabled={isBusy}
+            />
+          </label>
+          <div className="flex flex-col gap-1">
+            <span className="text-gray-600">New transcript</span>
+            <label className="flex items-center gap-2">
+              <input type="radio" checked={mode === 'replace'} onChange={() => setMode('replace')} disabled={isBusy} />
+              Replace the current one
+            </label>
+            <label className="flex items-center gap-2">
+              <input type="radio" checked={mode === 'alternate'} onChange={() => setMode('alternate')} disabled={isBusy} />
+              Keep as a version
+            </label>
+          </div>
+        </div>
+
+        <div className="flex items-center justify-end gap-2">
+          {isBusy && progress && (
+            <span className="flex-1 text-xs text-gray-500">
+              {STAGE_LABELS[progress.status] || progress.status} · {Math.round(progress.progress)}%
+            </span>
+          )}
+          {isBusy && (
+            <Button variant="outline" size="sm" onClick={handleCancel}>
+              Cancel
+            </Button>
+          )}
+          <Button size="sm" disabled={isBusy} onClick={handleRetranscribe}>
+            Re-transcribe
+          </Button>
+        </div>
+
+        <div className="border-t border-gray-200 pt-3">
+          <p className="text-sm font-medium mb-2">Transcript versions</p>
+          {versions.length === 0 ? (
+            <p className="text-sm text-gray-500">No other versions yet.</p>
+          ) : (
+            <div className="overflow-y-auto space-y-1 max-h-48">
+              {versions.map(version => (
+                <div key={version.id} className="flex items-center gap-3 px-2 py-1.5 rounded hover:bg-gray-50 text-sm">
+                  <span className="font-medium w-8">v{version.version}</span>
+                  <span className="flex-1 truncate text-gray-700">{version.label}</span>
+                  <span className="text-gray-500 text-xs">
+                    {version.segment_count} segments · {new Date(version.created_at).toLocaleString()}
+                  </span>
+                  <Button variant="outline" size="sm" disabled={isBusy} onClick={() => handleRestore(version)}>
+                    Restore
+                  </Button>
+                  <Button variant="ghost" size="sm" disabled={isBusy} onClick={() => handleDelete(version)}>
+                    Delete
+                  </Button>
+                </div>
+              ))}
+            </div>
+          )}
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -14,6 +14,7 @@ import {
   DropdownMenu,
   DropdownMenuContent,
   DropdownMenuItem,
+  DropdownMenuSeparator,
   DropdownMenuTrigger,
 } from '@/components/ui/dropdown-menu';
 import { Sparkles, Settings, Loader2, FileText, Check } from 'lucide-react';
@@ -33,6 +34,8 @@ interface SummaryGeneratorButtonGroupProps {
   availableTemplates: Array<{id: string, name: string, description: string}>;
   selectedTemplate: string;
   onTemplateSelect: (templateId: string, templateName: string) => void;
+  structuredOutput: boolean;
+  onStructuredOutputToggle: () => void;
   hasTranscripts?: boolean;
   isModelConfigLoading?: boolean;
 }
@@ -47,6 +50,8 @@ export function SummaryGeneratorButtonGroup({
   availableTemplates,
   selectedTemplate,
   onTemplateSelect,
+  structuredOutput,
+  onStructuredOutputToggle,
   hasTranscripts = true,
   isModelConfigLoading = false
 }: SummaryGeneratorButtonGroupProps) {
@@ -188,7 +193,21 @@ export function SummaryGeneratorButtonGroup({
                 )}
               </DropdownMenuItem>
             ))}
-
+            <DropdownMenuSeparator />
+            <DropdownMenuItem
+              onSelect={(event) => {
+                // Keep the menu open so the toggle state stays visible
+                event.preventDefault();
+                onStructuredOutputToggle();
+              }}
+              title={t('summary.structuredOutputDescription')}
+              className="flex items-center justify-between gap-2"
+            >
+              <span>{t('summary.structuredOutput')}</span>
+              {structuredOutput && (
+                <Check className="h-4 w-4 text-green-600" />
+              )}
+            </DropdownMenuItem>
           </DropdownMenuContent>
         </DropdownMenu>
       )}
@@ -8,6 +8,8 @@ import { ModelConfig } from '@/components/ModelSettingsModal';
 import { SummaryGeneratorButtonGroup } from './SummaryGeneratorButtonGroup';
 import { SummaryUpdaterButtonGroup } from './SummaryUpdaterButtonGroup';
 import { MeetingChat } from './MeetingChat';
+import { ActionItemsPanel } from './ActionItemsPanel';
+import { SummaryVersionsDialog, SummaryVersion } from './SummaryVersionsDialog';
 import Analytics from '@/lib/analytics';
 import { RefObject, useState } from 'react';
 
@@ -28,6 +30,7 @@ interface SummaryPanelProps {
   onSaveAll: () => Promise<void>;
   onCopySummary: () => Promise<void>;
   onOpenFolder: () => Promise<void>;
+  folderMissing?: boolean;
   aiSummary: Summary | null;
   summaryStatus: 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error';
   transcripts: Transcript[];
@@ -42,10 +45,15 @@ interface SummaryPanelProps {
   onDirtyChange: (isDirty: boolean) => void;
   summaryError: string | null;
   onRegenerateSummary: () => Promise<void>;
+  streamingSummary?: string;
+  onCancelSummary?: () => Promise<void>;
+  onSummaryRestored?: (summary: Summary) => void;
   getSummaryStatusMessage: (status: 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error') => string;
   availableTemplates: Array<{id: string, name: string, description: string}>;
   selectedTemplate: string;
   onTemplateSelect: (templateId: string, templateName: string) => void;
+  structuredOutput: boolean;
+  onStructuredOutputToggle: () => void;
   isModelConfigLoading?: boolean;
 }
 
@@ -62,6 +70,7 @@ export function SummaryPanel({
   onSaveAll,
   onCopySummary,
   onOpenFolder,
+  folderMissing = false,
   aiSummary,
   summaryStatus,
   transcripts,
@@ -76,16 +85,31 @@ export function SummaryPanel({
   onDirtyChange,
   summaryError,
   onRegenerateSummary,
+  streamingSummary = '',
+  onCancelSummary,
+  onSummaryRestored,
   getSummaryStatusMessage,
   availableTemplates,
   selectedTemplate,
   onTemplateSelect,
+  structuredOutput,
+  onStructuredOutputToggle,
   isModelConfigLoading = false
 }: SummaryPanelProps) {
   const isSummaryLoading = summaryStatus === 'processing' || summaryStatus === 'summarizing' || summaryStatus === 'regenerating';
 
   // Tab state - Chat is default (core business)
-  const [activeTab, setActiveTab] = useState<'chat' | 'summary'>('chat');
+  const [activeTab, setActiveTab] = useState<'chat' | 'summary' | 'actions'>('chat');
+  const [isHistoryOpen, setIsHistoryOpen] = useState(false);
+
+  const handleVersionRestored = (version: SummaryVersion) => {
+    try {
+      const result = JSON.parse(version.result);
+      onSummaryRestored?.(result.markdown ? ({ markdown: result.markdown } as any) : result);
+    } catch (error) {
+      console.error('Failed to parse restored summary:', error);
+    }
+  };
 
   return (
     <div className="flex-1 min-w-0 flex flex-col bg-white overflow-hidden">
@@ -129,6 +153,25 @@ export function SummaryPanel({
               📝 Summary
             </span>
           </button>
+
+          {/* Action Items Tab */}
+          <button
+            onClick={() => {
+              setActiveTab('actions');
+              Analytics.trackButtonClick('action_items_tab', 'summary_panel');
+            }}
+            className={`
+              px-4 py-2 font-medium text-sm rounded-t-lg transition-colors
+              ${activeTab === 'actions'
+                ? 'bg-white text-blue-600 border-t border-l border-r border-gray-200'
+                : 'text-gray-600 hover:text-gray-900 hover:bg-gray-50'
+              }
+            `}
+          >
+            <span className="flex items-center gap-2">
+              ✅ Action Items
+            </span>
+          </button>
         </div>
 
         {/* Action Buttons - Only show in Summary tab when summary exists */}
@@ -146,6 +189,8 @@ export function SummaryPanel({
                 availableTemplates={availableTemplates}
                 selectedTemplate={selectedTemplate}
                 onTemplateSelect={onTemplateSelect}
+                structuredOutput={structuredOutput}
+                onStructuredOutputToggle={onStructuredOutputToggle}
                 hasTranscripts={transcripts.length > 0}
                 isModelConfigLoading={isModelConfigLoading}
               />
@@ -163,6 +208,8 @@ export function SummaryPanel({
                   console.log('Find in summary clicked');
                 }}
                 onOpenFolder={onOpenFolder}
+                folderMissing={folderMissing}
+                onShowHistory={() => setIsHistoryOpen(true)}
                 hasSummary={!!aiSummary}
               />
             </div>
@@ -170,6 +217,13 @@ export function SummaryPanel({
         )}
       </div>
 
+      <SummaryVersionsDialog
+        meetingId={meeting.id}
+        open={isHistoryOpen}
+        onOpenChange={setIsHistoryOpen}
+        onRestored={handleVersionRestored}
+      />
+
       {/* Tab Content */}
       <div className="flex-1 overflow-hidden">
         {/* Chat Tab Content */}
@@ -179,10 +233,28 @@ export function SummaryPanel({
               meetingId={meeting.id}
               modelProvider={modelConfig.provider || ''}
               modelName={modelConfig.model || ''}
+              onCitationClick={(citation) => {
+                // Jump the transcript panel to the first cited segment
+                const firstId = citation.transcript_ids[0];
+                if (firstId) {
+                  document
+                    .getElementById(`transcript-${firstId}`)
+                    ?.scrollIntoView({ behavior: 'smooth', block: 'center' });
+                }
+              }}
             />
           </div>
         )}
 
+        {/* Action Items Tab Content */}
+        {activeTab === 'actions' && (
+          <ActionItemsPanel
+            meetingId={meeting.id}
+            modelProvider={modelConfig.provider || ''}
+            modelName={modelConfig.model || ''}
+          />
+        )}
+
         {/* Summary Tab Content */}
         {activeTab === 'summary' && (
           <>
@@ -200,17 +272,39 @@ export function SummaryPanel({
                     availableTemplates={availableTemplates}
                     selectedTemplate={selectedTemplate}
                     onTemplateSelect={onTemplateSelect}
+                    structuredOutput={structuredOutput}
+                    onStructuredOutputToggle={onStructuredOutputToggle}
                     hasTranscripts={transcripts.length > 0}
                     isModelConfigLoading={isModelConfigLoading}
                   />
                 </div>
-                {/* Loading spinner */}
-                <div className="flex items-center justify-center flex-1">
-                  <div className="text-center">
-                    <div className="inline-block animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500 mb-4"></div>
-                    <p className="text-gray-600">Generating AI Summary...</p>
+                {streamingSummary ? (
+                  /* Final report as it streams in */
+                  <div className="flex-1 overflow-y-auto px-6 pb-6">
+                    <div className="text-sm text-gray-800 whitespace-pre-wrap break-words">{streamingSummary}</div>
                   </div>
-                </div>
+                ) : (
+                  /* Loading spinner */
+                  <div className="flex items-center justify-center flex-1">
+                    <div className="text-center">
+                      <div className="inline-block animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500 mb-4"></div>
+                      <p className="text-gray-600">Generating AI Summary...</p>
+                    </div>
+                  </div>
+                )}
+                {onCancelSummary && (
+                  <div className="flex justify-center pb-6">
+                    <button
+                      onClick={() => {
+                        Analytics.trackButtonClick('cancel_summary', 'meeting_details');
+                        onCancelSummary();
+                      }}
+                      className="px-4 py-2 text-sm text-gray-700 bg-gray-100 rounded-lg hover:bg-gray-200"
+                    >
+                      Stop generating
+                    </button>
+                  </div>
+                )}
               </div>
             ) : !aiSummary ? (
               <div className="flex flex-col h-full">
@@ -226,6 +320,8 @@ export function SummaryPanel({
                     availableTemplates={availableTemplates}
                     selectedTemplate={selectedTemplate}
                     onTemplateSelect={onTemplateSelect}
+                    structuredOutput={structuredOutput}
+                    onStructuredOutputToggle={onStructuredOutputToggle}
                     hasTranscripts={transcripts.length > 0}
                     isModelConfigLoading={isModelConfigLoading}
                   />
@@ -2,7 +2,7 @@
 
 import { Button } from '@/components/ui/button';
 import { ButtonGroup } from '@/components/ui/button-group';
-import { Copy, Save, Loader2, Search, FolderOpen } from 'lucide-react';
+import { Copy, Save, Loader2, Search, FolderOpen, History } from 'lucide-react';
 import Analytics from '@/lib/analytics';
 
 interface SummaryUpdaterButtonGroupProps {
@@ -12,7 +12,9 @@ interface SummaryUpdaterButtonGroupProps {
   onCopy: () => Promise<void>;
   onFind?: () => void;
   onOpenFolder: () => Promise<void>;
+  onShowHistory?: () => void;
   hasSummary: boolean;
+  folderMissing?: boolean;
 }
 
 export function SummaryUpdaterButtonGroup({
@@ -22,7 +24,9 @@ export function SummaryUpdaterButtonGroup({
   onCopy,
   onFind,
   onOpenFolder,
-  hasSummary
+  onShowHistory,
+  hasSummary,
+  folderMissing = false
 }: SummaryUpdaterButtonGroupProps) {
   return (
     <ButtonGroup>
@@ -67,6 +71,23 @@ export function SummaryUpdaterButtonGroup({
         <span className="hidden lg:inline">Copy</span>
       </Button>
 
+      {/* Version history button */}
+      {onShowHistory && (
+        <Button
+          variant="outline"
+          size="sm"
+          title="Summary History"
+          onClick={() => {
+            Analytics.trackButtonClick('summary_history', 'meeting_details');
+            onShowHistory();
+          }}
+          className="cursor-pointer"
+        >
+          <History />
+          <span className="hidden lg:inline">History</span>
+        </Button>
+      )}
+
       {/* Recording folder button */}
       <Button
         variant="outline"
@@ -76,7 +97,8 @@ export function SummaryUpdaterButtonGroup({
           Analytics.trackButtonClick('open_recording_folder', 'meeting_details');
           onOpenFolder();
         }}
-        title="Open Recording Folder"
+        disabled={folderMissing}
+        title={folderMissing ? 'Recording folder not found' : 'Open Recording Folder'}
       >
         <FolderOpen className="xl:mr-2" size={18} />
         <span className="hidden xl:inline">Recording</span>
@@ -0,0 +1,194 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { toast } from 'sonner';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription } from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import Analytics from '@/lib/analytics';
+
+export interface SummaryVersion {
+  id: string;
+  meeting_id: string;
+  version: number;
+  template_id?: string | null;
+  provider?: string | null;
+  model?: string | null;
+  custom_prompt?: string | null;
+  language?: string | null;
+  processing_time: number;
+  chunk_count: number;
+  result: string;
+  created_at: string;
+  /** 'generated', or 'manual' for edits kept when another version was restored */
+  source: string;
+}
+
+interface DiffLine {
+  kind: 'equal' | 'added' | 'removed';
+  text: string;
+}
+
+interface SummaryVersionDiff {
+  from: SummaryVersion;
+  to: SummaryVersion;
+  lines: DiffLine[];
+  added: number;
+  removed: number;
+}
+
+interface SummaryVersionsDialogProps {
+  meetingId: string;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  /** Called with the restored version once it is the meeting's current summary */
+  onRestored: (version: SummaryVersion) => void;
+}
+
+const describeVersion = (version: SummaryVersion) =>
+  version.source === 'manual'
+    ? 'Manual edits'
+    : [version.provider, version.model, version.template_id].filter(Boolean).join(' · ');
+
+export function SummaryVersionsDialog({ meetingId, open, onOpenChange, onRestored }: SummaryVersionsDialogProps) {
+  const [versions, setVersions] = useState<SummaryVersion[]>([]);
+  const [selected, setSelected] = useState<string[]>([]);
+  const [diff, setDiff] = useState<SummaryVersionDiff | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+
+  useEffect(() => {
+    if (!open) return;
+    setSelected([]);
+    setDiff(null);
+    invoke<SummaryVersion[]>('api_list_summary_versions', { meetingId })
+      .then(setVersions)
+      .catch(error => {
+        console.error('Failed to load summary versions:', error);
+        toast.error('Failed to load summary history');
+      });
+  }, [open, meetingId]);
+
+  const toggleSelected = (versionId: string) => {
+    setDiff(null);
+    setSelected(prev =>
+      prev.includes(versionId)
+        ? prev.filter(id => id !== versionId)
+        : [...prev, versionId].slice(-2)
+    );
+  };
+
+  const handleCompare = async () => {
+    if (selected.length !== 2) return;
+    // Always diff older -> newer
+    const [from, to] = versions
+      .filter(v => selected.includes(v.id))
+      .sort((a, b) => a.version - b.version);
+    setIsLoading(true);
+    try {
+      Analytics.trackButtonClick('compare_summary_versions', 'meeting_details');
+      setDiff(await invoke<SummaryVersionDiff>('api_diff_summary_versions', {
+        meetingId,
+        fromVersionId: from.id,
+        toVersionId: to.id,
+      }));
+    } catch (error) {
+      console.error('Failed to compare summary versions:', error);
+      toast.error('Failed to compare versions');
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  const handleRestore = async (version: SummaryVersion) => {
+    setIsLoading(true);
+    try {
+      Analytics.trackButtonClick('restore_summary_version', 'meeting_details');
+      const restored = await invoke<SummaryVersion>('api_restore_summary_version', {
+        meetingId,
+        versionId: version.id,
+      });
+      onRestored(restored);
+      toast.success(`Restored version ${restored.version}`);
+      onOpenChange(false);
+    } catch (error) {
+      console.error('Failed to restore summary version:', error);
+      toast.error('Failed to restore version');
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-[720px] max-h-[80vh] flex flex-col">
+        <DialogHeader>
+          <DialogTitle>Summary history</DialogTitle>
+          <DialogDescription>
+            Every generated summary is kept. Select two versions to compare them, or restore one.
+          </DialogDescription>
+        </DialogHeader>
+
+        {versions.length === 0 ? (
+          <p className="text-sm text-gray-500 py-4">No summaries generated yet.</p>
+        ) : (
+          <div className="overflow-y-auto space-y-1 max-h-64">
+            {versions.map(version => (
+              <div
+                key={version.id}
+                className="flex items-center gap-3 px-2 py-1.5 rounded hover:bg-gray-50 text-sm"
+              >
+                <input
+                  type="checkbox"
+                  checked={selected.includes(version.id)}
+                  onChange={() => toggleSelected(version.id)}
+                />
+                <span className="font-medium w-8">v{version.version}</span>
+                <span className="flex-1 truncate text-gray-700">{describeVersion(version) || '—'}</span>
+                <span className="text-gray-500 text-xs">
+                  {new Date(version.created_at).toLocaleString()}
+                  {version.processing_time > 0 && ` · ${version.processing_time.toFixed(1)}s`}
+                </span>
+                <Button variant="outline" size="sm" disabled={isLoading} onClick={() => handleRestore(version)}>
+                  Restore
+                </Button>
+              </div>
+            ))}
+          </div>
+        )}
+
+        <div className="flex justify-end">
+          <Button size="sm" disabled={selected.length !== 2 || isLoading} onClick={handleCompare}>
+            Compare selected
+          </Button>
+        </div>
+
+        {diff && (
+          <div className="flex-1 min-h-0 flex flex-col border-t border-gray-200 pt-3">
+            <p className="text-xs text-gray-500 mb-2">
+              v{diff.from.version} → v{diff.to.version}:{' '}
+              <span className="text-green-700">+{diff.added}</span>{' '}
+              <span className="text-red-700">-{diff.removed}</span>
+            </p>
+            <pre className="flex-1 overflow-auto text-xs font-mono bg-gray-50 rounded p-2">
+              {diff.lines.map((line, i) => (
+                <div
+                  key={i}
+                  className={
+                    line.kind === 'added'
+                      ? 'bg-green-100 text-green-900'
+                      : line.kind === 'removed'
+                        ? 'bg-red-100 text-red-900'
+                        : 'text-gray-700'
+                  }
+                >
+                  {line.kind === 'added' ? '+ ' : line.kind === 'removed' ? '- ' : '  '}
+                  {line.text}
+                </div>
+              ))}
+            </pre>
+          </div>
+        )}
+      </DialogContent>
+    </Dialog>
+  );
+}
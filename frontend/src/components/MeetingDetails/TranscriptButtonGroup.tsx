@@ -1,23 +1,47 @@
 "use client";
 
+import { useState } from 'react';
 import { Button } from '@/components/ui/button';
 import { ButtonGroup } from '@/components/ui/button-group';
-import { Copy, FolderOpen } from 'lucide-react';
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuSeparator,
+  DropdownMenuTrigger,
+} from '@/components/ui/dropdown-menu';
+import { Copy, Download, FolderOpen, RefreshCw } from 'lucide-react';
 import Analytics from '@/lib/analytics';
+import { RetranscribeDialog } from './RetranscribeDialog';
 
 
 interface TranscriptButtonGroupProps {
   transcriptCount: number;
   onCopyTranscript: () => void;
   onOpenMeetingFolder: () => Promise<void>;
+  onExportSubtitles: (format: 'srt' | 'vtt') => Promise<void>;
+  onExportArchive: () => Promise<void>;
+  meetingId: string;
+  onTranscriptChanged: () => Promise<void>;
+  isRecording: boolean;
+  /** The recording folder is gone: nothing to open or re-transcribe */
+  folderMissing: boolean;
 }
 
 
 export function TranscriptButtonGroup({
   transcriptCount,
   onCopyTranscript,
-  onOpenMeetingFolder
+  onOpenMeetingFolder,
+  onExportSubtitles,
+  onExportArchive,
+  meetingId,
+  onTranscriptChanged,
+  isRecording,
+  folderMissing
 }: TranscriptButtonGroupProps) {
+  const [isRetranscribeOpen, setIsRetranscribeOpen] = useState(false);
+
   return (
     <div className="flex items-center justify-center w-full gap-2">
       <ButtonGroup>
@@ -43,12 +67,81 @@ export function TranscriptButtonGroup({
             Analytics.trackButtonClick('open_recording_folder', 'meeting_details');
             onOpenMeetingFolder();
           }}
-          title="Open Recording Folder"
+          disabled={folderMissing}
+          title={folderMissing ? 'Recording folder not found' : 'Open Recording Folder'}
         >
           <FolderOpen className="xl:mr-2" size={18} />
           <span className="hidden lg:inline">Recording</span>
         </Button>
+
+        <DropdownMenu>
+          <DropdownMenuTrigger asChild>
+            <Button
+              variant="outline"
+              size="sm"
+              disabled={isRecording}
+              title={isRecording ? 'Not available while recording' : 'Export'}
+            >
+              <Download />
+              <span className="hidden lg:inline">Export</span>
+            </Button>
+          </DropdownMenuTrigger>
+          <DropdownMenuContent align="end">
+            <DropdownMenuItem
+              disabled={transcriptCount === 0}
+              onClick={() => {
+                Analytics.trackButtonClick('export_subtitles_srt', 'meeting_details');
+                onExportSubtitles('srt');
+              }}
+            >
+              Captions, SubRip (.srt)
+            </DropdownMenuItem>
+            <DropdownMenuItem
+              disabled={transcriptCount === 0}
+              onClick={() => {
+                Analytics.trackButtonClick('export_subtitles_vtt', 'meeting_details');
+                onExportSubtitles('vtt');
+              }}
+            >
+              Captions, WebVTT (.vtt)
+            </DropdownMenuItem>
+            <DropdownMenuSeparator />
+            <DropdownMenuItem
+              onClick={() => {
+                Analytics.trackButtonClick('export_meeting_archive', 'meeting_details');
+                onExportArchive();
+              }}
+            >
+              Meeting archive (.zip)
+            </DropdownMenuItem>
+          </DropdownMenuContent>
+        </DropdownMenu>
+
+        <Button
+          variant="outline"
+          size="sm"
+          onClick={() => {
+            Analytics.trackButtonClick('open_retranscribe', 'meeting_details');
+            setIsRetranscribeOpen(true);
+          }}
+          disabled={isRecording || folderMissing}
+          title={
+            isRecording ? 'Not available while recording'
+              : folderMissing ? 'Recording folder not found'
+              : 'Re-transcribe'
+          }
+        >
+          <RefreshCw />
+          <span className="hidden lg:inline">Re-transcribe</span>
+        </Button>
       </ButtonGroup>
+
+      <RetranscribeDialog
+        meetingId={meetingId}
+        open={isRetranscribeOpen}
+        onOpenChange={setIsRetranscribeOpen}
+        onTranscriptChanged={onTranscriptChanged}
+      />
     </div>
   );
 }
@@ -10,7 +10,12 @@ interface TranscriptPanelProps {
   onPromptChange: (value: string) => void;
   onCopyTranscript: () => void;
   onOpenMeetingFolder: () => Promise<void>;
+  onExportSubtitles: (format: 'srt' | 'vtt') => Promise<void>;
+  onExportArchive: () => Promise<void>;
+  meetingId: string;
+  onTranscriptChanged: () => Promise<void>;
   isRecording: boolean;
+  folderMissing: boolean;
 }
 
 export function TranscriptPanel({
@@ -19,7 +24,12 @@ export function TranscriptPanel({
   onPromptChange,
   onCopyTranscript,
   onOpenMeetingFolder,
-  isRecording
+  onExportSubtitles,
+  onExportArchive,
+  meetingId,
+  onTranscriptChanged,
+  isRecording,
+  folderMissing
 }: TranscriptPanelProps) {
   return (
     <div className="hidden md:flex md:w-1/4 lg:w-1/3 min-w-0 border-r border-gray-200 bg-white flex-col relative shrink-0">
@@ -29,7 +39,23 @@ export function TranscriptPanel({
           transcriptCount={transcripts?.length || 0}
           onCopyTranscript={onCopyTranscript}
           onOpenMeetingFolder={onOpenMeetingFolder}
+          onExportSubtitles={onExportSubtitles}
+          onExportArchive={onExportArchive}
+          meetingId={meetingId}
+          onTranscriptChanged={onTranscriptChanged}
+          isRecording={isRecording}
+          folderMissing={folderMissing}
         />
+        {folderMissing && (
+          <div className="mt-3 flex justify-center">
+            <span
+              className="px-2 py-0.5 text-xs font-medium rounded bg-amber-100 text-amber-700"
+              title="A library rescan did not find this meeting's recording folder on disk"
+            >
+              Recording folder missing
+            </span>
+          </div>
+        )}
       </div>
 
       {/* Transcript content */}
@@ -0,0 +1,133 @@
+import { useEffect, useState } from 'react';
+import { toast } from 'sonner';
+import { useTranslation } from 'react-i18next';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from './ui/select';
+import { Input } from './ui/input';
+import { Label } from './ui/label';
+import { Textarea } from './ui/textarea';
+import { ParakeetAPI, ParakeetDecodingConfig } from '../lib/parakeet';
+import Analytics from '@/lib/analytics';
+
+const BEAM_WIDTHS = [1, 2, 4, 8];
+
+const parseHotwords = (text: string) =>
+  text.split('\n').map(line => line.trim()).filter(line => line.length > 0);
+
+export function ParakeetDecodingSettings() {
+  const { t } = useTranslation('common');
+  const [config, setConfig] = useState<ParakeetDecodingConfig | null>(null);
+  const [hotwordsText, setHotwordsText] = useState('');
+  const [boostText, setBoostText] = useState('');
+
+  useEffect(() => {
+    ParakeetAPI.getDecodingConfig()
+      .then(loaded => {
+        setConfig(loaded);
+        setHotwordsText(loaded.hotwords.join('\n'));
+        setBoostText(String(loaded.hotword_boost));
+      })
+      .catch(error => console.error('Failed to load decoding settings:', error));
+  }, []);
+
+  const save = async (changes: Partial<ParakeetDecodingConfig>) => {
+    if (!config) return;
+    try {
+      const saved = await ParakeetAPI.setDecodingConfig({ ...config, ...changes });
+      setConfig(saved);
+      setHotwordsText(saved.hotwords.join('\n'));
+      setBoostText(String(saved.hotword_boost));
+      toast.success(t('settings.transcription.decoding.saved'));
+      await Analytics.track('parakeet_decoding_changed', {
+        beam_width: saved.beam_width.toString(),
+        hotwords: saved.hotwords.length.toString()
+      });
+    } catch (error) {
+      console.error('Failed to save decoding settings:', error);
+      toast.error(t('settings.transcription.decoding.saveFailed'));
+    }
+  };
+
+  if (!config) return null;
+
+  const beamSearch = config.beam_width > 1;
+
+  return (
+    <div className="space-y-4 p-4 border rounded-lg">
+      <div>
+        <div className="font-medium">{t('settings.transcription.decoding.title')}</div>
+        <div className="text-sm text-gray-600">{t('settings.transcription.decoding.description')}</div>
+      </div>
+
+      <div>
+        <Label className="block text-sm font-medium text-gray-700 mb-1">
+          {t('settings.transcription.decoding.beamWidth')}
+        </Label>
+        <Select
+          value={String(config.beam_width)}
+          onValueChange={value => save({ beam_width: Number(value) })}
+        >
+          <SelectTrigger className="focus:ring-1 focus:ring-blue-500 focus:border-blue-500">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {BEAM_WIDTHS.map(width => (
+              <SelectItem key={width} value={String(width)}>
+                {width === 1
+                  ? t('settings.transcription.decoding.greedy')
+                  : t('settings.transcription.decoding.beam', { width })}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </div>
+
+      <div>
+        <Label className="block text-sm font-medium text-gray-700 mb-1">
+          {t('settings.transcription.decoding.hotwords')}
+        </Label>
+        <Textarea
+          value={hotwordsText}
+          onChange={e => setHotwordsText(e.target.value)}
+          onBlur={() => {
+            const hotwords = parseHotwords(hotwordsText);
+            if (hotwords.join('\n') !== config.hotwords.join('\n')) {
+              save({ hotwords });
+            }
+          }}
+          disabled={!beamSearch}
+          placeholder={t('settings.transcription.decoding.hotwordsPlaceholder')}
+          className="min-h-[80px]"
+        />
+        <div className="text-xs text-gray-500 mt-1">
+          {beamSearch
+            ? t('settings.transcription.decoding.hotwordsHint')
+            : t('settings.transcription.decoding.hotwordsNeedBeam')}
+        </div>
+      </div>
+
+      <div>
+        <Label className="block text-sm font-medium text-gray-700 mb-1">
+          {t('settings.transcription.decoding.boost')}
+        </Label>
+        <Input
+          type="number"
+          min={0}
+          max={10}
+          step={0.5}
+          value={boostText}
+          onChange={e => setBoostText(e.target.value)}
+          onBlur={() => {
+            const boost = Number(boostText);
+            if (boostText.trim() !== '' && !Number.isNaN(boost) && boost !== config.hotword_boost) {
+              save({ hotword_boost: boost });
+            } else {
+              setBoostText(String(config.hotword_boost));
+            }
+          }}
+          disabled={!beamSearch}
+          className="w-32"
+        />
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,85 @@
+"use client";
+
+import { useEffect, useRef } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { useRouter } from 'next/navigation';
+import { toast } from 'sonner';
+import { useSidebar } from './Sidebar/SidebarProvider';
+import Analytics from '@/lib/analytics';
+
+interface InterruptedRecording {
+  folder_path: string;
+  meeting_name: string | null;
+  created_at: string;
+  checkpoint_count: number;
+  transcript_segments: number;
+}
+
+/**
+ * Offers to recover recordings that were interrupted by a crash or power loss.
+ * Runs once on startup; renders nothing itself.
+ */
+export function RecordingRecovery() {
+  const router = useRouter();
+  const { refetchMeetings, setCurrentMeeting } = useSidebar();
+  const checked = useRef(false);
+
+  useEffect(() => {
+    if (checked.current) return;
+    checked.current = true;
+
+    const recover = async (recording: InterruptedRecording, title: string) => {
+      const toastId = toast.loading(`Recovering "${title}"...`);
+      try {
+        Analytics.trackButtonClick('recover_recording', 'startup');
+        const meetingId = await invoke<string | null>('api_recover_recording', {
+          folderPath: recording.folder_path,
+        });
+        if (!meetingId) {
+          toast.info('Recovery cancelled', { id: toastId });
+          return;
+        }
+        toast.success('Recording recovered', { id: toastId, description: title });
+        await refetchMeetings();
+        setCurrentMeeting({ id: meetingId, title });
+        router.push(`/meeting-details?id=${meetingId}`);
+      } catch (error) {
+        console.error('Failed to recover recording:', error);
+        toast.error('Failed to recover recording', { id: toastId, description: String(error) });
+      }
+    };
+
+    const dismiss = async (recording: InterruptedRecording) => {
+      try {
+        await invoke('api_dismiss_interrupted_recording', { folderPath: recording.folder_path });
+      } catch (error) {
+        console.error('Failed to dismiss interrupted recording:', error);
+      }
+    };
+
+    invoke<InterruptedRecording[]>('api_find_interrupted_recordings')
+      .then((recordings) => {
+        for (const recording of recordings) {
+          const title = recording.meeting_name || 'Untitled recording';
+          const minutes = Math.round((recording.checkpoint_count * 30) / 60);
+          toast.warning(`"${title}" was interrupted`, {
+            description: `About ${minutes} min of audio and ${recording.transcript_segments} transcript segments can be recovered.`,
+            duration: Infinity,
+            action: {
+              label: 'Recover',
+              onClick: () => recover(recording, title),
+            },
+            cancel: {
+              label: 'Dismiss',
+              onClick: () => dismiss(recording),
+            },
+          });
+        }
+      })
+      .catch((error) => {
+        console.error('Failed to check for interrupted recordings:', error);
+      });
+  }, [refetchMeetings, setCurrentMeeting, router]);
+
+  return null;
+}
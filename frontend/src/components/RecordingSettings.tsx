@@ -1,12 +1,17 @@
 import React, { useState, useEffect } from 'react';
 import { Switch } from '@/components/ui/switch';
-import { FolderOpen } from 'lucide-react';
+import { FolderOpen, FolderSync } from 'lucide-react';
 import { invoke } from '@tauri-apps/api/core';
 import { DeviceSelection, SelectedDevices } from '@/components/DeviceSelection';
 import Analytics from '@/lib/analytics';
 import { toast } from 'sonner';
 import { LanguageSelector } from '@/components/LanguageSelector';
 import { useTranslation } from 'react-i18next';
+import { ParakeetAPI } from '@/lib/parakeet';
+import { LibraryRescanDialog } from '@/components/LibraryRescanDialog';
+
+// Model the refinement pass after recording transcribes with
+const REFINE_MODEL = 'parakeet-tdt-0.6b-v3-fp32';
 
 export interface RecordingPreferences {
   save_folder: string;
@@ -32,6 +37,9 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
   const [loading, setLoading] = useState(true);
   const [saving, setSaving] = useState(false);
   const [showRecordingNotification, setShowRecordingNotification] = useState(true);
+  const [refineTranscript, setRefineTranscript] = useState(false);
+  const [maxSegmentSeconds, setMaxSegmentSeconds] = useState(20);
+  const [showRescanDialog, setShowRescanDialog] = useState(false);
 
   // Load recording preferences on component mount
   useEffect(() => {
@@ -64,6 +72,11 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
         const store = await Store.load('preferences.json');
         const show = await store.get<boolean>('show_recording_notification') ?? true;
         setShowRecordingNotification(show);
+        setRefineTranscript(await store.get<boolean>('refine_transcript_after_recording') ?? false);
+        const maxSegmentMs = await store.get<number>('max_segment_ms');
+        if (maxSegmentMs) {
+          setMaxSegmentSeconds(maxSegmentMs / 1000);
+        }
       } catch (error) {
         console.error('Failed to load notification preference:', error);
       }
@@ -124,6 +137,46 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
     }
   };
 
+  const handleRefineToggle = async (enabled: boolean) => {
+    try {
+      setRefineTranscript(enabled);
+      const { Store } = await import('@tauri-apps/plugin-store');
+      const store = await Store.load('preferences.json');
+      await store.set('refine_transcript_after_recording', enabled);
+      await store.save();
+      toast.success('Preference saved');
+      await Analytics.track('refine_transcript_preference_changed', {
+        enabled: enabled.toString()
+      });
+
+      if (enabled) {
+        const models = await ParakeetAPI.getAvailableModels();
+        const refineModel = models.find(m => m.name === REFINE_MODEL);
+        if (refineModel?.status !== 'Available') {
+          toast.warning(t('recording.settings.refineModelMissing'));
+        }
+      }
+    } catch (error) {
+      console.error('Failed to save refinement preference:', error);
+      toast.error('Failed to save preference');
+    }
+  };
+
+  const handleMaxSegmentSave = async () => {
+    const seconds = Math.min(120, Math.max(5, Math.round(maxSegmentSeconds) || 20));
+    setMaxSegmentSeconds(seconds);
+    try {
+      const { Store } = await import('@tauri-apps/plugin-store');
+      const store = await Store.load('preferences.json');
+      await store.set('max_segment_ms', seconds * 1000);
+      await store.save();
+      toast.success('Preference saved');
+    } catch (error) {
+      console.error('Failed to save maximum segment length:', error);
+      toast.error('Failed to save preference');
+    }
+  };
+
   const savePreferences = async (prefs: RecordingPreferences) => {
     setSaving(true);
     try {
@@ -192,13 +245,22 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
             <div className="text-sm text-gray-600 mb-3 break-all">
               {preferences.save_folder || t('settings.recordingFolder.description')}
             </div>
-            <button
-              onClick={handleOpenFolder}
-              className="flex items-center gap-2 px-3 py-2 text-sm border border-gray-300 rounded-md hover:bg-gray-50 transition-colors"
-            >
-              <FolderOpen className="w-4 h-4" />
-              {t('recording.settings.openFolder')}
-            </button>
+            <div className="flex gap-2">
+              <button
+                onClick={handleOpenFolder}
+                className="flex items-center gap-2 px-3 py-2 text-sm border border-gray-300 rounded-md hover:bg-gray-50 transition-colors"
+              >
+                <FolderOpen className="w-4 h-4" />
+                {t('recording.settings.openFolder')}
+              </button>
+              <button
+                onClick={() => setShowRescanDialog(true)}
+                className="flex items-center gap-2 px-3 py-2 text-sm border border-gray-300 rounded-md hover:bg-gray-50 transition-colors"
+              >
+                <FolderSync className="w-4 h-4" />
+                {t('recording.settings.rescanLibrary')}
+              </button>
+            </div>
           </div>
 
           <div className="p-4 border rounded-lg bg-blue-50">
@@ -212,6 +274,8 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
         </div>
       )}
 
+      <LibraryRescanDialog open={showRescanDialog} onOpenChange={setShowRescanDialog} />
+
       {/* Info when auto_save is disabled */}
       {!preferences.auto_save && (
         <div className="p-4 border rounded-lg bg-yellow-50">
@@ -235,6 +299,40 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
         />
       </div>
 
+      {/* Refinement Pass Toggle */}
+      <div className="flex items-center justify-between p-4 border rounded-lg">
+        <div className="flex-1">
+          <div className="font-medium">{t('recording.settings.refineTitle')}</div>
+          <div className="text-sm text-gray-600">
+            {t('recording.settings.refineDescription')}
+          </div>
+        </div>
+        <Switch
+          checked={refineTranscript}
+          onCheckedChange={handleRefineToggle}
+        />
+      </div>
+
+      {/* Maximum Segment Length */}
+      <div className="flex items-center justify-between p-4 border rounded-lg">
+        <div className="flex-1">
+          <div className="font-medium">{t('recording.settings.maxSegmentTitle')}</div>
+          <div className="text-sm text-gray-600">
+            {t('recording.settings.maxSegmentDescription')}
+          </div>
+        </div>
+        <input
+          type="number"
+          min={5}
+          max={120}
+          step={5}
+          className="w-20 border border-gray-200 rounded px-2 py-1"
+          value={maxSegmentSeconds}
+          onChange={e => setMaxSegmentSeconds(Number(e.target.value))}
+          onBlur={handleMaxSegmentSave}
+        />
+      </div>
+
       {/* Device Preferences */}
       <div className="space-y-4">
         <div className="border-t pt-6">
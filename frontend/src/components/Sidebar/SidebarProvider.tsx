@@ -11,11 +11,14 @@ interface SidebarItem {
   title: string;
   type: 'folder' | 'file';
   children?: SidebarItem[];
+  status?: string;
 }
 
 export interface CurrentMeeting {
   id: string;
   title: string;
+  /** 'folder_missing' when a library rescan did not find the recording folder */
+  status?: string;
 }
 
 // Search result type for transcript search
@@ -24,6 +27,11 @@ interface TranscriptSearchResult {
   title: string;
   matchContext: string;
   timestamp: string;
+  highlightedContext?: string; // Snippet with <mark>...</mark> around matched terms
+  sourceType?: 'transcript' | 'summary' | 'chat';
+  sourceId?: string;
+  audioStartTime?: number; // Seconds from recording start (transcript hits)
+  rank?: number;
 };
 
 interface SidebarContextType {
@@ -86,10 +94,11 @@ export function SidebarProvider({ children }: { children: React.ReactNode }) {
   const fetchMeetings = React.useCallback(async () => {
     if (serverAddress) {
       try {
-        const meetings = await invoke('api_get_meetings') as Array<{id: string, title: string}>;
+        const meetings = await invoke('api_get_meetings') as Array<{id: string, title: string, status: string}>;
         const transformedMeetings = meetings.map((meeting: any) => ({
           id: meeting.id,
-          title: meeting.title
+          title: meeting.title,
+          status: meeting.status
         }));
         setMeetings(transformedMeetings);
         Analytics.trackBackendConnection(true);
@@ -122,7 +131,7 @@ export function SidebarProvider({ children }: { children: React.ReactNode }) {
       title: 'Meeting Notes',
       type: 'folder' as const,
       children: [
-        ...meetings.map(meeting => ({ id: meeting.id, title: meeting.title, type: 'file' as const }))
+        ...meetings.map(meeting => ({ id: meeting.id, title: meeting.title, type: 'file' as const, status: meeting.status }))
       ]
     },
   ];
@@ -224,8 +233,8 @@ export function SidebarProvider({ children }: { children: React.ReactNode }) {
         // Call the update callback with result
         onUpdate(result);
 
-        // Stop polling if completed, error, failed, or idle (after initial processing)
-        if (result.status === 'completed' || result.status === 'error' || result.status === 'failed') {
+        // Stop polling if completed, error, failed, cancelled, or idle (after initial processing)
+        if (result.status === 'completed' || result.status === 'error' || result.status === 'failed' || result.status === 'cancelled') {
           console.log(`✅ Polling completed for ${meetingId}, status: ${result.status}`);
           clearInterval(pollInterval);
           setActiveSummaryPolls(prev => {
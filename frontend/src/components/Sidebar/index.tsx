@@ -1,7 +1,7 @@
 'use client';
 
 import React, { useState, useMemo, useEffect, useCallback } from 'react';
-import { ChevronDown, ChevronRight, File, Settings, ChevronLeftCircle, ChevronRightCircle, Calendar, StickyNote, Home, Trash2, Mic, Square, Plus, Search, Pencil } from 'lucide-react';
+import { ChevronDown, ChevronRight, File, Settings, ChevronLeftCircle, ChevronRightCircle, Calendar, StickyNote, Home, Trash2, Mic, Square, Plus, Search, Pencil, MessageSquare } from 'lucide-react';
 import { useRouter, usePathname } from 'next/navigation';
 import { useSidebar } from './SidebarProvider';
 import type { CurrentMeeting } from '@/components/Sidebar/SidebarProvider';
@@ -26,14 +26,30 @@ import { MessageToast } from '../MessageToast';
 import Logo from '../Logo';
 import Info from '../Info';
 import { ComplianceNotification } from '../ComplianceNotification';
+import { ImportAudioButton } from '../ImportAudioButton';
+import { ImportTranscriptButton } from '../ImportTranscriptButton';
+import { ImportMeetingArchiveButton } from '../ImportMeetingArchiveButton';
 
 interface SidebarItem {
   id: string;
   title: string;
   type: 'folder' | 'file';
   children?: SidebarItem[];
+  status?: string;
 }
 
+// Render a search snippet, highlighting the <mark>...</mark> ranges returned by full-text search
+const renderHighlightedSnippet = (snippet: string) =>
+  snippet.split(/(<mark>.*?<\/mark>)/g).map((part, index) =>
+    part.startsWith('<mark>') ? (
+      <mark key={index} className="bg-yellow-200 text-gray-800 rounded-sm">
+        {part.slice('<mark>'.length, -'</mark>'.length)}
+      </mark>
+    ) : (
+      <React.Fragment key={index}>{part}</React.Fragment>
+    )
+  );
+
 const Sidebar: React.FC = () => {
   const router = useRouter();
   const pathname = usePathname();
@@ -464,6 +480,22 @@ const Sidebar: React.FC = () => {
             </TooltipContent>
           </Tooltip>
 
+          <Tooltip>
+            <TooltipTrigger asChild>
+              <button
+                onClick={() => router.push('/chat')}
+                className={`p-2 rounded-lg transition-colors duration-150 ${
+                  pathname === '/chat' ? 'bg-gray-100' : 'hover:bg-gray-100'
+                }`}
+              >
+                <MessageSquare className="w-5 h-5 text-gray-600" />
+              </button>
+            </TooltipTrigger>
+            <TooltipContent side="right">
+              <p>Ask all meetings</p>
+            </TooltipContent>
+          </Tooltip>
+
           <Tooltip>
             <TooltipTrigger asChild>
               <button
@@ -483,6 +515,10 @@ const Sidebar: React.FC = () => {
             </TooltipContent>
           </Tooltip>
 
+          <ImportAudioButton isCollapsed={isCollapsed} />
+          <ImportTranscriptButton isCollapsed={isCollapsed} />
+          <ImportMeetingArchiveButton isCollapsed={isCollapsed} />
+
           <Tooltip>
             <TooltipTrigger asChild>
               <button
@@ -597,6 +633,14 @@ const Sidebar: React.FC = () => {
                   </div>
                 )}
                 <span className="flex-1 break-words">{item.title}</span>
+                {item.status === 'folder_missing' && (
+                  <span
+                    className="flex-shrink-0 ml-1 px-1.5 py-0.5 text-[10px] font-medium rounded bg-amber-100 text-amber-700"
+                    title="The recording folder was not found on disk"
+                  >
+                    Folder missing
+                  </span>
+                )}
                 {isMeetingItem && (
                   <div className="flex items-center gap-1 opacity-0 group-hover:opacity-100 transition-opacity duration-150">
                     <button
@@ -626,7 +670,9 @@ const Sidebar: React.FC = () => {
               {/* Show transcript match snippet if available */}
               {hasTranscriptMatch && (
                 <div className="mt-1 ml-8 text-xs text-gray-500 bg-yellow-50 p-1.5 rounded border border-yellow-100 line-clamp-2">
-                  <span className="font-medium text-yellow-600">Match:</span> {matchingResult.matchContext}
+                  <span className="font-medium text-yellow-600">Match:</span> {matchingResult.highlightedContext
+                    ? renderHighlightedSnippet(matchingResult.highlightedContext)
+                    : matchingResult.matchContext}
                 </div>
               )}
             </div>
@@ -715,6 +761,17 @@ const Sidebar: React.FC = () => {
                 <span>Home</span>
               </div>
             )}
+            {!isCollapsed && (
+              <div
+                onClick={() => router.push('/chat')}
+                className={`p-3 text-lg font-semibold items-center h-10 flex mx-3 mt-1 rounded-lg cursor-pointer ${
+                  pathname === '/chat' ? 'bg-gray-100' : 'hover:bg-gray-100'
+                }`}
+              >
+                <MessageSquare className="w-4 h-4 mr-2" />
+                <span>Ask all meetings</span>
+              </div>
+            )}
           </div>
           
           {/* Content area */}
@@ -775,6 +832,10 @@ const Sidebar: React.FC = () => {
                   </>
                 )}
               </button>
+
+              <ImportAudioButton isCollapsed={isCollapsed} />
+              <ImportTranscriptButton isCollapsed={isCollapsed} />
+              <ImportMeetingArchiveButton isCollapsed={isCollapsed} />
         
               <button
                 onClick={() => router.push('/settings')}
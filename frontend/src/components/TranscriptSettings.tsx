@@ -8,6 +8,7 @@ import { Eye, EyeOff, Lock, Unlock } from 'lucide-react';
 // NOTE: WhisperModelManager was removed - using ParakeetModelManager only
 // import { ModelManager } from './WhisperModelManager';
 import { ParakeetModelManager } from './ParakeetModelManager';
+import { ParakeetDecodingSettings } from './ParakeetDecodingSettings';
 import { useTranslation } from 'react-i18next';
 
 
@@ -164,6 +165,9 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
                                 onModelSelect={handleParakeetModelSelect}
                                 autoSave={true}
                             />
+                            <div className="mt-4">
+                                <ParakeetDecodingSettings />
+                            </div>
                         </div>
                     )}
 
@@ -1,7 +1,7 @@
 'use client';
 
-import { Transcript } from '@/types';
-import { useEffect, useRef, useState } from 'react';
+import { Transcript, WordTimestamp } from '@/types';
+import { ReactNode, useEffect, useRef, useState } from 'react';
 import { ConfidenceIndicator } from './ConfidenceIndicator';
 import { Tooltip, TooltipContent, TooltipTrigger } from './ui/tooltip';
 import { RecordingStatusBar } from './RecordingStatusBar';
@@ -31,6 +31,42 @@ function formatRecordingTime(seconds: number | undefined): string {
   return `[${minutes.toString().padStart(2, '0')}:${secs.toString().padStart(2, '0')}]`;
 }
 
+// Words below this confidence are highlighted (same threshold as the backend)
+const LOW_CONFIDENCE_THRESHOLD = 0.5;
+
+const normalizeWord = (word: string) => word.toLowerCase().replace(/[^\w\u00C0-\u024F]/g, '');
+
+// Helper function to highlight low-confidence words in the displayed text.
+// The display text has filler words removed, so each display word is matched
+// to the next word timing with the same spelling.
+function highlightLowConfidence(text: string, words: WordTimestamp[] | undefined): ReactNode {
+  if (!words?.some(word => word.confidence !== undefined && word.confidence < LOW_CONFIDENCE_THRESHOLD)) {
+    return text;
+  }
+
+  let cursor = 0;
+  return text.split(/(\s+)/).map((part, index) => {
+    const normalized = normalizeWord(part);
+    if (!normalized) return part;
+
+    const match = words.findIndex((word, i) => i >= cursor && normalizeWord(word.text) === normalized);
+    if (match === -1) return part;
+    cursor = match + 1;
+
+    const confidence = words[match].confidence;
+    if (confidence === undefined || confidence >= LOW_CONFIDENCE_THRESHOLD) return part;
+    return (
+      <span
+        key={index}
+        className="underline decoration-dotted decoration-orange-500 bg-orange-50 rounded-sm"
+        title={`${(confidence * 100).toFixed(0)}% confidence`}
+      >
+        {part}
+      </span>
+    );
+  });
+}
+
 // Helper function to remove consecutive word repetitions (especially short words ≤2 letters)
 function cleanRepetitions(text: string): string {
   if (!text || text.trim().length === 0) return text;
@@ -186,6 +222,7 @@ export const TranscriptView: React.FC<TranscriptViewProps> = ({ transcripts, isR
 
     // Find the latest non-partial transcript
     const latestTranscript = transcripts
+      .filter(t => !t.is_partial)
       .slice(-1)[0];
 
     if (!latestTranscript) return;
@@ -276,6 +313,7 @@ export const TranscriptView: React.FC<TranscriptViewProps> = ({ transcripts, isR
         return (
           <motion.div
             key={transcript.id ? `${transcript.id}-${index}` : `transcript-${index}`}
+            id={transcript.id ? `transcript-${transcript.id}` : undefined}
             initial={{ opacity: 0, y: 5 }}
             animate={{ opacity: 1, y: 0 }}
             transition={{ duration: 0.15 }}
@@ -318,13 +356,14 @@ export const TranscriptView: React.FC<TranscriptViewProps> = ({ transcripts, isR
                     </div>
                   </div>
                 ) : (
-                  // Regular transcript - simple text
+                  // Regular transcript - simple text, low-confidence words highlighted.
+                  // Partial results (speech still in progress) are dimmed.
                   <div className="relative">
                     <p className="text-base text-gray-800 leading-relaxed" style={{ visibility: 'hidden' }}>
                       {sizerText}
                     </p>
-                    <p className="text-base text-gray-800 leading-relaxed absolute top-0 left-0">
-                      {displayText}
+                    <p className={`text-base leading-relaxed absolute top-0 left-0 ${transcript.is_partial ? 'text-gray-500 italic' : 'text-gray-800'}`}>
+                      {showConfidence ? highlightLowConfidence(displayText, transcript.words) : displayText}
                     </p>
                   </div>
                 )}
@@ -12,8 +12,9 @@ interface UseMeetingDataProps {
 }
 
 export function useMeetingData({ meeting, summaryData, onMeetingUpdated }: UseMeetingDataProps) {
+  // Follows the meeting, whose transcript changes when it is re-transcribed
+  const transcripts: Transcript[] = meeting.transcripts;
   // State
-  const [transcripts] = useState<Transcript[]>(meeting.transcripts);
   const [meetingTitle, setMeetingTitle] = useState(meeting.title || '+ New Call');
   const [isEditingTitle, setIsEditingTitle] = useState(false);
   const [isTitleDirty, setIsTitleDirty] = useState(false);
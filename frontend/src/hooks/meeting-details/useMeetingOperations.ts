@@ -20,7 +20,46 @@ export function useMeetingOperations({
     }
   }, [meeting.id]);
 
+  // Write SRT/WebVTT captions next to the recording in the meeting folder
+  const handleExportSubtitles = useCallback(async (format: 'srt' | 'vtt') => {
+    try {
+      const path = await invokeTauri<string>('api_export_subtitles', {
+        meetingId: meeting.id,
+        format,
+      });
+      toast.success('Captions exported', { description: path });
+    } catch (error) {
+      console.error('Failed to export captions:', error);
+      toast.error('Failed to export captions', { description: String(error) });
+    }
+  }, [meeting.id]);
+
+  // Save the meeting with its audio, summaries and chat as one zip, to move it to another library
+  const handleExportArchive = useCallback(async () => {
+    try {
+      const destination = await invokeTauri<string | null>('select_meeting_archive_destination', {
+        title: meeting.title || 'Meeting',
+      });
+      if (!destination) return;
+      const toastId = toast.loading('Exporting meeting...');
+      try {
+        await invokeTauri('api_export_meeting_archive', {
+          meetingId: meeting.id,
+          destination,
+        });
+        toast.success('Meeting exported', { id: toastId, description: destination });
+      } catch (error) {
+        console.error('Failed to export meeting archive:', error);
+        toast.error('Failed to export meeting', { id: toastId, description: String(error) });
+      }
+    } catch (error) {
+      console.error('Failed to choose archive destination:', error);
+    }
+  }, [meeting.id, meeting.title]);
+
   return {
     handleOpenMeetingFolder,
+    handleExportSubtitles,
+    handleExportArchive,
   };
 }
@@ -1,19 +1,27 @@
-import { useState, useCallback } from 'react';
+import { useState, useCallback, useEffect } from 'react';
 import { Transcript, Summary } from '@/types';
 import { ModelConfig } from '@/components/ModelSettingsModal';
 import { CurrentMeeting, useSidebar } from '@/components/Sidebar/SidebarProvider';
 import { invoke as invokeTauri } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { toast } from 'sonner';
 import Analytics from '@/lib/analytics';
 
 type SummaryStatus = 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error';
 
+interface SummaryStreamEvent {
+  meeting_id: string;
+  status: 'token' | 'completed' | 'cancelled' | 'failed';
+  delta?: string;
+}
+
 interface UseSummaryGenerationProps {
   meeting: any;
   transcripts: Transcript[];
   modelConfig: ModelConfig;
   isModelConfigLoading: boolean;
   selectedTemplate: string;
+  structuredOutput: boolean;
   onMeetingUpdated?: () => Promise<void>;
   updateMeetingTitle: (title: string) => void;
   setAiSummary: (summary: Summary | null) => void;
@@ -25,6 +33,7 @@ export function useSummaryGeneration({
   modelConfig,
   isModelConfigLoading,
   selectedTemplate,
+  structuredOutput,
   onMeetingUpdated,
   updateMeetingTitle,
   setAiSummary,
@@ -32,9 +41,25 @@ export function useSummaryGeneration({
   const [summaryStatus, setSummaryStatus] = useState<SummaryStatus>('idle');
   const [summaryError, setSummaryError] = useState<string | null>(null);
   const [originalTranscript, setOriginalTranscript] = useState<string>('');
+  // Final report text as the LLM writes it; cleared when generation ends
+  const [streamingSummary, setStreamingSummary] = useState('');
 
   const { startSummaryPolling } = useSidebar();
 
+  useEffect(() => {
+    const unlisten = listen<SummaryStreamEvent>('summary-stream', (event) => {
+      if (event.payload.meeting_id !== meeting.id) return;
+      if (event.payload.status === 'token') {
+        setStreamingSummary(prev => prev + (event.payload.delta || ''));
+      } else {
+        setStreamingSummary('');
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [meeting.id]);
+
   // Helper to get status message
   const getSummaryStatusMessage = useCallback((status: SummaryStatus) => {
     switch (status) {
@@ -65,6 +90,7 @@ export function useSummaryGeneration({
   }) => {
     setSummaryStatus(isRegeneration ? 'regenerating' : 'processing');
     setSummaryError(null);
+    setStreamingSummary('');
 
     try {
       if (!transcriptText.trim()) {
@@ -103,6 +129,7 @@ export function useSummaryGeneration({
         overlap: 1000,
         customPrompt: customPrompt,
         templateId: selectedTemplate,
+        structuredOutput,
       }) as any;
 
       const process_id = result.process_id;
@@ -135,6 +162,13 @@ export function useSummaryGeneration({
           return;
         }
 
+        // Handle cancellation by the user
+        if (pollingResult.status === 'cancelled') {
+          setSummaryStatus('idle');
+          toast.info(`Summary ${isRegeneration ? 'regeneration' : 'generation'} cancelled`);
+          return;
+        }
+
         // Handle successful completion
         if (pollingResult.status === 'completed' && pollingResult.data) {
           console.log('✅ Summary generation completed:', pollingResult.data);
@@ -256,6 +290,7 @@ export function useSummaryGeneration({
     meeting.created_at,
     modelConfig,
     selectedTemplate,
+    structuredOutput,
     startSummaryPolling,
     setAiSummary,
     updateMeetingTitle,
@@ -311,6 +346,15 @@ export function useSummaryGeneration({
     await processSummary({ transcriptText: fullTranscript, customPrompt });
   }, [transcripts, processSummary, modelConfig, isModelConfigLoading, selectedTemplate]);
 
+  // Public API: Stop a running generation
+  const handleCancelSummary = useCallback(async () => {
+    try {
+      await invokeTauri('api_cancel_summary', { meetingId: meeting.id });
+    } catch (error) {
+      console.error('Failed to cancel summary generation:', error);
+    }
+  }, [meeting.id]);
+
   // Public API: Regenerate summary from original transcript
   const handleRegenerateSummary = useCallback(async () => {
     if (!originalTranscript.trim()) {
@@ -327,8 +371,10 @@ export function useSummaryGeneration({
   return {
     summaryStatus,
     summaryError,
+    streamingSummary,
     handleGenerateSummary,
     handleRegenerateSummary,
+    handleCancelSummary,
     getSummaryStatusMessage,
   };
 }
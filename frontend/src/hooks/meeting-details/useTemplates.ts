@@ -10,6 +10,8 @@ export function useTemplates() {
     description: string;
   }>>([]);
   const [selectedTemplate, setSelectedTemplate] = useState<string>('standard_meeting');
+  // Ask the LLM for JSON keyed by the template's sections (validated and rendered to markdown)
+  const [structuredOutput, setStructuredOutput] = useState<boolean>(false);
 
   // Fetch available templates on mount
   useEffect(() => {
@@ -38,9 +40,17 @@ export function useTemplates() {
     Analytics.trackFeatureUsed('template_selected');
   }, []);
 
+  // Handle structured output toggle
+  const handleStructuredOutputToggle = useCallback(() => {
+    setStructuredOutput(prev => !prev);
+    Analytics.trackFeatureUsed('structured_output_toggled');
+  }, []);
+
   return {
     availableTemplates,
     selectedTemplate,
     handleTemplateSelection,
+    structuredOutput,
+    handleStructuredOutputToggle,
   };
 }
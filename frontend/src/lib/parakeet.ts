@@ -21,6 +21,13 @@ export type ModelStatus =
   | { Error: string }
   | { Corrupted: { file_size: number; expected_min_size: number } };
 
+// Greedy (beam_width 1) or beam search decoding, with hotwords beam search favours
+export interface ParakeetDecodingConfig {
+  beam_width: number;
+  hotword_boost: number;
+  hotwords: string[];
+}
+
 export interface ParakeetEngineState {
   currentModel: string | null;
   availableModels: ParakeetModelInfo[];
@@ -200,6 +207,14 @@ export class ParakeetAPI {
     return await invoke('parakeet_validate_model_ready');
   }
 
+  static async getDecodingConfig(): Promise<ParakeetDecodingConfig> {
+    return await invoke('parakeet_get_decoding_config');
+  }
+
+  static async setDecodingConfig(config: ParakeetDecodingConfig): Promise<ParakeetDecodingConfig> {
+    return await invoke('parakeet_set_decoding_config', { config });
+  }
+
   static async openModelsFolder(): Promise<void> {
     await invoke('open_parakeet_models_folder');
   }
@@ -4,6 +4,14 @@ export interface Message {
   timestamp: string;
 }
 
+// Word-level timing, seconds from recording start
+export interface WordTimestamp {
+  text: string;
+  start: number;
+  end: number;
+  confidence?: number;       // 0.0 - 1.0, if the transcription model scores words
+}
+
 export interface Transcript {
   id: string;
   text: string;
@@ -16,12 +24,16 @@ export interface Transcript {
   audio_start_time?: number; // Seconds from recording start (e.g., 125.3)
   audio_end_time?: number;   // Seconds from recording start (e.g., 128.6)
   duration?: number;          // Segment duration in seconds (e.g., 3.3)
+  words?: WordTimestamp[];    // Word-level timing for playback highlighting
+  speaker?: string;           // Diarization speaker ID (e.g., "speaker_1")
+  speaker_name?: string;      // Display name (user rename or "Speaker N")
+  source?: string;            // "local" | "remote" | "overlap" | "unknown"
 }
 
 export interface TranscriptUpdate {
   text: string;
   timestamp: string; // Wall-clock time for reference
-  source: string;
+  source: string;    // "local" | "remote" | "overlap" | "unknown" (mic vs. system attribution)
   sequence_id: number;
   chunk_start_time: number; // Legacy field
   is_partial: boolean;
@@ -30,6 +42,8 @@ export interface TranscriptUpdate {
   audio_start_time: number; // Seconds from recording start
   audio_end_time: number;   // Seconds from recording start
   duration: number;          // Segment duration in seconds
+  words?: WordTimestamp[];   // Word-level timing (empty if unsupported)
+  speaker?: string | null;   // Diarization speaker ID, null if unavailable
 }
 
 export interface Block {
@@ -84,3 +98,9 @@ export interface SummaryDataResponse {
   _section_order?: string[];
   [key: string]: any; // For legacy section data
 }
+
+export interface MeetingSpeaker {
+  speaker_id: string;
+  display_name: string | null;
+  segment_count: number;
+}